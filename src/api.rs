@@ -1,3 +1,36 @@
 pub mod api_management;
 pub mod apm_retention_filters;
 pub mod audit;
+pub mod downtimes;
+pub mod events;
+pub mod fastly_integration;
+pub mod gcp_integration;
+pub mod generated_metrics;
+pub mod hosts;
+pub mod incidents;
+pub mod key_management;
+pub mod logs;
+pub mod logs_archives;
+pub mod logs_custom_destinations;
+pub mod logs_indexes;
+pub mod logs_metrics;
+pub mod logs_pipelines;
+pub mod metrics;
+pub mod monitors;
+pub mod notebooks;
+pub mod okta_integration;
+pub mod opsgenie_integration;
+pub mod roles;
+pub mod rum;
+pub mod security_monitoring;
+pub mod sensitive_data_scanner;
+pub mod service_definitions;
+pub mod slo_corrections;
+pub mod slos;
+pub mod spans;
+pub mod spans_metrics;
+pub mod synthetics;
+pub mod tags;
+pub mod teams;
+pub mod usage_metering;
+pub mod users;
@@ -0,0 +1,106 @@
+use reqwest::Method;
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::downtimes::{
+    CancelDowntimeRequest, CancelDowntimeResponse, CreateDowntimeRequest, DowntimeResponse,
+    GetDowntimeRequest, ListDowntimesRequest, ListDowntimesResponse, ListMonitorDowntimesRequest,
+    ListMonitorDowntimesResponse, UpdateDowntimeRequest,
+};
+
+/// Base path of the downtimes endpoints.
+static BASE_PATH: &str = "api/v2/downtime";
+/// Base path of the monitors, whose active downtimes can be listed.
+static MONITOR_BASE_PATH: &str = "api/v2/monitor";
+
+impl Client {
+    /// Get all scheduled downtimes.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/downtimes/#get-all-downtimes)
+    pub async fn list_downtimes(
+        &self,
+        request: ListDowntimesRequest,
+    ) -> Result<ListDowntimesResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(Method::GET, &format!("{}?{}", BASE_PATH, query))?;
+
+        self.send_request::<ListDowntimesResponse>(req).await
+    }
+
+    /// Schedule a downtime.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/downtimes/#schedule-a-downtime)
+    pub async fn create_downtime(
+        &self,
+        request: CreateDowntimeRequest,
+    ) -> Result<DowntimeResponse, Error> {
+        let req = self.build_request(Method::POST, BASE_PATH)?;
+        let req = req.json(&request);
+
+        self.send_request::<DowntimeResponse>(req).await
+    }
+
+    /// Get downtime detail by `downtime_id`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/downtimes/#get-a-downtime)
+    pub async fn get_downtime(
+        &self,
+        request: GetDowntimeRequest,
+    ) -> Result<DowntimeResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}?{}", BASE_PATH, request.downtime_id, query),
+        )?;
+
+        self.send_request::<DowntimeResponse>(req).await
+    }
+
+    /// Update a downtime by `downtime_id`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/downtimes/#update-a-downtime)
+    pub async fn update_downtime(
+        &self,
+        request: UpdateDowntimeRequest,
+    ) -> Result<DowntimeResponse, Error> {
+        let req =
+            self.build_request(Method::PATCH, &format!("{}/{}", BASE_PATH, request.data.id))?;
+        let req = req.json(&request);
+
+        self.send_request::<DowntimeResponse>(req).await
+    }
+
+    /// Cancel a downtime. Canceled downtimes are kept for about two days before being removed.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/downtimes/#cancel-a-downtime)
+    pub async fn cancel_downtime(
+        &self,
+        request: CancelDowntimeRequest,
+    ) -> Result<CancelDowntimeResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/{}", BASE_PATH, request.downtime_id),
+        )?;
+
+        self.send_request(req).await
+    }
+
+    /// Get all active downtimes for the specified monitor.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/downtimes/#get-active-downtimes-for-a-monitor)
+    pub async fn list_monitor_downtimes(
+        &self,
+        request: ListMonitorDowntimesRequest,
+    ) -> Result<ListMonitorDowntimesResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(
+            Method::GET,
+            &format!(
+                "{}/{}/downtime_matches?{}",
+                MONITOR_BASE_PATH, request.monitor_id, query
+            ),
+        )?;
+
+        self.send_request::<ListMonitorDowntimesResponse>(req).await
+    }
+}
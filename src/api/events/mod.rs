@@ -0,0 +1,59 @@
+use reqwest::Method;
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::events::{
+    CreateEventRequest, CreateEventResponse, ListEventsRequest, ListEventsResponse,
+    SearchEventsRequest,
+};
+
+/// Base path of the v1 events endpoints.
+static V1_BASE_PATH: &str = "api/v1/events";
+/// Base path of the v2 events endpoints.
+static V2_BASE_PATH: &str = "api/v2/events";
+
+impl Client {
+    /// This endpoint allows you to post events to the stream.
+    /// Tag them, set priority and event aggregate them with other events.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/events/#post-an-event)
+    pub async fn create_event(
+        &self,
+        request: CreateEventRequest,
+    ) -> Result<CreateEventResponse, Error> {
+        let req = self.build_request(Method::POST, V1_BASE_PATH)?;
+        let req = req.json(&request);
+
+        self.send_request::<CreateEventResponse>(req).await
+    }
+
+    /// List endpoint returns events that match an events search query.
+    /// [Results are paginated similarly to logs](https://docs.datadoghq.com/logs/guide/collect-multiple-logs-with-pagination).
+    /// Use this endpoint to see your latest events.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/events/#get-a-list-of-events)
+    pub async fn list_events(
+        &self,
+        request: ListEventsRequest,
+    ) -> Result<ListEventsResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(Method::GET, &format!("{}?{}", V2_BASE_PATH, query))?;
+
+        self.send_request::<ListEventsResponse>(req).await
+    }
+
+    /// List endpoint returns events that match an events search query.
+    /// [Results are paginated similarly to logs](https://docs.datadoghq.com/logs/guide/collect-multiple-logs-with-pagination).
+    /// Use this endpoint to build complex events filtering and search.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/events/#search-events)
+    pub async fn search_events(
+        &self,
+        request: SearchEventsRequest,
+    ) -> Result<ListEventsResponse, Error> {
+        let req = self.build_request(Method::POST, &format!("{}/search", V2_BASE_PATH))?;
+        let req = req.json(&request);
+
+        self.send_request::<ListEventsResponse>(req).await
+    }
+}
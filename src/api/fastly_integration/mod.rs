@@ -0,0 +1,169 @@
+use reqwest::Method;
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::fastly_integration::{
+    AddFastlyAccountRequest, DeleteFastlyAccountResponse, DeleteFastlyServiceResponse,
+    FastlyAccountRequest, FastlyAccountResponse, FastlyAccountsResponse, FastlyServiceRequest,
+    FastlyServiceResponse, FastlyServiceWriteRequest, FastlyServicesResponse,
+    UpdateFastlyAccountRequest,
+};
+
+/// Base path of the Fastly integration endpoints.
+static BASE_PATH: &str = "api/v2/integrations/fastly/accounts";
+
+impl Client {
+    /// List Fastly accounts.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/fastly-integration/#list-fastly-accounts)
+    pub async fn list_fastly_accounts(&self) -> Result<FastlyAccountsResponse, Error> {
+        let req = self.build_request(Method::GET, BASE_PATH)?;
+
+        self.send_request::<FastlyAccountsResponse>(req).await
+    }
+
+    /// Add a Fastly account. The API key is sent to Datadog, but never returned.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/fastly-integration/#add-fastly-account)
+    pub async fn add_fastly_account(
+        &self,
+        request: AddFastlyAccountRequest,
+    ) -> Result<FastlyAccountResponse, Error> {
+        let req = self.build_request(Method::POST, BASE_PATH)?;
+        let req = req.json(&request);
+
+        self.send_request::<FastlyAccountResponse>(req).await
+    }
+
+    /// Get a Fastly account.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/fastly-integration/#get-fastly-account)
+    pub async fn get_fastly_account(
+        &self,
+        request: FastlyAccountRequest,
+    ) -> Result<FastlyAccountResponse, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}", BASE_PATH, request.account_id),
+        )?;
+
+        self.send_request::<FastlyAccountResponse>(req).await
+    }
+
+    /// Update a Fastly account.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/fastly-integration/#update-fastly-account)
+    pub async fn update_fastly_account(
+        &self,
+        request: UpdateFastlyAccountRequest,
+    ) -> Result<FastlyAccountResponse, Error> {
+        let req = self.build_request(
+            Method::PATCH,
+            &format!("{}/{}", BASE_PATH, request.account_id),
+        )?;
+        let req = req.json(&request.account);
+
+        self.send_request::<FastlyAccountResponse>(req).await
+    }
+
+    /// Delete a Fastly account.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/fastly-integration/#delete-fastly-account)
+    pub async fn delete_fastly_account(
+        &self,
+        request: FastlyAccountRequest,
+    ) -> Result<DeleteFastlyAccountResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/{}", BASE_PATH, request.account_id),
+        )?;
+
+        self.send_request::<DeleteFastlyAccountResponse>(req).await
+    }
+
+    /// List the Fastly services of an account.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/fastly-integration/#list-fastly-services)
+    pub async fn list_fastly_services(
+        &self,
+        request: FastlyAccountRequest,
+    ) -> Result<FastlyServicesResponse, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}/services", BASE_PATH, request.account_id),
+        )?;
+
+        self.send_request::<FastlyServicesResponse>(req).await
+    }
+
+    /// Add a Fastly service to an account.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/fastly-integration/#add-fastly-service)
+    pub async fn add_fastly_service(
+        &self,
+        request: FastlyServiceWriteRequest,
+    ) -> Result<FastlyServiceResponse, Error> {
+        let req = self.build_request(
+            Method::POST,
+            &format!("{}/{}/services", BASE_PATH, request.account_id),
+        )?;
+        let req = req.json(&request.service);
+
+        self.send_request::<FastlyServiceResponse>(req).await
+    }
+
+    /// Get a Fastly service of an account.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/fastly-integration/#get-fastly-service)
+    pub async fn get_fastly_service(
+        &self,
+        request: FastlyServiceRequest,
+    ) -> Result<FastlyServiceResponse, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!(
+                "{}/{}/services/{}",
+                BASE_PATH, request.account_id, request.service_id
+            ),
+        )?;
+
+        self.send_request::<FastlyServiceResponse>(req).await
+    }
+
+    /// Update a Fastly service of an account.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/fastly-integration/#update-fastly-service)
+    pub async fn update_fastly_service(
+        &self,
+        request: FastlyServiceWriteRequest,
+    ) -> Result<FastlyServiceResponse, Error> {
+        let req = self.build_request(
+            Method::PATCH,
+            &format!(
+                "{}/{}/services/{}",
+                BASE_PATH, request.account_id, request.service.data.id
+            ),
+        )?;
+        let req = req.json(&request.service);
+
+        self.send_request::<FastlyServiceResponse>(req).await
+    }
+
+    /// Delete a Fastly service of an account.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/fastly-integration/#delete-fastly-service)
+    pub async fn delete_fastly_service(
+        &self,
+        request: FastlyServiceRequest,
+    ) -> Result<DeleteFastlyServiceResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!(
+                "{}/{}/services/{}",
+                BASE_PATH, request.account_id, request.service_id
+            ),
+        )?;
+
+        self.send_request::<DeleteFastlyServiceResponse>(req).await
+    }
+}
@@ -0,0 +1,90 @@
+use reqwest::Method;
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::gcp_integration::{
+    CreateGcpStsAccountRequest, DeleteGcpStsAccountRequest, DeleteGcpStsAccountResponse,
+    GcpStsDelegateAccountResponse, GcpStsServiceAccountResponse, GcpStsServiceAccountsResponse,
+    UpdateGcpStsAccountRequest,
+};
+
+/// Base path of the GCP integration endpoints.
+static BASE_PATH: &str = "api/v2/integration/gcp";
+
+impl Client {
+    /// List all the GCP STS enabled service accounts configured in your Datadog account.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/gcp-integration/#list-all-gcp-sts-enabled-service-accounts)
+    pub async fn list_gcp_sts_accounts(&self) -> Result<GcpStsServiceAccountsResponse, Error> {
+        let req = self.build_request(Method::GET, &format!("{}/accounts", BASE_PATH))?;
+
+        self.send_request::<GcpStsServiceAccountsResponse>(req)
+            .await
+    }
+
+    /// Create a new entry within Datadog for your STS enabled service account.
+    /// The Datadog STS delegate must have been granted the token creator role on it first.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/gcp-integration/#create-a-new-entry-for-your-service-account)
+    pub async fn create_gcp_sts_account(
+        &self,
+        request: CreateGcpStsAccountRequest,
+    ) -> Result<GcpStsServiceAccountResponse, Error> {
+        let req = self.build_request(Method::POST, &format!("{}/accounts", BASE_PATH))?;
+        let req = req.json(&request);
+
+        self.send_request::<GcpStsServiceAccountResponse>(req).await
+    }
+
+    /// Update an STS enabled service account.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/gcp-integration/#update-sts-service-account)
+    pub async fn update_gcp_sts_account(
+        &self,
+        request: UpdateGcpStsAccountRequest,
+    ) -> Result<GcpStsServiceAccountResponse, Error> {
+        let req = self.build_request(
+            Method::PATCH,
+            &format!("{}/accounts/{}", BASE_PATH, request.account_id),
+        )?;
+        let req = req.json(&request.account);
+
+        self.send_request::<GcpStsServiceAccountResponse>(req).await
+    }
+
+    /// Delete an STS enabled GCP account from within Datadog.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/gcp-integration/#delete-an-sts-enabled-gcp-account)
+    pub async fn delete_gcp_sts_account(
+        &self,
+        request: DeleteGcpStsAccountRequest,
+    ) -> Result<DeleteGcpStsAccountResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/accounts/{}", BASE_PATH, request.account_id),
+        )?;
+
+        self.send_request::<DeleteGcpStsAccountResponse>(req).await
+    }
+
+    /// Get the Datadog STS delegate of your organization, which must be granted access to the
+    /// service accounts.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/gcp-integration/#list-delegate-account)
+    pub async fn get_gcp_sts_delegate(&self) -> Result<GcpStsDelegateAccountResponse, Error> {
+        let req = self.build_request(Method::GET, &format!("{}/sts_delegate", BASE_PATH))?;
+
+        self.send_request::<GcpStsDelegateAccountResponse>(req)
+            .await
+    }
+
+    /// Create the Datadog STS delegate of your organization, or return it when it already exists.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/gcp-integration/#create-a-datadog-gcp-principal)
+    pub async fn make_gcp_sts_delegate(&self) -> Result<GcpStsDelegateAccountResponse, Error> {
+        let req = self.build_request(Method::POST, &format!("{}/sts_delegate", BASE_PATH))?;
+
+        self.send_request::<GcpStsDelegateAccountResponse>(req)
+            .await
+    }
+}
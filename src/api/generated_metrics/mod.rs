@@ -0,0 +1,90 @@
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::client::{EmptyResponse, Request, Response};
+use crate::models::generated_metrics::{
+    GeneratedMetric, GeneratedMetricCreateData, GeneratedMetricIdRequest,
+    UpdateGeneratedMetricRequest,
+};
+
+/// Endpoints shared by the log-based and span-based metrics, which only differ by their
+/// base path and resource type `T`.
+impl Client {
+    /// List the metrics configured under `base_path`.
+    pub(crate) async fn list_generated_metrics<T>(
+        &self,
+        base_path: &str,
+    ) -> Result<Response<Vec<GeneratedMetric<T>>>, Error>
+    where
+        T: DeserializeOwned,
+    {
+        let req = self.build_request(Method::GET, base_path)?;
+
+        self.send_request(req).await
+    }
+
+    /// Create a metric under `base_path`.
+    pub(crate) async fn create_generated_metric<T>(
+        &self,
+        base_path: &str,
+        request: Request<GeneratedMetricCreateData<T>>,
+    ) -> Result<Response<GeneratedMetric<T>>, Error>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let req = self.build_request(Method::POST, base_path)?;
+        let req = req.json(&request);
+
+        self.send_request(req).await
+    }
+
+    /// Get a metric configured under `base_path`.
+    pub(crate) async fn get_generated_metric<T>(
+        &self,
+        base_path: &str,
+        request: GeneratedMetricIdRequest,
+    ) -> Result<Response<GeneratedMetric<T>>, Error>
+    where
+        T: DeserializeOwned,
+    {
+        let req =
+            self.build_request(Method::GET, &format!("{}/{}", base_path, request.metric_id))?;
+
+        self.send_request(req).await
+    }
+
+    /// Update a metric configured under `base_path`.
+    pub(crate) async fn update_generated_metric<T>(
+        &self,
+        base_path: &str,
+        request: UpdateGeneratedMetricRequest<T>,
+    ) -> Result<Response<GeneratedMetric<T>>, Error>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let req = self.build_request(
+            Method::PATCH,
+            &format!("{}/{}", base_path, request.metric_id),
+        )?;
+        let req = req.json(&request.metric);
+
+        self.send_request(req).await
+    }
+
+    /// Delete a metric configured under `base_path`.
+    pub(crate) async fn delete_generated_metric(
+        &self,
+        base_path: &str,
+        request: GeneratedMetricIdRequest,
+    ) -> Result<EmptyResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/{}", base_path, request.metric_id),
+        )?;
+
+        self.send_request(req).await
+    }
+}
@@ -0,0 +1,68 @@
+use reqwest::Method;
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::hosts::{
+    GetHostTotalsRequest, HostListResponse, HostMuteResponse, HostTotals, ListHostsRequest,
+    MuteHostRequest, UnmuteHostRequest,
+};
+
+/// Base path of the hosts search endpoints.
+static HOSTS_PATH: &str = "api/v1/hosts";
+/// Base path of the single host endpoints.
+static HOST_PATH: &str = "api/v1/host";
+
+impl Client {
+    /// This endpoint allows searching for hosts by name, alias, or tag.
+    /// Hosts live within the past 3 hours are included by default.
+    /// Results are paginated with a max of 1000 results at a time, use `start` and `count`
+    /// to get the next pages.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/hosts/#get-all-hosts)
+    pub async fn list_hosts(&self, request: ListHostsRequest) -> Result<HostListResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(Method::GET, &format!("{}?{}", HOSTS_PATH, query))?;
+
+        self.send_request::<HostListResponse>(req).await
+    }
+
+    /// This endpoint returns the total number of active and up hosts in your Datadog account.
+    /// Active means the host has reported in the past hour, and up means it has reported
+    /// in the past two hours.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/hosts/#get-the-total-number-of-active-hosts)
+    pub async fn get_host_totals(
+        &self,
+        request: GetHostTotalsRequest,
+    ) -> Result<HostTotals, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(Method::GET, &format!("{}/totals?{}", HOSTS_PATH, query))?;
+
+        self.send_request::<HostTotals>(req).await
+    }
+
+    /// Mute a host. Omitting `end` mutes the host until it is explicitly unmuted.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/hosts/#mute-a-host)
+    pub async fn mute_host(&self, request: MuteHostRequest) -> Result<HostMuteResponse, Error> {
+        let req = self.build_request(
+            Method::POST,
+            &format!("{}/{}/mute", HOST_PATH, request.host_name),
+        )?;
+        let req = req.json(&request.settings);
+
+        self.send_request::<HostMuteResponse>(req).await
+    }
+
+    /// Unmutes a host. This endpoint takes no JSON arguments.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/hosts/#unmute-a-host)
+    pub async fn unmute_host(&self, request: UnmuteHostRequest) -> Result<HostMuteResponse, Error> {
+        let req = self.build_request(
+            Method::POST,
+            &format!("{}/{}/unmute", HOST_PATH, request.host_name),
+        )?;
+
+        self.send_request::<HostMuteResponse>(req).await
+    }
+}
@@ -0,0 +1,381 @@
+use reqwest::Method;
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::incidents::{
+    CreateIncidentIntegrationRequest, CreateIncidentRequest, CreateIncidentTodoRequest,
+    DeleteIncidentIntegrationResponse, DeleteIncidentRequest, DeleteIncidentResponse,
+    DeleteIncidentTodoResponse, GetIncidentRequest, IncidentIntegrationMetadataResponse,
+    IncidentIntegrationPathRequest, IncidentResponse, IncidentSearchResponse,
+    IncidentTodoPathRequest, IncidentTodoResponse, ListIncidentAttachmentsRequest,
+    ListIncidentAttachmentsResponse, ListIncidentIntegrationsRequest,
+    ListIncidentIntegrationsResponse, ListIncidentTodosRequest, ListIncidentTodosResponse,
+    ListIncidentsRequest, ListIncidentsResponse, SearchIncidentsRequest,
+    UpdateIncidentAttachmentsRequest, UpdateIncidentAttachmentsResponse,
+    UpdateIncidentIntegrationRequest, UpdateIncidentRequest, UpdateIncidentTodoRequest,
+};
+
+/// Base path of the incidents endpoints.
+static BASE_PATH: &str = "api/v2/incidents";
+
+impl Client {
+    /// Get all incidents for the user's organization.
+    ///
+    /// This operation is unstable and must be enabled with `v2.list_incidents`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/incidents/#get-a-list-of-incidents)
+    pub async fn list_incidents(
+        &self,
+        request: ListIncidentsRequest,
+    ) -> Result<ListIncidentsResponse, Error> {
+        self.check_unstable_operation("v2.list_incidents")?;
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(Method::GET, &format!("{}?{}", BASE_PATH, query))?;
+
+        self.send_request::<ListIncidentsResponse>(req).await
+    }
+
+    /// Create an incident.
+    ///
+    /// This operation is unstable and must be enabled with `v2.create_incident`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/incidents/#create-an-incident)
+    pub async fn create_incident(
+        &self,
+        request: CreateIncidentRequest,
+    ) -> Result<IncidentResponse, Error> {
+        self.check_unstable_operation("v2.create_incident")?;
+        let req = self.build_request(Method::POST, BASE_PATH)?;
+        let req = req.json(&request);
+
+        self.send_request::<IncidentResponse>(req).await
+    }
+
+    /// Get the details of an incident.
+    ///
+    /// This operation is unstable and must be enabled with `v2.get_incident`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/incidents/#get-the-details-of-an-incident)
+    pub async fn get_incident(
+        &self,
+        request: GetIncidentRequest,
+    ) -> Result<IncidentResponse, Error> {
+        self.check_unstable_operation("v2.get_incident")?;
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}?{}", BASE_PATH, request.incident_id, query),
+        )?;
+
+        self.send_request::<IncidentResponse>(req).await
+    }
+
+    /// Update an incident. Only the given attributes are changed.
+    ///
+    /// This operation is unstable and must be enabled with `v2.update_incident`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/incidents/#update-an-existing-incident)
+    pub async fn update_incident(
+        &self,
+        request: UpdateIncidentRequest,
+    ) -> Result<IncidentResponse, Error> {
+        self.check_unstable_operation("v2.update_incident")?;
+        let req = self.build_request(
+            Method::PATCH,
+            &format!("{}/{}", BASE_PATH, request.incident_id),
+        )?;
+        let req = req.json(&request.incident);
+
+        self.send_request::<IncidentResponse>(req).await
+    }
+
+    /// Delete an existing incident from the user's organization.
+    ///
+    /// This operation is unstable and must be enabled with `v2.delete_incident`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/incidents/#delete-an-existing-incident)
+    pub async fn delete_incident(
+        &self,
+        request: DeleteIncidentRequest,
+    ) -> Result<DeleteIncidentResponse, Error> {
+        self.check_unstable_operation("v2.delete_incident")?;
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/{}", BASE_PATH, request.incident_id),
+        )?;
+
+        self.send_request::<DeleteIncidentResponse>(req).await
+    }
+
+    /// Search for incidents matching a certain query.
+    ///
+    /// This operation is unstable and must be enabled with `v2.search_incidents`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/incidents/#search-for-incidents)
+    pub async fn search_incidents(
+        &self,
+        request: SearchIncidentsRequest,
+    ) -> Result<IncidentSearchResponse, Error> {
+        self.check_unstable_operation("v2.search_incidents")?;
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(Method::GET, &format!("{}/search?{}", BASE_PATH, query))?;
+
+        self.send_request::<IncidentSearchResponse>(req).await
+    }
+
+    /// Get all todos for an incident.
+    ///
+    /// This operation is unstable and must be enabled with `v2.list_incident_todos`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/incidents/#get-a-list-of-an-incidents-todos)
+    pub async fn list_incident_todos(
+        &self,
+        request: ListIncidentTodosRequest,
+    ) -> Result<ListIncidentTodosResponse, Error> {
+        self.check_unstable_operation("v2.list_incident_todos")?;
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}/relationships/todos", BASE_PATH, request.incident_id),
+        )?;
+
+        self.send_request::<ListIncidentTodosResponse>(req).await
+    }
+
+    /// Create an incident todo.
+    ///
+    /// This operation is unstable and must be enabled with `v2.create_incident_todo`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/incidents/#create-an-incident-todo)
+    pub async fn create_incident_todo(
+        &self,
+        request: CreateIncidentTodoRequest,
+    ) -> Result<IncidentTodoResponse, Error> {
+        self.check_unstable_operation("v2.create_incident_todo")?;
+        let req = self.build_request(
+            Method::POST,
+            &format!("{}/{}/relationships/todos", BASE_PATH, request.incident_id),
+        )?;
+        let req = req.json(&request.todo);
+
+        self.send_request::<IncidentTodoResponse>(req).await
+    }
+
+    /// Get the details of an incident todo.
+    ///
+    /// This operation is unstable and must be enabled with `v2.get_incident_todo`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/incidents/#get-incident-todo-details)
+    pub async fn get_incident_todo(
+        &self,
+        request: IncidentTodoPathRequest,
+    ) -> Result<IncidentTodoResponse, Error> {
+        self.check_unstable_operation("v2.get_incident_todo")?;
+        let req = self.build_request(
+            Method::GET,
+            &format!(
+                "{}/{}/relationships/todos/{}",
+                BASE_PATH, request.incident_id, request.todo_id
+            ),
+        )?;
+
+        self.send_request::<IncidentTodoResponse>(req).await
+    }
+
+    /// Update an incident todo.
+    ///
+    /// This operation is unstable and must be enabled with `v2.update_incident_todo`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/incidents/#update-an-incident-todo)
+    pub async fn update_incident_todo(
+        &self,
+        request: UpdateIncidentTodoRequest,
+    ) -> Result<IncidentTodoResponse, Error> {
+        self.check_unstable_operation("v2.update_incident_todo")?;
+        let req = self.build_request(
+            Method::PATCH,
+            &format!(
+                "{}/{}/relationships/todos/{}",
+                BASE_PATH, request.incident_id, request.todo_id
+            ),
+        )?;
+        let req = req.json(&request.todo);
+
+        self.send_request::<IncidentTodoResponse>(req).await
+    }
+
+    /// Delete an incident todo.
+    ///
+    /// This operation is unstable and must be enabled with `v2.delete_incident_todo`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/incidents/#delete-an-incident-todo)
+    pub async fn delete_incident_todo(
+        &self,
+        request: IncidentTodoPathRequest,
+    ) -> Result<DeleteIncidentTodoResponse, Error> {
+        self.check_unstable_operation("v2.delete_incident_todo")?;
+        let req = self.build_request(
+            Method::DELETE,
+            &format!(
+                "{}/{}/relationships/todos/{}",
+                BASE_PATH, request.incident_id, request.todo_id
+            ),
+        )?;
+
+        self.send_request::<DeleteIncidentTodoResponse>(req).await
+    }
+
+    /// Get all integration metadata for an incident.
+    ///
+    /// This operation is unstable and must be enabled with `v2.list_incident_integrations`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/incidents/#get-a-list-of-an-incidents-integration-metadata)
+    pub async fn list_incident_integrations(
+        &self,
+        request: ListIncidentIntegrationsRequest,
+    ) -> Result<ListIncidentIntegrationsResponse, Error> {
+        self.check_unstable_operation("v2.list_incident_integrations")?;
+        let req = self.build_request(
+            Method::GET,
+            &format!(
+                "{}/{}/relationships/integrations",
+                BASE_PATH, request.incident_id
+            ),
+        )?;
+
+        self.send_request::<ListIncidentIntegrationsResponse>(req)
+            .await
+    }
+
+    /// Create an incident integration metadata.
+    ///
+    /// This operation is unstable and must be enabled with `v2.create_incident_integration`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/incidents/#create-an-incident-integration-metadata)
+    pub async fn create_incident_integration(
+        &self,
+        request: CreateIncidentIntegrationRequest,
+    ) -> Result<IncidentIntegrationMetadataResponse, Error> {
+        self.check_unstable_operation("v2.create_incident_integration")?;
+        let req = self.build_request(
+            Method::POST,
+            &format!(
+                "{}/{}/relationships/integrations",
+                BASE_PATH, request.incident_id
+            ),
+        )?;
+        let req = req.json(&request.integration);
+
+        self.send_request::<IncidentIntegrationMetadataResponse>(req)
+            .await
+    }
+
+    /// Get incident integration metadata details.
+    ///
+    /// This operation is unstable and must be enabled with `v2.get_incident_integration`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/incidents/#get-incident-integration-metadata-details)
+    pub async fn get_incident_integration(
+        &self,
+        request: IncidentIntegrationPathRequest,
+    ) -> Result<IncidentIntegrationMetadataResponse, Error> {
+        self.check_unstable_operation("v2.get_incident_integration")?;
+        let req = self.build_request(
+            Method::GET,
+            &format!(
+                "{}/{}/relationships/integrations/{}",
+                BASE_PATH, request.incident_id, request.integration_metadata_id
+            ),
+        )?;
+
+        self.send_request::<IncidentIntegrationMetadataResponse>(req)
+            .await
+    }
+
+    /// Update an existing incident integration metadata.
+    ///
+    /// This operation is unstable and must be enabled with `v2.update_incident_integration`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/incidents/#update-an-existing-incident-integration-metadata)
+    pub async fn update_incident_integration(
+        &self,
+        request: UpdateIncidentIntegrationRequest,
+    ) -> Result<IncidentIntegrationMetadataResponse, Error> {
+        self.check_unstable_operation("v2.update_incident_integration")?;
+        let req = self.build_request(
+            Method::PATCH,
+            &format!(
+                "{}/{}/relationships/integrations/{}",
+                BASE_PATH, request.incident_id, request.integration_metadata_id
+            ),
+        )?;
+        let req = req.json(&request.integration);
+
+        self.send_request::<IncidentIntegrationMetadataResponse>(req)
+            .await
+    }
+
+    /// Delete an incident integration metadata.
+    ///
+    /// This operation is unstable and must be enabled with `v2.delete_incident_integration`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/incidents/#delete-an-incident-integration-metadata)
+    pub async fn delete_incident_integration(
+        &self,
+        request: IncidentIntegrationPathRequest,
+    ) -> Result<DeleteIncidentIntegrationResponse, Error> {
+        self.check_unstable_operation("v2.delete_incident_integration")?;
+        let req = self.build_request(
+            Method::DELETE,
+            &format!(
+                "{}/{}/relationships/integrations/{}",
+                BASE_PATH, request.incident_id, request.integration_metadata_id
+            ),
+        )?;
+
+        self.send_request::<DeleteIncidentIntegrationResponse>(req)
+            .await
+    }
+
+    /// Get all attachments for a given incident.
+    ///
+    /// This operation is unstable and must be enabled with `v2.list_incident_attachments`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/incidents/#get-a-list-of-attachments)
+    pub async fn list_incident_attachments(
+        &self,
+        request: ListIncidentAttachmentsRequest,
+    ) -> Result<ListIncidentAttachmentsResponse, Error> {
+        self.check_unstable_operation("v2.list_incident_attachments")?;
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(
+            Method::GET,
+            &format!(
+                "{}/{}/attachments?{}",
+                BASE_PATH, request.incident_id, query
+            ),
+        )?;
+
+        self.send_request::<ListIncidentAttachmentsResponse>(req)
+            .await
+    }
+
+    /// Create, update or delete the attachments of an incident in one request.
+    ///
+    /// This operation is unstable and must be enabled with `v2.update_incident_attachments`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/incidents/#create-update-or-delete-incident-attachments)
+    pub async fn update_incident_attachments(
+        &self,
+        request: UpdateIncidentAttachmentsRequest,
+    ) -> Result<UpdateIncidentAttachmentsResponse, Error> {
+        self.check_unstable_operation("v2.update_incident_attachments")?;
+        let req = self.build_request(
+            Method::PATCH,
+            &format!("{}/{}/attachments", BASE_PATH, request.incident_id),
+        )?;
+        let req = req.json(&request.attachments);
+
+        self.send_request::<UpdateIncidentAttachmentsResponse>(req)
+            .await
+    }
+}
@@ -0,0 +1,359 @@
+use reqwest::Method;
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::client::Request;
+use crate::models::key_management::{
+    ApiKeyCreateData, ApiKeyCreateUpdateAttributes, ApiKeyIdRequest, ApiKeyResponse,
+    ApiKeysResponse, ApiKeysType, ApplicationKeyCreateAttributes, ApplicationKeyCreateData,
+    ApplicationKeyIdRequest, ApplicationKeyResponse, ApplicationKeysResponse, ApplicationKeysType,
+    CreateApiKeyRequest, CreateApplicationKeyRequest, DeleteApiKeyResponse,
+    DeleteApplicationKeyResponse, GetApiKeyRequest, GetApplicationKeyRequest, ListApiKeysRequest,
+    ListApplicationKeysRequest, UpdateApiKeyRequest, UpdateApplicationKeyRequest,
+};
+
+/// Base path of the API keys endpoints.
+static API_KEYS_PATH: &str = "api/v2/api_keys";
+/// Base path of the application keys endpoints of the organization.
+static APPLICATION_KEYS_PATH: &str = "api/v2/application_keys";
+/// Base path of the application keys endpoints of the current user.
+static CURRENT_USER_APPLICATION_KEYS_PATH: &str = "api/v2/current_user/application_keys";
+
+impl Client {
+    /// List all API keys available for your account.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/key-management/#get-all-api-keys)
+    pub async fn list_api_keys(
+        &self,
+        request: ListApiKeysRequest,
+    ) -> Result<ApiKeysResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(Method::GET, &format!("{}?{}", API_KEYS_PATH, query))?;
+
+        self.send_request::<ApiKeysResponse>(req).await
+    }
+
+    /// Create an API key. The value of the key is only returned in this response.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/key-management/#create-an-api-key)
+    pub async fn create_api_key(
+        &self,
+        request: CreateApiKeyRequest,
+    ) -> Result<ApiKeyResponse, Error> {
+        let req = self.build_request(Method::POST, API_KEYS_PATH)?;
+        let req = req.json(&request);
+
+        self.send_request::<ApiKeyResponse>(req).await
+    }
+
+    /// Get an API key.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/key-management/#get-api-key)
+    pub async fn get_api_key(&self, request: GetApiKeyRequest) -> Result<ApiKeyResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}?{}", API_KEYS_PATH, request.api_key_id, query),
+        )?;
+
+        self.send_request::<ApiKeyResponse>(req).await
+    }
+
+    /// Update an API key.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/key-management/#edit-an-api-key)
+    pub async fn update_api_key(
+        &self,
+        request: UpdateApiKeyRequest,
+    ) -> Result<ApiKeyResponse, Error> {
+        let req = self.build_request(
+            Method::PATCH,
+            &format!("{}/{}", API_KEYS_PATH, request.api_key_id),
+        )?;
+        let req = req.json(&request.api_key);
+
+        self.send_request::<ApiKeyResponse>(req).await
+    }
+
+    /// Delete an API key.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/key-management/#delete-an-api-key)
+    pub async fn delete_api_key(
+        &self,
+        request: ApiKeyIdRequest,
+    ) -> Result<DeleteApiKeyResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/{}", API_KEYS_PATH, request.api_key_id),
+        )?;
+
+        self.send_request::<DeleteApiKeyResponse>(req).await
+    }
+
+    /// Rotate the API key this client authenticates with.
+    ///
+    /// A new API key is created with the name of the given one, then the given key is deleted
+    /// using a [Client] authenticated with the new key. That client is returned along with the
+    /// created key, whose value is only available in this response.
+    /// If any step after the creation fails, such as deleting the given key, the new key is
+    /// deleted as well and the error returned.
+    pub async fn rotate_api_key(
+        &self,
+        request: ApiKeyIdRequest,
+    ) -> Result<(Client, ApiKeyResponse), Error> {
+        let current = self
+            .get_api_key(GetApiKeyRequest {
+                api_key_id: request.api_key_id.clone(),
+                include: None,
+            })
+            .await?;
+        let attributes = current.data.attributes;
+        let created = self
+            .create_api_key(Request {
+                data: ApiKeyCreateData {
+                    attributes: ApiKeyCreateUpdateAttributes {
+                        name: attributes
+                            .as_ref()
+                            .and_then(|attributes| attributes.name.clone())
+                            .unwrap_or_else(|| request.api_key_id.clone()),
+                        remote_config_read_enabled: attributes
+                            .as_ref()
+                            .and_then(|attributes| attributes.remote_config_read_enabled),
+                    },
+                    typ: ApiKeysType::ApiKeys,
+                },
+            })
+            .await?;
+
+        // Every failure past this point deletes the new key, so that it does not outlive the call.
+        let rotated = async {
+            let key = created
+                .data
+                .attributes
+                .as_ref()
+                .and_then(|attributes| attributes.key.as_ref())
+                .ok_or(Error::KeyValueMissing)?;
+            let client = self.with_keys(Some(key), None)?;
+            client.delete_api_key(request).await?;
+            Ok::<_, Error>(client)
+        }
+        .await;
+
+        match rotated {
+            Ok(client) => Ok((client, created)),
+            Err(error) => {
+                let _ = self
+                    .delete_api_key(ApiKeyIdRequest {
+                        api_key_id: created.data.id,
+                    })
+                    .await;
+                Err(error)
+            }
+        }
+    }
+
+    /// List all application keys available for your org.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/key-management/#get-all-application-keys)
+    pub async fn list_application_keys(
+        &self,
+        request: ListApplicationKeysRequest,
+    ) -> Result<ApplicationKeysResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req =
+            self.build_request(Method::GET, &format!("{}?{}", APPLICATION_KEYS_PATH, query))?;
+
+        self.send_request::<ApplicationKeysResponse>(req).await
+    }
+
+    /// Get an application key for your org.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/key-management/#get-an-application-key)
+    pub async fn get_application_key(
+        &self,
+        request: GetApplicationKeyRequest,
+    ) -> Result<ApplicationKeyResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}?{}", APPLICATION_KEYS_PATH, request.app_key_id, query),
+        )?;
+
+        self.send_request::<ApplicationKeyResponse>(req).await
+    }
+
+    /// Edit an application key of your org.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/key-management/#edit-an-application-key)
+    pub async fn update_application_key(
+        &self,
+        request: UpdateApplicationKeyRequest,
+    ) -> Result<ApplicationKeyResponse, Error> {
+        let req = self.build_request(
+            Method::PATCH,
+            &format!("{}/{}", APPLICATION_KEYS_PATH, request.app_key_id),
+        )?;
+        let req = req.json(&request.application_key);
+
+        self.send_request::<ApplicationKeyResponse>(req).await
+    }
+
+    /// Delete an application key of your org.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/key-management/#delete-an-application-key)
+    pub async fn delete_application_key(
+        &self,
+        request: ApplicationKeyIdRequest,
+    ) -> Result<DeleteApplicationKeyResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/{}", APPLICATION_KEYS_PATH, request.app_key_id),
+        )?;
+
+        self.send_request::<DeleteApplicationKeyResponse>(req).await
+    }
+
+    /// List all application keys available for the current user.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/key-management/#get-all-application-keys-owned-by-current-user)
+    pub async fn list_current_user_application_keys(
+        &self,
+        request: ListApplicationKeysRequest,
+    ) -> Result<ApplicationKeysResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}?{}", CURRENT_USER_APPLICATION_KEYS_PATH, query),
+        )?;
+
+        self.send_request::<ApplicationKeysResponse>(req).await
+    }
+
+    /// Create an application key for the current user. The value of the key is only returned
+    /// in this response.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/key-management/#create-an-application-key-for-current-user)
+    pub async fn create_current_user_application_key(
+        &self,
+        request: CreateApplicationKeyRequest,
+    ) -> Result<ApplicationKeyResponse, Error> {
+        let req = self.build_request(Method::POST, CURRENT_USER_APPLICATION_KEYS_PATH)?;
+        let req = req.json(&request);
+
+        self.send_request::<ApplicationKeyResponse>(req).await
+    }
+
+    /// Get an application key owned by the current user.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/key-management/#get-one-application-key-owned-by-current-user)
+    pub async fn get_current_user_application_key(
+        &self,
+        request: ApplicationKeyIdRequest,
+    ) -> Result<ApplicationKeyResponse, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!(
+                "{}/{}",
+                CURRENT_USER_APPLICATION_KEYS_PATH, request.app_key_id
+            ),
+        )?;
+
+        self.send_request::<ApplicationKeyResponse>(req).await
+    }
+
+    /// Edit an application key owned by the current user.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/key-management/#edit-an-application-key-owned-by-current-user)
+    pub async fn update_current_user_application_key(
+        &self,
+        request: UpdateApplicationKeyRequest,
+    ) -> Result<ApplicationKeyResponse, Error> {
+        let req = self.build_request(
+            Method::PATCH,
+            &format!(
+                "{}/{}",
+                CURRENT_USER_APPLICATION_KEYS_PATH, request.app_key_id
+            ),
+        )?;
+        let req = req.json(&request.application_key);
+
+        self.send_request::<ApplicationKeyResponse>(req).await
+    }
+
+    /// Delete an application key owned by the current user.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/key-management/#delete-an-application-key-owned-by-current-user)
+    pub async fn delete_current_user_application_key(
+        &self,
+        request: ApplicationKeyIdRequest,
+    ) -> Result<DeleteApplicationKeyResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!(
+                "{}/{}",
+                CURRENT_USER_APPLICATION_KEYS_PATH, request.app_key_id
+            ),
+        )?;
+
+        self.send_request::<DeleteApplicationKeyResponse>(req).await
+    }
+
+    /// Rotate the application key this client authenticates with, owned by the current user.
+    ///
+    /// A new application key is created with the name and scopes of the given one, then the
+    /// given key is deleted using a [Client] authenticated with the new key. That client is
+    /// returned along with the created key, whose value is only available in this response.
+    /// If any step after the creation fails, such as deleting the given key, the new key is
+    /// deleted as well and the error returned.
+    pub async fn rotate_current_user_application_key(
+        &self,
+        request: ApplicationKeyIdRequest,
+    ) -> Result<(Client, ApplicationKeyResponse), Error> {
+        let current = self
+            .get_current_user_application_key(ApplicationKeyIdRequest {
+                app_key_id: request.app_key_id.clone(),
+            })
+            .await?;
+        let attributes = current.data.attributes;
+        let created = self
+            .create_current_user_application_key(Request {
+                data: ApplicationKeyCreateData {
+                    attributes: ApplicationKeyCreateAttributes {
+                        name: attributes
+                            .as_ref()
+                            .and_then(|attributes| attributes.name.clone())
+                            .unwrap_or_else(|| request.app_key_id.clone()),
+                        scopes: attributes.and_then(|attributes| attributes.scopes),
+                    },
+                    typ: ApplicationKeysType::ApplicationKeys,
+                },
+            })
+            .await?;
+
+        // Every failure past this point deletes the new key, so that it does not outlive the call.
+        let rotated = async {
+            let key = created
+                .data
+                .attributes
+                .as_ref()
+                .and_then(|attributes| attributes.key.as_ref())
+                .ok_or(Error::KeyValueMissing)?;
+            let client = self.with_keys(None, Some(key))?;
+            client.delete_current_user_application_key(request).await?;
+            Ok::<_, Error>(client)
+        }
+        .await;
+
+        match rotated {
+            Ok(client) => Ok((client, created)),
+            Err(error) => {
+                let _ = self
+                    .delete_current_user_application_key(ApplicationKeyIdRequest {
+                        app_key_id: created.data.id,
+                    })
+                    .await;
+                Err(error)
+            }
+        }
+    }
+}
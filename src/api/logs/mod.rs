@@ -0,0 +1,128 @@
+use std::io::Write;
+
+use flate2::write::{GzEncoder, ZlibEncoder};
+use flate2::Compression;
+use reqwest::{header, Method, StatusCode};
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::client::EmptyResponse;
+use crate::models::logs::{
+    ContentEncoding, ListLogsGetRequest, ListLogsRequest, ListLogsResponse, LogsAggregateRequest,
+    LogsAggregateResponse, SubmitLogRequest,
+};
+
+/// Path of the logs intake endpoint, relative to the logs intake url.
+static INTAKE_PATH: &str = "api/v2/logs";
+/// Base path of the logs query endpoints.
+static BASE_PATH: &str = "api/v2/logs";
+
+impl Client {
+    /// List endpoint returns logs that match a log search query.
+    /// [Results are paginated](https://docs.datadoghq.com/logs/guide/collect-multiple-logs-with-pagination).
+    /// Use this endpoint to see your latest logs.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs/#search-logs-get)
+    pub async fn list_logs(&self, request: ListLogsGetRequest) -> Result<ListLogsResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(Method::GET, &format!("{}/events?{}", BASE_PATH, query))?;
+
+        self.send_request::<ListLogsResponse>(req).await
+    }
+
+    /// List endpoint returns logs that match a log search query.
+    /// [Results are paginated](https://docs.datadoghq.com/logs/guide/collect-multiple-logs-with-pagination).
+    /// Use this endpoint to build complex logs filtering and search.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs/#search-logs-post)
+    pub async fn search_logs(&self, request: ListLogsRequest) -> Result<ListLogsResponse, Error> {
+        let req = self.build_request(Method::POST, &format!("{}/events/search", BASE_PATH))?;
+        let req = req.json(&request);
+
+        self.send_request::<ListLogsResponse>(req).await
+    }
+
+    /// The API endpoint to aggregate events into buckets and compute metrics and timeseries.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs/#aggregate-events)
+    pub async fn aggregate_logs(
+        &self,
+        request: LogsAggregateRequest,
+    ) -> Result<LogsAggregateResponse, Error> {
+        let req =
+            self.build_request(Method::POST, &format!("{}/analytics/aggregate", BASE_PATH))?;
+        let req = req.json(&request);
+
+        self.send_request::<LogsAggregateResponse>(req).await
+    }
+
+    /// Send your logs to your Datadog platform over HTTP. Limits per HTTP request are:
+    ///
+    /// - Maximum content size per payload (uncompressed): 5MB
+    /// - Maximum size for a single log: 1MB
+    /// - Maximum array size if sending multiple logs in an array: 1000 entries
+    ///
+    /// Use [LogsSubmitter](crate::logs_submitter::LogsSubmitter) to have these limits enforced.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs/#send-logs)
+    pub async fn submit_log(&self, request: SubmitLogRequest) -> Result<EmptyResponse, Error> {
+        let payload = serde_json::to_vec(&request.body)?;
+
+        self.submit_log_payload(
+            payload,
+            request
+                .content_encoding
+                .unwrap_or(ContentEncoding::Identity),
+            request.ddtags.as_deref(),
+        )
+        .await
+    }
+
+    /// Sends an already serialized JSON array of logs, compressing it with the given encoding.
+    /// Any status other than `202 Accepted` is surfaced as [Error::UnexpectedStatus] so that
+    /// callers can decide whether the payload is worth sending again.
+    pub(crate) async fn submit_log_payload(
+        &self,
+        payload: Vec<u8>,
+        content_encoding: ContentEncoding,
+        ddtags: Option<&str>,
+    ) -> Result<EmptyResponse, Error> {
+        let path = match ddtags {
+            Some(ddtags) => format!(
+                "{}?ddtags={}",
+                INTAKE_PATH,
+                url::form_urlencoded::byte_serialize(ddtags.as_bytes()).collect::<String>()
+            ),
+            None => INTAKE_PATH.to_string(),
+        };
+        let req = self.build_logs_intake_request(Method::POST, &path)?;
+        let req = req
+            .header(header::CONTENT_ENCODING, content_encoding.as_str())
+            .body(encode_payload(payload, content_encoding)?);
+
+        let response = req.send().await?;
+        let status = response.status();
+        if status == StatusCode::ACCEPTED || status == StatusCode::OK {
+            Ok(EmptyResponse {})
+        } else {
+            Err(Error::UnexpectedStatus(status))
+        }
+    }
+}
+
+/// Compresses the payload according to the `Content-Encoding` it will be sent with.
+fn encode_payload(payload: Vec<u8>, content_encoding: ContentEncoding) -> Result<Vec<u8>, Error> {
+    match content_encoding {
+        ContentEncoding::Identity => Ok(payload),
+        ContentEncoding::Gzip => {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(&payload)?;
+            Ok(encoder.finish()?)
+        }
+        ContentEncoding::Deflate => {
+            let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(&payload)?;
+            Ok(encoder.finish()?)
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use reqwest::Method;
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::client::{EmptyResponse, Request};
+use crate::models::logs_archives::{
+    CreateLogsArchiveRequest, DeleteLogsArchiveRequest, DeleteLogsArchiveResponse,
+    GetLogsArchiveRequest, ListLogsArchiveReadRolesRequest, ListLogsArchivesResponse,
+    LogsArchiveOrder, LogsArchiveReadRoleRequest, LogsArchiveResponse,
+    UpdateLogsArchiveOrderRequest, UpdateLogsArchiveRequest,
+};
+use crate::models::roles::RolesResponse;
+
+/// Base path of the logs archives endpoints.
+static BASE_PATH: &str = "api/v2/logs/config/archives";
+/// Path of the logs archives order endpoints.
+static ORDER_PATH: &str = "api/v2/logs/config/archive-order";
+
+impl Client {
+    /// Get the list of configured logs archives with their definitions.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-archives/#get-all-archives)
+    pub async fn list_logs_archives(&self) -> Result<ListLogsArchivesResponse, Error> {
+        let req = self.build_request(Method::GET, BASE_PATH)?;
+
+        self.send_request::<ListLogsArchivesResponse>(req).await
+    }
+
+    /// Create an archive in your organization.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-archives/#create-an-archive)
+    pub async fn create_logs_archive(
+        &self,
+        request: CreateLogsArchiveRequest,
+    ) -> Result<LogsArchiveResponse, Error> {
+        let req = self.build_request(Method::POST, BASE_PATH)?;
+        let req = req.json(&request);
+
+        self.send_request::<LogsArchiveResponse>(req).await
+    }
+
+    /// Get a specific archive from your organization.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-archives/#get-an-archive)
+    pub async fn get_logs_archive(
+        &self,
+        request: GetLogsArchiveRequest,
+    ) -> Result<LogsArchiveResponse, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}", BASE_PATH, request.archive_id),
+        )?;
+
+        self.send_request::<LogsArchiveResponse>(req).await
+    }
+
+    /// Update a given archive configuration.
+    ///
+    /// **Note**: Using this method updates your archive configuration by **replacing**
+    /// your current configuration with the new one sent to your Datadog organization.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-archives/#update-an-archive)
+    pub async fn update_logs_archive(
+        &self,
+        request: UpdateLogsArchiveRequest,
+    ) -> Result<LogsArchiveResponse, Error> {
+        let req = self.build_request(
+            Method::PUT,
+            &format!("{}/{}", BASE_PATH, request.archive_id),
+        )?;
+        let req = req.json(&request.archive);
+
+        self.send_request::<LogsArchiveResponse>(req).await
+    }
+
+    /// Delete a given archive from your organization.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-archives/#delete-an-archive)
+    pub async fn delete_logs_archive(
+        &self,
+        request: DeleteLogsArchiveRequest,
+    ) -> Result<DeleteLogsArchiveResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/{}", BASE_PATH, request.archive_id),
+        )?;
+
+        self.send_request(req).await
+    }
+
+    /// Returns all read roles a given archive is restricted to.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-archives/#list-read-roles-for-an-archive)
+    pub async fn list_logs_archive_read_roles(
+        &self,
+        request: ListLogsArchiveReadRolesRequest,
+    ) -> Result<RolesResponse, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}/readers", BASE_PATH, request.archive_id),
+        )?;
+
+        self.send_request::<RolesResponse>(req).await
+    }
+
+    /// Adds a read role to an archive.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-archives/#grant-role-to-an-archive)
+    pub async fn add_logs_archive_read_role(
+        &self,
+        request: LogsArchiveReadRoleRequest,
+    ) -> Result<EmptyResponse, Error> {
+        let req = self.build_request(
+            Method::POST,
+            &format!("{}/{}/readers", BASE_PATH, request.archive_id),
+        )?;
+        let req = req.json(&Request { data: request.role });
+
+        self.send_request(req).await
+    }
+
+    /// Removes a role from an archive.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-archives/#revoke-role-from-an-archive)
+    pub async fn remove_logs_archive_read_role(
+        &self,
+        request: LogsArchiveReadRoleRequest,
+    ) -> Result<EmptyResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/{}/readers", BASE_PATH, request.archive_id),
+        )?;
+        let req = req.json(&Request { data: request.role });
+
+        self.send_request(req).await
+    }
+
+    /// Get the current order of your archives.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-archives/#get-archive-order)
+    pub async fn get_logs_archive_order(&self) -> Result<LogsArchiveOrder, Error> {
+        let req = self.build_request(Method::GET, ORDER_PATH)?;
+
+        self.send_request::<LogsArchiveOrder>(req).await
+    }
+
+    /// Update the order of your archives. Since logs are processed sequentially,
+    /// reordering an archive may change the structure and content of the data processed by other archives.
+    ///
+    /// **Note**: Using the `PUT` method updates your archive's order by replacing the current order
+    /// with the new one.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-archives/#update-archive-order)
+    pub async fn update_logs_archive_order(
+        &self,
+        request: UpdateLogsArchiveOrderRequest,
+    ) -> Result<LogsArchiveOrder, Error> {
+        let req = self.build_request(Method::PUT, ORDER_PATH)?;
+        let req = req.json(&request);
+
+        self.send_request::<LogsArchiveOrder>(req).await
+    }
+}
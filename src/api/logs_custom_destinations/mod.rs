@@ -0,0 +1,87 @@
+use reqwest::Method;
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::logs_custom_destinations::{
+    CreateLogsCustomDestinationRequest, DeleteLogsCustomDestinationRequest,
+    DeleteLogsCustomDestinationResponse, GetLogsCustomDestinationRequest,
+    ListLogsCustomDestinationsResponse, LogsCustomDestinationResponse,
+    UpdateLogsCustomDestinationRequest,
+};
+
+/// Base path of the logs custom destinations endpoints.
+static BASE_PATH: &str = "api/v2/logs/config/custom-destinations";
+
+impl Client {
+    /// Get the list of configured custom destinations in your organization with their definitions.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-custom-destinations/#get-all-custom-destinations)
+    pub async fn list_logs_custom_destinations(
+        &self,
+    ) -> Result<ListLogsCustomDestinationsResponse, Error> {
+        let req = self.build_request(Method::GET, BASE_PATH)?;
+
+        self.send_request::<ListLogsCustomDestinationsResponse>(req)
+            .await
+    }
+
+    /// Create a custom destination in your organization.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-custom-destinations/#create-a-custom-destination)
+    pub async fn create_logs_custom_destination(
+        &self,
+        request: CreateLogsCustomDestinationRequest,
+    ) -> Result<LogsCustomDestinationResponse, Error> {
+        let req = self.build_request(Method::POST, BASE_PATH)?;
+        let req = req.json(&request);
+
+        self.send_request::<LogsCustomDestinationResponse>(req)
+            .await
+    }
+
+    /// Get a specific custom destination in your organization.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-custom-destinations/#get-a-custom-destination)
+    pub async fn get_logs_custom_destination(
+        &self,
+        request: GetLogsCustomDestinationRequest,
+    ) -> Result<LogsCustomDestinationResponse, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}", BASE_PATH, request.custom_destination_id),
+        )?;
+
+        self.send_request::<LogsCustomDestinationResponse>(req)
+            .await
+    }
+
+    /// Update the given fields of a specific custom destination in your organization.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-custom-destinations/#update-a-custom-destination)
+    pub async fn update_logs_custom_destination(
+        &self,
+        request: UpdateLogsCustomDestinationRequest,
+    ) -> Result<LogsCustomDestinationResponse, Error> {
+        let req =
+            self.build_request(Method::PATCH, &format!("{}/{}", BASE_PATH, request.data.id))?;
+        let req = req.json(&request);
+
+        self.send_request::<LogsCustomDestinationResponse>(req)
+            .await
+    }
+
+    /// Delete a specific custom destination in your organization.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-custom-destinations/#delete-a-custom-destination)
+    pub async fn delete_logs_custom_destination(
+        &self,
+        request: DeleteLogsCustomDestinationRequest,
+    ) -> Result<DeleteLogsCustomDestinationResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/{}", BASE_PATH, request.custom_destination_id),
+        )?;
+
+        self.send_request(req).await
+    }
+}
@@ -0,0 +1,102 @@
+use reqwest::Method;
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::logs_indexes::{
+    CreateLogsIndexRequest, DeleteLogsIndexRequest, DeleteLogsIndexResponse, GetLogsIndexRequest,
+    ListLogsIndexesResponse, LogsIndex, LogsIndexesOrder, UpdateLogsIndexOrderRequest,
+    UpdateLogsIndexRequest,
+};
+
+/// Base path of the logs indexes endpoints.
+static BASE_PATH: &str = "api/v1/logs/config/indexes";
+/// Path of the logs indexes order endpoints.
+static ORDER_PATH: &str = "api/v1/logs/config/index-order";
+
+impl Client {
+    /// The Index object describes the configuration of a log index.
+    /// This endpoint returns an array of the `LogIndex` objects of your organization.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-indexes/#get-all-indexes)
+    pub async fn list_logs_indexes(&self) -> Result<ListLogsIndexesResponse, Error> {
+        let req = self.build_request(Method::GET, BASE_PATH)?;
+
+        self.send_request::<ListLogsIndexesResponse>(req).await
+    }
+
+    /// Get one log index from your organization.
+    /// This endpoint takes no JSON arguments.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-indexes/#get-an-index)
+    pub async fn get_logs_index(&self, request: GetLogsIndexRequest) -> Result<LogsIndex, Error> {
+        let req = self.build_request(Method::GET, &format!("{}/{}", BASE_PATH, request.name))?;
+
+        self.send_request::<LogsIndex>(req).await
+    }
+
+    /// Creates a new index. Returns the Index object passed in the request body when the request is successful.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-indexes/#create-an-index)
+    pub async fn create_logs_index(
+        &self,
+        request: CreateLogsIndexRequest,
+    ) -> Result<LogsIndex, Error> {
+        let req = self.build_request(Method::POST, BASE_PATH)?;
+        let req = req.json(&request);
+
+        self.send_request::<LogsIndex>(req).await
+    }
+
+    /// Update an index as identified by its name.
+    /// Returns the Index object passed in the request body when the request is successful.
+    ///
+    /// Using the `PUT` method updates your index's configuration by replacing your current configuration
+    /// with the new one sent to your Datadog organization.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-indexes/#update-an-index)
+    pub async fn update_logs_index(
+        &self,
+        request: UpdateLogsIndexRequest,
+    ) -> Result<LogsIndex, Error> {
+        let req = self.build_request(Method::PUT, &format!("{}/{}", BASE_PATH, request.name))?;
+        let req = req.json(&request.index);
+
+        self.send_request::<LogsIndex>(req).await
+    }
+
+    /// Delete an existing index from your organization.
+    /// Index deletions are permanent and cannot be reverted.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-indexes/#delete-an-index)
+    pub async fn delete_logs_index(
+        &self,
+        request: DeleteLogsIndexRequest,
+    ) -> Result<DeleteLogsIndexResponse, Error> {
+        let req = self.build_request(Method::DELETE, &format!("{}/{}", BASE_PATH, request.name))?;
+
+        self.send_request(req).await
+    }
+
+    /// Get the current order of your log indexes. This endpoint takes no JSON arguments.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-indexes/#get-indexes-order)
+    pub async fn get_logs_index_order(&self) -> Result<LogsIndexesOrder, Error> {
+        let req = self.build_request(Method::GET, ORDER_PATH)?;
+
+        self.send_request::<LogsIndexesOrder>(req).await
+    }
+
+    /// This endpoint updates the index order of your organization.
+    /// It returns the index order object passed in the request body when the request is successful.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-indexes/#update-indexes-order)
+    pub async fn update_logs_index_order(
+        &self,
+        request: UpdateLogsIndexOrderRequest,
+    ) -> Result<LogsIndexesOrder, Error> {
+        let req = self.build_request(Method::PUT, ORDER_PATH)?;
+        let req = req.json(&request);
+
+        self.send_request::<LogsIndexesOrder>(req).await
+    }
+}
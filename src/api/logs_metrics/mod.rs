@@ -0,0 +1,60 @@
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::logs_metrics::{
+    CreateLogsMetricRequest, DeleteLogsMetricRequest, DeleteLogsMetricResponse,
+    GetLogsMetricRequest, ListLogsMetricsResponse, LogsMetricResponse, UpdateLogsMetricRequest,
+};
+
+/// Base path of the log-based metrics endpoints.
+static BASE_PATH: &str = "api/v2/logs/config/metrics";
+
+impl Client {
+    /// Get the list of configured log-based metrics with their definitions.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-metrics/#get-all-log-based-metrics)
+    pub async fn list_logs_metrics(&self) -> Result<ListLogsMetricsResponse, Error> {
+        self.list_generated_metrics(BASE_PATH).await
+    }
+
+    /// Create a metric based on your ingested logs in your organization.
+    /// Returns the log-based metric object from the request body when the request is successful.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-metrics/#create-a-log-based-metric)
+    pub async fn create_logs_metric(
+        &self,
+        request: CreateLogsMetricRequest,
+    ) -> Result<LogsMetricResponse, Error> {
+        self.create_generated_metric(BASE_PATH, request).await
+    }
+
+    /// Get a specific log-based metric from your organization.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-metrics/#get-a-log-based-metric)
+    pub async fn get_logs_metric(
+        &self,
+        request: GetLogsMetricRequest,
+    ) -> Result<LogsMetricResponse, Error> {
+        self.get_generated_metric(BASE_PATH, request).await
+    }
+
+    /// Update a specific log-based metric from your organization.
+    /// Returns the log-based metric object from the request body when the request is successful.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-metrics/#update-a-log-based-metric)
+    pub async fn update_logs_metric(
+        &self,
+        request: UpdateLogsMetricRequest,
+    ) -> Result<LogsMetricResponse, Error> {
+        self.update_generated_metric(BASE_PATH, request).await
+    }
+
+    /// Delete a specific log-based metric from your organization.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-metrics/#delete-a-log-based-metric)
+    pub async fn delete_logs_metric(
+        &self,
+        request: DeleteLogsMetricRequest,
+    ) -> Result<DeleteLogsMetricResponse, Error> {
+        self.delete_generated_metric(BASE_PATH, request).await
+    }
+}
@@ -0,0 +1,118 @@
+use reqwest::Method;
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::logs_pipelines::{
+    CreateLogsPipelineRequest, DeleteLogsPipelineRequest, DeleteLogsPipelineResponse,
+    GetLogsPipelineRequest, ListLogsPipelinesResponse, LogsPipeline, LogsPipelinesOrder,
+    UpdateLogsPipelineOrderRequest, UpdateLogsPipelineRequest,
+};
+
+/// Base path of the logs pipelines endpoints.
+static BASE_PATH: &str = "api/v1/logs/config/pipelines";
+/// Path of the logs pipelines order endpoints.
+static ORDER_PATH: &str = "api/v1/logs/config/pipeline-order";
+
+impl Client {
+    /// Get all pipelines from your organization.
+    /// This endpoint takes no JSON arguments.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-pipelines/#get-all-pipelines)
+    pub async fn list_logs_pipelines(&self) -> Result<ListLogsPipelinesResponse, Error> {
+        let req = self.build_request(Method::GET, BASE_PATH)?;
+
+        self.send_request::<ListLogsPipelinesResponse>(req).await
+    }
+
+    /// Get a specific pipeline from your organization.
+    /// This endpoint takes no JSON arguments.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-pipelines/#get-a-pipeline)
+    pub async fn get_logs_pipeline(
+        &self,
+        request: GetLogsPipelineRequest,
+    ) -> Result<LogsPipeline, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}", BASE_PATH, request.pipeline_id),
+        )?;
+
+        self.send_request::<LogsPipeline>(req).await
+    }
+
+    /// Create a pipeline in your organization.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-pipelines/#create-a-pipeline)
+    pub async fn create_logs_pipeline(
+        &self,
+        request: CreateLogsPipelineRequest,
+    ) -> Result<LogsPipeline, Error> {
+        let req = self.build_request(Method::POST, BASE_PATH)?;
+        let req = req.json(&request);
+
+        self.send_request::<LogsPipeline>(req).await
+    }
+
+    /// Update a given pipeline configuration to change its processors or their order.
+    ///
+    /// **Note**: Using this method updates your pipeline configuration by **replacing**
+    /// your current configuration with the new one sent to your Datadog organization.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-pipelines/#update-a-pipeline)
+    pub async fn update_logs_pipeline(
+        &self,
+        request: UpdateLogsPipelineRequest,
+    ) -> Result<LogsPipeline, Error> {
+        let req = self.build_request(
+            Method::PUT,
+            &format!("{}/{}", BASE_PATH, request.pipeline_id),
+        )?;
+        let req = req.json(&request.pipeline);
+
+        self.send_request::<LogsPipeline>(req).await
+    }
+
+    /// Delete a given pipeline from your organization.
+    /// This endpoint takes no JSON arguments.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-pipelines/#delete-a-pipeline)
+    pub async fn delete_logs_pipeline(
+        &self,
+        request: DeleteLogsPipelineRequest,
+    ) -> Result<DeleteLogsPipelineResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/{}", BASE_PATH, request.pipeline_id),
+        )?;
+
+        self.send_request(req).await
+    }
+
+    /// Get the current order of your pipelines.
+    /// This endpoint takes no JSON arguments.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-pipelines/#get-pipeline-order)
+    pub async fn get_logs_pipeline_order(&self) -> Result<LogsPipelinesOrder, Error> {
+        let req = self.build_request(Method::GET, ORDER_PATH)?;
+
+        self.send_request::<LogsPipelinesOrder>(req).await
+    }
+
+    /// Update the order of your pipelines. Since logs are processed sequentially,
+    /// reordering a pipeline may change the structure and content of the data processed
+    /// by other pipelines and their processors.
+    ///
+    /// **Note**: Using the `PUT` method updates your pipeline order by replacing your current
+    /// order with the new one sent to your Datadog organization.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/logs-pipelines/#update-pipeline-order)
+    pub async fn update_logs_pipeline_order(
+        &self,
+        request: UpdateLogsPipelineOrderRequest,
+    ) -> Result<LogsPipelinesOrder, Error> {
+        let req = self.build_request(Method::PUT, ORDER_PATH)?;
+        let req = req.json(&request);
+
+        self.send_request::<LogsPipelinesOrder>(req).await
+    }
+}
@@ -0,0 +1,169 @@
+use reqwest::Method;
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::client::EmptyResponse;
+use crate::models::metrics::{
+    CreateMetricTagConfigurationRequest, CreateMetricTagConfigurationResponse,
+    DeleteMetricTagConfigurationRequest, EstimateMetricCardinalityRequest,
+    GetMetricMetadataRequest, GetMetricTagConfigurationRequest, GetMetricVolumesRequest,
+    ListActiveMetricsRequest, ListActiveMetricsResponse, MetricEstimateResponse, MetricMetadata,
+    MetricTagConfigurationResponse, MetricVolumesResponse, UpdateMetricMetadataRequest,
+    UpdateMetricTagConfigurationRequest, UpdateMetricTagConfigurationResponse,
+};
+
+/// Base path of the v1 metrics endpoints.
+static V1_BASE_PATH: &str = "api/v1/metrics";
+/// Base path of the v2 metrics endpoints.
+static V2_BASE_PATH: &str = "api/v2/metrics";
+
+impl Client {
+    /// Get the list of actively reporting metrics from a given time until now.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/metrics/#get-active-metrics-list)
+    pub async fn list_active_metrics(
+        &self,
+        request: ListActiveMetricsRequest,
+    ) -> Result<ListActiveMetricsResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(Method::GET, &format!("{}?{}", V1_BASE_PATH, query))?;
+
+        self.send_request::<ListActiveMetricsResponse>(req).await
+    }
+
+    /// Get metadata about a specific metric.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/metrics/#get-metric-metadata)
+    pub async fn get_metric_metadata(
+        &self,
+        request: GetMetricMetadataRequest,
+    ) -> Result<MetricMetadata, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}", V1_BASE_PATH, request.metric_name),
+        )?;
+
+        self.send_request::<MetricMetadata>(req).await
+    }
+
+    /// Edit metadata of a specific metric.
+    /// Find out more about [supported types](https://docs.datadoghq.com/developers/metrics).
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/metrics/#edit-metric-metadata)
+    pub async fn update_metric_metadata(
+        &self,
+        request: UpdateMetricMetadataRequest,
+    ) -> Result<MetricMetadata, Error> {
+        let req = self.build_request(
+            Method::PUT,
+            &format!("{}/{}", V1_BASE_PATH, request.metric_name),
+        )?;
+        let req = req.json(&request.metadata);
+
+        self.send_request::<MetricMetadata>(req).await
+    }
+
+    /// Returns the tag configuration for the given metric name.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/metrics/#list-tag-configuration-by-name)
+    pub async fn get_metric_tag_configuration(
+        &self,
+        request: GetMetricTagConfigurationRequest,
+    ) -> Result<MetricTagConfigurationResponse, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}/tags", V2_BASE_PATH, request.metric_name),
+        )?;
+
+        self.send_request::<MetricTagConfigurationResponse>(req)
+            .await
+    }
+
+    /// Create and define a list of queryable tag keys for an existing count/gauge/rate/distribution metric.
+    /// Optionally, include percentile aggregations on any distribution metric or configure custom aggregations
+    /// on any count, rate, or gauge metric.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/metrics/#create-a-tag-configuration)
+    pub async fn create_metric_tag_configuration(
+        &self,
+        request: CreateMetricTagConfigurationRequest,
+    ) -> Result<CreateMetricTagConfigurationResponse, Error> {
+        let req = self.build_request(
+            Method::POST,
+            &format!("{}/{}/tags", V2_BASE_PATH, request.data.id),
+        )?;
+        let req = req.json(&request);
+
+        self.send_request::<CreateMetricTagConfigurationResponse>(req)
+            .await
+    }
+
+    /// Update the tag configuration of a metric or percentile aggregations of a distribution metric
+    /// or custom aggregations of a count, rate, or gauge metric.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/metrics/#update-a-tag-configuration)
+    pub async fn update_metric_tag_configuration(
+        &self,
+        request: UpdateMetricTagConfigurationRequest,
+    ) -> Result<UpdateMetricTagConfigurationResponse, Error> {
+        let req = self.build_request(
+            Method::PATCH,
+            &format!("{}/{}/tags", V2_BASE_PATH, request.data.id),
+        )?;
+        let req = req.json(&request);
+
+        self.send_request::<UpdateMetricTagConfigurationResponse>(req)
+            .await
+    }
+
+    /// Deletes a metric's tag configuration.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/metrics/#delete-a-tag-configuration)
+    pub async fn delete_metric_tag_configuration(
+        &self,
+        request: DeleteMetricTagConfigurationRequest,
+    ) -> Result<EmptyResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/{}/tags", V2_BASE_PATH, request.metric_name),
+        )?;
+
+        self.send_request(req).await
+    }
+
+    /// Returns the estimated cardinality for a metric with a given tag, percentile and number of aggregations configuration.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/metrics/#tag-configuration-cardinality-estimator)
+    pub async fn estimate_metric_cardinality(
+        &self,
+        request: EstimateMetricCardinalityRequest,
+    ) -> Result<MetricEstimateResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(
+            Method::GET,
+            &format!(
+                "{}/{}/estimate?{}",
+                V2_BASE_PATH, request.metric_name, query
+            ),
+        )?;
+
+        self.send_request::<MetricEstimateResponse>(req).await
+    }
+
+    /// View distinct metrics volumes for the given metric name.
+    ///
+    /// Custom metrics generated in-app from other products will return `null` for ingested volumes.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/metrics/#list-distinct-metric-volumes-by-metric-name)
+    pub async fn get_metric_volumes(
+        &self,
+        request: GetMetricVolumesRequest,
+    ) -> Result<MetricVolumesResponse, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}/volumes", V2_BASE_PATH, request.metric_name),
+        )?;
+
+        self.send_request::<MetricVolumesResponse>(req).await
+    }
+}
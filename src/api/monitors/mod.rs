@@ -0,0 +1,135 @@
+use reqwest::{Method, StatusCode};
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::monitors::{
+    CheckCanDeleteMonitorRequest, CheckCanDeleteMonitorResponse, CreateMonitorRequest,
+    DeleteMonitorRequest, DeletedMonitor, GetMonitorRequest, ListMonitorsRequest,
+    ListMonitorsResponse, Monitor, MonitorSearchResponse, SearchMonitorsRequest,
+    UpdateMonitorRequest, ValidateMonitorRequest, ValidateMonitorResponse,
+};
+
+/// Base path of the monitors endpoints.
+static BASE_PATH: &str = "api/v1/monitor";
+
+impl Client {
+    /// Get details about the specified monitors from your organization.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/monitors/#get-all-monitor-details)
+    pub async fn list_monitors(
+        &self,
+        request: ListMonitorsRequest,
+    ) -> Result<ListMonitorsResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(Method::GET, &format!("{}?{}", BASE_PATH, query))?;
+
+        self.send_request::<ListMonitorsResponse>(req).await
+    }
+
+    /// Create a monitor using the specified options.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/monitors/#create-a-monitor)
+    pub async fn create_monitor(&self, request: CreateMonitorRequest) -> Result<Monitor, Error> {
+        let req = self.build_request(Method::POST, BASE_PATH)?;
+        let req = req.json(&request);
+
+        self.send_request::<Monitor>(req).await
+    }
+
+    /// Get details about the specified monitor from your organization.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/monitors/#get-a-monitors-details)
+    pub async fn get_monitor(&self, request: GetMonitorRequest) -> Result<Monitor, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}?{}", BASE_PATH, request.monitor_id, query),
+        )?;
+
+        self.send_request::<Monitor>(req).await
+    }
+
+    /// Edit the specified monitor.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/monitors/#edit-a-monitor)
+    pub async fn update_monitor(&self, request: UpdateMonitorRequest) -> Result<Monitor, Error> {
+        let req = self.build_request(
+            Method::PUT,
+            &format!("{}/{}", BASE_PATH, request.monitor_id),
+        )?;
+        let req = req.json(&request.monitor);
+
+        self.send_request::<Monitor>(req).await
+    }
+
+    /// Delete the specified monitor.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/monitors/#delete-a-monitor)
+    pub async fn delete_monitor(
+        &self,
+        request: DeleteMonitorRequest,
+    ) -> Result<DeletedMonitor, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/{}?{}", BASE_PATH, request.monitor_id, query),
+        )?;
+
+        self.send_request::<DeletedMonitor>(req).await
+    }
+
+    /// Search and filter your monitors details.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/monitors/#monitors-search)
+    pub async fn search_monitors(
+        &self,
+        request: SearchMonitorsRequest,
+    ) -> Result<MonitorSearchResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(Method::GET, &format!("{}/search?{}", BASE_PATH, query))?;
+
+        self.send_request::<MonitorSearchResponse>(req).await
+    }
+
+    /// Validate the monitor provided in the request, without creating it.
+    /// The reasons why an invalid monitor is rejected are returned as [Error::InvalidRequest].
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/monitors/#validate-a-monitor)
+    pub async fn validate_monitor(
+        &self,
+        request: ValidateMonitorRequest,
+    ) -> Result<ValidateMonitorResponse, Error> {
+        let req = self.build_request(Method::POST, &format!("{}/validate", BASE_PATH))?;
+        let req = req.json(&request);
+
+        self.send_request::<ValidateMonitorResponse>(req).await
+    }
+
+    /// Check if the given monitors can be deleted.
+    ///
+    /// Monitors referenced by other resources are listed in the `errors` of the response
+    /// rather than failing the call, even though Datadog answers with `409 Conflict`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/monitors/#check-if-a-monitor-can-be-deleted)
+    pub async fn can_delete_monitors(
+        &self,
+        request: CheckCanDeleteMonitorRequest,
+    ) -> Result<CheckCanDeleteMonitorResponse, Error> {
+        let monitor_ids = request
+            .monitor_ids
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/can_delete?monitor_ids={}", BASE_PATH, monitor_ids),
+        )?;
+
+        self.send_request_accepting::<CheckCanDeleteMonitorResponse>(
+            req,
+            &[StatusCode::OK, StatusCode::CONFLICT],
+        )
+        .await
+    }
+}
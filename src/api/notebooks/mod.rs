@@ -0,0 +1,87 @@
+use reqwest::Method;
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::notebooks::{
+    CreateNotebookRequest, DeleteNotebookRequest, DeleteNotebookResponse, GetNotebookRequest,
+    ListNotebooksRequest, NotebookResponse, NotebooksResponse, UpdateNotebookRequest,
+};
+
+/// Base path of the notebooks endpoints.
+static BASE_PATH: &str = "api/v1/notebooks";
+
+impl Client {
+    /// Get all notebooks. This can also be used to search for notebooks with a particular
+    /// `query` in the notebook `name` or author `handle`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/notebooks/#get-all-notebooks)
+    pub async fn list_notebooks(
+        &self,
+        request: ListNotebooksRequest,
+    ) -> Result<NotebooksResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(Method::GET, &format!("{}?{}", BASE_PATH, query))?;
+
+        self.send_request::<NotebooksResponse>(req).await
+    }
+
+    /// Create a notebook using the specified options.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/notebooks/#create-a-notebook)
+    pub async fn create_notebook(
+        &self,
+        request: CreateNotebookRequest,
+    ) -> Result<NotebookResponse, Error> {
+        let req = self.build_request(Method::POST, BASE_PATH)?;
+        let req = req.json(&request);
+
+        self.send_request::<NotebookResponse>(req).await
+    }
+
+    /// Get a notebook using the specified notebook ID.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/notebooks/#get-a-notebook)
+    pub async fn get_notebook(
+        &self,
+        request: GetNotebookRequest,
+    ) -> Result<NotebookResponse, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}", BASE_PATH, request.notebook_id),
+        )?;
+
+        self.send_request::<NotebookResponse>(req).await
+    }
+
+    /// Update a notebook using the specified ID. The cells sent replace the current ones:
+    /// cells with an `id` are updated, cells without one are created, the others are removed.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/notebooks/#update-a-notebook)
+    pub async fn update_notebook(
+        &self,
+        request: UpdateNotebookRequest,
+    ) -> Result<NotebookResponse, Error> {
+        let req = self.build_request(
+            Method::PUT,
+            &format!("{}/{}", BASE_PATH, request.notebook_id),
+        )?;
+        let req = req.json(&request.notebook);
+
+        self.send_request::<NotebookResponse>(req).await
+    }
+
+    /// Delete a notebook using the specified ID.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/notebooks/#delete-a-notebook)
+    pub async fn delete_notebook(
+        &self,
+        request: DeleteNotebookRequest,
+    ) -> Result<DeleteNotebookResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/{}", BASE_PATH, request.notebook_id),
+        )?;
+
+        self.send_request::<DeleteNotebookResponse>(req).await
+    }
+}
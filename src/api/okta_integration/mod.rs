@@ -0,0 +1,82 @@
+use reqwest::Method;
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::okta_integration::{
+    AddOktaAccountRequest, DeleteOktaAccountResponse, OktaAccountRequest, OktaAccountResponse,
+    OktaAccountsResponse, UpdateOktaAccountRequest,
+};
+
+/// Base path of the Okta integration endpoints.
+static BASE_PATH: &str = "api/v2/integrations/okta/accounts";
+
+impl Client {
+    /// List Okta accounts.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/okta-integration/#list-okta-accounts)
+    pub async fn list_okta_accounts(&self) -> Result<OktaAccountsResponse, Error> {
+        let req = self.build_request(Method::GET, BASE_PATH)?;
+
+        self.send_request::<OktaAccountsResponse>(req).await
+    }
+
+    /// Add an Okta account. The API key and client secret are sent to Datadog, but never
+    /// returned.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/okta-integration/#add-okta-account)
+    pub async fn add_okta_account(
+        &self,
+        request: AddOktaAccountRequest,
+    ) -> Result<OktaAccountResponse, Error> {
+        let req = self.build_request(Method::POST, BASE_PATH)?;
+        let req = req.json(&request);
+
+        self.send_request::<OktaAccountResponse>(req).await
+    }
+
+    /// Get an Okta account.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/okta-integration/#get-okta-account)
+    pub async fn get_okta_account(
+        &self,
+        request: OktaAccountRequest,
+    ) -> Result<OktaAccountResponse, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}", BASE_PATH, request.account_id),
+        )?;
+
+        self.send_request::<OktaAccountResponse>(req).await
+    }
+
+    /// Update an Okta account.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/okta-integration/#update-okta-account)
+    pub async fn update_okta_account(
+        &self,
+        request: UpdateOktaAccountRequest,
+    ) -> Result<OktaAccountResponse, Error> {
+        let req = self.build_request(
+            Method::PATCH,
+            &format!("{}/{}", BASE_PATH, request.account_id),
+        )?;
+        let req = req.json(&request.account);
+
+        self.send_request::<OktaAccountResponse>(req).await
+    }
+
+    /// Delete an Okta account.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/okta-integration/#delete-okta-account)
+    pub async fn delete_okta_account(
+        &self,
+        request: OktaAccountRequest,
+    ) -> Result<DeleteOktaAccountResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/{}", BASE_PATH, request.account_id),
+        )?;
+
+        self.send_request::<DeleteOktaAccountResponse>(req).await
+    }
+}
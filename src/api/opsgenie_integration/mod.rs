@@ -0,0 +1,83 @@
+use reqwest::Method;
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::opsgenie_integration::{
+    AddOpsgenieServiceRequest, DeleteOpsgenieServiceResponse, OpsgenieServiceRequest,
+    OpsgenieServiceResponse, OpsgenieServicesResponse, UpdateOpsgenieServiceRequest,
+};
+
+/// Base path of the OpsGenie integration endpoints.
+static BASE_PATH: &str = "api/v2/integration/opsgenie/services";
+
+impl Client {
+    /// Get a list of all services from the Datadog OpsGenie integration.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/opsgenie-integration/#get-all-service-objects)
+    pub async fn list_opsgenie_services(&self) -> Result<OpsgenieServicesResponse, Error> {
+        let req = self.build_request(Method::GET, BASE_PATH)?;
+
+        self.send_request::<OpsgenieServicesResponse>(req).await
+    }
+
+    /// Add a new service to the Datadog OpsGenie integration. The OpsGenie API key is sent to
+    /// Datadog, but never returned.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/opsgenie-integration/#create-a-new-service-object)
+    pub async fn add_opsgenie_service(
+        &self,
+        request: AddOpsgenieServiceRequest,
+    ) -> Result<OpsgenieServiceResponse, Error> {
+        let req = self.build_request(Method::POST, BASE_PATH)?;
+        let req = req.json(&request);
+
+        self.send_request::<OpsgenieServiceResponse>(req).await
+    }
+
+    /// Get a single service from the Datadog OpsGenie integration.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/opsgenie-integration/#get-a-single-service-object)
+    pub async fn get_opsgenie_service(
+        &self,
+        request: OpsgenieServiceRequest,
+    ) -> Result<OpsgenieServiceResponse, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}", BASE_PATH, request.integration_service_id),
+        )?;
+
+        self.send_request::<OpsgenieServiceResponse>(req).await
+    }
+
+    /// Update a single service in the Datadog OpsGenie integration.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/opsgenie-integration/#update-a-single-service-object)
+    pub async fn update_opsgenie_service(
+        &self,
+        request: UpdateOpsgenieServiceRequest,
+    ) -> Result<OpsgenieServiceResponse, Error> {
+        let req = self.build_request(
+            Method::PATCH,
+            &format!("{}/{}", BASE_PATH, request.integration_service_id),
+        )?;
+        let req = req.json(&request.service);
+
+        self.send_request::<OpsgenieServiceResponse>(req).await
+    }
+
+    /// Delete a single service from the Datadog OpsGenie integration.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/opsgenie-integration/#delete-a-single-service-object)
+    pub async fn delete_opsgenie_service(
+        &self,
+        request: OpsgenieServiceRequest,
+    ) -> Result<DeleteOpsgenieServiceResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/{}", BASE_PATH, request.integration_service_id),
+        )?;
+
+        self.send_request::<DeleteOpsgenieServiceResponse>(req)
+            .await
+    }
+}
@@ -0,0 +1,191 @@
+use reqwest::Method;
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::client::Request;
+use crate::models::roles::{
+    CloneRoleRequest, CreateRoleRequest, DeleteRoleRequest, DeleteRoleResponse, GetRoleRequest,
+    ListRolePermissionsRequest, ListRoleUsersRequest, ListRolesRequest, PermissionsResponse,
+    RolePermissionRequest, RoleResponse, RoleUserRequest, RolesResponse, UpdateRoleRequest,
+};
+use crate::models::users::UsersResponse;
+
+/// Base path of the roles endpoints.
+static BASE_PATH: &str = "api/v2/roles";
+/// Path of the permissions endpoint.
+static PERMISSIONS_PATH: &str = "api/v2/permissions";
+
+impl Client {
+    /// Returns all roles, including their names and their unique identifiers.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/roles/#list-roles)
+    pub async fn list_roles(&self, request: ListRolesRequest) -> Result<RolesResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(Method::GET, &format!("{}?{}", BASE_PATH, query))?;
+
+        self.send_request::<RolesResponse>(req).await
+    }
+
+    /// Create a new role for your organization.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/roles/#create-role)
+    pub async fn create_role(&self, request: CreateRoleRequest) -> Result<RoleResponse, Error> {
+        let req = self.build_request(Method::POST, BASE_PATH)?;
+        let req = req.json(&request);
+
+        self.send_request::<RoleResponse>(req).await
+    }
+
+    /// Get a role in the organization specified by the role's `role_id`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/roles/#get-a-role)
+    pub async fn get_role(&self, request: GetRoleRequest) -> Result<RoleResponse, Error> {
+        let req = self.build_request(Method::GET, &format!("{}/{}", BASE_PATH, request.role_id))?;
+
+        self.send_request::<RoleResponse>(req).await
+    }
+
+    /// Edit a role. Can only be used with application keys belonging to administrators.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/roles/#update-a-role)
+    pub async fn update_role(&self, request: UpdateRoleRequest) -> Result<RoleResponse, Error> {
+        let req =
+            self.build_request(Method::PATCH, &format!("{}/{}", BASE_PATH, request.role_id))?;
+        let req = req.json(&request.role);
+
+        self.send_request::<RoleResponse>(req).await
+    }
+
+    /// Disables a role.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/roles/#delete-role)
+    pub async fn delete_role(
+        &self,
+        request: DeleteRoleRequest,
+    ) -> Result<DeleteRoleResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/{}", BASE_PATH, request.role_id),
+        )?;
+
+        self.send_request::<DeleteRoleResponse>(req).await
+    }
+
+    /// Clone an existing role, with its permissions, under a new name.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/roles/#create-a-new-role-by-cloning-an-existing-role)
+    pub async fn clone_role(&self, request: CloneRoleRequest) -> Result<RoleResponse, Error> {
+        let req = self.build_request(
+            Method::POST,
+            &format!("{}/{}/clone", BASE_PATH, request.role_id),
+        )?;
+        let req = req.json(&request.role);
+
+        self.send_request::<RoleResponse>(req).await
+    }
+
+    /// Gets all users of a role.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/roles/#get-all-users-of-a-role)
+    pub async fn list_role_users(
+        &self,
+        request: ListRoleUsersRequest,
+    ) -> Result<UsersResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}/users?{}", BASE_PATH, request.role_id, query),
+        )?;
+
+        self.send_request::<UsersResponse>(req).await
+    }
+
+    /// Adds a user to a role.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/roles/#add-a-user-to-a-role)
+    pub async fn add_user_to_role(&self, request: RoleUserRequest) -> Result<UsersResponse, Error> {
+        let req = self.build_request(
+            Method::POST,
+            &format!("{}/{}/users", BASE_PATH, request.role_id),
+        )?;
+        let req = req.json(&Request { data: request.user });
+
+        self.send_request::<UsersResponse>(req).await
+    }
+
+    /// Removes a user from a role.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/roles/#remove-a-user-from-a-role)
+    pub async fn remove_user_from_role(
+        &self,
+        request: RoleUserRequest,
+    ) -> Result<UsersResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/{}/users", BASE_PATH, request.role_id),
+        )?;
+        let req = req.json(&Request { data: request.user });
+
+        self.send_request::<UsersResponse>(req).await
+    }
+
+    /// Returns a list of all permissions for a single role.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/roles/#list-permissions-for-a-role)
+    pub async fn list_role_permissions(
+        &self,
+        request: ListRolePermissionsRequest,
+    ) -> Result<PermissionsResponse, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}/permissions", BASE_PATH, request.role_id),
+        )?;
+
+        self.send_request::<PermissionsResponse>(req).await
+    }
+
+    /// Adds a permission to a role.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/roles/#grant-permission-to-a-role)
+    pub async fn grant_role_permission(
+        &self,
+        request: RolePermissionRequest,
+    ) -> Result<PermissionsResponse, Error> {
+        let req = self.build_request(
+            Method::POST,
+            &format!("{}/{}/permissions", BASE_PATH, request.role_id),
+        )?;
+        let req = req.json(&Request {
+            data: request.permission,
+        });
+
+        self.send_request::<PermissionsResponse>(req).await
+    }
+
+    /// Removes a permission from a role.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/roles/#revoke-permission)
+    pub async fn revoke_role_permission(
+        &self,
+        request: RolePermissionRequest,
+    ) -> Result<PermissionsResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/{}/permissions", BASE_PATH, request.role_id),
+        )?;
+        let req = req.json(&Request {
+            data: request.permission,
+        });
+
+        self.send_request::<PermissionsResponse>(req).await
+    }
+
+    /// Returns a list of all permissions, including name, description, and ID.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/roles/#list-permissions)
+    pub async fn list_permissions(&self) -> Result<PermissionsResponse, Error> {
+        let req = self.build_request(Method::GET, PERMISSIONS_PATH)?;
+
+        self.send_request::<PermissionsResponse>(req).await
+    }
+}
@@ -0,0 +1,129 @@
+use reqwest::Method;
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::rum::{
+    CreateRumApplicationRequest, DeleteRumApplicationRequest, DeleteRumApplicationResponse,
+    GetRumApplicationRequest, ListRumEventsRequest, ListRumEventsResponse, RumAggregateRequest,
+    RumAggregateResponse, RumApplicationResponse, RumApplicationsResponse, SearchRumEventsRequest,
+    UpdateRumApplicationRequest,
+};
+
+/// Base path of the RUM endpoints.
+static BASE_PATH: &str = "api/v2/rum";
+
+impl Client {
+    /// List all the RUM applications in your organization.
+    ///
+    /// The client token of an application is only returned by [Client::get_rum_application].
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/rum/#list-all-the-rum-applications)
+    pub async fn list_rum_applications(&self) -> Result<RumApplicationsResponse, Error> {
+        let req = self.build_request(Method::GET, &format!("{}/applications", BASE_PATH))?;
+
+        self.send_request::<RumApplicationsResponse>(req).await
+    }
+
+    /// Create a new RUM application in your organization.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/rum/#create-a-new-rum-application)
+    pub async fn create_rum_application(
+        &self,
+        request: CreateRumApplicationRequest,
+    ) -> Result<RumApplicationResponse, Error> {
+        let req = self.build_request(Method::POST, &format!("{}/applications", BASE_PATH))?;
+        let req = req.json(&request);
+
+        self.send_request::<RumApplicationResponse>(req).await
+    }
+
+    /// Get the RUM application with given ID in your organization, including its client token.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/rum/#get-a-rum-application)
+    pub async fn get_rum_application(
+        &self,
+        request: GetRumApplicationRequest,
+    ) -> Result<RumApplicationResponse, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/applications/{}", BASE_PATH, request.id),
+        )?;
+
+        self.send_request::<RumApplicationResponse>(req).await
+    }
+
+    /// Update the RUM application with given ID in your organization.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/rum/#update-a-rum-application)
+    pub async fn update_rum_application(
+        &self,
+        request: UpdateRumApplicationRequest,
+    ) -> Result<RumApplicationResponse, Error> {
+        let req = self.build_request(
+            Method::PATCH,
+            &format!("{}/applications/{}", BASE_PATH, request.id),
+        )?;
+        let req = req.json(&request.application);
+
+        self.send_request::<RumApplicationResponse>(req).await
+    }
+
+    /// Delete an existing RUM application in your organization.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/rum/#delete-a-rum-application)
+    pub async fn delete_rum_application(
+        &self,
+        request: DeleteRumApplicationRequest,
+    ) -> Result<DeleteRumApplicationResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/applications/{}", BASE_PATH, request.id),
+        )?;
+
+        self.send_request::<DeleteRumApplicationResponse>(req).await
+    }
+
+    /// List endpoint returns events that match a RUM search query.
+    /// [Results are paginated](https://docs.datadoghq.com/logs/guide/collect-multiple-logs-with-pagination).
+    /// Use this endpoint to see your latest RUM events.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/rum/#get-a-list-of-rum-events)
+    pub async fn list_rum_events(
+        &self,
+        request: ListRumEventsRequest,
+    ) -> Result<ListRumEventsResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(Method::GET, &format!("{}/events?{}", BASE_PATH, query))?;
+
+        self.send_request::<ListRumEventsResponse>(req).await
+    }
+
+    /// List endpoint returns RUM events that match a RUM search query.
+    /// [Results are paginated](https://docs.datadoghq.com/logs/guide/collect-multiple-logs-with-pagination).
+    /// Use this endpoint to build complex RUM events filtering and search.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/rum/#search-rum-events)
+    pub async fn search_rum_events(
+        &self,
+        request: SearchRumEventsRequest,
+    ) -> Result<ListRumEventsResponse, Error> {
+        let req = self.build_request(Method::POST, &format!("{}/events/search", BASE_PATH))?;
+        let req = req.json(&request);
+
+        self.send_request::<ListRumEventsResponse>(req).await
+    }
+
+    /// The API endpoint to aggregate RUM events into buckets of computed metrics and timeseries.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/rum/#aggregate-rum-events)
+    pub async fn aggregate_rum_events(
+        &self,
+        request: RumAggregateRequest,
+    ) -> Result<RumAggregateResponse, Error> {
+        let req =
+            self.build_request(Method::POST, &format!("{}/analytics/aggregate", BASE_PATH))?;
+        let req = req.json(&request);
+
+        self.send_request::<RumAggregateResponse>(req).await
+    }
+}
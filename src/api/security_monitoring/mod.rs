@@ -0,0 +1,387 @@
+use reqwest::Method;
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::security_monitoring::{
+    CreateSecurityFilterRequest, CreateSecurityMonitoringRuleRequest,
+    CreateSecurityMonitoringSuppressionRequest, DeleteSecurityFilterRequest,
+    DeleteSecurityFilterResponse, DeleteSecurityMonitoringRuleRequest,
+    DeleteSecurityMonitoringRuleResponse, DeleteSecurityMonitoringSuppressionRequest,
+    DeleteSecurityMonitoringSuppressionResponse, EditSecurityMonitoringSignalAssigneeRequest,
+    EditSecurityMonitoringSignalIncidentsRequest, EditSecurityMonitoringSignalStateRequest,
+    GetSecurityFilterRequest, GetSecurityMonitoringRuleRequest, GetSecurityMonitoringSignalRequest,
+    GetSecurityMonitoringSuppressionRequest, ListSecurityMonitoringRulesRequest,
+    ListSecurityMonitoringSignalsRequest, ListSecurityMonitoringSignalsResponse,
+    SearchSecurityMonitoringSignalsRequest, SecurityFilterResponse, SecurityFiltersResponse,
+    SecurityMonitoringListRulesResponse, SecurityMonitoringRule, SecurityMonitoringSignalResponse,
+    SecurityMonitoringSignalTriageUpdateResponse, SecurityMonitoringSuppressionResponse,
+    SecurityMonitoringSuppressionsResponse, UpdateSecurityFilterRequest,
+    UpdateSecurityMonitoringRuleRequest, UpdateSecurityMonitoringSuppressionRequest,
+    ValidateSecurityMonitoringRuleRequest, ValidateSecurityMonitoringRuleResponse,
+};
+
+/// Base path of the security monitoring endpoints.
+static BASE_PATH: &str = "api/v2/security_monitoring";
+
+impl Client {
+    /// List rules.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/security-monitoring/#list-rules)
+    pub async fn list_security_monitoring_rules(
+        &self,
+        request: ListSecurityMonitoringRulesRequest,
+    ) -> Result<SecurityMonitoringListRulesResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(Method::GET, &format!("{}/rules?{}", BASE_PATH, query))?;
+
+        self.send_request::<SecurityMonitoringListRulesResponse>(req)
+            .await
+    }
+
+    /// Create a detection rule.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/security-monitoring/#create-a-detection-rule)
+    pub async fn create_security_monitoring_rule(
+        &self,
+        request: CreateSecurityMonitoringRuleRequest,
+    ) -> Result<SecurityMonitoringRule, Error> {
+        let req = self.build_request(Method::POST, &format!("{}/rules", BASE_PATH))?;
+        let req = req.json(&request);
+
+        self.send_request::<SecurityMonitoringRule>(req).await
+    }
+
+    /// Get a rule's details.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/security-monitoring/#get-a-rules-details)
+    pub async fn get_security_monitoring_rule(
+        &self,
+        request: GetSecurityMonitoringRuleRequest,
+    ) -> Result<SecurityMonitoringRule, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/rules/{}", BASE_PATH, request.rule_id),
+        )?;
+
+        self.send_request::<SecurityMonitoringRule>(req).await
+    }
+
+    /// Update an existing rule. When updating `cases`, `queries` or `options`, the whole field
+    /// must be included. Default rules can only be updated to be enabled, to change notifications,
+    /// or to update the tags.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/security-monitoring/#update-an-existing-rule)
+    pub async fn update_security_monitoring_rule(
+        &self,
+        request: UpdateSecurityMonitoringRuleRequest,
+    ) -> Result<SecurityMonitoringRule, Error> {
+        let req = self.build_request(
+            Method::PUT,
+            &format!("{}/rules/{}", BASE_PATH, request.rule_id),
+        )?;
+        let req = req.json(&request.rule);
+
+        self.send_request::<SecurityMonitoringRule>(req).await
+    }
+
+    /// Delete an existing rule. Default rules cannot be deleted.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/security-monitoring/#delete-an-existing-rule)
+    pub async fn delete_security_monitoring_rule(
+        &self,
+        request: DeleteSecurityMonitoringRuleRequest,
+    ) -> Result<DeleteSecurityMonitoringRuleResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/rules/{}", BASE_PATH, request.rule_id),
+        )?;
+
+        self.send_request::<DeleteSecurityMonitoringRuleResponse>(req)
+            .await
+    }
+
+    /// Validate a detection rule. An invalid rule is reported as [Error::InvalidRequest]
+    /// with the reasons given by Datadog.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/security-monitoring/#validate-a-detection-rule)
+    pub async fn validate_security_monitoring_rule(
+        &self,
+        request: ValidateSecurityMonitoringRuleRequest,
+    ) -> Result<ValidateSecurityMonitoringRuleResponse, Error> {
+        let req = self.build_request(Method::POST, &format!("{}/rules/validation", BASE_PATH))?;
+        let req = req.json(&request);
+
+        self.send_request::<ValidateSecurityMonitoringRuleResponse>(req)
+            .await
+    }
+
+    /// The list endpoint returns security signals that match a search query.
+    /// Both this endpoint and the POST endpoint can be used interchangeably when listing security signals.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/security-monitoring/#get-a-quick-list-of-security-signals)
+    pub async fn list_security_monitoring_signals(
+        &self,
+        request: ListSecurityMonitoringSignalsRequest,
+    ) -> Result<ListSecurityMonitoringSignalsResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(Method::GET, &format!("{}/signals?{}", BASE_PATH, query))?;
+
+        self.send_request::<ListSecurityMonitoringSignalsResponse>(req)
+            .await
+    }
+
+    /// Returns security signals that match a search query.
+    /// Both this endpoint and the GET endpoint can be used interchangeably for listing security signals.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/security-monitoring/#get-a-list-of-security-signals)
+    pub async fn search_security_monitoring_signals(
+        &self,
+        request: SearchSecurityMonitoringSignalsRequest,
+    ) -> Result<ListSecurityMonitoringSignalsResponse, Error> {
+        let req = self.build_request(Method::POST, &format!("{}/signals/search", BASE_PATH))?;
+        let req = req.json(&request);
+
+        self.send_request::<ListSecurityMonitoringSignalsResponse>(req)
+            .await
+    }
+
+    /// Get a signal's details.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/security-monitoring/#get-a-signals-details)
+    pub async fn get_security_monitoring_signal(
+        &self,
+        request: GetSecurityMonitoringSignalRequest,
+    ) -> Result<SecurityMonitoringSignalResponse, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/signals/{}", BASE_PATH, request.signal_id),
+        )?;
+
+        self.send_request::<SecurityMonitoringSignalResponse>(req)
+            .await
+    }
+
+    /// Modify the triage assignee of a security signal.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/security-monitoring/#modify-the-triage-assignee-of-a-security-signal)
+    pub async fn edit_security_monitoring_signal_assignee(
+        &self,
+        request: EditSecurityMonitoringSignalAssigneeRequest,
+    ) -> Result<SecurityMonitoringSignalTriageUpdateResponse, Error> {
+        let req = self.build_request(
+            Method::PATCH,
+            &format!("{}/signals/{}/assignee", BASE_PATH, request.signal_id),
+        )?;
+        let req = req.json(&request.assignee);
+
+        self.send_request::<SecurityMonitoringSignalTriageUpdateResponse>(req)
+            .await
+    }
+
+    /// Change the related incidents for a security signal.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/security-monitoring/#change-the-related-incidents-of-a-security-signal)
+    pub async fn edit_security_monitoring_signal_incidents(
+        &self,
+        request: EditSecurityMonitoringSignalIncidentsRequest,
+    ) -> Result<SecurityMonitoringSignalTriageUpdateResponse, Error> {
+        let req = self.build_request(
+            Method::PATCH,
+            &format!("{}/signals/{}/incidents", BASE_PATH, request.signal_id),
+        )?;
+        let req = req.json(&request.incidents);
+
+        self.send_request::<SecurityMonitoringSignalTriageUpdateResponse>(req)
+            .await
+    }
+
+    /// Change the triage state of a security signal.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/security-monitoring/#change-the-triage-state-of-a-security-signal)
+    pub async fn edit_security_monitoring_signal_state(
+        &self,
+        request: EditSecurityMonitoringSignalStateRequest,
+    ) -> Result<SecurityMonitoringSignalTriageUpdateResponse, Error> {
+        let req = self.build_request(
+            Method::PATCH,
+            &format!("{}/signals/{}/state", BASE_PATH, request.signal_id),
+        )?;
+        let req = req.json(&request.state);
+
+        self.send_request::<SecurityMonitoringSignalTriageUpdateResponse>(req)
+            .await
+    }
+
+    /// Get the list of all suppression rules.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/security-monitoring/#get-all-suppression-rules)
+    pub async fn list_security_monitoring_suppressions(
+        &self,
+    ) -> Result<SecurityMonitoringSuppressionsResponse, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/configuration/suppressions", BASE_PATH),
+        )?;
+
+        self.send_request::<SecurityMonitoringSuppressionsResponse>(req)
+            .await
+    }
+
+    /// Create a new suppression rule.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/security-monitoring/#create-a-suppression-rule)
+    pub async fn create_security_monitoring_suppression(
+        &self,
+        request: CreateSecurityMonitoringSuppressionRequest,
+    ) -> Result<SecurityMonitoringSuppressionResponse, Error> {
+        let req = self.build_request(
+            Method::POST,
+            &format!("{}/configuration/suppressions", BASE_PATH),
+        )?;
+        let req = req.json(&request);
+
+        self.send_request::<SecurityMonitoringSuppressionResponse>(req)
+            .await
+    }
+
+    /// Get the details of a specific suppression rule.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/security-monitoring/#get-a-suppression-rule)
+    pub async fn get_security_monitoring_suppression(
+        &self,
+        request: GetSecurityMonitoringSuppressionRequest,
+    ) -> Result<SecurityMonitoringSuppressionResponse, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!(
+                "{}/configuration/suppressions/{}",
+                BASE_PATH, request.suppression_id
+            ),
+        )?;
+
+        self.send_request::<SecurityMonitoringSuppressionResponse>(req)
+            .await
+    }
+
+    /// Update a specific suppression rule.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/security-monitoring/#update-a-suppression-rule)
+    pub async fn update_security_monitoring_suppression(
+        &self,
+        request: UpdateSecurityMonitoringSuppressionRequest,
+    ) -> Result<SecurityMonitoringSuppressionResponse, Error> {
+        let req = self.build_request(
+            Method::PATCH,
+            &format!(
+                "{}/configuration/suppressions/{}",
+                BASE_PATH, request.suppression_id
+            ),
+        )?;
+        let req = req.json(&request.suppression);
+
+        self.send_request::<SecurityMonitoringSuppressionResponse>(req)
+            .await
+    }
+
+    /// Delete a specific suppression rule.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/security-monitoring/#delete-a-suppression-rule)
+    pub async fn delete_security_monitoring_suppression(
+        &self,
+        request: DeleteSecurityMonitoringSuppressionRequest,
+    ) -> Result<DeleteSecurityMonitoringSuppressionResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!(
+                "{}/configuration/suppressions/{}",
+                BASE_PATH, request.suppression_id
+            ),
+        )?;
+
+        self.send_request::<DeleteSecurityMonitoringSuppressionResponse>(req)
+            .await
+    }
+
+    /// Get the list of configured security filters with their definitions.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/security-monitoring/#get-all-security-filters)
+    pub async fn list_security_filters(&self) -> Result<SecurityFiltersResponse, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/configuration/security_filters", BASE_PATH),
+        )?;
+
+        self.send_request::<SecurityFiltersResponse>(req).await
+    }
+
+    /// Create a security filter.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/security-monitoring/#create-a-security-filter)
+    pub async fn create_security_filter(
+        &self,
+        request: CreateSecurityFilterRequest,
+    ) -> Result<SecurityFilterResponse, Error> {
+        let req = self.build_request(
+            Method::POST,
+            &format!("{}/configuration/security_filters", BASE_PATH),
+        )?;
+        let req = req.json(&request);
+
+        self.send_request::<SecurityFilterResponse>(req).await
+    }
+
+    /// Get the details of a specific security filter.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/security-monitoring/#get-a-security-filter)
+    pub async fn get_security_filter(
+        &self,
+        request: GetSecurityFilterRequest,
+    ) -> Result<SecurityFilterResponse, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!(
+                "{}/configuration/security_filters/{}",
+                BASE_PATH, request.security_filter_id
+            ),
+        )?;
+
+        self.send_request::<SecurityFilterResponse>(req).await
+    }
+
+    /// Update a specific security filter.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/security-monitoring/#update-a-security-filter)
+    pub async fn update_security_filter(
+        &self,
+        request: UpdateSecurityFilterRequest,
+    ) -> Result<SecurityFilterResponse, Error> {
+        let req = self.build_request(
+            Method::PATCH,
+            &format!(
+                "{}/configuration/security_filters/{}",
+                BASE_PATH, request.security_filter_id
+            ),
+        )?;
+        let req = req.json(&request.security_filter);
+
+        self.send_request::<SecurityFilterResponse>(req).await
+    }
+
+    /// Delete a specific security filter.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/security-monitoring/#delete-a-security-filter)
+    pub async fn delete_security_filter(
+        &self,
+        request: DeleteSecurityFilterRequest,
+    ) -> Result<DeleteSecurityFilterResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!(
+                "{}/configuration/security_filters/{}",
+                BASE_PATH, request.security_filter_id
+            ),
+        )?;
+
+        self.send_request::<DeleteSecurityFilterResponse>(req).await
+    }
+}
@@ -0,0 +1,164 @@
+use reqwest::Method;
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::sensitive_data_scanner::{
+    DeleteScanningGroupRequest, DeleteScanningRuleRequest, SensitiveDataScannerConfigRequest,
+    SensitiveDataScannerCreateGroupResponse, SensitiveDataScannerCreateRuleResponse,
+    SensitiveDataScannerGetConfigResponse, SensitiveDataScannerGroupCreateRequest,
+    SensitiveDataScannerGroupDeleteResponse, SensitiveDataScannerGroupUpdateResponse,
+    SensitiveDataScannerReorderGroupsResponse, SensitiveDataScannerRuleCreateRequest,
+    SensitiveDataScannerRuleDeleteResponse, SensitiveDataScannerRuleUpdateResponse,
+    SensitiveDataScannerStandardPatternsResponse, UpdateScanningGroupRequest,
+    UpdateScanningRuleRequest,
+};
+
+/// Base path of the Sensitive Data Scanner endpoints.
+static BASE_PATH: &str = "api/v2/sensitive-data-scanner/config";
+
+impl Client {
+    /// List all the Scanning groups in your organization, along with their rules.
+    /// The `meta.version` of the response is the version to send with the next change.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/sensitive-data-scanner/#list-scanning-groups)
+    pub async fn list_scanning_groups(
+        &self,
+    ) -> Result<SensitiveDataScannerGetConfigResponse, Error> {
+        let req = self.build_request(Method::GET, BASE_PATH)?;
+
+        self.send_request::<SensitiveDataScannerGetConfigResponse>(req)
+            .await
+    }
+
+    /// Reorder the list of groups.
+    /// Fails with [Error::VersionConflict] if the configuration changed since `meta.version`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/sensitive-data-scanner/#reorder-groups)
+    pub async fn reorder_scanning_groups(
+        &self,
+        request: SensitiveDataScannerConfigRequest,
+    ) -> Result<SensitiveDataScannerReorderGroupsResponse, Error> {
+        let req = self.build_request(Method::PATCH, BASE_PATH)?;
+        let req = req.json(&request);
+
+        self.send_versioned_request::<SensitiveDataScannerReorderGroupsResponse>(req)
+            .await
+    }
+
+    /// Create a scanning group. The group is added at the end of the configuration's groups,
+    /// and the rules relationship must be empty.
+    /// Fails with [Error::VersionConflict] if the configuration changed since `meta.version`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/sensitive-data-scanner/#create-scanning-group)
+    pub async fn create_scanning_group(
+        &self,
+        request: SensitiveDataScannerGroupCreateRequest,
+    ) -> Result<SensitiveDataScannerCreateGroupResponse, Error> {
+        let req = self.build_request(Method::POST, &format!("{}/groups", BASE_PATH))?;
+        let req = req.json(&request);
+
+        self.send_versioned_request::<SensitiveDataScannerCreateGroupResponse>(req)
+            .await
+    }
+
+    /// Update a group, including the order of the rules. Rules cannot be added or removed
+    /// through this endpoint, only reordered.
+    /// Fails with [Error::VersionConflict] if the configuration changed since `meta.version`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/sensitive-data-scanner/#update-scanning-group)
+    pub async fn update_scanning_group(
+        &self,
+        request: UpdateScanningGroupRequest,
+    ) -> Result<SensitiveDataScannerGroupUpdateResponse, Error> {
+        let req = self.build_request(
+            Method::PATCH,
+            &format!("{}/groups/{}", BASE_PATH, request.group_id),
+        )?;
+        let req = req.json(&request.group);
+
+        self.send_versioned_request::<SensitiveDataScannerGroupUpdateResponse>(req)
+            .await
+    }
+
+    /// Delete a given group.
+    /// Fails with [Error::VersionConflict] if the configuration changed since `meta.version`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/sensitive-data-scanner/#delete-scanning-group)
+    pub async fn delete_scanning_group(
+        &self,
+        request: DeleteScanningGroupRequest,
+    ) -> Result<SensitiveDataScannerGroupDeleteResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/groups/{}", BASE_PATH, request.group_id),
+        )?;
+        let req = req.json(&request.body);
+
+        self.send_versioned_request::<SensitiveDataScannerGroupDeleteResponse>(req)
+            .await
+    }
+
+    /// Create a scanning rule in a sensitive data scanner group, ordered last.
+    /// The `pattern` attribute is ignored when a standard pattern relationship is given.
+    /// Fails with [Error::VersionConflict] if the configuration changed since `meta.version`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/sensitive-data-scanner/#create-scanning-rule)
+    pub async fn create_scanning_rule(
+        &self,
+        request: SensitiveDataScannerRuleCreateRequest,
+    ) -> Result<SensitiveDataScannerCreateRuleResponse, Error> {
+        let req = self.build_request(Method::POST, &format!("{}/rules", BASE_PATH))?;
+        let req = req.json(&request);
+
+        self.send_versioned_request::<SensitiveDataScannerCreateRuleResponse>(req)
+            .await
+    }
+
+    /// Update a scanning rule. The group relationship of a rule cannot be changed.
+    /// Fails with [Error::VersionConflict] if the configuration changed since `meta.version`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/sensitive-data-scanner/#update-scanning-rule)
+    pub async fn update_scanning_rule(
+        &self,
+        request: UpdateScanningRuleRequest,
+    ) -> Result<SensitiveDataScannerRuleUpdateResponse, Error> {
+        let req = self.build_request(
+            Method::PATCH,
+            &format!("{}/rules/{}", BASE_PATH, request.rule_id),
+        )?;
+        let req = req.json(&request.rule);
+
+        self.send_versioned_request::<SensitiveDataScannerRuleUpdateResponse>(req)
+            .await
+    }
+
+    /// Delete a given rule.
+    /// Fails with [Error::VersionConflict] if the configuration changed since `meta.version`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/sensitive-data-scanner/#delete-scanning-rule)
+    pub async fn delete_scanning_rule(
+        &self,
+        request: DeleteScanningRuleRequest,
+    ) -> Result<SensitiveDataScannerRuleDeleteResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/rules/{}", BASE_PATH, request.rule_id),
+        )?;
+        let req = req.json(&request.body);
+
+        self.send_versioned_request::<SensitiveDataScannerRuleDeleteResponse>(req)
+            .await
+    }
+
+    /// Returns all standard patterns of the library.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/sensitive-data-scanner/#list-standard-patterns)
+    pub async fn list_standard_patterns(
+        &self,
+    ) -> Result<SensitiveDataScannerStandardPatternsResponse, Error> {
+        let req = self.build_request(Method::GET, &format!("{}/standard-patterns", BASE_PATH))?;
+
+        self.send_request::<SensitiveDataScannerStandardPatternsResponse>(req)
+            .await
+    }
+}
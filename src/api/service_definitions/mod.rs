@@ -0,0 +1,77 @@
+use reqwest::Method;
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::service_definitions::{
+    CreateOrUpdateServiceDefinitionRequest, DeleteServiceDefinitionRequest,
+    DeleteServiceDefinitionResponse, GetServiceDefinitionRequest, ListServiceDefinitionsRequest,
+    ServiceDefinitionCreateResponse, ServiceDefinitionGetResponse, ServiceDefinitionsListResponse,
+};
+
+/// Base path of the service definitions endpoints.
+static BASE_PATH: &str = "api/v2/services/definitions";
+
+impl Client {
+    /// Get a list of all service definitions from the Datadog Service Catalog.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/service-definition/#get-all-service-definitions)
+    pub async fn list_service_definitions(
+        &self,
+        request: ListServiceDefinitionsRequest,
+    ) -> Result<ServiceDefinitionsListResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(Method::GET, &format!("{}?{}", BASE_PATH, query))?;
+
+        self.send_request::<ServiceDefinitionsListResponse>(req)
+            .await
+    }
+
+    /// Create or update a service definition in the Datadog Service Catalog.
+    ///
+    /// Definitions read from a `service.datadog.yaml` file can be parsed with
+    /// [ServiceDefinitionSchema::from_yaml](crate::models::service_definitions::ServiceDefinitionSchema::from_yaml).
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/service-definition/#create-or-update-service-definition)
+    pub async fn create_or_update_service_definition(
+        &self,
+        request: CreateOrUpdateServiceDefinitionRequest,
+    ) -> Result<ServiceDefinitionCreateResponse, Error> {
+        let req = self.build_request(Method::POST, BASE_PATH)?;
+        let req = req.json(&request);
+
+        self.send_request::<ServiceDefinitionCreateResponse>(req)
+            .await
+    }
+
+    /// Get a single service definition from the Datadog Service Catalog.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/service-definition/#get-a-single-service-definition)
+    pub async fn get_service_definition(
+        &self,
+        request: GetServiceDefinitionRequest,
+    ) -> Result<ServiceDefinitionGetResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}?{}", BASE_PATH, request.service_name, query),
+        )?;
+
+        self.send_request::<ServiceDefinitionGetResponse>(req).await
+    }
+
+    /// Delete a single service definition in the Datadog Service Catalog.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/service-definition/#delete-a-single-service-definition)
+    pub async fn delete_service_definition(
+        &self,
+        request: DeleteServiceDefinitionRequest,
+    ) -> Result<DeleteServiceDefinitionResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/{}", BASE_PATH, request.service_name),
+        )?;
+
+        self.send_request::<DeleteServiceDefinitionResponse>(req)
+            .await
+    }
+}
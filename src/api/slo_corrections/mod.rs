@@ -0,0 +1,86 @@
+use reqwest::Method;
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::slo_corrections::{
+    CreateSloCorrectionRequest, DeleteSloCorrectionRequest, DeleteSloCorrectionResponse,
+    GetSloCorrectionRequest, ListSloCorrectionsRequest, ListSloCorrectionsResponse,
+    SloCorrectionResponse, UpdateSloCorrectionRequest,
+};
+
+/// Base path of the SLO corrections endpoints.
+static BASE_PATH: &str = "api/v1/slo/correction";
+
+impl Client {
+    /// Get all Service Level Objective corrections.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/service-level-objective-corrections/#get-all-slo-corrections)
+    pub async fn list_slo_corrections(
+        &self,
+        request: ListSloCorrectionsRequest,
+    ) -> Result<ListSloCorrectionsResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(Method::GET, &format!("{}?{}", BASE_PATH, query))?;
+
+        self.send_request::<ListSloCorrectionsResponse>(req).await
+    }
+
+    /// Create an SLO correction.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/service-level-objective-corrections/#create-an-slo-correction)
+    pub async fn create_slo_correction(
+        &self,
+        request: CreateSloCorrectionRequest,
+    ) -> Result<SloCorrectionResponse, Error> {
+        let req = self.build_request(Method::POST, BASE_PATH)?;
+        let req = req.json(&request);
+
+        self.send_request::<SloCorrectionResponse>(req).await
+    }
+
+    /// Get an SLO correction.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/service-level-objective-corrections/#get-an-slo-correction-for-an-slo)
+    pub async fn get_slo_correction(
+        &self,
+        request: GetSloCorrectionRequest,
+    ) -> Result<SloCorrectionResponse, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}", BASE_PATH, request.slo_correction_id),
+        )?;
+
+        self.send_request::<SloCorrectionResponse>(req).await
+    }
+
+    /// Update the specified SLO correction object.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/service-level-objective-corrections/#update-an-slo-correction)
+    pub async fn update_slo_correction(
+        &self,
+        request: UpdateSloCorrectionRequest,
+    ) -> Result<SloCorrectionResponse, Error> {
+        let req = self.build_request(
+            Method::PATCH,
+            &format!("{}/{}", BASE_PATH, request.slo_correction_id),
+        )?;
+        let req = req.json(&request.correction);
+
+        self.send_request::<SloCorrectionResponse>(req).await
+    }
+
+    /// Permanently delete the specified SLO correction object.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/service-level-objective-corrections/#delete-an-slo-correction)
+    pub async fn delete_slo_correction(
+        &self,
+        request: DeleteSloCorrectionRequest,
+    ) -> Result<DeleteSloCorrectionResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/{}", BASE_PATH, request.slo_correction_id),
+        )?;
+
+        self.send_request(req).await
+    }
+}
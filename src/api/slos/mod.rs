@@ -0,0 +1,139 @@
+use reqwest::{Method, StatusCode};
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::slos::{
+    CheckCanDeleteSloRequest, CheckCanDeleteSloResponse, CreateSloRequest, DeleteSloRequest,
+    GetSloHistoryRequest, GetSloRequest, ListSlosRequest, SearchSloResponse, SearchSlosRequest,
+    SloBulkDeleteRequest, SloBulkDeleteResponse, SloDeleteResponse, SloHistoryResponse,
+    SloListResponse, SloResponse, UpdateSloRequest,
+};
+
+/// Base path of the service level objectives endpoints.
+static BASE_PATH: &str = "api/v1/slo";
+
+impl Client {
+    /// Get a list of service level objective objects for your organization.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/service-level-objectives/#get-all-slos)
+    pub async fn list_slos(&self, request: ListSlosRequest) -> Result<SloListResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(Method::GET, &format!("{}?{}", BASE_PATH, query))?;
+
+        self.send_request::<SloListResponse>(req).await
+    }
+
+    /// Create a service level objective object.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/service-level-objectives/#create-an-slo-object)
+    pub async fn create_slo(&self, request: CreateSloRequest) -> Result<SloListResponse, Error> {
+        let req = self.build_request(Method::POST, BASE_PATH)?;
+        let req = req.json(&request);
+
+        self.send_request::<SloListResponse>(req).await
+    }
+
+    /// Get a service level objective object.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/service-level-objectives/#get-an-slos-details)
+    pub async fn get_slo(&self, request: GetSloRequest) -> Result<SloResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}?{}", BASE_PATH, request.slo_id, query),
+        )?;
+
+        self.send_request::<SloResponse>(req).await
+    }
+
+    /// Update the specified service level objective object.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/service-level-objectives/#update-an-slo)
+    pub async fn update_slo(&self, request: UpdateSloRequest) -> Result<SloListResponse, Error> {
+        let req = self.build_request(Method::PUT, &format!("{}/{}", BASE_PATH, request.slo_id))?;
+        let req = req.json(&request.slo);
+
+        self.send_request::<SloListResponse>(req).await
+    }
+
+    /// Permanently delete the specified service level objective object.
+    ///
+    /// If an SLO is used in a dashboard, the request returns a `409 Conflict` error
+    /// unless `force` is set.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/service-level-objectives/#delete-an-slo)
+    pub async fn delete_slo(&self, request: DeleteSloRequest) -> Result<SloDeleteResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/{}?{}", BASE_PATH, request.slo_id, query),
+        )?;
+
+        self.send_request::<SloDeleteResponse>(req).await
+    }
+
+    /// Delete (or partially delete) multiple service level objective objects.
+    /// Deleting all the thresholds of an SLO deletes the SLO.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/service-level-objectives/#bulk-delete-slo-timeframes)
+    pub async fn bulk_delete_slos(
+        &self,
+        request: SloBulkDeleteRequest,
+    ) -> Result<SloBulkDeleteResponse, Error> {
+        let req = self.build_request(Method::POST, &format!("{}/bulk_delete", BASE_PATH))?;
+        let req = req.json(&request);
+
+        self.send_request::<SloBulkDeleteResponse>(req).await
+    }
+
+    /// Get a specific SLO's history, regardless of its SLO type.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/service-level-objectives/#get-an-slos-history)
+    pub async fn get_slo_history(
+        &self,
+        request: GetSloHistoryRequest,
+    ) -> Result<SloHistoryResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}/history?{}", BASE_PATH, request.slo_id, query),
+        )?;
+
+        self.send_request::<SloHistoryResponse>(req).await
+    }
+
+    /// Get a list of service level objective objects for your organization, matching a search query.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/service-level-objectives/#search-for-slos)
+    pub async fn search_slos(
+        &self,
+        request: SearchSlosRequest,
+    ) -> Result<SearchSloResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(Method::GET, &format!("{}/search?{}", BASE_PATH, query))?;
+
+        self.send_request::<SearchSloResponse>(req).await
+    }
+
+    /// Check if the given SLOs can be deleted.
+    ///
+    /// SLOs referenced by other resources are listed in the `errors` of the response
+    /// rather than failing the call, even though Datadog answers with `409 Conflict`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/service-level-objectives/#check-if-slos-can-be-safely-deleted)
+    pub async fn can_delete_slos(
+        &self,
+        request: CheckCanDeleteSloRequest,
+    ) -> Result<CheckCanDeleteSloResponse, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/can_delete?ids={}", BASE_PATH, request.ids.join(",")),
+        )?;
+
+        self.send_request_accepting::<CheckCanDeleteSloResponse>(
+            req,
+            &[StatusCode::OK, StatusCode::CONFLICT],
+        )
+        .await
+    }
+}
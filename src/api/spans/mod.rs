@@ -0,0 +1,54 @@
+use reqwest::Method;
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::spans::{
+    AggregateSpansRequest, ListSpansGetRequest, ListSpansRequest, ListSpansResponse,
+    SpansAggregateResponse,
+};
+
+/// Base path of the spans endpoints.
+static BASE_PATH: &str = "api/v2/spans";
+
+impl Client {
+    /// List endpoint returns spans that match a span search query.
+    /// [Results are paginated](https://docs.datadoghq.com/logs/guide/collect-multiple-logs-with-pagination).
+    /// Use this endpoint to see your latest spans.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/spans/#get-a-list-of-spans)
+    pub async fn list_spans_get(
+        &self,
+        request: ListSpansGetRequest,
+    ) -> Result<ListSpansResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(Method::GET, &format!("{}/events?{}", BASE_PATH, query))?;
+
+        self.send_request::<ListSpansResponse>(req).await
+    }
+
+    /// List endpoint returns spans that match a span search query.
+    /// [Results are paginated](https://docs.datadoghq.com/logs/guide/collect-multiple-logs-with-pagination).
+    /// Use this endpoint to build complex spans filtering and search.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/spans/#search-spans)
+    pub async fn list_spans(&self, request: ListSpansRequest) -> Result<ListSpansResponse, Error> {
+        let req = self.build_request(Method::POST, &format!("{}/events/search", BASE_PATH))?;
+        let req = req.json(&request);
+
+        self.send_request::<ListSpansResponse>(req).await
+    }
+
+    /// The API endpoint to aggregate spans into buckets and compute metrics and timeseries.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/spans/#aggregate-spans)
+    pub async fn aggregate_spans(
+        &self,
+        request: AggregateSpansRequest,
+    ) -> Result<SpansAggregateResponse, Error> {
+        let req =
+            self.build_request(Method::POST, &format!("{}/analytics/aggregate", BASE_PATH))?;
+        let req = req.json(&request);
+
+        self.send_request::<SpansAggregateResponse>(req).await
+    }
+}
@@ -0,0 +1,60 @@
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::spans_metrics::{
+    CreateSpansMetricRequest, DeleteSpansMetricRequest, DeleteSpansMetricResponse,
+    GetSpansMetricRequest, ListSpansMetricsResponse, SpansMetricResponse, UpdateSpansMetricRequest,
+};
+
+/// Base path of the span-based metrics endpoints.
+static BASE_PATH: &str = "api/v2/apm/config/metrics";
+
+impl Client {
+    /// Get the list of configured span-based metrics with their definitions.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/spans-metrics/#get-all-span-based-metrics)
+    pub async fn list_spans_metrics(&self) -> Result<ListSpansMetricsResponse, Error> {
+        self.list_generated_metrics(BASE_PATH).await
+    }
+
+    /// Create a metric based on your ingested spans in your organization.
+    /// Returns the span-based metric object from the request body when the request is successful.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/spans-metrics/#create-a-span-based-metric)
+    pub async fn create_spans_metric(
+        &self,
+        request: CreateSpansMetricRequest,
+    ) -> Result<SpansMetricResponse, Error> {
+        self.create_generated_metric(BASE_PATH, request).await
+    }
+
+    /// Get a specific span-based metric from your organization.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/spans-metrics/#get-a-span-based-metric)
+    pub async fn get_spans_metric(
+        &self,
+        request: GetSpansMetricRequest,
+    ) -> Result<SpansMetricResponse, Error> {
+        self.get_generated_metric(BASE_PATH, request).await
+    }
+
+    /// Update a specific span-based metric from your organization.
+    /// Returns the span-based metric object from the request body when the request is successful.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/spans-metrics/#update-a-span-based-metric)
+    pub async fn update_spans_metric(
+        &self,
+        request: UpdateSpansMetricRequest,
+    ) -> Result<SpansMetricResponse, Error> {
+        self.update_generated_metric(BASE_PATH, request).await
+    }
+
+    /// Delete a specific span-based metric from your organization.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/spans-metrics/#delete-a-span-based-metric)
+    pub async fn delete_spans_metric(
+        &self,
+        request: DeleteSpansMetricRequest,
+    ) -> Result<DeleteSpansMetricResponse, Error> {
+        self.delete_generated_metric(BASE_PATH, request).await
+    }
+}
@@ -0,0 +1,469 @@
+use std::time::{Duration, Instant};
+
+use reqwest::Method;
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::synthetics::{
+    CreateSyntheticsApiTestRequest, CreateSyntheticsBrowserTestRequest,
+    CreateSyntheticsGlobalVariableRequest, CreateSyntheticsPrivateLocationRequest,
+    DeleteSyntheticsGlobalVariableRequest, DeleteSyntheticsGlobalVariableResponse,
+    DeleteSyntheticsPrivateLocationRequest, DeleteSyntheticsPrivateLocationResponse,
+    DeleteSyntheticsTestsRequest, DeleteSyntheticsTestsResponse, GetSyntheticsCiBatchRequest,
+    GetSyntheticsGlobalVariableRequest, GetSyntheticsPrivateLocationRequest,
+    GetSyntheticsTestRequest, GetSyntheticsTestResultRequest,
+    ListSyntheticsGlobalVariablesResponse, ListSyntheticsLocationsResponse,
+    ListSyntheticsTestResultsRequest, ListSyntheticsTestResultsResponse,
+    ListSyntheticsTestsRequest, ListSyntheticsTestsResponse, SyntheticsApiTest,
+    SyntheticsBatchDetails, SyntheticsBrowserTest, SyntheticsGlobalVariable,
+    SyntheticsPrivateLocation, SyntheticsPrivateLocationCreationResponse, SyntheticsStatus,
+    SyntheticsTestDetails, SyntheticsTestResult, SyntheticsTriggerCiTestsResponse,
+    TriggerCiSyntheticsTestsRequest, TriggerSyntheticsTestsRequest, UpdateSyntheticsApiTestRequest,
+    UpdateSyntheticsBrowserTestRequest, UpdateSyntheticsGlobalVariableRequest,
+    UpdateSyntheticsPrivateLocationRequest, UpdateSyntheticsTestStatusRequest,
+    UpdateSyntheticsTestStatusResponse,
+};
+
+/// Base path of the Synthetics endpoints.
+static BASE_PATH: &str = "api/v1/synthetics";
+
+impl Client {
+    /// Get the list of all Synthetic tests.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/synthetics/#get-the-list-of-all-synthetic-tests)
+    pub async fn list_synthetics_tests(
+        &self,
+        request: ListSyntheticsTestsRequest,
+    ) -> Result<ListSyntheticsTestsResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(Method::GET, &format!("{}/tests?{}", BASE_PATH, query))?;
+
+        self.send_request::<ListSyntheticsTestsResponse>(req).await
+    }
+
+    /// Get the detailed configuration associated with a Synthetic test, whatever its type.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/synthetics/#get-a-test-configuration)
+    pub async fn get_synthetics_test(
+        &self,
+        request: GetSyntheticsTestRequest,
+    ) -> Result<SyntheticsTestDetails, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/tests/{}", BASE_PATH, request.public_id),
+        )?;
+
+        self.send_request::<SyntheticsTestDetails>(req).await
+    }
+
+    /// Create a Synthetic API test.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/synthetics/#create-an-api-test)
+    pub async fn create_synthetics_api_test(
+        &self,
+        request: CreateSyntheticsApiTestRequest,
+    ) -> Result<SyntheticsApiTest, Error> {
+        let req = self.build_request(Method::POST, &format!("{}/tests/api", BASE_PATH))?;
+        let req = req.json(&request);
+
+        self.send_request::<SyntheticsApiTest>(req).await
+    }
+
+    /// Get the detailed configuration associated with a Synthetic API test.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/synthetics/#get-an-api-test)
+    pub async fn get_synthetics_api_test(
+        &self,
+        request: GetSyntheticsTestRequest,
+    ) -> Result<SyntheticsApiTest, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/tests/api/{}", BASE_PATH, request.public_id),
+        )?;
+
+        self.send_request::<SyntheticsApiTest>(req).await
+    }
+
+    /// Edit the configuration of a Synthetic API test.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/synthetics/#edit-an-api-test)
+    pub async fn update_synthetics_api_test(
+        &self,
+        request: UpdateSyntheticsApiTestRequest,
+    ) -> Result<SyntheticsApiTest, Error> {
+        let req = self.build_request(
+            Method::PUT,
+            &format!("{}/tests/api/{}", BASE_PATH, request.public_id),
+        )?;
+        let req = req.json(&request.test);
+
+        self.send_request::<SyntheticsApiTest>(req).await
+    }
+
+    /// Create a Synthetic browser test.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/synthetics/#create-a-browser-test)
+    pub async fn create_synthetics_browser_test(
+        &self,
+        request: CreateSyntheticsBrowserTestRequest,
+    ) -> Result<SyntheticsBrowserTest, Error> {
+        let req = self.build_request(Method::POST, &format!("{}/tests/browser", BASE_PATH))?;
+        let req = req.json(&request);
+
+        self.send_request::<SyntheticsBrowserTest>(req).await
+    }
+
+    /// Get the detailed configuration (including steps) associated with a Synthetic browser test.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/synthetics/#get-a-browser-test)
+    pub async fn get_synthetics_browser_test(
+        &self,
+        request: GetSyntheticsTestRequest,
+    ) -> Result<SyntheticsBrowserTest, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/tests/browser/{}", BASE_PATH, request.public_id),
+        )?;
+
+        self.send_request::<SyntheticsBrowserTest>(req).await
+    }
+
+    /// Edit the configuration of a Synthetic browser test.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/synthetics/#edit-a-browser-test)
+    pub async fn update_synthetics_browser_test(
+        &self,
+        request: UpdateSyntheticsBrowserTestRequest,
+    ) -> Result<SyntheticsBrowserTest, Error> {
+        let req = self.build_request(
+            Method::PUT,
+            &format!("{}/tests/browser/{}", BASE_PATH, request.public_id),
+        )?;
+        let req = req.json(&request.test);
+
+        self.send_request::<SyntheticsBrowserTest>(req).await
+    }
+
+    /// Pause or start a Synthetic test.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/synthetics/#pause-or-start-a-test)
+    pub async fn update_synthetics_test_status(
+        &self,
+        request: UpdateSyntheticsTestStatusRequest,
+    ) -> Result<UpdateSyntheticsTestStatusResponse, Error> {
+        let req = self.build_request(
+            Method::PUT,
+            &format!("{}/tests/{}/status", BASE_PATH, request.public_id),
+        )?;
+        let req = req.json(&request);
+
+        self.send_request::<UpdateSyntheticsTestStatusResponse>(req)
+            .await
+    }
+
+    /// Delete multiple Synthetic tests by ID.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/synthetics/#delete-tests)
+    pub async fn delete_synthetics_tests(
+        &self,
+        request: DeleteSyntheticsTestsRequest,
+    ) -> Result<DeleteSyntheticsTestsResponse, Error> {
+        let req = self.build_request(Method::POST, &format!("{}/tests/delete", BASE_PATH))?;
+        let req = req.json(&request);
+
+        self.send_request::<DeleteSyntheticsTestsResponse>(req)
+            .await
+    }
+
+    /// Trigger a set of Synthetic tests.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/synthetics/#trigger-tests-from-ci/cd-pipelines)
+    pub async fn trigger_synthetics_tests(
+        &self,
+        request: TriggerSyntheticsTestsRequest,
+    ) -> Result<SyntheticsTriggerCiTestsResponse, Error> {
+        let req = self.build_request(Method::POST, &format!("{}/tests/trigger", BASE_PATH))?;
+        let req = req.json(&request);
+
+        self.send_request::<SyntheticsTriggerCiTestsResponse>(req)
+            .await
+    }
+
+    /// Trigger a set of Synthetic tests for continuous integration.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/synthetics/#trigger-tests-from-ci/cd-pipelines)
+    pub async fn trigger_ci_synthetics_tests(
+        &self,
+        request: TriggerCiSyntheticsTestsRequest,
+    ) -> Result<SyntheticsTriggerCiTestsResponse, Error> {
+        let req = self.build_request(Method::POST, &format!("{}/tests/trigger/ci", BASE_PATH))?;
+        let req = req.json(&request);
+
+        self.send_request::<SyntheticsTriggerCiTestsResponse>(req)
+            .await
+    }
+
+    /// Get a batch's updated details.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/synthetics/#get-details-of-batch)
+    pub async fn get_synthetics_ci_batch(
+        &self,
+        request: GetSyntheticsCiBatchRequest,
+    ) -> Result<SyntheticsBatchDetails, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/ci/batch/{}", BASE_PATH, request.batch_id),
+        )?;
+
+        self.send_request::<SyntheticsBatchDetails>(req).await
+    }
+
+    /// Polls a batch triggered from CI every `poll_interval` until it is no longer `in_progress`,
+    /// or until `timeout` elapsed. In the latter case, the last details fetched are returned
+    /// and their status is still `in_progress`.
+    pub async fn wait_for_synthetics_ci_batch(
+        &self,
+        batch_id: &str,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<SyntheticsBatchDetails, Error> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let details = self
+                .get_synthetics_ci_batch(GetSyntheticsCiBatchRequest {
+                    batch_id: batch_id.to_string(),
+                })
+                .await?;
+            let in_progress = details
+                .data
+                .as_ref()
+                .and_then(|data| data.status.as_ref())
+                .is_none_or(|status| *status == SyntheticsStatus::InProgress);
+            if !in_progress || Instant::now() + poll_interval > deadline {
+                return Ok(details);
+            }
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+
+    /// Get the last 150 test results summaries for a given Synthetic API test.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/synthetics/#get-an-api-tests-latest-results-summaries)
+    pub async fn list_synthetics_api_test_results(
+        &self,
+        request: ListSyntheticsTestResultsRequest,
+    ) -> Result<ListSyntheticsTestResultsResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(
+            Method::GET,
+            &format!(
+                "{}/tests/{}/results?{}",
+                BASE_PATH, request.public_id, query
+            ),
+        )?;
+
+        self.send_request::<ListSyntheticsTestResultsResponse>(req)
+            .await
+    }
+
+    /// Get a specific full result from a given Synthetic API test.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/synthetics/#get-an-api-test-result)
+    pub async fn get_synthetics_api_test_result(
+        &self,
+        request: GetSyntheticsTestResultRequest,
+    ) -> Result<SyntheticsTestResult, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!(
+                "{}/tests/{}/results/{}",
+                BASE_PATH, request.public_id, request.result_id
+            ),
+        )?;
+
+        self.send_request::<SyntheticsTestResult>(req).await
+    }
+
+    /// Get the last 150 test results summaries for a given Synthetic browser test.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/synthetics/#get-a-browser-tests-latest-results-summaries)
+    pub async fn list_synthetics_browser_test_results(
+        &self,
+        request: ListSyntheticsTestResultsRequest,
+    ) -> Result<ListSyntheticsTestResultsResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(
+            Method::GET,
+            &format!(
+                "{}/tests/browser/{}/results?{}",
+                BASE_PATH, request.public_id, query
+            ),
+        )?;
+
+        self.send_request::<ListSyntheticsTestResultsResponse>(req)
+            .await
+    }
+
+    /// Get a specific full result from a given Synthetic browser test.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/synthetics/#get-a-browser-test-result)
+    pub async fn get_synthetics_browser_test_result(
+        &self,
+        request: GetSyntheticsTestResultRequest,
+    ) -> Result<SyntheticsTestResult, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!(
+                "{}/tests/browser/{}/results/{}",
+                BASE_PATH, request.public_id, request.result_id
+            ),
+        )?;
+
+        self.send_request::<SyntheticsTestResult>(req).await
+    }
+
+    /// Get the list of all Synthetic global variables.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/synthetics/#get-all-global-variables)
+    pub async fn list_synthetics_global_variables(
+        &self,
+    ) -> Result<ListSyntheticsGlobalVariablesResponse, Error> {
+        let req = self.build_request(Method::GET, &format!("{}/variables", BASE_PATH))?;
+
+        self.send_request::<ListSyntheticsGlobalVariablesResponse>(req)
+            .await
+    }
+
+    /// Create a Synthetic global variable.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/synthetics/#create-a-global-variable)
+    pub async fn create_synthetics_global_variable(
+        &self,
+        request: CreateSyntheticsGlobalVariableRequest,
+    ) -> Result<SyntheticsGlobalVariable, Error> {
+        let req = self.build_request(Method::POST, &format!("{}/variables", BASE_PATH))?;
+        let req = req.json(&request);
+
+        self.send_request::<SyntheticsGlobalVariable>(req).await
+    }
+
+    /// Get the detailed configuration of a global variable.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/synthetics/#get-a-global-variable)
+    pub async fn get_synthetics_global_variable(
+        &self,
+        request: GetSyntheticsGlobalVariableRequest,
+    ) -> Result<SyntheticsGlobalVariable, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/variables/{}", BASE_PATH, request.variable_id),
+        )?;
+
+        self.send_request::<SyntheticsGlobalVariable>(req).await
+    }
+
+    /// Edit a Synthetic global variable.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/synthetics/#edit-a-global-variable)
+    pub async fn update_synthetics_global_variable(
+        &self,
+        request: UpdateSyntheticsGlobalVariableRequest,
+    ) -> Result<SyntheticsGlobalVariable, Error> {
+        let req = self.build_request(
+            Method::PUT,
+            &format!("{}/variables/{}", BASE_PATH, request.variable_id),
+        )?;
+        let req = req.json(&request.variable);
+
+        self.send_request::<SyntheticsGlobalVariable>(req).await
+    }
+
+    /// Delete a Synthetic global variable.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/synthetics/#delete-a-global-variable)
+    pub async fn delete_synthetics_global_variable(
+        &self,
+        request: DeleteSyntheticsGlobalVariableRequest,
+    ) -> Result<DeleteSyntheticsGlobalVariableResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/variables/{}", BASE_PATH, request.variable_id),
+        )?;
+
+        self.send_request::<DeleteSyntheticsGlobalVariableResponse>(req)
+            .await
+    }
+
+    /// Get the list of public and private locations available for Synthetic tests.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/synthetics/#get-all-locations-public-and-private)
+    pub async fn list_synthetics_locations(
+        &self,
+    ) -> Result<ListSyntheticsLocationsResponse, Error> {
+        let req = self.build_request(Method::GET, &format!("{}/locations", BASE_PATH))?;
+
+        self.send_request::<ListSyntheticsLocationsResponse>(req)
+            .await
+    }
+
+    /// Create a new Synthetic private location.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/synthetics/#create-a-private-location)
+    pub async fn create_synthetics_private_location(
+        &self,
+        request: CreateSyntheticsPrivateLocationRequest,
+    ) -> Result<SyntheticsPrivateLocationCreationResponse, Error> {
+        let req = self.build_request(Method::POST, &format!("{}/private-locations", BASE_PATH))?;
+        let req = req.json(&request);
+
+        self.send_request::<SyntheticsPrivateLocationCreationResponse>(req)
+            .await
+    }
+
+    /// Get a Synthetic private location.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/synthetics/#get-a-private-location)
+    pub async fn get_synthetics_private_location(
+        &self,
+        request: GetSyntheticsPrivateLocationRequest,
+    ) -> Result<SyntheticsPrivateLocation, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/private-locations/{}", BASE_PATH, request.location_id),
+        )?;
+
+        self.send_request::<SyntheticsPrivateLocation>(req).await
+    }
+
+    /// Edit a Synthetic private location.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/synthetics/#edit-a-private-location)
+    pub async fn update_synthetics_private_location(
+        &self,
+        request: UpdateSyntheticsPrivateLocationRequest,
+    ) -> Result<SyntheticsPrivateLocation, Error> {
+        let req = self.build_request(
+            Method::PUT,
+            &format!("{}/private-locations/{}", BASE_PATH, request.location_id),
+        )?;
+        let req = req.json(&request.location);
+
+        self.send_request::<SyntheticsPrivateLocation>(req).await
+    }
+
+    /// Delete a Synthetic private location.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/synthetics/#delete-a-private-location)
+    pub async fn delete_synthetics_private_location(
+        &self,
+        request: DeleteSyntheticsPrivateLocationRequest,
+    ) -> Result<DeleteSyntheticsPrivateLocationResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/private-locations/{}", BASE_PATH, request.location_id),
+        )?;
+
+        self.send_request::<DeleteSyntheticsPrivateLocationResponse>(req)
+            .await
+    }
+}
@@ -0,0 +1,88 @@
+use reqwest::Method;
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::tags::{
+    DeleteHostTagsResponse, HostTags, HostTagsRequest, ListHostTagsRequest, TagToHosts,
+    UpdateHostTagsRequest,
+};
+
+/// Base path of the host tags endpoints.
+static BASE_PATH: &str = "api/v1/tags/hosts";
+
+impl Client {
+    /// Return a mapping of tags to hosts for your whole infrastructure.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/tags/#get-tags)
+    pub async fn list_host_tags(&self, request: ListHostTagsRequest) -> Result<TagToHosts, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(Method::GET, &format!("{}?{}", BASE_PATH, query))?;
+
+        self.send_request::<TagToHosts>(req).await
+    }
+
+    /// Return the list of tags that apply to a given host.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/tags/#get-host-tags)
+    pub async fn get_host_tags(&self, request: HostTagsRequest) -> Result<HostTags, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}?{}", BASE_PATH, request.host_name, query),
+        )?;
+
+        self.send_request::<HostTags>(req).await
+    }
+
+    /// This endpoint allows you to add new tags to a host, optionally specifying where these
+    /// tags come from.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/tags/#add-tags-to-a-host)
+    pub async fn create_host_tags(
+        &self,
+        request: UpdateHostTagsRequest,
+    ) -> Result<HostTags, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(
+            Method::POST,
+            &format!("{}/{}?{}", BASE_PATH, request.host_name, query),
+        )?;
+        let req = req.json(&request.tags);
+
+        self.send_request::<HostTags>(req).await
+    }
+
+    /// This endpoint allows you to replace all tags in an integration source with those
+    /// supplied in the request.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/tags/#update-host-tags)
+    pub async fn update_host_tags(
+        &self,
+        request: UpdateHostTagsRequest,
+    ) -> Result<HostTags, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(
+            Method::PUT,
+            &format!("{}/{}?{}", BASE_PATH, request.host_name, query),
+        )?;
+        let req = req.json(&request.tags);
+
+        self.send_request::<HostTags>(req).await
+    }
+
+    /// This endpoint allows you to remove all user-assigned tags for a single host.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/tags/#remove-host-tags)
+    pub async fn delete_host_tags(
+        &self,
+        request: HostTagsRequest,
+    ) -> Result<DeleteHostTagsResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/{}?{}", BASE_PATH, request.host_name, query),
+        )?;
+
+        self.send_request::<DeleteHostTagsResponse>(req).await
+    }
+}
@@ -0,0 +1,263 @@
+use reqwest::Method;
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::teams::{
+    CreateTeamLinkRequest, CreateTeamMembershipRequest, CreateTeamRequest, DeleteTeamLinkResponse,
+    DeleteTeamMembershipRequest, DeleteTeamMembershipResponse, DeleteTeamRequest,
+    DeleteTeamResponse, GetTeamRequest, ListTeamLinksRequest, ListTeamMembershipsRequest,
+    ListTeamPermissionSettingsRequest, ListTeamsRequest, TeamLinkRequest, TeamLinkResponse,
+    TeamLinksResponse, TeamPermissionSettingResponse, TeamPermissionSettingsResponse, TeamResponse,
+    TeamsResponse, UpdateTeamLinkRequest, UpdateTeamMembershipRequest,
+    UpdateTeamPermissionSettingRequest, UpdateTeamRequest, UserTeamResponse, UserTeamsResponse,
+};
+
+/// Base path of the teams endpoints.
+static BASE_PATH: &str = "api/v2/team";
+
+impl Client {
+    /// Get all teams. Can be used to search for teams using the `filter[keyword]` and `filter[me]` query parameters.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/teams/#get-all-teams)
+    pub async fn list_teams(&self, request: ListTeamsRequest) -> Result<TeamsResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(Method::GET, &format!("{}?{}", BASE_PATH, query))?;
+
+        self.send_request::<TeamsResponse>(req).await
+    }
+
+    /// Create a new team. User IDs passed through the `users` relationship field are added to the team.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/teams/#create-a-team)
+    pub async fn create_team(&self, request: CreateTeamRequest) -> Result<TeamResponse, Error> {
+        let req = self.build_request(Method::POST, BASE_PATH)?;
+        let req = req.json(&request);
+
+        self.send_request::<TeamResponse>(req).await
+    }
+
+    /// Get a single team using the team's `id`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/teams/#get-a-team)
+    pub async fn get_team(&self, request: GetTeamRequest) -> Result<TeamResponse, Error> {
+        let req = self.build_request(Method::GET, &format!("{}/{}", BASE_PATH, request.team_id))?;
+
+        self.send_request::<TeamResponse>(req).await
+    }
+
+    /// Update a team using the team's `id`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/teams/#update-a-team)
+    pub async fn update_team(&self, request: UpdateTeamRequest) -> Result<TeamResponse, Error> {
+        let req =
+            self.build_request(Method::PATCH, &format!("{}/{}", BASE_PATH, request.team_id))?;
+        let req = req.json(&request.team);
+
+        self.send_request::<TeamResponse>(req).await
+    }
+
+    /// Remove a team using the team's `id`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/teams/#remove-a-team)
+    pub async fn delete_team(
+        &self,
+        request: DeleteTeamRequest,
+    ) -> Result<DeleteTeamResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/{}", BASE_PATH, request.team_id),
+        )?;
+
+        self.send_request::<DeleteTeamResponse>(req).await
+    }
+
+    /// Get a paginated list of members for a team.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/teams/#get-team-memberships)
+    pub async fn list_team_memberships(
+        &self,
+        request: ListTeamMembershipsRequest,
+    ) -> Result<UserTeamsResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}/memberships?{}", BASE_PATH, request.team_id, query),
+        )?;
+
+        self.send_request::<UserTeamsResponse>(req).await
+    }
+
+    /// Add a user to a team.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/teams/#add-a-user-to-a-team)
+    pub async fn create_team_membership(
+        &self,
+        request: CreateTeamMembershipRequest,
+    ) -> Result<UserTeamResponse, Error> {
+        let req = self.build_request(
+            Method::POST,
+            &format!("{}/{}/memberships", BASE_PATH, request.team_id),
+        )?;
+        let req = req.json(&request.membership);
+
+        self.send_request::<UserTeamResponse>(req).await
+    }
+
+    /// Update a user's membership attributes on a team, such as their role.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/teams/#update-a-users-membership-attributes-on-a-team)
+    pub async fn update_team_membership(
+        &self,
+        request: UpdateTeamMembershipRequest,
+    ) -> Result<UserTeamResponse, Error> {
+        let req = self.build_request(
+            Method::PATCH,
+            &format!(
+                "{}/{}/memberships/{}",
+                BASE_PATH, request.team_id, request.user_id
+            ),
+        )?;
+        let req = req.json(&request.membership);
+
+        self.send_request::<UserTeamResponse>(req).await
+    }
+
+    /// Remove a user from a team.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/teams/#remove-a-user-from-a-team)
+    pub async fn delete_team_membership(
+        &self,
+        request: DeleteTeamMembershipRequest,
+    ) -> Result<DeleteTeamMembershipResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!(
+                "{}/{}/memberships/{}",
+                BASE_PATH, request.team_id, request.user_id
+            ),
+        )?;
+
+        self.send_request::<DeleteTeamMembershipResponse>(req).await
+    }
+
+    /// Get all links for a given team.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/teams/#get-links-for-a-team)
+    pub async fn list_team_links(
+        &self,
+        request: ListTeamLinksRequest,
+    ) -> Result<TeamLinksResponse, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}/links", BASE_PATH, request.team_id),
+        )?;
+
+        self.send_request::<TeamLinksResponse>(req).await
+    }
+
+    /// Add a new link to a team.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/teams/#create-a-team-link)
+    pub async fn create_team_link(
+        &self,
+        request: CreateTeamLinkRequest,
+    ) -> Result<TeamLinkResponse, Error> {
+        let req = self.build_request(
+            Method::POST,
+            &format!("{}/{}/links", BASE_PATH, request.team_id),
+        )?;
+        let req = req.json(&request.link);
+
+        self.send_request::<TeamLinkResponse>(req).await
+    }
+
+    /// Get a single link for a team.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/teams/#get-a-team-link)
+    pub async fn get_team_link(&self, request: TeamLinkRequest) -> Result<TeamLinkResponse, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!(
+                "{}/{}/links/{}",
+                BASE_PATH, request.team_id, request.link_id
+            ),
+        )?;
+
+        self.send_request::<TeamLinkResponse>(req).await
+    }
+
+    /// Update a team link.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/teams/#update-a-team-link)
+    pub async fn update_team_link(
+        &self,
+        request: UpdateTeamLinkRequest,
+    ) -> Result<TeamLinkResponse, Error> {
+        let req = self.build_request(
+            Method::PATCH,
+            &format!(
+                "{}/{}/links/{}",
+                BASE_PATH, request.team_id, request.link_id
+            ),
+        )?;
+        let req = req.json(&request.link);
+
+        self.send_request::<TeamLinkResponse>(req).await
+    }
+
+    /// Remove a link from a team.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/teams/#remove-a-team-link)
+    pub async fn delete_team_link(
+        &self,
+        request: TeamLinkRequest,
+    ) -> Result<DeleteTeamLinkResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!(
+                "{}/{}/links/{}",
+                BASE_PATH, request.team_id, request.link_id
+            ),
+        )?;
+
+        self.send_request::<DeleteTeamLinkResponse>(req).await
+    }
+
+    /// Get all permission settings for a given team.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/teams/#get-permission-settings-for-a-team)
+    pub async fn list_team_permission_settings(
+        &self,
+        request: ListTeamPermissionSettingsRequest,
+    ) -> Result<TeamPermissionSettingsResponse, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}/permission-settings", BASE_PATH, request.team_id),
+        )?;
+
+        self.send_request::<TeamPermissionSettingsResponse>(req)
+            .await
+    }
+
+    /// Update a team permission setting for a given team.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/teams/#update-permission-setting-for-team)
+    pub async fn update_team_permission_setting(
+        &self,
+        request: UpdateTeamPermissionSettingRequest,
+    ) -> Result<TeamPermissionSettingResponse, Error> {
+        let req = self.build_request(
+            Method::PUT,
+            &format!(
+                "{}/{}/permission-settings/{}",
+                BASE_PATH,
+                request.team_id,
+                request.action.as_str()
+            ),
+        )?;
+        let req = req.json(&request.setting);
+
+        self.send_request::<TeamPermissionSettingResponse>(req)
+            .await
+    }
+}
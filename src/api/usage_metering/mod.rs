@@ -0,0 +1,179 @@
+use chrono::Duration;
+use reqwest::{header, Method};
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::usage_metering::{
+    CostByOrgResponse, GetEstimatedCostRequest, GetHistoricalCostRequest, GetHourlyUsageRequest,
+    GetMonthlyUsageAttributionRequest, GetProjectedCostRequest, GetUsageBillableSummaryRequest,
+    HourlyUsage, HourlyUsageResponse, MonthlyUsageAttributionResponse, ProjectedCostResponse,
+    UsageBillableSummaryHour, UsageBillableSummaryResponse, UsageDateRange,
+};
+
+/// Base path of the v1 usage endpoints.
+static V1_BASE_PATH: &str = "api/v1/usage";
+/// Base path of the v2 usage endpoints.
+static V2_BASE_PATH: &str = "api/v2/usage";
+/// The v2 usage endpoints only answer to clients accepting RFC 3339 datetimes.
+static V2_ACCEPT: &str = "application/json;datetime-format=rfc3339";
+/// Longest range of hours requested at once by [Client::get_hourly_usage_over_range].
+const HOURLY_USAGE_MAX_WINDOW_DAYS: i64 = 31;
+
+impl Client {
+    /// Get hourly usage by product family.
+    /// Results are paginated, use the `next_record_id` of the response to get the next page.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/usage-metering/#get-hourly-usage-by-product-family)
+    pub async fn get_hourly_usage(
+        &self,
+        request: GetHourlyUsageRequest,
+    ) -> Result<HourlyUsageResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/hourly_usage?{}", V2_BASE_PATH, query),
+        )?;
+        let req = req.header(header::ACCEPT, V2_ACCEPT);
+
+        self.send_request::<HourlyUsageResponse>(req).await
+    }
+
+    /// Get all the hourly usage of the `range`, by product family.
+    ///
+    /// The range is split into windows of at most a month, and every page of every window
+    /// is fetched. The timestamps and the page cursor of `request` are replaced, its other
+    /// filters are kept. Use [total_hourly_usage](crate::models::usage_metering::total_hourly_usage)
+    /// to sum the result.
+    pub async fn get_hourly_usage_over_range(
+        &self,
+        range: UsageDateRange,
+        request: GetHourlyUsageRequest,
+    ) -> Result<Vec<HourlyUsage>, Error> {
+        let mut usages = Vec::new();
+        for window in range.windows(Duration::days(HOURLY_USAGE_MAX_WINDOW_DAYS)) {
+            let mut request = GetHourlyUsageRequest {
+                filter_timestamp_start: window.start_hour(),
+                filter_timestamp_end: Some(window.end_hour()),
+                page_next_record_id: None,
+                ..request.clone()
+            };
+            loop {
+                let mut response = self.get_hourly_usage(request.clone()).await?;
+                let next_record_id = response.next_record_id().map(str::to_string);
+                usages.append(&mut response.data);
+                match next_record_id {
+                    Some(next_record_id) => request.page_next_record_id = Some(next_record_id),
+                    None => break,
+                }
+            }
+        }
+        Ok(usages)
+    }
+
+    /// Get estimated cost across multi-org and single root-org accounts.
+    /// Estimated cost data is only available for the current month and previous month
+    /// and is delayed by up to 72 hours from when it was incurred.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/usage-metering/#get-estimated-cost-across-your-account)
+    pub async fn get_estimated_cost(
+        &self,
+        request: GetEstimatedCostRequest,
+    ) -> Result<CostByOrgResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/estimated_cost?{}", V2_BASE_PATH, query),
+        )?;
+        let req = req.header(header::ACCEPT, V2_ACCEPT);
+
+        self.send_request::<CostByOrgResponse>(req).await
+    }
+
+    /// Get historical cost across multi-org and single root-org accounts.
+    /// Cost data for a given month becomes available no later than the 16th of the following month.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/usage-metering/#get-historical-cost-across-your-account)
+    pub async fn get_historical_cost(
+        &self,
+        request: GetHistoricalCostRequest,
+    ) -> Result<CostByOrgResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/historical_cost?{}", V2_BASE_PATH, query),
+        )?;
+        let req = req.header(header::ACCEPT, V2_ACCEPT);
+
+        self.send_request::<CostByOrgResponse>(req).await
+    }
+
+    /// Get projected cost across multi-org and single root-org accounts.
+    /// Projected cost data is only available for the current month and becomes available
+    /// around the 12th of the month.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/usage-metering/#get-projected-cost-across-your-account)
+    pub async fn get_projected_cost(
+        &self,
+        request: GetProjectedCostRequest,
+    ) -> Result<ProjectedCostResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/projected_cost?{}", V2_BASE_PATH, query),
+        )?;
+        let req = req.header(header::ACCEPT, V2_ACCEPT);
+
+        self.send_request::<ProjectedCostResponse>(req).await
+    }
+
+    /// Get monthly usage attribution, by tag. Multi-region data is available starting March 1, 2023.
+    /// Results are paginated, use the `next_record_id` of the response to get the next page.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/usage-metering/#get-monthly-usage-attribution)
+    pub async fn get_monthly_usage_attribution(
+        &self,
+        request: GetMonthlyUsageAttributionRequest,
+    ) -> Result<MonthlyUsageAttributionResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/monthly-attribution?{}", V1_BASE_PATH, query),
+        )?;
+
+        self.send_request::<MonthlyUsageAttributionResponse>(req)
+            .await
+    }
+
+    /// Get billable usage across your account, for a single month.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/usage-metering/#get-billable-usage-across-your-account)
+    pub async fn get_usage_billable_summary(
+        &self,
+        request: GetUsageBillableSummaryRequest,
+    ) -> Result<UsageBillableSummaryResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/billable-summary?{}", V1_BASE_PATH, query),
+        )?;
+
+        self.send_request::<UsageBillableSummaryResponse>(req).await
+    }
+
+    /// Get the billable usage of every month of the `range`, one request per month.
+    pub async fn get_usage_billable_summary_over_range(
+        &self,
+        range: UsageDateRange,
+    ) -> Result<Vec<UsageBillableSummaryHour>, Error> {
+        let mut usages = Vec::new();
+        for window in range.month_windows() {
+            let mut response = self
+                .get_usage_billable_summary(GetUsageBillableSummaryRequest {
+                    month: Some(window.start_month()),
+                })
+                .await?;
+            usages.append(&mut response.usage);
+        }
+        Ok(usages)
+    }
+}
@@ -0,0 +1,116 @@
+use reqwest::Method;
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::roles::PermissionsResponse;
+use crate::models::users::{
+    CreateUserRequest, DisableUserRequest, DisableUserResponse, GetInvitationRequest,
+    GetUserRequest, ListUserPermissionsRequest, ListUsersRequest, SendInvitationsRequest,
+    UpdateUserRequest, UserInvitationResponse, UserInvitationsResponse, UserResponse,
+    UsersResponse,
+};
+
+/// Base path of the users endpoints.
+static BASE_PATH: &str = "api/v2/users";
+/// Base path of the user invitations endpoints.
+static INVITATIONS_PATH: &str = "api/v2/user_invitations";
+
+impl Client {
+    /// Get the list of all users in the organization.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/users/#list-all-users)
+    pub async fn list_users(&self, request: ListUsersRequest) -> Result<UsersResponse, Error> {
+        let query = serde_qs::to_string(&request)?;
+        let req = self.build_request(Method::GET, &format!("{}?{}", BASE_PATH, query))?;
+
+        self.send_request::<UsersResponse>(req).await
+    }
+
+    /// Create a user for your organization.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/users/#create-a-user)
+    pub async fn create_user(&self, request: CreateUserRequest) -> Result<UserResponse, Error> {
+        let req = self.build_request(Method::POST, BASE_PATH)?;
+        let req = req.json(&request);
+
+        self.send_request::<UserResponse>(req).await
+    }
+
+    /// Get a user in the organization specified by the user's `user_id`.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/users/#get-user-details)
+    pub async fn get_user(&self, request: GetUserRequest) -> Result<UserResponse, Error> {
+        let req = self.build_request(Method::GET, &format!("{}/{}", BASE_PATH, request.user_id))?;
+
+        self.send_request::<UserResponse>(req).await
+    }
+
+    /// Edit a user. Can only be used with an application key belonging to an administrator user.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/users/#update-a-user)
+    pub async fn update_user(&self, request: UpdateUserRequest) -> Result<UserResponse, Error> {
+        let req =
+            self.build_request(Method::PATCH, &format!("{}/{}", BASE_PATH, request.user_id))?;
+        let req = req.json(&request.user);
+
+        self.send_request::<UserResponse>(req).await
+    }
+
+    /// Disable a user. Can only be used with an application key belonging to an administrator user.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/users/#disable-a-user)
+    pub async fn disable_user(
+        &self,
+        request: DisableUserRequest,
+    ) -> Result<DisableUserResponse, Error> {
+        let req = self.build_request(
+            Method::DELETE,
+            &format!("{}/{}", BASE_PATH, request.user_id),
+        )?;
+
+        self.send_request::<DisableUserResponse>(req).await
+    }
+
+    /// Get a user permission set, as returned by the user's roles.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/users/#get-a-user-permissions)
+    pub async fn list_user_permissions(
+        &self,
+        request: ListUserPermissionsRequest,
+    ) -> Result<PermissionsResponse, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}/permissions", BASE_PATH, request.user_id),
+        )?;
+
+        self.send_request::<PermissionsResponse>(req).await
+    }
+
+    /// Sends emails to one or more users inviting them to join the organization.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/users/#send-invitation-emails)
+    pub async fn send_invitations(
+        &self,
+        request: SendInvitationsRequest,
+    ) -> Result<UserInvitationsResponse, Error> {
+        let req = self.build_request(Method::POST, INVITATIONS_PATH)?;
+        let req = req.json(&request);
+
+        self.send_request::<UserInvitationsResponse>(req).await
+    }
+
+    /// Returns a single user invitation by its UUID.
+    ///
+    /// [Datadog documentation](https://docs.datadoghq.com/api/latest/users/#get-a-user-invitation)
+    pub async fn get_invitation(
+        &self,
+        request: GetInvitationRequest,
+    ) -> Result<UserInvitationResponse, Error> {
+        let req = self.build_request(
+            Method::GET,
+            &format!("{}/{}", INVITATIONS_PATH, request.user_invitation_uuid),
+        )?;
+
+        self.send_request::<UserInvitationResponse>(req).await
+    }
+}
@@ -1,22 +1,41 @@
 use crate::{
     error::{Error, ErrorVec},
     models::client::ErrorResponse,
+    secret::SecretString,
 };
 use reqwest::{header, Method, RequestBuilder, StatusCode, Url};
 use serde::de::DeserializeOwned;
+use std::collections::HashSet;
 
 const DATADOG_API_URL: &str = "https://api.datadoghq.com/";
+/// Logs are sent to a dedicated host.
+const DATADOG_LOGS_INTAKE_URL: &str = "https://http-intake.logs.datadoghq.com/";
+/// Statuses of the successful responses of most endpoints.
+const SUCCESS_STATUSES: &[StatusCode] = &[
+    StatusCode::OK,
+    StatusCode::CREATED,
+    StatusCode::ACCEPTED,
+    StatusCode::NO_CONTENT,
+];
 
 /// Datadog's API client, designed to perform asynchronous calls.
 pub struct Client {
     inner: reqwest::Client,
+    /// Kept to build a [Client] with the same configuration but other keys.
+    api_key: SecretString,
+    /// Kept to build a [Client] with the same configuration but other keys.
+    application_key: SecretString,
     api_url: Url,
+    logs_intake_url: Url,
+    unstable_operations: HashSet<String>,
 }
 
 struct Config {
-    api_key: String,
+    api_key: SecretString,
     api_url: Url,
-    application_key: String,
+    application_key: SecretString,
+    logs_intake_url: Url,
+    unstable_operations: HashSet<String>,
 }
 
 /// Client builder for the [Client].
@@ -29,10 +48,12 @@ impl ClientBuilder {
     pub fn new(api_key: &str, application_key: &str) -> Self {
         ClientBuilder {
             config: Config {
-                api_key: api_key.to_string(),
+                api_key: api_key.into(),
                 // TODO: Remove unwrap.
                 api_url: Url::parse(DATADOG_API_URL).unwrap(),
-                application_key: application_key.to_string(),
+                application_key: application_key.into(),
+                logs_intake_url: Url::parse(DATADOG_LOGS_INTAKE_URL).unwrap(),
+                unstable_operations: HashSet::new(),
             },
         }
     }
@@ -43,15 +64,38 @@ impl ClientBuilder {
         self
     }
 
+    /// Set's Datadog's logs intake url. Needed for sites other than `datadoghq.com` and for internal test.
+    pub fn set_logs_intake_url(mut self, url: Url) -> ClientBuilder {
+        self.config.logs_intake_url = url;
+        self
+    }
+
     /// Set [Datadog application key](https://docs.datadoghq.com/account_management/api-app-keys/#application-keys)
     pub fn set_application_key(mut self, application_key: &str) -> ClientBuilder {
-        self.config.application_key = application_key.to_string();
+        self.config.application_key = application_key.into();
         self
     }
 
     /// Set [Datadog api key](https://docs.datadoghq.com/account_management/api-app-keys/#api-keys)
     pub fn set_api_key(mut self, api_key: &str) -> ClientBuilder {
-        self.config.api_key = api_key.to_string();
+        self.config.api_key = api_key.into();
+        self
+    }
+
+    /// Enable or disable an operation that Datadog still considers unstable, such as `v2.create_incident`.
+    /// Unstable operations are disabled by default and fail with [Error::UnstableOperationDisabled].
+    pub fn set_unstable_operation_enabled(
+        mut self,
+        operation: &str,
+        enabled: bool,
+    ) -> ClientBuilder {
+        if enabled {
+            self.config
+                .unstable_operations
+                .insert(operation.to_string());
+        } else {
+            self.config.unstable_operations.remove(operation);
+        }
         self
     }
 
@@ -67,10 +111,11 @@ impl ClientBuilder {
             header::HeaderValue::from_static("application/json"),
         );
         let mut application_key_value =
-            header::HeaderValue::from_str(&self.config.application_key).unwrap();
+            header::HeaderValue::from_str(self.config.application_key.expose_secret()).unwrap();
         application_key_value.set_sensitive(true);
         headers.insert("DD-APPLICATION-KEY", application_key_value);
-        let mut api_key_value = header::HeaderValue::from_str(&self.config.api_key).unwrap();
+        let mut api_key_value =
+            header::HeaderValue::from_str(self.config.api_key.expose_secret()).unwrap();
         api_key_value.set_sensitive(true);
         headers.insert("DD-API-KEY", api_key_value);
 
@@ -80,7 +125,11 @@ impl ClientBuilder {
 
         Ok(Client {
             inner: client,
+            api_key: self.config.api_key,
+            application_key: self.config.application_key,
             api_url: self.config.api_url,
+            logs_intake_url: self.config.logs_intake_url,
+            unstable_operations: self.config.unstable_operations,
         })
     }
 }
@@ -100,6 +149,24 @@ impl Client {
             .expect("Client::new()")
     }
 
+    /// A [Client] with the same configuration, authenticated with other keys.
+    pub(crate) fn with_keys(
+        &self,
+        api_key: Option<&SecretString>,
+        application_key: Option<&SecretString>,
+    ) -> Result<Client, Error> {
+        ClientBuilder {
+            config: Config {
+                api_key: api_key.unwrap_or(&self.api_key).clone(),
+                api_url: self.api_url.clone(),
+                application_key: application_key.unwrap_or(&self.application_key).clone(),
+                logs_intake_url: self.logs_intake_url.clone(),
+                unstable_operations: self.unstable_operations.clone(),
+            },
+        }
+        .build()
+    }
+
     pub(crate) fn build_request(
         &self,
         method: Method,
@@ -111,24 +178,91 @@ impl Client {
         Ok(req)
     }
 
+    pub(crate) fn build_logs_intake_request(
+        &self,
+        method: Method,
+        path: &str,
+    ) -> Result<RequestBuilder, Error> {
+        let extended_url = self.logs_intake_url.join(path)?;
+        let req = self.inner.request(method, extended_url);
+
+        Ok(req)
+    }
+
+    /// Fails unless the unstable `operation` was enabled on the [ClientBuilder].
+    pub(crate) fn check_unstable_operation(&self, operation: &str) -> Result<(), Error> {
+        if self.unstable_operations.contains(operation) {
+            Ok(())
+        } else {
+            Err(Error::UnstableOperationDisabled(operation.to_string()))
+        }
+    }
+
     pub(crate) async fn send_request<T>(&self, request: RequestBuilder) -> Result<T, Error>
     where
         T: DeserializeOwned,
     {
-        let response = request.send().await?;
-        let status = response.status();
-
         // TODO: Handle the [400, 403, 404, 429] in particular ways
         // parsing the errors returned by Datadog.
         // For other errors, display in a different way.
-        if status == StatusCode::OK
-            || status == StatusCode::CREATED
-            || status == StatusCode::NO_CONTENT
-        {
+        self.send_request_accepting(request, SUCCESS_STATUSES).await
+    }
+
+    /// Sends the request and decodes the body of the responses with one of the `accepted` statuses.
+    /// Some endpoints report partial results with an error status, such as `409 Conflict`.
+    pub(crate) async fn send_request_accepting<T>(
+        &self,
+        request: RequestBuilder,
+        accepted: &[StatusCode],
+    ) -> Result<T, Error>
+    where
+        T: DeserializeOwned,
+    {
+        self.send_request_mapping_errors(request, accepted, |_, errors| {
+            Error::InvalidRequest(errors)
+        })
+        .await
+    }
+
+    /// Sends a change to a versioned configuration, such as the Sensitive Data Scanner one.
+    /// Datadog answers with `409 Conflict` when the version sent is no longer the current one,
+    /// which is surfaced as [Error::VersionConflict] so callers can fetch the configuration again.
+    pub(crate) async fn send_versioned_request<T>(
+        &self,
+        request: RequestBuilder,
+    ) -> Result<T, Error>
+    where
+        T: DeserializeOwned,
+    {
+        self.send_request_mapping_errors(request, SUCCESS_STATUSES, |status, errors| {
+            if status == StatusCode::CONFLICT {
+                Error::VersionConflict(errors)
+            } else {
+                Error::InvalidRequest(errors)
+            }
+        })
+        .await
+    }
+
+    /// Sends the request and decodes the body of the responses with one of the `accepted` statuses.
+    /// The errors listed by the other responses are turned into an [Error] by `error`.
+    async fn send_request_mapping_errors<T>(
+        &self,
+        request: RequestBuilder,
+        accepted: &[StatusCode],
+        error: fn(StatusCode, ErrorVec) -> Error,
+    ) -> Result<T, Error>
+    where
+        T: DeserializeOwned,
+    {
+        let response = request.send().await?;
+        let status = response.status();
+
+        if accepted.contains(&status) {
             Ok(response.json().await?)
         } else {
             let response: ErrorResponse = response.json().await?;
-            Err(Error::InvalidRequest(ErrorVec(response.errors)))
+            Err(error(status, ErrorVec(response.errors)))
         }
     }
 }
@@ -1,16 +1,52 @@
 use core::fmt;
+use reqwest::StatusCode;
 use thiserror::Error;
 
+/// Errors returned by the [Client](crate::client::Client).
 #[derive(Error, Debug)]
 pub enum Error {
+    /// The request could not be sent or its response could not be read.
     #[error("Transport error")]
     RequestTransportError(#[from] reqwest::Error),
+    /// The request URL is invalid.
     #[error("URL parse error")]
     UrlParseError(#[from] url::ParseError),
+    /// Datadog rejected the request and listed the reasons.
     #[error("Invalid request: {0}")]
     InvalidRequest(ErrorVec),
+    /// The configuration was changed since the version the request was based on.
+    #[error("Version conflict: {0}")]
+    VersionConflict(ErrorVec),
+    /// The query parameters could not be encoded.
     #[error("Invalid query")]
     RequestQueryHeadersError(#[from] serde_qs::Error),
+    /// A request body could not be encoded as JSON.
+    #[error("JSON serialization error")]
+    JsonSerializationError(#[from] serde_json::Error),
+    /// A YAML document could not be decoded.
+    #[error("YAML deserialization error")]
+    YamlDeserializationError(#[source] YamlError),
+    /// A request body could not be compressed.
+    #[error("Compression error")]
+    CompressionError(#[from] std::io::Error),
+    /// Datadog answered with a status code the endpoint does not document.
+    #[error("Unexpected response status: {0}")]
+    UnexpectedStatus(StatusCode),
+    /// The operation is unstable and was not enabled with
+    /// [ClientBuilder::set_unstable_operation_enabled](crate::client::ClientBuilder::set_unstable_operation_enabled).
+    #[error("Unstable operation '{0}' is disabled")]
+    UnstableOperationDisabled(String),
+    /// Datadog did not return the value of a key it created, so it cannot be used.
+    #[error("The value of the created key was not returned")]
+    KeyValueMissing,
+    /// A single log entry is bigger than what the logs intake accepts.
+    #[error("Log entry of {size} bytes exceeds the limit of {limit} bytes")]
+    LogEntryTooLarge {
+        /// Size of the serialized log entry, in bytes.
+        size: usize,
+        /// Maximum size allowed, in bytes.
+        limit: usize,
+    },
 }
 
 /// The thiserror crate lacks support for directly formatting collections like Vec.
@@ -25,3 +61,16 @@ impl fmt::Display for ErrorVec {
         write!(f, "{}", self.0.join(", "))
     }
 }
+
+/// The error of the YAML parser, kept opaque so that the parser is not part of the public API.
+/// Use its `Display` implementation to get the reason.
+#[derive(Debug)]
+pub struct YamlError(pub(crate) serde_norway::Error);
+
+impl fmt::Display for YamlError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for YamlError {}
@@ -3,5 +3,7 @@
 
 mod api;
 pub mod client;
-mod error;
+pub mod error;
+pub mod logs_submitter;
 pub mod models;
+pub mod secret;
@@ -0,0 +1,373 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use reqwest::StatusCode;
+use tokio::sync::{Mutex, Notify};
+use tokio::task::JoinHandle;
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::models::logs::{ContentEncoding, HttpLogItem};
+
+/// Maximum size for a single log accepted by the logs intake.
+pub const MAX_ENTRY_BYTES: usize = 1024 * 1024;
+/// Maximum content size per payload (uncompressed) accepted by the logs intake.
+pub const MAX_PAYLOAD_BYTES: usize = 5 * 1024 * 1024;
+/// Maximum array size when sending multiple logs in an array.
+pub const MAX_PAYLOAD_ENTRIES: usize = 1000;
+
+/// Default maximum time a log stays buffered.
+const DEFAULT_FLUSH_INTERVAL: Duration = Duration::from_secs(5);
+/// Default number of retries of a failed batch.
+const DEFAULT_MAX_RETRIES: u32 = 3;
+/// Default delay before the first retry.
+const DEFAULT_RETRY_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Configuration of a [LogsSubmitter].
+struct Config {
+    /// Maximum size of a single serialized log.
+    max_entry_bytes: usize,
+    /// Maximum uncompressed size of a batch.
+    max_payload_bytes: usize,
+    /// Maximum number of logs in a batch.
+    max_payload_entries: usize,
+    /// Maximum time a log stays buffered.
+    flush_interval: Duration,
+    /// Number of retries of a failed batch.
+    max_retries: u32,
+    /// Delay before the first retry.
+    retry_backoff: Duration,
+    /// Tags added to every batch.
+    ddtags: Option<String>,
+}
+
+/// Builder for the [LogsSubmitter].
+pub struct LogsSubmitterBuilder {
+    /// Client used to send the batches.
+    client: Client,
+    /// Configuration being built.
+    config: Config,
+}
+
+impl LogsSubmitterBuilder {
+    /// Constructs a new [LogsSubmitterBuilder] sending logs through the given [Client].
+    pub fn new(client: Client) -> Self {
+        LogsSubmitterBuilder {
+            client,
+            config: Config {
+                max_entry_bytes: MAX_ENTRY_BYTES,
+                max_payload_bytes: MAX_PAYLOAD_BYTES,
+                max_payload_entries: MAX_PAYLOAD_ENTRIES,
+                flush_interval: DEFAULT_FLUSH_INTERVAL,
+                max_retries: DEFAULT_MAX_RETRIES,
+                retry_backoff: DEFAULT_RETRY_BACKOFF,
+                ddtags: None,
+            },
+        }
+    }
+
+    /// Set the maximum size of a single serialized log entry.
+    /// Values above [MAX_ENTRY_BYTES] are capped to it.
+    pub fn set_max_entry_bytes(mut self, max_entry_bytes: usize) -> LogsSubmitterBuilder {
+        self.config.max_entry_bytes = max_entry_bytes.min(MAX_ENTRY_BYTES);
+        self
+    }
+
+    /// Set the maximum uncompressed size of a batch.
+    /// Values above [MAX_PAYLOAD_BYTES] are capped to it.
+    pub fn set_max_payload_bytes(mut self, max_payload_bytes: usize) -> LogsSubmitterBuilder {
+        self.config.max_payload_bytes = max_payload_bytes.min(MAX_PAYLOAD_BYTES);
+        self
+    }
+
+    /// Set the maximum number of entries in a batch.
+    /// Values above [MAX_PAYLOAD_ENTRIES] are capped to it.
+    pub fn set_max_payload_entries(mut self, max_payload_entries: usize) -> LogsSubmitterBuilder {
+        self.config.max_payload_entries = max_payload_entries.clamp(1, MAX_PAYLOAD_ENTRIES);
+        self
+    }
+
+    /// Set the maximum time a log stays buffered before its batch is flushed by the background
+    /// flush task.
+    ///
+    /// Default: 5 seconds.
+    pub fn set_flush_interval(mut self, flush_interval: Duration) -> LogsSubmitterBuilder {
+        self.config.flush_interval = flush_interval;
+        self
+    }
+
+    /// Set how many times a batch is sent again after a retryable failure.
+    ///
+    /// Default: 3.
+    pub fn set_max_retries(mut self, max_retries: u32) -> LogsSubmitterBuilder {
+        self.config.max_retries = max_retries;
+        self
+    }
+
+    /// Set the delay before the first retry. The delay doubles on every following retry.
+    ///
+    /// Default: 500 milliseconds.
+    pub fn set_retry_backoff(mut self, retry_backoff: Duration) -> LogsSubmitterBuilder {
+        self.config.retry_backoff = retry_backoff;
+        self
+    }
+
+    /// Set tags added to every batch through the `ddtags` query parameter.
+    pub fn set_ddtags(mut self, ddtags: &str) -> LogsSubmitterBuilder {
+        self.config.ddtags = Some(ddtags.to_string());
+        self
+    }
+
+    /// Returns a [LogsSubmitter] that uses this [LogsSubmitterBuilder] configuration.
+    ///
+    /// # Panics
+    ///
+    /// Panics when called outside of a Tokio runtime, which runs the background flush task.
+    pub fn build(self) -> LogsSubmitter {
+        let buffer = Arc::new(Mutex::new(Buffer {
+            client: self.client,
+            config: self.config,
+            entries: Vec::new(),
+            payload_bytes: 2,
+            oldest_entry_at: None,
+        }));
+        let buffered = Arc::new(Notify::new());
+        let flusher = tokio::spawn(flush_when_due(Arc::clone(&buffer), Arc::clone(&buffered)));
+
+        LogsSubmitter {
+            buffer,
+            buffered,
+            flusher,
+        }
+    }
+}
+
+/// Buffers logs and sends them to the logs intake in gzip-compressed batches.
+///
+/// A batch is flushed when adding a log would exceed the payload size or entries limits,
+/// and by a background task once the flush interval elapsed since the oldest buffered log.
+/// Errors of the background flushes are not reported: logs failing with a retryable error stay
+/// buffered and are sent again one flush interval later.
+///
+/// Dropping the submitter spawns a last flush of the buffered logs, which is not awaited.
+/// Call [LogsSubmitter::flush] before dropping it to make sure the logs were sent.
+pub struct LogsSubmitter {
+    /// Buffered logs, shared with the background flush task.
+    buffer: Arc<Mutex<Buffer>>,
+    /// Wakes the background flush task up when a log is buffered.
+    buffered: Arc<Notify>,
+    /// Background task flushing the buffer once the flush interval elapsed.
+    flusher: JoinHandle<()>,
+}
+
+impl LogsSubmitter {
+    /// Constructs a new [LogsSubmitter] with the default configuration.
+    ///
+    /// # Panics
+    ///
+    /// Panics when called outside of a Tokio runtime, see [LogsSubmitterBuilder::build].
+    pub fn new(client: Client) -> Self {
+        LogsSubmitterBuilder::new(client).build()
+    }
+
+    /// Number of logs waiting to be sent.
+    pub async fn len(&self) -> usize {
+        self.buffer.lock().await.entries.len()
+    }
+
+    /// Returns `true` if no log is waiting to be sent.
+    pub async fn is_empty(&self) -> bool {
+        self.buffer.lock().await.entries.is_empty()
+    }
+
+    /// Buffers a log, flushing the current batch first if the log would not fit in it.
+    ///
+    /// Logs bigger than the maximum entry size are rejected with [Error::LogEntryTooLarge].
+    /// Errors of the flushes it triggers are returned, see [LogsSubmitter::flush] for what happens
+    /// to the buffered logs then. If the flush making room for the log fails, the log is not buffered.
+    pub async fn submit(&self, log: &HttpLogItem) -> Result<(), Error> {
+        let result = self.buffer.lock().await.submit(log).await;
+        self.buffered.notify_one();
+        result
+    }
+
+    /// Flushes the buffered logs if the flush interval elapsed since the oldest of them was submitted.
+    pub async fn flush_if_due(&self) -> Result<(), Error> {
+        self.buffer.lock().await.flush_if_due().await
+    }
+
+    /// Sends the buffered logs as a single gzip-compressed batch.
+    ///
+    /// Transport errors, `408`, `429` and `5xx` responses are retried with an exponential backoff.
+    /// When retries are exhausted the logs stay buffered so that the next flush sends them again.
+    /// Any other failure means the intake will never accept the batch, so it is dropped.
+    pub async fn flush(&self) -> Result<(), Error> {
+        self.buffer.lock().await.flush().await
+    }
+}
+
+impl Drop for LogsSubmitter {
+    fn drop(&mut self) {
+        self.flusher.abort();
+
+        if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+            let buffer = Arc::clone(&self.buffer);
+            runtime.spawn(async move {
+                let _ = buffer.lock().await.flush().await;
+            });
+        }
+    }
+}
+
+/// Background task of a [LogsSubmitter], flushing its buffer once the flush interval elapsed.
+async fn flush_when_due(buffer: Arc<Mutex<Buffer>>, buffered: Arc<Notify>) {
+    loop {
+        let (due_at, flush_interval) = {
+            let buffer = buffer.lock().await;
+            (buffer.due_at(), buffer.config.flush_interval)
+        };
+        match due_at {
+            Some(due_at) => tokio::time::sleep_until(due_at.into()).await,
+            None => {
+                buffered.notified().await;
+                continue;
+            }
+        }
+
+        // The error is not reported, wait a whole interval before sending the kept logs again.
+        if buffer.lock().await.flush_if_due().await.is_err() {
+            tokio::time::sleep(flush_interval).await;
+        }
+    }
+}
+
+/// Logs buffered by a [LogsSubmitter].
+struct Buffer {
+    /// Client used to send the batches.
+    client: Client,
+    /// Limits, interval and retry configuration.
+    config: Config,
+    /// Serialized logs waiting to be sent.
+    entries: Vec<Vec<u8>>,
+    /// Size of the JSON array holding the buffered entries.
+    payload_bytes: usize,
+    /// When the oldest buffered entry was submitted.
+    oldest_entry_at: Option<Instant>,
+}
+
+impl Buffer {
+    /// When the buffered logs must be flushed, if any.
+    fn due_at(&self) -> Option<Instant> {
+        self.oldest_entry_at
+            .map(|oldest_entry_at| oldest_entry_at + self.config.flush_interval)
+    }
+
+    /// See [LogsSubmitter::submit].
+    async fn submit(&mut self, log: &HttpLogItem) -> Result<(), Error> {
+        let entry = serde_json::to_vec(log)?;
+        if entry.len() > self.config.max_entry_bytes {
+            return Err(Error::LogEntryTooLarge {
+                size: entry.len(),
+                limit: self.config.max_entry_bytes,
+            });
+        }
+
+        let separator = usize::from(!self.entries.is_empty());
+        if self.entries.len() >= self.config.max_payload_entries
+            || (!self.entries.is_empty()
+                && self.payload_bytes + separator + entry.len() > self.config.max_payload_bytes)
+        {
+            self.flush().await?;
+        }
+
+        let separator = usize::from(!self.entries.is_empty());
+        self.payload_bytes += separator + entry.len();
+        self.entries.push(entry);
+        self.oldest_entry_at.get_or_insert_with(Instant::now);
+
+        if self.entries.len() >= self.config.max_payload_entries {
+            self.flush().await
+        } else {
+            self.flush_if_due().await
+        }
+    }
+
+    /// See [LogsSubmitter::flush_if_due].
+    async fn flush_if_due(&mut self) -> Result<(), Error> {
+        match self.oldest_entry_at {
+            Some(oldest_entry_at) if oldest_entry_at.elapsed() >= self.config.flush_interval => {
+                self.flush().await
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// See [LogsSubmitter::flush].
+    async fn flush(&mut self) -> Result<(), Error> {
+        if self.entries.is_empty() {
+            return Ok(());
+        }
+
+        let mut payload = Vec::with_capacity(self.payload_bytes);
+        payload.push(b'[');
+        for (i, entry) in self.entries.iter().enumerate() {
+            if i > 0 {
+                payload.push(b',');
+            }
+            payload.extend_from_slice(entry);
+        }
+        payload.push(b']');
+
+        let mut backoff = self.config.retry_backoff;
+        let mut attempt = 0;
+        loop {
+            let result = self
+                .client
+                .submit_log_payload(
+                    payload.clone(),
+                    ContentEncoding::Gzip,
+                    self.config.ddtags.as_deref(),
+                )
+                .await;
+
+            match result {
+                Ok(_) => {
+                    self.clear();
+                    return Ok(());
+                }
+                Err(err) if is_retryable(&err) => {
+                    if attempt >= self.config.max_retries {
+                        return Err(err);
+                    }
+                    attempt += 1;
+                    tokio::time::sleep(backoff).await;
+                    backoff *= 2;
+                }
+                Err(err) => {
+                    self.clear();
+                    return Err(err);
+                }
+            }
+        }
+    }
+
+    /// Empties the buffer once its logs were sent or dropped.
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.payload_bytes = 2;
+        self.oldest_entry_at = None;
+    }
+}
+
+/// Whether sending the same batch again may succeed.
+fn is_retryable(err: &Error) -> bool {
+    match err {
+        Error::RequestTransportError(_) => true,
+        Error::UnexpectedStatus(status) => {
+            *status == StatusCode::REQUEST_TIMEOUT
+                || *status == StatusCode::TOO_MANY_REQUESTS
+                || status.is_server_error()
+        }
+        _ => false,
+    }
+}
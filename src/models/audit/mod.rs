@@ -2,6 +2,7 @@ use serde_derive::{Deserialize, Serialize};
 use serde_json::Value;
 
 use super::client::PagedResponse;
+use super::search::{SearchOptions, SearchPage, SearchSort};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub enum AuditTypes {
@@ -43,26 +44,6 @@ pub struct SearchAuditLogsEventsFilter {
     pub to: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct SearchAuditLogsEventsOptions {
-    /// Time offset (in seconds) to apply to the query.
-    pub time_offset: i64,
-    /// The timezone can be specified as GMT, UTC, an offset from UTC (like UTC+1), or as a Timezone Database identifier (like America/New_York).
-    ///
-    /// default: `UTC`
-    pub timezone: String,
-}
-
-/// Paging attributes for listing events.
-#[derive(Debug, Serialize, Deserialize)]
-pub struct SearchAuditLogsEventsPage {
-    /// List following results with a cursor provided in the previous query.
-    pub cursor: Option<String>,
-    /// Maximum number of events in the response.
-    /// default: `10`
-    pub limit: i32,
-}
-
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SearchAuditLogsEventsRequest {
     /// Search and filter query settings.
@@ -77,15 +58,6 @@ pub struct SearchAuditLogsEventsRequest {
     pub sort: SearchAuditLogsEventsSort,
 }
 
-/// Sort parameters when querying events.
-#[derive(Debug, Serialize, Deserialize)]
-pub enum AuditLogsEventsSort {
-    #[serde(rename = "timestamp")]
-    Timestamp,
-    #[serde(rename = "-timestamp")]
-    NegativeTimestamp,
-}
-
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ListAuditLogsEventsRequest {
     /// Search query following Audit Logs syntax.
@@ -111,6 +83,9 @@ pub struct ListAuditLogsEvents {
     pub typ: AuditTypes,
 }
 
+pub type SearchAuditLogsEventsOptions = SearchOptions;
+pub type SearchAuditLogsEventsPage = SearchPage;
+pub type AuditLogsEventsSort = SearchSort;
 pub type SearchAuditLogsEventsSort = AuditLogsEventsSort;
 pub type ListAuditLogsEventsSort = AuditLogsEventsSort;
 pub type ListAuditLogsEventsResponse = PagedResponse<ListAuditLogsEvents>;
@@ -20,24 +20,24 @@ impl<'de> Deserialize<'de> for EmptyResponse {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Link {
     /// Link for the next set of results. Note that the request can also be made using the POST endpoint.
-    next: String,
+    pub next: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Warnings {
     /// Unique code for this type of warning.
-    code: String,
+    pub code: String,
     /// Detailed explanation of this specific warning.
-    detail: String,
+    pub detail: String,
     /// Short human-readable summary of the warning.
-    title: String,
+    pub title: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Page {
     /// The cursor to use to get the next results, if any.
     /// To make the next request, use the same parameters with the addition of page[cursor].
-    after: String,
+    pub after: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -52,16 +52,16 @@ pub enum Status {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Meta {
     /// Time elapsed in milliseconds.
-    elapsed: i64,
-    /// Paging attributes.
-    page: Page,
+    pub elapsed: Option<i64>,
+    /// Paging attributes. Only present when there are more results to fetch.
+    pub page: Option<Page>,
     /// The identifier of the request.
-    request_id: String,
+    pub request_id: Option<String>,
     /// The status of the response. Allowed enum values: `done`,`timeout`
-    status: Status,
+    pub status: Option<Status>,
     /// A list of warnings (non-fatal errors) encountered.
     /// Partial results may return if warnings are present in the response.
-    warnings: Vec<Warnings>,
+    pub warnings: Option<Vec<Warnings>>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -76,6 +76,16 @@ pub struct PagedResponse<T> {
     pub meta: Option<Meta>,
 }
 
+impl<T> PagedResponse<T> {
+    /// The cursor to request the next results with, if there are any.
+    pub fn next_cursor(&self) -> Option<&str> {
+        self.meta
+            .as_ref()
+            .and_then(|meta| meta.page.as_ref())
+            .map(|page| page.after.as_str())
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Request<T> {
     pub data: T,
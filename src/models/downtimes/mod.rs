@@ -0,0 +1,490 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::client::{EmptyResponse, Request};
+
+/// Downtime resource type.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum DowntimeResourceType {
+    /// Equals to `downtime`.
+    #[serde(rename = "downtime")]
+    Downtime,
+}
+
+/// The current status of the downtime.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DowntimeStatus {
+    /// Equals to `active`.
+    Active,
+    /// Equals to `canceled`.
+    Canceled,
+    /// Equals to `ended`.
+    Ended,
+    /// Equals to `scheduled`.
+    Scheduled,
+}
+
+/// State that will trigger a monitor notification when the `notify_end_types` action occurs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DowntimeNotifyEndState {
+    /// Equals to `alert`.
+    #[serde(rename = "alert")]
+    Alert,
+    /// Equals to `no data`.
+    #[serde(rename = "no data")]
+    NoData,
+    /// Equals to `warn`.
+    #[serde(rename = "warn")]
+    Warn,
+}
+
+/// Action that will trigger a monitor notification if the downtime is in the `notify_end_types` state.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DowntimeNotifyEndType {
+    /// Equals to `canceled`.
+    Canceled,
+    /// Equals to `expired`.
+    Expired,
+}
+
+/// Monitor identifier for the downtime, either a monitor ID or a list of monitor tags.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DowntimeMonitorIdentifier {
+    /// Object of the monitor identifier.
+    Id {
+        /// ID of the monitor to prevent notifications.
+        monitor_id: i64,
+    },
+    /// Object of the monitor tags.
+    Tags {
+        /// A list of monitor tags. For example, tags that are applied directly to monitors,
+        /// not tags that are used in monitor queries (which are filtered by the scope parameter),
+        /// to which the downtime applies. The resulting downtime applies to monitors that match all provided monitor tags.
+        monitor_tags: Vec<String>,
+    },
+}
+
+/// An RRULE-based recurring downtime.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DowntimeScheduleRecurrence {
+    /// The length of the downtime. Must begin with an integer and end with one of 'm', 'h', d', or 'w'.
+    pub duration: String,
+    /// The `RRULE` standard for defining recurring events.
+    /// For example, to have a recurring event on the first day of each month, set the type to `rrule`
+    /// and set the `FREQ` to `MONTHLY` and `BYMONTHDAY` to `1`.
+    /// Most common `rrule` options from the [iCalendar Spec](https://tools.ietf.org/html/rfc5545) are supported.
+    pub rrule: String,
+    /// ISO-8601 Datetime to start the downtime. Must not include a UTC offset.
+    /// If not provided, the downtime starts the moment it is created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<String>,
+}
+
+/// The most recent actual start and end dates for a recurring downtime.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DowntimeScheduleCurrentDowntime {
+    /// The end of the current downtime.
+    pub end: Option<String>,
+    /// The start of the current downtime.
+    pub start: Option<String>,
+}
+
+/// The schedule that defines when the monitor starts, stops, and recurs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DowntimeSchedule {
+    /// A recurring downtime schedule definition.
+    Recurring {
+        /// The most recent actual start and end dates for a recurring downtime.
+        /// This value is set by the API.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        current_downtime: Option<DowntimeScheduleCurrentDowntime>,
+        /// A list of downtime recurrences.
+        recurrences: Vec<DowntimeScheduleRecurrence>,
+        /// The timezone in which to schedule the downtime. Defaults to `UTC`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        timezone: Option<String>,
+    },
+    /// A one-time downtime definition.
+    OneTime {
+        /// ISO-8601 Datetime to end the downtime. Must include a UTC offset of zero.
+        /// If not provided, the downtime continues forever.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        end: Option<String>,
+        /// ISO-8601 Datetime to start the downtime. Must include a UTC offset of zero.
+        /// If not provided, the downtime starts the moment it is created.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        start: Option<String>,
+    },
+}
+
+/// Downtime details.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DowntimeAttributes {
+    /// Time that the downtime was canceled.
+    pub canceled: Option<String>,
+    /// Creation time of the downtime.
+    pub created: Option<String>,
+    /// The timezone in which to display the downtime's start and end times in Datadog applications.
+    pub display_timezone: Option<String>,
+    /// A message to include with notifications for this downtime.
+    pub message: Option<String>,
+    /// Time that the downtime was last modified.
+    pub modified: Option<String>,
+    /// Monitor identifier for the downtime.
+    pub monitor_identifier: Option<DowntimeMonitorIdentifier>,
+    /// If the first recovery notification during a downtime should be muted.
+    pub mute_first_recovery_notification: Option<bool>,
+    /// States that will trigger a monitor notification when the `notify_end_types` action occurs.
+    pub notify_end_states: Option<Vec<DowntimeNotifyEndState>>,
+    /// Actions that will trigger a monitor notification if the downtime is in the `notify_end_types` state.
+    pub notify_end_types: Option<Vec<DowntimeNotifyEndType>>,
+    /// The schedule that defines when the monitor starts, stops, and recurs.
+    pub schedule: Option<DowntimeSchedule>,
+    /// The scope to which the downtime applies. Must follow the
+    /// [common search syntax](https://docs.datadoghq.com/logs/explorer/search_syntax/).
+    pub scope: Option<String>,
+    /// The current status of the downtime.
+    pub status: Option<DowntimeStatus>,
+}
+
+/// Type of the user the downtime relationships point to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum DowntimeUsersType {
+    /// Equals to `users`.
+    #[serde(rename = "users")]
+    Users,
+}
+
+/// Type of the monitor the downtime relationships point to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum DowntimeMonitorsType {
+    /// Equals to `monitors`.
+    #[serde(rename = "monitors")]
+    Monitors,
+}
+
+/// Data for the user who created the downtime.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DowntimeRelationshipsCreatedByData {
+    /// User ID of the downtime creator.
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    /// Users resource type.
+    pub typ: Option<DowntimeUsersType>,
+}
+
+/// The user who created the downtime.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DowntimeRelationshipsCreatedBy {
+    /// Data for the user who created the downtime.
+    pub data: Option<DowntimeRelationshipsCreatedByData>,
+}
+
+/// Data for the monitor.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DowntimeRelationshipsMonitorData {
+    /// Monitor ID of the downtime.
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    /// Monitor resource type.
+    pub typ: Option<DowntimeMonitorsType>,
+}
+
+/// The monitor identified by the downtime.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DowntimeRelationshipsMonitor {
+    /// Data for the monitor. Null when the downtime identifies monitors by tags.
+    pub data: Option<DowntimeRelationshipsMonitorData>,
+}
+
+/// All relationships associated with the downtime.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DowntimeRelationships {
+    /// The user who created the downtime.
+    pub created_by: Option<DowntimeRelationshipsCreatedBy>,
+    /// The monitor identified by the downtime.
+    pub monitor: Option<DowntimeRelationshipsMonitor>,
+}
+
+/// Downtime data.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Downtime {
+    /// Downtime details.
+    pub attributes: Option<DowntimeAttributes>,
+    /// The downtime ID.
+    pub id: Option<String>,
+    /// All relationships associated with the downtime.
+    pub relationships: Option<DowntimeRelationships>,
+    #[serde(rename = "type")]
+    /// Downtime resource type.
+    pub typ: Option<DowntimeResourceType>,
+}
+
+/// Attributes of the user who created the downtime.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DowntimeIncludedUserAttributes {
+    /// Email of the user.
+    pub email: Option<String>,
+    /// Handle of the user.
+    pub handle: Option<String>,
+    /// Name of the user.
+    pub name: Option<String>,
+    /// Status of the user.
+    pub status: Option<String>,
+}
+
+/// The user who created the downtime, included through `include=created_by`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DowntimeIncludedUser {
+    /// Attributes of the user.
+    pub attributes: Option<DowntimeIncludedUserAttributes>,
+    /// ID of the user.
+    pub id: Option<String>,
+}
+
+/// Attributes of the monitor identified by the downtime.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DowntimeIncludedMonitorAttributes {
+    /// The name of the monitor identified by the downtime.
+    pub name: Option<String>,
+}
+
+/// The monitor identified by the downtime, included through `include=monitor`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DowntimeIncludedMonitor {
+    /// Attributes of the monitor.
+    pub attributes: Option<DowntimeIncludedMonitorAttributes>,
+    /// ID of the monitor.
+    pub id: Option<i64>,
+}
+
+/// A resource related to a downtime, tagged by its `type`.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum DowntimeIncluded {
+    /// Equals to `users`.
+    #[serde(rename = "users")]
+    User(DowntimeIncludedUser),
+    /// Equals to `monitors`.
+    #[serde(rename = "monitors")]
+    Monitor(DowntimeIncludedMonitor),
+}
+
+/// Downtime data with the related resources requested through `include`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DowntimeResponse {
+    /// Downtime data.
+    pub data: Downtime,
+    /// Array of objects related to the downtime that the user requested.
+    pub included: Option<Vec<DowntimeIncluded>>,
+}
+
+/// Object containing the total filtered count.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DowntimeMetaPage {
+    /// Total count of elements matched by the filter.
+    pub total_filtered_count: Option<i64>,
+}
+
+/// Pagination metadata returned by the API.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DowntimeMeta {
+    /// Object containing the total filtered count.
+    pub page: Option<DowntimeMetaPage>,
+}
+
+/// Response for retrieving all downtimes.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListDowntimesResponse {
+    /// An array of downtimes.
+    pub data: Vec<Downtime>,
+    /// Array of objects related to the downtimes.
+    pub included: Option<Vec<DowntimeIncluded>>,
+    /// Pagination metadata returned by the API.
+    pub meta: Option<DowntimeMeta>,
+}
+
+/// Downtime details to create.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DowntimeCreateRequestAttributes {
+    /// The timezone in which to display the downtime's start and end times in Datadog applications.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_timezone: Option<String>,
+    /// A message to include with notifications for this downtime.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Monitor identifier for the downtime.
+    pub monitor_identifier: DowntimeMonitorIdentifier,
+    /// If the first recovery notification during a downtime should be muted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mute_first_recovery_notification: Option<bool>,
+    /// States that will trigger a monitor notification when the `notify_end_types` action occurs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_end_states: Option<Vec<DowntimeNotifyEndState>>,
+    /// Actions that will trigger a monitor notification if the downtime is in the `notify_end_types` state.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_end_types: Option<Vec<DowntimeNotifyEndType>>,
+    /// The schedule that defines when the monitor starts, stops, and recurs.
+    /// If not provided, the downtime starts now and continues forever.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<DowntimeSchedule>,
+    /// The scope to which the downtime applies. Must follow the
+    /// [common search syntax](https://docs.datadoghq.com/logs/explorer/search_syntax/).
+    pub scope: String,
+}
+
+/// Object to create a downtime.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DowntimeCreateRequestData {
+    /// Downtime details to create.
+    pub attributes: DowntimeCreateRequestAttributes,
+    #[serde(rename = "type")]
+    /// Downtime resource type.
+    pub typ: DowntimeResourceType,
+}
+
+/// Downtime details to update. Only the given attributes are changed.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct DowntimeUpdateRequestAttributes {
+    /// The timezone in which to display the downtime's start and end times in Datadog applications.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_timezone: Option<String>,
+    /// A message to include with notifications for this downtime.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Monitor identifier for the downtime.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub monitor_identifier: Option<DowntimeMonitorIdentifier>,
+    /// If the first recovery notification during a downtime should be muted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mute_first_recovery_notification: Option<bool>,
+    /// States that will trigger a monitor notification when the `notify_end_types` action occurs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_end_states: Option<Vec<DowntimeNotifyEndState>>,
+    /// Actions that will trigger a monitor notification if the downtime is in the `notify_end_types` state.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_end_types: Option<Vec<DowntimeNotifyEndType>>,
+    /// The schedule that defines when the monitor starts, stops, and recurs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<DowntimeSchedule>,
+    /// The scope to which the downtime applies.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+}
+
+/// Object to update a downtime.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DowntimeUpdateRequestData {
+    /// Downtime details to update.
+    pub attributes: DowntimeUpdateRequestAttributes,
+    /// ID of the downtime to update.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// Downtime resource type.
+    pub typ: DowntimeResourceType,
+}
+
+/// Query parameters to list downtimes.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ListDowntimesRequest {
+    /// Only return downtimes that are active when the request is made.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_only: Option<bool>,
+    /// Comma-separated list of resource paths for related resources to include in the response.
+    /// Supported resource paths are `created_by` and `monitor`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include: Option<String>,
+    /// Specific offset to use as the beginning of the returned page.
+    #[serde(rename = "page[offset]", skip_serializing_if = "Option::is_none")]
+    pub page_offset: Option<i64>,
+    /// Maximum number of downtimes in the response.
+    #[serde(rename = "page[limit]", skip_serializing_if = "Option::is_none")]
+    pub page_limit: Option<i64>,
+}
+
+/// Path and query parameters to get a downtime.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetDowntimeRequest {
+    /// ID of the downtime to fetch.
+    #[serde(skip)]
+    pub downtime_id: String,
+    /// Comma-separated list of resource paths for related resources to include in the response.
+    /// Supported resource paths are `created_by` and `monitor`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include: Option<String>,
+}
+
+/// Path parameters to cancel a downtime.
+#[derive(Debug)]
+pub struct CancelDowntimeRequest {
+    /// ID of the downtime to cancel.
+    pub downtime_id: String,
+}
+
+/// Path and query parameters to list the active downtimes of a monitor.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListMonitorDowntimesRequest {
+    /// The ID of the monitor.
+    #[serde(skip)]
+    pub monitor_id: i64,
+    /// Specific offset to use as the beginning of the returned page.
+    #[serde(rename = "page[offset]", skip_serializing_if = "Option::is_none")]
+    pub page_offset: Option<i64>,
+    /// Maximum number of downtimes in the response.
+    #[serde(rename = "page[limit]", skip_serializing_if = "Option::is_none")]
+    pub page_limit: Option<i64>,
+}
+
+/// Monitor downtime match resource type.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum MonitorDowntimeMatchResourceType {
+    /// Equals to `downtime_match`.
+    #[serde(rename = "downtime_match")]
+    DowntimeMatch,
+}
+
+/// Downtime match details.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MonitorDowntimeMatchAttributes {
+    /// The end of the downtime.
+    pub end: Option<String>,
+    /// An array of groups associated with the downtime.
+    pub groups: Option<Vec<String>>,
+    /// The scope to which the downtime applies.
+    pub scope: Option<String>,
+    /// The start of the downtime.
+    pub start: Option<String>,
+}
+
+/// A downtime match.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MonitorDowntimeMatch {
+    /// Downtime match details.
+    pub attributes: Option<MonitorDowntimeMatchAttributes>,
+    /// The downtime ID.
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    /// Monitor downtime match resource type.
+    pub typ: Option<MonitorDowntimeMatchResourceType>,
+}
+
+/// Response for retrieving all downtime matches for a monitor.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListMonitorDowntimesResponse {
+    /// An array of downtime matches.
+    pub data: Vec<MonitorDowntimeMatch>,
+    /// Pagination metadata returned by the API.
+    pub meta: Option<DowntimeMeta>,
+}
+
+// Responses and requests
+/// Response to the cancellation of a downtime.
+pub type CancelDowntimeResponse = EmptyResponse;
+
+/// The downtime to create.
+pub type CreateDowntimeRequest = Request<DowntimeCreateRequestData>;
+/// The downtime to update.
+pub type UpdateDowntimeRequest = Request<DowntimeUpdateRequestData>;
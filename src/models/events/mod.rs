@@ -0,0 +1,213 @@
+use serde_derive::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use super::client::PagedResponse;
+use super::search::{SearchOptions, SearchPage, SearchSort};
+
+/// If an alert event is enabled, set its type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventAlertType {
+    /// Equals to `error`.
+    Error,
+    /// Equals to `warning`.
+    Warning,
+    /// Equals to `info`.
+    Info,
+    /// Equals to `success`.
+    Success,
+    /// Equals to `user_update`.
+    UserUpdate,
+    /// Equals to `recommendation`.
+    Recommendation,
+    /// Equals to `snapshot`.
+    Snapshot,
+}
+
+/// The priority of the event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EventPriority {
+    /// Equals to `normal`.
+    Normal,
+    /// Equals to `low`.
+    Low,
+}
+
+/// Object representing an event to post.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct CreateEventRequest {
+    /// An arbitrary string to use for aggregation. Limited to 100 characters.
+    /// If you specify a key, all events using that key are grouped together in the Event Stream.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aggregation_key: Option<String>,
+    /// If an alert event is enabled, set its type. Defaults to `info`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alert_type: Option<EventAlertType>,
+    /// POSIX timestamp of the event. Must be sent as an integer (that is no quotes).
+    /// Limited to events no older than 18 hours. Defaults to now.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_happened: Option<i64>,
+    /// A device name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_name: Option<String>,
+    /// Host name to associate with the event. Any tags associated with the host are also applied to this event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    /// The priority of the event. Defaults to `normal`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<EventPriority>,
+    /// ID of the parent event. Must be sent as an integer (that is no quotes).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub related_event_id: Option<i64>,
+    /// The type of event being posted. Option examples include nagios, hudson, jenkins, my_apps, chef, puppet, git, bitbucket, etc.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_type_name: Option<String>,
+    /// A list of tags to apply to the event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// The body of the event. Limited to 4000 characters. The text supports markdown.
+    /// To use markdown in the event text, start the text block with `%%% \n` and end the text block with `\n %%%`.
+    pub text: String,
+    /// The event title.
+    pub title: String,
+}
+
+/// Object representing an event.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Event {
+    /// If an alert event is enabled, set its type.
+    pub alert_type: Option<EventAlertType>,
+    /// POSIX timestamp of the event.
+    pub date_happened: Option<i64>,
+    /// A device name.
+    pub device_name: Option<String>,
+    /// Host name to associate with the event.
+    pub host: Option<String>,
+    /// Integer ID of the event.
+    pub id: Option<i64>,
+    /// Handling IDs as large 64-bit numbers can cause loss of accuracy issues with some programming languages.
+    /// Instead, use the string representation of the Event ID to avoid losing accuracy.
+    pub id_str: Option<String>,
+    /// Payload of the event.
+    pub payload: Option<String>,
+    /// The priority of the event.
+    pub priority: Option<EventPriority>,
+    /// The type of event being posted.
+    pub source_type_name: Option<String>,
+    /// A list of tags to apply to the event.
+    pub tags: Option<Vec<String>>,
+    /// The body of the event.
+    pub text: Option<String>,
+    /// The event title.
+    pub title: Option<String>,
+    /// URL of the event.
+    pub url: Option<String>,
+}
+
+/// Object containing an event response.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateEventResponse {
+    /// Object representing an event.
+    pub event: Option<Event>,
+    /// A status.
+    pub status: Option<String>,
+}
+
+/// Type of the event.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum EventType {
+    /// Equals to `event`.
+    #[serde(rename = "event")]
+    Event,
+}
+
+/// The object description of an event response attribute.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EventResponseAttributes {
+    /// Object description of attributes from your event.
+    pub attributes: Option<Map<String, Value>>,
+    /// The message of the event.
+    pub message: Option<String>,
+    /// An array of tags associated with the event.
+    pub tags: Option<Vec<String>>,
+    /// The timestamp of the event.
+    // TODO: Use a datetime type.
+    pub timestamp: Option<String>,
+}
+
+/// The object description of an event after being processed and stored by Datadog.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EventResponse {
+    /// The object description of an event response attribute.
+    pub attributes: EventResponseAttributes,
+    /// the unique ID of the event.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// Type of the event.
+    pub typ: EventType,
+}
+
+/// The search and filter query settings.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct EventsQueryFilter {
+    /// The minimum time for the requested events. Supports date math and regular timestamps in milliseconds.
+    ///
+    /// default: `now-15m`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    /// The search query following the event search syntax.
+    ///
+    /// default: `*`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// The maximum time for the requested events. Supports date math and regular timestamps in milliseconds.
+    ///
+    /// default: `now`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+}
+
+/// The object sent with the request to retrieve a list of events from your organization.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SearchEventsRequest {
+    /// The search and filter query settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<EventsQueryFilter>,
+    /// Global query options that are used during the query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<SearchOptions>,
+    /// Pagination settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<SearchPage>,
+    /// The sort parameters when querying events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<SearchSort>,
+}
+
+/// Query parameters to get a list of events.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ListEventsRequest {
+    /// Search query following events syntax.
+    #[serde(rename = "filter[query]", skip_serializing_if = "Option::is_none")]
+    pub filter_query: Option<String>,
+    /// Minimum timestamp for requested events.
+    #[serde(rename = "filter[from]", skip_serializing_if = "Option::is_none")]
+    pub filter_from: Option<String>,
+    /// Maximum timestamp for requested events.
+    #[serde(rename = "filter[to]", skip_serializing_if = "Option::is_none")]
+    pub filter_to: Option<String>,
+    /// Order of events in results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<SearchSort>,
+    /// List following results with a cursor provided in the previous query.
+    #[serde(rename = "page[cursor]", skip_serializing_if = "Option::is_none")]
+    pub page_cursor: Option<String>,
+    /// Maximum number of events in the response.
+    #[serde(rename = "page[limit]", skip_serializing_if = "Option::is_none")]
+    pub page_limit: Option<i32>,
+}
+
+// Responses and requests
+/// The events matching a query, with the cursor to the next results.
+pub type ListEventsResponse = PagedResponse<EventResponse>;
@@ -0,0 +1,167 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::client::{EmptyResponse, Request, Response};
+use crate::secret::{serialize_exposed, serialize_exposed_option, SecretString};
+
+/// Fastly account resource type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum FastlyAccountType {
+    /// Equals to `fastly-accounts`.
+    #[serde(rename = "fastly-accounts")]
+    FastlyAccounts,
+}
+
+/// Fastly service resource type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum FastlyServiceType {
+    /// Equals to `fastly-services`.
+    #[serde(rename = "fastly-services")]
+    FastlyServices,
+}
+
+/// A Fastly service monitored with an account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FastlyService {
+    /// The ID of the Fastly service.
+    pub id: String,
+    /// A list of tags for the Fastly service.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+}
+
+/// Attributes of a Fastly account. The API key is never returned.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FastlyAccountAttributes {
+    /// The name of the Fastly account.
+    pub name: Option<String>,
+    /// A list of services belonging to the account.
+    pub services: Option<Vec<FastlyService>>,
+}
+
+/// A Fastly account.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FastlyAccount {
+    /// Attributes of the Fastly account.
+    pub attributes: Option<FastlyAccountAttributes>,
+    /// The ID of the Fastly account, a hash of the account name.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// Fastly account resource type.
+    pub typ: FastlyAccountType,
+}
+
+/// Attributes of the Fastly account to add.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FastlyAccountCreateAttributes {
+    /// The API key of the Fastly account.
+    #[serde(serialize_with = "serialize_exposed")]
+    pub api_key: SecretString,
+    /// The name of the Fastly account.
+    pub name: String,
+    /// A list of services belonging to the account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub services: Option<Vec<FastlyService>>,
+}
+
+/// Data of the Fastly account to add.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FastlyAccountCreateData {
+    /// Attributes of the Fastly account.
+    pub attributes: FastlyAccountCreateAttributes,
+    #[serde(rename = "type")]
+    /// Fastly account resource type.
+    pub typ: FastlyAccountType,
+}
+
+/// Attributes of the Fastly account to update.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct FastlyAccountUpdateAttributes {
+    /// The new API key of the Fastly account.
+    #[serde(
+        serialize_with = "serialize_exposed_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub api_key: Option<SecretString>,
+}
+
+/// Data of the Fastly account to update.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FastlyAccountUpdateData {
+    /// Attributes of the Fastly account.
+    pub attributes: FastlyAccountUpdateAttributes,
+    #[serde(rename = "type")]
+    /// Fastly account resource type.
+    pub typ: FastlyAccountType,
+}
+
+/// Path parameters to get or delete a Fastly account, or list its services.
+#[derive(Debug)]
+pub struct FastlyAccountRequest {
+    /// The ID of the Fastly account.
+    pub account_id: String,
+}
+
+/// Path parameters and body to update a Fastly account.
+#[derive(Debug)]
+pub struct UpdateFastlyAccountRequest {
+    /// The ID of the Fastly account.
+    pub account_id: String,
+    /// The changes to the Fastly account.
+    pub account: Request<FastlyAccountUpdateData>,
+}
+
+/// Attributes of a Fastly service.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct FastlyServiceAttributes {
+    /// A list of tags for the Fastly service.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+}
+
+/// A Fastly service of an account.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FastlyServiceData {
+    /// Attributes of the Fastly service.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<FastlyServiceAttributes>,
+    /// The ID of the Fastly service.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// Fastly service resource type.
+    pub typ: FastlyServiceType,
+}
+
+/// Path parameters and body to add or update a Fastly service.
+#[derive(Debug)]
+pub struct FastlyServiceWriteRequest {
+    /// The ID of the Fastly account.
+    pub account_id: String,
+    /// The Fastly service. Its ID is the one of the service to update.
+    pub service: Request<FastlyServiceData>,
+}
+
+/// Path parameters to get or delete a Fastly service.
+#[derive(Debug)]
+pub struct FastlyServiceRequest {
+    /// The ID of the Fastly account.
+    pub account_id: String,
+    /// The ID of the Fastly service.
+    pub service_id: String,
+}
+
+// Responses and requests
+/// Response containing a Fastly account.
+pub type FastlyAccountResponse = Response<FastlyAccount>;
+/// Response containing all the Fastly accounts.
+pub type FastlyAccountsResponse = Response<Vec<FastlyAccount>>;
+/// Response to the deletion of a Fastly account.
+pub type DeleteFastlyAccountResponse = EmptyResponse;
+/// Response containing a Fastly service.
+pub type FastlyServiceResponse = Response<FastlyServiceData>;
+/// Response containing the Fastly services of an account.
+pub type FastlyServicesResponse = Response<Vec<FastlyServiceData>>;
+/// Response to the deletion of a Fastly service.
+pub type DeleteFastlyServiceResponse = EmptyResponse;
+
+/// The Fastly account to add.
+pub type AddFastlyAccountRequest = Request<FastlyAccountCreateData>;
@@ -0,0 +1,268 @@
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::client::{EmptyResponse, Request, Response};
+
+/// Type of the GCP STS enabled service account resource.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum GcpServiceAccountType {
+    /// Equals to `gcp_service_account`.
+    #[serde(rename = "gcp_service_account")]
+    GcpServiceAccount,
+}
+
+/// Type of the Datadog STS delegate resource.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum GcpStsDelegateAccountType {
+    /// Equals to `gcp_sts_delegate`.
+    #[serde(rename = "gcp_sts_delegate")]
+    GcpStsDelegate,
+}
+
+/// Filter on the labels of the GCP hosts, sent as `key:value` or `!key:value` to the API.
+/// `*` and `?` wildcards are supported in both the key and the value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GcpHostFilter {
+    /// Label key to filter on.
+    pub key: String,
+    /// Label value to filter on. Only the presence of the key is checked when not set.
+    pub value: Option<String>,
+    /// Whether the matching hosts are excluded instead of included.
+    pub exclude: bool,
+}
+
+impl GcpHostFilter {
+    /// Only include the hosts with the given label.
+    pub fn include(key: impl Into<String>, value: impl Into<String>) -> Self {
+        GcpHostFilter {
+            key: key.into(),
+            value: Some(value.into()),
+            exclude: false,
+        }
+    }
+
+    /// Exclude the hosts with the given label.
+    pub fn exclude(key: impl Into<String>, value: impl Into<String>) -> Self {
+        GcpHostFilter {
+            key: key.into(),
+            value: Some(value.into()),
+            exclude: true,
+        }
+    }
+}
+
+impl From<&str> for GcpHostFilter {
+    fn from(filter: &str) -> Self {
+        let (exclude, filter) = match filter.strip_prefix('!') {
+            Some(filter) => (true, filter),
+            None => (false, filter),
+        };
+        let (key, value) = match filter.split_once(':') {
+            Some((key, value)) => (key, Some(value.to_string())),
+            None => (filter, None),
+        };
+
+        GcpHostFilter {
+            key: key.to_string(),
+            value,
+            exclude,
+        }
+    }
+}
+
+impl fmt::Display for GcpHostFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.exclude {
+            write!(f, "!")?;
+        }
+        match &self.value {
+            Some(value) => write!(f, "{}:{}", self.key, value),
+            None => write!(f, "{}", self.key),
+        }
+    }
+}
+
+impl Serialize for GcpHostFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GcpHostFilter {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let filter = String::deserialize(deserializer)?;
+        Ok(GcpHostFilter::from(filter.as_str()))
+    }
+}
+
+/// Configuration of a GCP metric namespace.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GcpMetricNamespaceConfig {
+    /// The id of the metric namespace, such as `aiplatform` or `cloudsql`.
+    pub id: String,
+    /// Whether the collection of the metrics of the namespace is disabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
+}
+
+impl GcpMetricNamespaceConfig {
+    /// Disable the collection of the metrics of the given namespace.
+    pub fn disabled(id: impl Into<String>) -> Self {
+        GcpMetricNamespaceConfig {
+            id: id.into(),
+            disabled: Some(true),
+        }
+    }
+}
+
+/// Attributes associated with a GCP STS enabled service account.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GcpStsServiceAccountAttributes {
+    /// Tags to be associated with GCP metrics and service checks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_tags: Option<Vec<String>>,
+    /// Silence monitors for expected GCE instance shutdowns.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automute: Option<bool>,
+    /// Your service account email address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_email: Option<String>,
+    /// Filters on the labels of the Cloud Run revisions to collect metrics from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud_run_revision_filters: Option<Vec<GcpHostFilter>>,
+    /// Filters on the labels of the hosts to collect metrics from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host_filters: Option<Vec<GcpHostFilter>>,
+    /// Whether Datadog collects cloud security posture management resources from your GCP
+    /// project. Requires `resource_collection_enabled`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_cspm_enabled: Option<bool>,
+    /// Whether the usage of the quota is attributed to the monitored project instead of the
+    /// project of the service account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_per_project_quota_enabled: Option<bool>,
+    /// Whether Datadog collects the changes of the resources of your GCP project.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_resource_change_collection_enabled: Option<bool>,
+    /// Whether Datadog collects Security Command Center findings from your GCP project.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_security_command_center_enabled: Option<bool>,
+    /// Configurations of the metric namespaces. All namespaces are collected by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metric_namespace_configs: Option<Vec<GcpMetricNamespaceConfig>>,
+    /// Whether Datadog collects the resources of your GCP project.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_collection_enabled: Option<bool>,
+}
+
+/// Additional information related to a GCP STS enabled service account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GcpServiceAccountMeta {
+    /// The current list of projects accessible from your service account.
+    pub accessible_projects: Option<Vec<String>>,
+}
+
+/// A GCP STS enabled service account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GcpStsServiceAccount {
+    /// Attributes associated with the service account.
+    pub attributes: Option<GcpStsServiceAccountAttributes>,
+    /// The ID of the service account.
+    pub id: String,
+    /// Additional information related to the service account.
+    pub meta: Option<GcpServiceAccountMeta>,
+    #[serde(rename = "type")]
+    /// Type of the GCP STS enabled service account resource.
+    pub typ: GcpServiceAccountType,
+}
+
+/// Data of a GCP STS enabled service account to create.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GcpStsServiceAccountCreateData {
+    /// Attributes of the service account. `client_email` is required.
+    pub attributes: GcpStsServiceAccountAttributes,
+    #[serde(rename = "type")]
+    /// Type of the GCP STS enabled service account resource.
+    pub typ: GcpServiceAccountType,
+}
+
+impl GcpStsServiceAccountCreateData {
+    /// Create the data of the service account with the given email.
+    pub fn new(client_email: impl Into<String>) -> Self {
+        GcpStsServiceAccountCreateData {
+            attributes: GcpStsServiceAccountAttributes {
+                client_email: Some(client_email.into()),
+                ..Default::default()
+            },
+            typ: GcpServiceAccountType::GcpServiceAccount,
+        }
+    }
+}
+
+/// Data of a GCP STS enabled service account to update.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GcpStsServiceAccountUpdateData {
+    /// Attributes to update. Attributes which are not set are left unchanged.
+    pub attributes: GcpStsServiceAccountAttributes,
+    /// The ID of the service account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    /// Type of the GCP STS enabled service account resource.
+    pub typ: GcpServiceAccountType,
+}
+
+/// Path parameters and body to update a GCP STS enabled service account.
+#[derive(Debug)]
+pub struct UpdateGcpStsAccountRequest {
+    /// The ID of the service account.
+    pub account_id: String,
+    /// The changes to the service account.
+    pub account: Request<GcpStsServiceAccountUpdateData>,
+}
+
+/// Path parameters to delete a GCP STS enabled service account.
+#[derive(Debug)]
+pub struct DeleteGcpStsAccountRequest {
+    /// The ID of the service account.
+    pub account_id: String,
+}
+
+/// Attributes of the Datadog STS delegate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GcpStsDelegateAccountAttributes {
+    /// Email of the Datadog service account to grant the token creator role to.
+    pub delegate_account_email: Option<String>,
+}
+
+/// The Datadog STS delegate of the organization.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GcpStsDelegateAccount {
+    /// Attributes of the delegate.
+    pub attributes: Option<GcpStsDelegateAccountAttributes>,
+    /// The ID of the delegate.
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    /// Type of the Datadog STS delegate resource.
+    pub typ: Option<GcpStsDelegateAccountType>,
+}
+
+// Responses and requests
+/// Response containing a GCP STS enabled service account.
+pub type GcpStsServiceAccountResponse = Response<GcpStsServiceAccount>;
+/// Response containing all the GCP STS enabled service accounts.
+pub type GcpStsServiceAccountsResponse = Response<Vec<GcpStsServiceAccount>>;
+/// Response containing the Datadog STS delegate.
+pub type GcpStsDelegateAccountResponse = Response<GcpStsDelegateAccount>;
+/// Response to the deletion of a GCP STS enabled service account.
+pub type DeleteGcpStsAccountResponse = EmptyResponse;
+
+/// The GCP STS enabled service account to create.
+pub type CreateGcpStsAccountRequest = Request<GcpStsServiceAccountCreateData>;
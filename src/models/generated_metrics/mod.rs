@@ -0,0 +1,144 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::client::Request;
+
+/// The type of aggregation to use.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GeneratedMetricAggregationType {
+    /// Equals to `count`.
+    Count,
+    /// Equals to `distribution`.
+    Distribution,
+}
+
+/// The compute rule to compute a metric generated from logs or spans.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneratedMetricCompute {
+    /// The type of aggregation to use.
+    pub aggregation_type: GeneratedMetricAggregationType,
+    /// Toggle to include or exclude percentile aggregations for distribution metrics.
+    /// Only present when the `aggregation_type` is `distribution`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_percentiles: Option<bool>,
+    /// The path to the value the metric will aggregate on.
+    /// Only present when the `aggregation_type` is `distribution`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+/// The compute rule to update on a metric generated from logs or spans.
+/// The aggregation type and path cannot be changed once the metric is created.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GeneratedMetricComputeUpdate {
+    /// Toggle to include or exclude percentile aggregations for distribution metrics.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_percentiles: Option<bool>,
+}
+
+/// The filter selecting the logs or spans the metric is generated from.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GeneratedMetricFilter {
+    /// The search query, following the logs or spans search syntax. Defaults to `*`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+}
+
+/// A group by rule of a metric generated from logs or spans.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneratedMetricGroupBy {
+    /// The path to the value the metric will be aggregated over.
+    pub path: String,
+    /// Eventual name of the tag that gets created. By default, the path attribute is used as the tag name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag_name: Option<String>,
+}
+
+/// The object describing a metric generated from logs or spans.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GeneratedMetricAttributes {
+    /// The compute rule to compute the metric.
+    pub compute: Option<GeneratedMetricCompute>,
+    /// The filter of the metric. Logs or spans matching this filter will be aggregated in this metric.
+    pub filter: Option<GeneratedMetricFilter>,
+    /// The rules for the group by.
+    pub group_by: Option<Vec<GeneratedMetricGroupBy>>,
+}
+
+/// A metric generated from logs or spans, of the resource type `T`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GeneratedMetric<T> {
+    /// The object describing the metric.
+    pub attributes: Option<GeneratedMetricAttributes>,
+    /// The name of the metric.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// The type of the resource.
+    pub typ: T,
+}
+
+/// The object describing the metric generated from logs or spans to create.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GeneratedMetricCreateAttributes {
+    /// The compute rule to compute the metric.
+    pub compute: GeneratedMetricCompute,
+    /// The filter of the metric. Logs or spans matching this filter will be aggregated in this metric.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<GeneratedMetricFilter>,
+    /// The rules for the group by.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_by: Option<Vec<GeneratedMetricGroupBy>>,
+}
+
+/// The new metric generated from logs or spans, of the resource type `T`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GeneratedMetricCreateData<T> {
+    /// The object describing the metric to create.
+    pub attributes: GeneratedMetricCreateAttributes,
+    /// The name of the metric.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// The type of the resource.
+    pub typ: T,
+}
+
+/// The properties to update on a metric generated from logs or spans.
+/// Only the given attributes are changed.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct GeneratedMetricUpdateAttributes {
+    /// The compute rule to update on the metric.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compute: Option<GeneratedMetricComputeUpdate>,
+    /// The filter of the metric. Logs or spans matching this filter will be aggregated in this metric.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<GeneratedMetricFilter>,
+    /// The rules for the group by.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_by: Option<Vec<GeneratedMetricGroupBy>>,
+}
+
+/// The changes to a metric generated from logs or spans, of the resource type `T`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GeneratedMetricUpdateData<T> {
+    /// The properties to update.
+    pub attributes: GeneratedMetricUpdateAttributes,
+    #[serde(rename = "type")]
+    /// The type of the resource.
+    pub typ: T,
+}
+
+/// Path parameters to get or delete a metric generated from logs or spans.
+#[derive(Debug)]
+pub struct GeneratedMetricIdRequest {
+    /// The name of the metric.
+    pub metric_id: String,
+}
+
+/// Path parameters and body to update a metric generated from logs or spans.
+#[derive(Debug)]
+pub struct UpdateGeneratedMetricRequest<T> {
+    /// The name of the metric.
+    pub metric_id: String,
+    /// The properties to update.
+    pub metric: Request<GeneratedMetricUpdateData<T>>,
+}
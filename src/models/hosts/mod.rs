@@ -0,0 +1,234 @@
+use std::collections::BTreeMap;
+
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Field to sort hosts by.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HostsSortField {
+    /// Equals to `status`.
+    Status,
+    /// Equals to `apps`.
+    Apps,
+    /// Equals to `cpu`.
+    Cpu,
+    /// Equals to `iowait`.
+    Iowait,
+    /// Equals to `load`.
+    Load,
+}
+
+/// Direction to sort hosts by.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HostsSortDirection {
+    /// Equals to `asc`.
+    Asc,
+    /// Equals to `desc`.
+    Desc,
+}
+
+/// Query parameters to list hosts.
+#[derive(Debug, Default, Serialize)]
+pub struct ListHostsRequest {
+    /// String to filter search results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    /// Sort hosts by this field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_field: Option<HostsSortField>,
+    /// Direction of sort.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_dir: Option<HostsSortDirection>,
+    /// Host result to start search from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<i64>,
+    /// Number of hosts to return. Max 1000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    /// Number of seconds since UNIX epoch from which you want to search your hosts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<i64>,
+    /// Include information on the muted status of hosts and when the mute expires.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_muted_hosts_data: Option<bool>,
+    /// Include additional metadata about the hosts (agent_version, machine, platform,
+    /// processor, etc.).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_hosts_metadata: Option<bool>,
+}
+
+/// Metadata associated with the agent install method.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HostMetaInstallMethod {
+    /// The installer version.
+    pub installer_version: Option<String>,
+    /// Tool used to install the agent.
+    pub tool: Option<String>,
+    /// The tool version.
+    pub tool_version: Option<String>,
+}
+
+/// Metadata associated with your host.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HostMeta {
+    /// A list of agent checks running on the host, each as a list of its name, instance,
+    /// status and messages.
+    pub agent_checks: Option<Vec<Vec<Value>>>,
+    /// The Datadog Agent version.
+    pub agent_version: Option<String>,
+    /// The number of cores.
+    #[serde(rename = "cpuCores")]
+    pub cpu_cores: Option<i64>,
+    /// An array of Mac versions.
+    #[serde(rename = "fbsdV")]
+    pub fbsd_v: Option<Vec<Value>>,
+    /// JSON string containing system information.
+    pub gohai: Option<String>,
+    /// Agent install method.
+    pub install_method: Option<HostMetaInstallMethod>,
+    /// An array of Mac versions.
+    #[serde(rename = "macV")]
+    pub mac_v: Option<Vec<Value>>,
+    /// The machine architecture.
+    pub machine: Option<String>,
+    /// Array of Unix versions.
+    #[serde(rename = "nixV")]
+    pub nix_v: Option<Vec<Value>>,
+    /// The OS platform.
+    pub platform: Option<String>,
+    /// The processor.
+    pub processor: Option<String>,
+    /// The Python version.
+    #[serde(rename = "pythonV")]
+    pub python_v: Option<String>,
+    /// The socket fqdn.
+    #[serde(rename = "socket-fqdn")]
+    pub socket_fqdn: Option<String>,
+    /// The socket hostname.
+    #[serde(rename = "socket-hostname")]
+    pub socket_hostname: Option<String>,
+    /// An array of time zones.
+    pub timezones: Option<Vec<String>>,
+    /// An array of Windows versions.
+    #[serde(rename = "winV")]
+    pub win_v: Option<Vec<Value>>,
+}
+
+/// Host Metrics collected.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HostMetrics {
+    /// The percent of CPU used (everything but idle).
+    pub cpu: Option<f64>,
+    /// The percent of CPU spent waiting on the IO (not reported for all platforms).
+    pub iowait: Option<f64>,
+    /// The system load over the last 15 minutes.
+    pub load: Option<f64>,
+}
+
+/// Object representing a host.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Host {
+    /// Host aliases collected by Datadog.
+    pub aliases: Option<Vec<String>>,
+    /// The Datadog integrations reporting metrics for the host.
+    pub apps: Option<Vec<String>>,
+    /// AWS name of your host.
+    pub aws_name: Option<String>,
+    /// The host name.
+    pub host_name: Option<String>,
+    /// The host ID.
+    pub id: Option<i64>,
+    /// If a host is muted or unmuted.
+    pub is_muted: Option<bool>,
+    /// Last time the host reported a metric data point.
+    pub last_reported_time: Option<i64>,
+    /// Metadata associated with your host.
+    pub meta: Option<HostMeta>,
+    /// Host Metrics collected.
+    pub metrics: Option<HostMetrics>,
+    /// Timeout of the mute applied to your host.
+    pub mute_timeout: Option<i64>,
+    /// The host name.
+    pub name: Option<String>,
+    /// Source or cloud provider associated with your host.
+    pub sources: Option<Vec<String>>,
+    /// List of tags for each source (AWS, Datadog Agent, Chef..).
+    pub tags_by_source: Option<BTreeMap<String, Vec<String>>>,
+    /// Displays UP when the expected metrics are received and displays `???` if no metrics
+    /// are received.
+    pub up: Option<bool>,
+}
+
+/// Response with Host information from Datadog.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HostListResponse {
+    /// Array of hosts.
+    pub host_list: Vec<Host>,
+    /// Number of host matching the query.
+    pub total_matching: Option<i64>,
+    /// Number of host returned.
+    pub total_returned: Option<i64>,
+}
+
+/// Query parameters to get the total number of hosts.
+#[derive(Debug, Default, Serialize)]
+pub struct GetHostTotalsRequest {
+    /// Number of seconds from which you want to get total number of active hosts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<i64>,
+}
+
+/// Total number of host currently monitored by Datadog.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HostTotals {
+    /// Total number of active host (UP and ???) reporting to Datadog.
+    pub total_active: Option<i64>,
+    /// Number of host that are UP and reporting to Datadog.
+    pub total_up: Option<i64>,
+}
+
+/// Combination of settings to mute a host.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct HostMuteSettings {
+    /// POSIX timestamp in seconds when the host is unmuted. If omitted, the host remains muted
+    /// until explicitly unmuted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<i64>,
+    /// Message to associate with the muting of this host.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// If true and the host is already muted, replaces existing host mute settings.
+    #[serde(rename = "override", skip_serializing_if = "Option::is_none")]
+    pub override_settings: Option<bool>,
+}
+
+/// Path parameters and body to mute a host.
+#[derive(Debug)]
+pub struct MuteHostRequest {
+    /// Name of the host to mute.
+    pub host_name: String,
+    /// The mute settings.
+    pub settings: HostMuteSettings,
+}
+
+/// Path parameters to unmute a host.
+#[derive(Debug)]
+pub struct UnmuteHostRequest {
+    /// Name of the host to unmute.
+    pub host_name: String,
+}
+
+/// Response with the list of muted host for your organization.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HostMuteResponse {
+    /// Action applied to the hosts, `Muted` or `Unmuted`.
+    pub action: Option<String>,
+    /// POSIX timestamp in seconds when the host is unmuted.
+    pub end: Option<i64>,
+    /// The host name.
+    pub hostname: Option<String>,
+    /// Message associated with the mute.
+    pub message: Option<String>,
+}
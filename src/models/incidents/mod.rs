@@ -0,0 +1,923 @@
+use std::collections::BTreeMap;
+
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::client::{EmptyResponse, Request};
+
+/// Incident resource type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum IncidentType {
+    /// Equals to `incidents`.
+    #[serde(rename = "incidents")]
+    Incidents,
+}
+
+/// Users resource type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum IncidentUsersType {
+    /// Equals to `users`.
+    #[serde(rename = "users")]
+    Users,
+}
+
+/// The severity of an incident.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IncidentSeverity {
+    /// Equals to `UNKNOWN`.
+    #[serde(rename = "UNKNOWN")]
+    Unknown,
+    /// Equals to `SEV-1`.
+    #[serde(rename = "SEV-1")]
+    Sev1,
+    /// Equals to `SEV-2`.
+    #[serde(rename = "SEV-2")]
+    Sev2,
+    /// Equals to `SEV-3`.
+    #[serde(rename = "SEV-3")]
+    Sev3,
+    /// Equals to `SEV-4`.
+    #[serde(rename = "SEV-4")]
+    Sev4,
+    /// Equals to `SEV-5`.
+    #[serde(rename = "SEV-5")]
+    Sev5,
+}
+
+impl IncidentSeverity {
+    /// The value of the severity, as expected in the `severity` field.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IncidentSeverity::Unknown => "UNKNOWN",
+            IncidentSeverity::Sev1 => "SEV-1",
+            IncidentSeverity::Sev2 => "SEV-2",
+            IncidentSeverity::Sev3 => "SEV-3",
+            IncidentSeverity::Sev4 => "SEV-4",
+            IncidentSeverity::Sev5 => "SEV-5",
+        }
+    }
+}
+
+/// The state of an incident.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IncidentState {
+    /// Equals to `active`.
+    Active,
+    /// Equals to `stable`.
+    Stable,
+    /// Equals to `resolved`.
+    Resolved,
+}
+
+impl IncidentState {
+    /// The value of the state, as expected in the `state` field.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IncidentState::Active => "active",
+            IncidentState::Stable => "stable",
+            IncidentState::Resolved => "resolved",
+        }
+    }
+}
+
+/// The value of an incident field, tagged by its `type`.
+/// Besides the `severity` and `state` fields, incidents accept the custom fields configured in Datadog.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum IncidentFieldAttributes {
+    /// Equals to `dropdown`.
+    Dropdown {
+        /// The selected value. Null to clear the field.
+        value: Option<String>,
+    },
+    /// Equals to `textbox`.
+    Textbox {
+        /// The text of the field. Null to clear the field.
+        value: Option<String>,
+    },
+    /// Equals to `multiselect`.
+    Multiselect {
+        /// The selected values. Null to clear the field.
+        value: Option<Vec<String>>,
+    },
+    /// Equals to `textarray`.
+    Textarray {
+        /// The values of the field. Null to clear the field.
+        value: Option<Vec<String>>,
+    },
+    /// Equals to `metrictag`.
+    Metrictag {
+        /// The metric tags of the field. Null to clear the field.
+        value: Option<Vec<String>>,
+    },
+    /// Equals to `autocomplete`.
+    Autocomplete {
+        /// The values of the field. Null to clear the field.
+        value: Option<Vec<String>>,
+    },
+}
+
+impl From<IncidentSeverity> for IncidentFieldAttributes {
+    fn from(severity: IncidentSeverity) -> Self {
+        IncidentFieldAttributes::Dropdown {
+            value: Some(severity.as_str().to_string()),
+        }
+    }
+}
+
+impl From<IncidentState> for IncidentFieldAttributes {
+    fn from(state: IncidentState) -> Self {
+        IncidentFieldAttributes::Dropdown {
+            value: Some(state.as_str().to_string()),
+        }
+    }
+}
+
+/// A notification handle that will be notified at incident creation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncidentNotificationHandle {
+    /// The name of the notified handle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// The email address used for the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub handle: Option<String>,
+}
+
+/// The attributes of an incident.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IncidentResponseAttributes {
+    /// Timestamp when the incident was created.
+    pub created: Option<String>,
+    /// Length of the incident's customer impact in seconds.
+    pub customer_impact_duration: Option<i64>,
+    /// Timestamp when customers were no longer impacted by the incident.
+    pub customer_impact_end: Option<String>,
+    /// A summary of the impact customers experienced during the incident.
+    pub customer_impact_scope: Option<String>,
+    /// Timestamp when customers began being impacted by the incident.
+    pub customer_impact_start: Option<String>,
+    /// A flag indicating whether the incident caused customer impact.
+    pub customer_impacted: Option<bool>,
+    /// Timestamp when the incident was detected.
+    pub detected: Option<String>,
+    /// The fields of the incident, including its severity, state and custom fields.
+    pub fields: Option<BTreeMap<String, IncidentFieldAttributes>>,
+    /// Timestamp when the incident was last modified.
+    pub modified: Option<String>,
+    /// Notification handles that will be notified of the incident during update.
+    pub notification_handles: Option<Vec<IncidentNotificationHandle>>,
+    /// The monotonically increasing integer ID for the incident.
+    pub public_id: Option<i64>,
+    /// Timestamp when the incident's state was last changed from active or stable to resolved or completed.
+    pub resolved: Option<String>,
+    /// The severity of the incident.
+    pub severity: Option<IncidentSeverity>,
+    /// The state of the incident.
+    pub state: Option<IncidentState>,
+    /// The amount of time in seconds to detect the incident.
+    pub time_to_detect: Option<i64>,
+    /// The amount of time in seconds to call incident after detection.
+    pub time_to_internal_response: Option<i64>,
+    /// The amount of time in seconds to resolve customer impact after detecting the issue.
+    pub time_to_repair: Option<i64>,
+    /// The amount of time in seconds to resolve customer impact after the issue began.
+    pub time_to_resolve: Option<i64>,
+    /// The title of the incident, which summarizes what happened.
+    pub title: String,
+}
+
+/// Relationship to a user.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncidentUserRelationshipData {
+    /// The ID of the user.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// Users resource type.
+    pub typ: IncidentUsersType,
+}
+
+/// Relationship to a user, which may be unset.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncidentUserRelationship {
+    /// Relationship to the user. Null when unset.
+    pub data: Option<IncidentUserRelationshipData>,
+}
+
+/// Relationship to an object of the incident, such as an attachment or an integration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncidentObjectRelationshipData {
+    /// The ID of the related object.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// The type of the related object, such as `incident_attachments` or `incident_integrations`.
+    pub typ: String,
+}
+
+/// Relationships to objects of the incident.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncidentObjectRelationships {
+    /// Relationships to the objects.
+    pub data: Vec<IncidentObjectRelationshipData>,
+}
+
+/// The relationships of an incident.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IncidentResponseRelationships {
+    /// The attachments of the incident.
+    pub attachments: Option<IncidentObjectRelationships>,
+    /// The user in charge of the incident.
+    pub commander_user: Option<IncidentUserRelationship>,
+    /// The user who created the incident.
+    pub created_by_user: Option<IncidentUserRelationship>,
+    /// The integration metadata of the incident.
+    pub integrations: Option<IncidentObjectRelationships>,
+    /// The user who last modified the incident.
+    pub last_modified_by_user: Option<IncidentUserRelationship>,
+}
+
+/// Incident data.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Incident {
+    /// The attributes of the incident.
+    pub attributes: Option<IncidentResponseAttributes>,
+    /// The incident's ID.
+    pub id: String,
+    /// The relationships of the incident.
+    pub relationships: Option<IncidentResponseRelationships>,
+    #[serde(rename = "type")]
+    /// Incident resource type.
+    pub typ: IncidentType,
+}
+
+/// Attributes of a user related to an incident.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IncidentIncludedUserAttributes {
+    /// Email of the user.
+    pub email: Option<String>,
+    /// Handle of the user.
+    pub handle: Option<String>,
+    /// Name of the user.
+    pub name: Option<String>,
+    /// UUID of the user.
+    pub uuid: Option<String>,
+}
+
+/// A user related to an incident, included through `include=users`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IncidentIncludedUser {
+    /// Attributes of the user.
+    pub attributes: Option<IncidentIncludedUserAttributes>,
+    /// ID of the user.
+    pub id: Option<String>,
+}
+
+/// An object related to an incident, tagged by its `type`.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum IncidentIncluded {
+    /// Equals to `users`.
+    #[serde(rename = "users")]
+    User(IncidentIncludedUser),
+    /// Equals to `incident_attachments`.
+    #[serde(rename = "incident_attachments")]
+    Attachment(IncidentIncludedAttachment),
+}
+
+/// An attachment of an incident, included through `include=attachments`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IncidentIncludedAttachment {
+    /// The attributes of the attachment.
+    pub attributes: Option<IncidentAttachmentAttributes>,
+    /// The ID of the attachment.
+    pub id: Option<String>,
+}
+
+/// Response with an incident.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IncidentResponse {
+    /// Incident data.
+    pub data: Incident,
+    /// Objects related to the incident that the user requested.
+    pub included: Option<Vec<IncidentIncluded>>,
+}
+
+/// Pagination properties.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IncidentsPagination {
+    /// The index of the first element in the next page of results. Equal to page size added to the current offset.
+    pub next_offset: Option<i64>,
+    /// The index of the first element in the results.
+    pub offset: Option<i64>,
+    /// Maximum size of pages to return.
+    pub size: Option<i64>,
+}
+
+/// The metadata object containing pagination metadata.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IncidentsResponseMeta {
+    /// Pagination properties.
+    pub pagination: Option<IncidentsPagination>,
+}
+
+/// Response with a list of incidents.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListIncidentsResponse {
+    /// An array of incidents.
+    pub data: Vec<Incident>,
+    /// Objects related to the incidents that the user requested.
+    pub included: Option<Vec<IncidentIncluded>>,
+    /// The metadata object containing pagination metadata.
+    pub meta: Option<IncidentsResponseMeta>,
+}
+
+/// Query parameters to list incidents.
+#[derive(Debug, Default, Serialize)]
+pub struct ListIncidentsRequest {
+    /// Comma-separated list of related objects to include in the response: `users`, `attachments`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include: Option<String>,
+    /// Size for a given page. The maximum allowed value is 100.
+    #[serde(rename = "page[size]", skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<i64>,
+    /// Specific offset to use as the beginning of the returned page.
+    #[serde(rename = "page[offset]", skip_serializing_if = "Option::is_none")]
+    pub page_offset: Option<i64>,
+}
+
+/// The order of the incidents returned by a search.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum IncidentSearchSortOrder {
+    /// Equals to `created`.
+    #[serde(rename = "created")]
+    Created,
+    /// Equals to `-created`.
+    #[serde(rename = "-created")]
+    NegativeCreated,
+}
+
+/// Query parameters to search incidents.
+#[derive(Debug, Default, Serialize)]
+pub struct SearchIncidentsRequest {
+    /// Comma-separated list of related objects to include in the response: `users`, `attachments`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include: Option<String>,
+    /// Specifies which incidents should be returned, such as `state:(active OR stable) AND severity:SEV-1`.
+    pub query: String,
+    /// Specifies the order of returned incidents.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<IncidentSearchSortOrder>,
+    /// Size for a given page. The maximum allowed value is 100.
+    #[serde(rename = "page[size]", skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<i64>,
+    /// Specific offset to use as the beginning of the returned page.
+    #[serde(rename = "page[offset]", skip_serializing_if = "Option::is_none")]
+    pub page_offset: Option<i64>,
+}
+
+/// An incident matching a search.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IncidentSearchResponseIncidentsData {
+    /// Incident data.
+    pub data: Incident,
+}
+
+/// Attributes returned by an incident search.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IncidentSearchResponseAttributes {
+    /// Facet data for the incidents matching the search.
+    pub facets: Option<Value>,
+    /// Incidents matching the search.
+    pub incidents: Vec<IncidentSearchResponseIncidentsData>,
+    /// Number of incidents returned by the search.
+    pub total: Option<i64>,
+}
+
+/// Data returned by an incident search.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IncidentSearchResponseData {
+    /// Attributes returned by the search.
+    pub attributes: Option<IncidentSearchResponseAttributes>,
+    #[serde(rename = "type")]
+    /// Incident search result type, `incidents_search_results`.
+    pub typ: Option<String>,
+}
+
+/// Response with incidents and facets.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IncidentSearchResponse {
+    /// Data returned by the search.
+    pub data: IncidentSearchResponseData,
+    /// Objects related to the incidents that the user requested.
+    pub included: Option<Vec<IncidentIncluded>>,
+    /// The metadata object containing pagination metadata.
+    pub meta: Option<IncidentsResponseMeta>,
+}
+
+/// Query parameters to get an incident.
+#[derive(Debug, Serialize)]
+pub struct GetIncidentRequest {
+    /// The UUID of the incident.
+    #[serde(skip)]
+    pub incident_id: String,
+    /// Comma-separated list of related objects to include in the response: `users`, `attachments`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include: Option<String>,
+}
+
+/// The attributes of the incident to create.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IncidentCreateAttributes {
+    /// Required if `customer_impacted:"true"`. A summary of the impact customers experienced during the incident.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_impact_scope: Option<String>,
+    /// A flag indicating whether the incident caused customer impact.
+    pub customer_impacted: bool,
+    /// The fields of the incident, including its severity, state and custom fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<BTreeMap<String, IncidentFieldAttributes>>,
+    /// Notification handles that will be notified of the incident at creation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notification_handles: Option<Vec<IncidentNotificationHandle>>,
+    /// The title of the incident, which summarizes what happened.
+    pub title: String,
+}
+
+/// The relationships of the incident to create or update.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct IncidentRequestRelationships {
+    /// The user in charge of the incident. Null to unset it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commander_user: Option<IncidentUserRelationship>,
+}
+
+/// The incident to create.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IncidentCreateData {
+    /// The attributes of the incident.
+    pub attributes: IncidentCreateAttributes,
+    /// The relationships of the incident.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationships: Option<IncidentRequestRelationships>,
+    #[serde(rename = "type")]
+    /// Incident resource type.
+    pub typ: IncidentType,
+}
+
+/// The attributes of the incident to update.
+/// Only the given attributes are changed.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct IncidentUpdateAttributes {
+    /// Timestamp when customers were no longer impacted by the incident.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_impact_end: Option<String>,
+    /// A summary of the impact customers experienced during the incident.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_impact_scope: Option<String>,
+    /// Timestamp when customers began being impacted by the incident.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_impact_start: Option<String>,
+    /// A flag indicating whether the incident caused customer impact.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_impacted: Option<bool>,
+    /// Timestamp when the incident was detected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detected: Option<String>,
+    /// The fields to update, including the severity, state and custom fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<BTreeMap<String, IncidentFieldAttributes>>,
+    /// Notification handles that will be notified of the incident during update.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notification_handles: Option<Vec<IncidentNotificationHandle>>,
+    /// The title of the incident, which summarizes what happened.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+/// The incident to update.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IncidentUpdateData {
+    /// The attributes of the incident.
+    pub attributes: IncidentUpdateAttributes,
+    /// The incident's ID.
+    pub id: String,
+    /// The relationships of the incident.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationships: Option<IncidentRequestRelationships>,
+    #[serde(rename = "type")]
+    /// Incident resource type.
+    pub typ: IncidentType,
+}
+
+/// Path parameters and body to update an incident.
+#[derive(Debug)]
+pub struct UpdateIncidentRequest {
+    /// The UUID of the incident.
+    pub incident_id: String,
+    /// The changes to the incident.
+    pub incident: Request<IncidentUpdateData>,
+}
+
+/// Path parameters to delete an incident.
+#[derive(Debug)]
+pub struct DeleteIncidentRequest {
+    /// The UUID of the incident.
+    pub incident_id: String,
+}
+
+/// Todo resource type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum IncidentTodoType {
+    /// Equals to `incident_todos`.
+    #[serde(rename = "incident_todos")]
+    IncidentTodos,
+}
+
+/// Assignee of a todo who is not a Datadog user, such as a Slack user.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncidentTodoAnonymousAssignee {
+    /// URL for the assignee's icon.
+    pub icon: String,
+    /// Anonymous assignee's ID.
+    pub id: String,
+    /// Assignee's name.
+    pub name: String,
+    /// The source of the anonymous assignee: `slack` or `microsoft_teams`.
+    pub source: String,
+}
+
+/// Assignee of a todo.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum IncidentTodoAssignee {
+    /// The handle of a Datadog user, such as `@test.user@test.com`.
+    Handle(String),
+    /// An assignee who is not a Datadog user.
+    Anonymous(IncidentTodoAnonymousAssignee),
+}
+
+/// Attributes of a todo.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncidentTodoAttributes {
+    /// Array of todo assignees.
+    pub assignees: Vec<IncidentTodoAssignee>,
+    /// Timestamp when the todo was completed. Null when it is not completed.
+    #[serde(default)]
+    pub completed: Option<String>,
+    /// The follow-up task's content.
+    pub content: String,
+    /// Timestamp when the todo should be completed by.
+    #[serde(default)]
+    pub due_date: Option<String>,
+    /// UUID of the incident the todo belongs to.
+    /// This value is set by the API.
+    #[serde(default, skip_serializing)]
+    pub incident_id: Option<String>,
+}
+
+/// A todo of an incident.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IncidentTodo {
+    /// Attributes of the todo.
+    pub attributes: Option<IncidentTodoAttributes>,
+    /// The todo's ID.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// Todo resource type.
+    pub typ: IncidentTodoType,
+}
+
+/// The todo to create or update.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IncidentTodoRequestData {
+    /// Attributes of the todo.
+    pub attributes: IncidentTodoAttributes,
+    #[serde(rename = "type")]
+    /// Todo resource type.
+    pub typ: IncidentTodoType,
+}
+
+/// Response with a todo.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IncidentTodoResponse {
+    /// Todo data.
+    pub data: IncidentTodo,
+    /// Objects related to the todo.
+    pub included: Option<Vec<IncidentIncluded>>,
+}
+
+/// Response with the todos of an incident.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListIncidentTodosResponse {
+    /// An array of todos.
+    pub data: Vec<IncidentTodo>,
+    /// Objects related to the todos.
+    pub included: Option<Vec<IncidentIncluded>>,
+    /// The metadata object containing pagination metadata.
+    pub meta: Option<IncidentsResponseMeta>,
+}
+
+/// Path parameters to list the todos of an incident.
+#[derive(Debug)]
+pub struct ListIncidentTodosRequest {
+    /// The UUID of the incident.
+    pub incident_id: String,
+}
+
+/// Path parameters and body to create a todo.
+#[derive(Debug)]
+pub struct CreateIncidentTodoRequest {
+    /// The UUID of the incident.
+    pub incident_id: String,
+    /// The todo to create.
+    pub todo: Request<IncidentTodoRequestData>,
+}
+
+/// Path parameters to get or delete a todo.
+#[derive(Debug)]
+pub struct IncidentTodoPathRequest {
+    /// The UUID of the incident.
+    pub incident_id: String,
+    /// The UUID of the todo.
+    pub todo_id: String,
+}
+
+/// Path parameters and body to update a todo.
+#[derive(Debug)]
+pub struct UpdateIncidentTodoRequest {
+    /// The UUID of the incident.
+    pub incident_id: String,
+    /// The UUID of the todo.
+    pub todo_id: String,
+    /// The new definition of the todo.
+    pub todo: Request<IncidentTodoRequestData>,
+}
+
+/// Integration metadata resource type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum IncidentIntegrationMetadataType {
+    /// Equals to `incident_integrations`.
+    #[serde(rename = "incident_integrations")]
+    IncidentIntegrations,
+}
+
+/// A Slack channel of an incident.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlackIntegrationMetadataChannelItem {
+    /// Slack channel ID.
+    pub channel_id: String,
+    /// Name of the Slack channel.
+    pub channel_name: String,
+    /// URL redirecting to the Slack channel.
+    pub redirect_url: String,
+    /// Slack team ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+}
+
+/// A Jira issue of an incident.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JiraIntegrationMetadataIssuesItem {
+    /// URL of issue's Jira account.
+    pub account: String,
+    /// Jira issue's issue key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_key: Option<String>,
+    /// Jira issue's issue type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issuetype_id: Option<String>,
+    /// Jira issue's project keys.
+    pub project_key: String,
+    /// URL redirecting to the Jira issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redirect_url: Option<String>,
+}
+
+/// Metadata of an integration, depending on its type.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum IncidentIntegrationMetadataMetadata {
+    /// Slack channels of the incident.
+    Slack {
+        /// Array of Slack channels.
+        channels: Vec<SlackIntegrationMetadataChannelItem>,
+    },
+    /// Jira issues of the incident.
+    Jira {
+        /// Array of Jira issues.
+        issues: Vec<JiraIntegrationMetadataIssuesItem>,
+    },
+    /// Metadata of another integration, kept as raw JSON.
+    Other(Value),
+}
+
+/// Attributes of an integration metadata.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncidentIntegrationMetadataAttributes {
+    /// Timestamp when the integration metadata was created.
+    /// This value is set by the API.
+    #[serde(default, skip_serializing)]
+    pub created: Option<String>,
+    /// UUID of the incident this integration metadata is connected to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub incident_id: Option<String>,
+    /// A number indicating the type of integration this metadata is for: 1 for Slack, 8 for Jira.
+    pub integration_type: i32,
+    /// Metadata of the integration.
+    pub metadata: IncidentIntegrationMetadataMetadata,
+    /// Timestamp when the integration metadata was last modified.
+    /// This value is set by the API.
+    #[serde(default, skip_serializing)]
+    pub modified: Option<String>,
+    /// A number indicating the status of this integration metadata:
+    /// 0 for unknown, 1 for pending, 2 for complete, 3 for manually created, 4 for manually updated and 5 for failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<i32>,
+}
+
+/// An integration metadata of an incident.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IncidentIntegrationMetadata {
+    /// Attributes of the integration metadata.
+    pub attributes: Option<IncidentIntegrationMetadataAttributes>,
+    /// The integration metadata's ID.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// Integration metadata resource type.
+    pub typ: IncidentIntegrationMetadataType,
+}
+
+/// The integration metadata to create or update.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IncidentIntegrationMetadataRequestData {
+    /// Attributes of the integration metadata.
+    pub attributes: IncidentIntegrationMetadataAttributes,
+    #[serde(rename = "type")]
+    /// Integration metadata resource type.
+    pub typ: IncidentIntegrationMetadataType,
+}
+
+/// Response with an integration metadata.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IncidentIntegrationMetadataResponse {
+    /// Integration metadata data.
+    pub data: IncidentIntegrationMetadata,
+    /// Objects related to the integration metadata.
+    pub included: Option<Vec<IncidentIncluded>>,
+}
+
+/// Response with the integration metadata of an incident.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListIncidentIntegrationsResponse {
+    /// An array of integration metadata.
+    pub data: Vec<IncidentIntegrationMetadata>,
+    /// Objects related to the integration metadata.
+    pub included: Option<Vec<IncidentIncluded>>,
+    /// The metadata object containing pagination metadata.
+    pub meta: Option<IncidentsResponseMeta>,
+}
+
+/// Path parameters to list the integration metadata of an incident.
+#[derive(Debug)]
+pub struct ListIncidentIntegrationsRequest {
+    /// The UUID of the incident.
+    pub incident_id: String,
+}
+
+/// Path parameters and body to create an integration metadata.
+#[derive(Debug)]
+pub struct CreateIncidentIntegrationRequest {
+    /// The UUID of the incident.
+    pub incident_id: String,
+    /// The integration metadata to create.
+    pub integration: Request<IncidentIntegrationMetadataRequestData>,
+}
+
+/// Path parameters to get or delete an integration metadata.
+#[derive(Debug)]
+pub struct IncidentIntegrationPathRequest {
+    /// The UUID of the incident.
+    pub incident_id: String,
+    /// The UUID of the integration metadata.
+    pub integration_metadata_id: String,
+}
+
+/// Path parameters and body to update an integration metadata.
+#[derive(Debug)]
+pub struct UpdateIncidentIntegrationRequest {
+    /// The UUID of the incident.
+    pub incident_id: String,
+    /// The UUID of the integration metadata.
+    pub integration_metadata_id: String,
+    /// The new definition of the integration metadata.
+    pub integration: Request<IncidentIntegrationMetadataRequestData>,
+}
+
+/// Attachment resource type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum IncidentAttachmentType {
+    /// Equals to `incident_attachments`.
+    #[serde(rename = "incident_attachments")]
+    IncidentAttachments,
+}
+
+/// A document attached to an incident.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncidentAttachmentDocument {
+    /// The URL of the document.
+    #[serde(rename = "documentUrl")]
+    pub document_url: String,
+    /// The title of the document.
+    pub title: String,
+}
+
+/// Attributes of an attachment, tagged by its `attachment_type`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "attachment_type", rename_all = "lowercase")]
+pub enum IncidentAttachmentAttributes {
+    /// Equals to `link`.
+    Link {
+        /// The attached link.
+        attachment: IncidentAttachmentDocument,
+    },
+    /// Equals to `postmortem`.
+    Postmortem {
+        /// The attached postmortem notebook.
+        attachment: IncidentAttachmentDocument,
+    },
+}
+
+/// An attachment of an incident.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IncidentAttachment {
+    /// Attributes of the attachment.
+    pub attributes: Option<IncidentAttachmentAttributes>,
+    /// The attachment's ID.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// Attachment resource type.
+    pub typ: IncidentAttachmentType,
+}
+
+/// An attachment to create, update or delete.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IncidentAttachmentUpdateData {
+    /// Attributes of the attachment. Omit them to delete the attachment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<IncidentAttachmentAttributes>,
+    /// The attachment's ID. Omit it to create the attachment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    /// Attachment resource type.
+    pub typ: IncidentAttachmentType,
+}
+
+/// Response with the attachments of an incident.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListIncidentAttachmentsResponse {
+    /// An array of attachments.
+    pub data: Vec<IncidentAttachment>,
+    /// Objects related to the attachments.
+    pub included: Option<Vec<IncidentIncluded>>,
+}
+
+/// Query parameters to list the attachments of an incident.
+#[derive(Debug, Serialize)]
+pub struct ListIncidentAttachmentsRequest {
+    /// The UUID of the incident.
+    #[serde(skip)]
+    pub incident_id: String,
+    /// Comma-separated list of attachment types to return: `link`, `postmortem`.
+    #[serde(
+        rename = "filter[attachment_type]",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub attachment_type: Option<String>,
+    /// Comma-separated list of related objects to include in the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include: Option<String>,
+}
+
+/// Path parameters and body to create, update or delete attachments of an incident.
+#[derive(Debug)]
+pub struct UpdateIncidentAttachmentsRequest {
+    /// The UUID of the incident.
+    pub incident_id: String,
+    /// The attachments to create, update or delete.
+    pub attachments: Request<Vec<IncidentAttachmentUpdateData>>,
+}
+
+// Responses and requests
+/// Response to the deletion of an incident.
+pub type DeleteIncidentResponse = EmptyResponse;
+/// Response to the deletion of a todo.
+pub type DeleteIncidentTodoResponse = EmptyResponse;
+/// Response to the deletion of an integration metadata.
+pub type DeleteIncidentIntegrationResponse = EmptyResponse;
+/// The attachments of the incident after the update.
+pub type UpdateIncidentAttachmentsResponse = ListIncidentAttachmentsResponse;
+
+/// The incident to create.
+pub type CreateIncidentRequest = Request<IncidentCreateData>;
@@ -0,0 +1,436 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::client::{EmptyResponse, Request};
+use super::users::{RelationshipToUser, UserAttributes, UsersResponseMeta};
+use crate::secret::SecretString;
+
+/// API Keys resource type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ApiKeysType {
+    /// Equals to `api_keys`.
+    #[serde(rename = "api_keys")]
+    ApiKeys,
+}
+
+/// Application Keys resource type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ApplicationKeysType {
+    /// Equals to `application_keys`.
+    #[serde(rename = "application_keys")]
+    ApplicationKeys,
+}
+
+/// Attributes of an API key.
+///
+/// The key itself is only returned when the key is created, and is never serialized back.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ApiKeyAttributes {
+    /// The category of the API key.
+    pub category: Option<String>,
+    /// Creation date of the API key.
+    pub created_at: Option<String>,
+    /// The API key.
+    #[serde(default, skip_serializing)]
+    pub key: Option<SecretString>,
+    /// The last four characters of the API key.
+    pub last4: Option<String>,
+    /// Date the API key was last modified.
+    pub modified_at: Option<String>,
+    /// Name of the API key.
+    pub name: Option<String>,
+    /// The remote config read enabled status.
+    pub remote_config_read_enabled: Option<bool>,
+}
+
+/// Resources related to the API key.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ApiKeyRelationships {
+    /// The user who created the API key.
+    pub created_by: Option<RelationshipToUser>,
+    /// The user who last modified the API key.
+    pub modified_by: Option<RelationshipToUser>,
+}
+
+/// Datadog API key.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ApiKey {
+    /// Attributes of the API key.
+    pub attributes: Option<ApiKeyAttributes>,
+    /// ID of the API key.
+    pub id: String,
+    /// Resources related to the API key.
+    pub relationships: Option<ApiKeyRelationships>,
+    #[serde(rename = "type")]
+    /// API Keys resource type.
+    pub typ: ApiKeysType,
+}
+
+/// A user related to a key, included through `include`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct KeyIncludedUser {
+    /// Attributes of the user.
+    pub attributes: Option<UserAttributes>,
+    /// ID of the user.
+    pub id: String,
+}
+
+/// An object related to a key, tagged by its `type`.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum KeyIncluded {
+    /// Equals to `users`.
+    #[serde(rename = "users")]
+    User(KeyIncludedUser),
+}
+
+/// Response for retrieving an API key.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ApiKeyResponse {
+    /// Datadog API key.
+    pub data: ApiKey,
+    /// Array of objects related to the API key.
+    pub included: Option<Vec<KeyIncluded>>,
+}
+
+/// Response for a list of API keys.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ApiKeysResponse {
+    /// Array of API keys.
+    pub data: Vec<ApiKey>,
+    /// Array of objects related to the API keys.
+    pub included: Option<Vec<KeyIncluded>>,
+    /// Additional information related to the API keys response.
+    pub meta: Option<UsersResponseMeta>,
+}
+
+/// Sorting options for API keys.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ApiKeysSort {
+    /// Equals to `created_at`.
+    #[serde(rename = "created_at")]
+    CreatedAtAscending,
+    /// Equals to `-created_at`.
+    #[serde(rename = "-created_at")]
+    CreatedAtDescending,
+    /// Equals to `last4`.
+    #[serde(rename = "last4")]
+    Last4Ascending,
+    /// Equals to `-last4`.
+    #[serde(rename = "-last4")]
+    Last4Descending,
+    /// Equals to `modified_at`.
+    #[serde(rename = "modified_at")]
+    ModifiedAtAscending,
+    /// Equals to `-modified_at`.
+    #[serde(rename = "-modified_at")]
+    ModifiedAtDescending,
+    /// Equals to `name`.
+    #[serde(rename = "name")]
+    NameAscending,
+    /// Equals to `-name`.
+    #[serde(rename = "-name")]
+    NameDescending,
+}
+
+/// Query parameters to list API keys.
+#[derive(Debug, Default, Serialize)]
+pub struct ListApiKeysRequest {
+    /// Size for a given page. The maximum allowed value is 100.
+    #[serde(rename = "page[size]", skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<i64>,
+    /// Specific page number to return.
+    #[serde(rename = "page[number]", skip_serializing_if = "Option::is_none")]
+    pub page_number: Option<i64>,
+    /// API key attribute used to sort results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<ApiKeysSort>,
+    /// Filter API keys by the specified string.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    /// Only include API keys created on or after the specified date.
+    #[serde(
+        rename = "filter[created_at][start]",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub filter_created_at_start: Option<String>,
+    /// Only include API keys created on or before the specified date.
+    #[serde(
+        rename = "filter[created_at][end]",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub filter_created_at_end: Option<String>,
+    /// Only include API keys modified on or after the specified date.
+    #[serde(
+        rename = "filter[modified_at][start]",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub filter_modified_at_start: Option<String>,
+    /// Only include API keys modified on or before the specified date.
+    #[serde(
+        rename = "filter[modified_at][end]",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub filter_modified_at_end: Option<String>,
+    /// Comma separated list of resource paths for related resources to include in the
+    /// response. Supported resource paths are `created_by` and `modified_by`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include: Option<String>,
+}
+
+/// Attributes used to create or update an API Key.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ApiKeyCreateUpdateAttributes {
+    /// Name of the API key.
+    pub name: String,
+    /// The remote config read enabled status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote_config_read_enabled: Option<bool>,
+}
+
+/// Object used to create an API key.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ApiKeyCreateData {
+    /// Attributes used to create an API Key.
+    pub attributes: ApiKeyCreateUpdateAttributes,
+    #[serde(rename = "type")]
+    /// API Keys resource type.
+    pub typ: ApiKeysType,
+}
+
+/// Object used to update an API key.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ApiKeyUpdateData {
+    /// Attributes used to update an API Key.
+    pub attributes: ApiKeyCreateUpdateAttributes,
+    /// ID of the API key.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// API Keys resource type.
+    pub typ: ApiKeysType,
+}
+
+/// Path and query parameters to get an API key.
+#[derive(Debug, Serialize)]
+pub struct GetApiKeyRequest {
+    /// The ID of the API key.
+    #[serde(skip)]
+    pub api_key_id: String,
+    /// Comma separated list of resource paths for related resources to include in the
+    /// response. Supported resource paths are `created_by` and `modified_by`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include: Option<String>,
+}
+
+/// Path parameters and body to update an API key.
+#[derive(Debug)]
+pub struct UpdateApiKeyRequest {
+    /// The ID of the API key.
+    pub api_key_id: String,
+    /// The changes to the API key.
+    pub api_key: Request<ApiKeyUpdateData>,
+}
+
+/// Path parameters to delete or rotate an API key.
+#[derive(Debug)]
+pub struct ApiKeyIdRequest {
+    /// The ID of the API key.
+    pub api_key_id: String,
+}
+
+/// Attributes of an application key.
+///
+/// The key itself is only returned when the key is created, and is never serialized back.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ApplicationKeyAttributes {
+    /// Creation date of the application key.
+    pub created_at: Option<String>,
+    /// The application key.
+    #[serde(default, skip_serializing)]
+    pub key: Option<SecretString>,
+    /// The last four characters of the application key.
+    pub last4: Option<String>,
+    /// Name of the application key.
+    pub name: Option<String>,
+    /// Array of scopes to grant the application key.
+    pub scopes: Option<Vec<String>>,
+}
+
+/// Resources related to the application key.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ApplicationKeyRelationships {
+    /// The user who owns the application key.
+    pub owned_by: Option<RelationshipToUser>,
+}
+
+/// Datadog application key.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ApplicationKey {
+    /// Attributes of the application key.
+    pub attributes: Option<ApplicationKeyAttributes>,
+    /// ID of the application key.
+    pub id: String,
+    /// Resources related to the application key.
+    pub relationships: Option<ApplicationKeyRelationships>,
+    #[serde(rename = "type")]
+    /// Application Keys resource type.
+    pub typ: ApplicationKeysType,
+}
+
+/// Response for retrieving an application key.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ApplicationKeyResponse {
+    /// Datadog application key.
+    pub data: ApplicationKey,
+    /// Array of objects related to the application key.
+    pub included: Option<Vec<KeyIncluded>>,
+}
+
+/// Response for a list of application keys.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ApplicationKeysResponse {
+    /// Array of application keys.
+    pub data: Vec<ApplicationKey>,
+    /// Array of objects related to the application keys.
+    pub included: Option<Vec<KeyIncluded>>,
+    /// Additional information related to the application keys response.
+    pub meta: Option<UsersResponseMeta>,
+}
+
+/// Sorting options for application keys.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ApplicationKeysSort {
+    /// Equals to `created_at`.
+    #[serde(rename = "created_at")]
+    CreatedAtAscending,
+    /// Equals to `-created_at`.
+    #[serde(rename = "-created_at")]
+    CreatedAtDescending,
+    /// Equals to `last4`.
+    #[serde(rename = "last4")]
+    Last4Ascending,
+    /// Equals to `-last4`.
+    #[serde(rename = "-last4")]
+    Last4Descending,
+    /// Equals to `name`.
+    #[serde(rename = "name")]
+    NameAscending,
+    /// Equals to `-name`.
+    #[serde(rename = "-name")]
+    NameDescending,
+}
+
+/// Query parameters to list application keys.
+#[derive(Debug, Default, Serialize)]
+pub struct ListApplicationKeysRequest {
+    /// Size for a given page. The maximum allowed value is 100.
+    #[serde(rename = "page[size]", skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<i64>,
+    /// Specific page number to return.
+    #[serde(rename = "page[number]", skip_serializing_if = "Option::is_none")]
+    pub page_number: Option<i64>,
+    /// Application key attribute used to sort results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<ApplicationKeysSort>,
+    /// Filter application keys by the specified string.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    /// Only include application keys created on or after the specified date.
+    #[serde(
+        rename = "filter[created_at][start]",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub filter_created_at_start: Option<String>,
+    /// Only include application keys created on or before the specified date.
+    #[serde(
+        rename = "filter[created_at][end]",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub filter_created_at_end: Option<String>,
+    /// Resource path for related resources to include in the response. Only `owned_by` is
+    /// supported.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include: Option<String>,
+}
+
+/// Attributes used to create an application key.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ApplicationKeyCreateAttributes {
+    /// Name of the application key.
+    pub name: String,
+    /// Array of scopes to grant the application key. All the scopes of the user are granted
+    /// when it is not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<String>>,
+}
+
+/// Object used to create an application key.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ApplicationKeyCreateData {
+    /// Attributes used to create an application key.
+    pub attributes: ApplicationKeyCreateAttributes,
+    #[serde(rename = "type")]
+    /// Application Keys resource type.
+    pub typ: ApplicationKeysType,
+}
+
+/// Attributes used to update an application key.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ApplicationKeyUpdateAttributes {
+    /// Name of the application key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Array of scopes to grant the application key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<String>>,
+}
+
+/// Object used to update an application key.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ApplicationKeyUpdateData {
+    /// Attributes used to update an application key.
+    pub attributes: ApplicationKeyUpdateAttributes,
+    /// ID of the application key.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// Application Keys resource type.
+    pub typ: ApplicationKeysType,
+}
+
+/// Path and query parameters to get an application key.
+#[derive(Debug, Serialize)]
+pub struct GetApplicationKeyRequest {
+    /// The ID of the application key.
+    #[serde(skip)]
+    pub app_key_id: String,
+    /// Resource path for related resources to include in the response. Only `owned_by` is
+    /// supported.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include: Option<String>,
+}
+
+/// Path parameters and body to update an application key.
+#[derive(Debug)]
+pub struct UpdateApplicationKeyRequest {
+    /// The ID of the application key.
+    pub app_key_id: String,
+    /// The changes to the application key.
+    pub application_key: Request<ApplicationKeyUpdateData>,
+}
+
+/// Path parameters to delete or rotate an application key.
+#[derive(Debug)]
+pub struct ApplicationKeyIdRequest {
+    /// The ID of the application key.
+    pub app_key_id: String,
+}
+
+// Responses and requests
+/// Response to the deletion of an API key.
+pub type DeleteApiKeyResponse = EmptyResponse;
+/// Response to the deletion of an application key.
+pub type DeleteApplicationKeyResponse = EmptyResponse;
+
+/// The API key to create.
+pub type CreateApiKeyRequest = Request<ApiKeyCreateData>;
+/// The application key to create.
+pub type CreateApplicationKeyRequest = Request<ApplicationKeyCreateData>;
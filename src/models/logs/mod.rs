@@ -0,0 +1,432 @@
+use std::collections::BTreeMap;
+
+use serde_derive::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use super::client::{Meta, PagedResponse};
+use super::search::{SearchOptions, SearchPage, SearchSort};
+
+/// Logs that are sent over HTTP.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HttpLogItem {
+    /// The integration name associated with your log: the technology from which the log originated.
+    /// When it matches an integration name, Datadog automatically installs the corresponding parsers and facets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ddsource: Option<String>,
+    /// Tags associated with your logs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ddtags: Option<String>,
+    /// The name of the originating host of the log.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    /// The message reserved attribute of your log.
+    /// By default, Datadog ingests the value of the message attribute as the body of the log entry.
+    pub message: String,
+    /// The name of the application or service generating the log events.
+    /// It is used to switch from Logs to APM, so make sure you define the same value when you use both products.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
+    /// Any other attribute of the log.
+    #[serde(flatten)]
+    pub additional_properties: Map<String, Value>,
+}
+
+/// Filter for logs, used by indexes and pipelines.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LogsFilter {
+    /// The filter query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+}
+
+/// HTTP header used to compress the media-type.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ContentEncoding {
+    /// Equals to `identity`.
+    Identity,
+    /// Equals to `gzip`.
+    Gzip,
+    /// Equals to `deflate`.
+    Deflate,
+}
+
+impl ContentEncoding {
+    /// Value of the `Content-Encoding` header.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContentEncoding::Identity => "identity",
+            ContentEncoding::Gzip => "gzip",
+            ContentEncoding::Deflate => "deflate",
+        }
+    }
+}
+
+/// Logs to send to the logs intake.
+#[derive(Debug)]
+pub struct SubmitLogRequest {
+    /// HTTP header used to compress the media-type.
+    /// When `None`, logs are sent uncompressed.
+    pub content_encoding: Option<ContentEncoding>,
+    /// Log tags can be passed as query parameters with `text/plain` content type.
+    pub ddtags: Option<String>,
+    /// Logs to send.
+    pub body: Vec<HttpLogItem>,
+}
+
+/// Type of the event.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum LogType {
+    /// Equals to `log`.
+    #[serde(rename = "log")]
+    Log,
+}
+
+/// JSON object containing all log attributes and their associated values.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LogAttributes {
+    /// JSON object of attributes from your log.
+    pub attributes: Option<Map<String, Value>>,
+    /// Name of the machine from where the logs are being sent.
+    pub host: Option<String>,
+    /// The message reserved attribute of your log.
+    pub message: Option<String>,
+    /// The name of the application or service generating the log events.
+    pub service: Option<String>,
+    /// Status of the message associated with your log.
+    pub status: Option<String>,
+    /// Array of tags associated with your log.
+    pub tags: Option<Vec<String>>,
+    /// Timestamp of your log.
+    // TODO: Use a datetime type.
+    pub timestamp: Option<String>,
+}
+
+/// Object description of a log after being processed and stored by Datadog.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Log {
+    /// JSON object containing all log attributes and their associated values.
+    pub attributes: LogAttributes,
+    /// Unique ID of the Log.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// Type of the event.
+    pub typ: LogType,
+}
+
+/// Specifies storage type as indexes or online-archives.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum LogsStorageTier {
+    /// Equals to `indexes`.
+    #[serde(rename = "indexes")]
+    Indexes,
+    /// Equals to `online-archives`.
+    #[serde(rename = "online-archives")]
+    OnlineArchives,
+}
+
+/// The search and filter query settings.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct LogsQueryFilter {
+    /// The minimum time for the requested logs, supports date math and regular timestamps (milliseconds).
+    ///
+    /// default: `now-15m`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    /// For customers with multiple indexes, the indexes to search.
+    /// Defaults to '*' which means all indexes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indexes: Option<Vec<String>>,
+    /// The search query - following the log search syntax.
+    ///
+    /// default: `*`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// Specifies storage type as indexes or online-archives.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_tier: Option<LogsStorageTier>,
+    /// The maximum time for the requested logs, supports date math and regular timestamps (milliseconds).
+    ///
+    /// default: `now`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+}
+
+/// The request for a logs list.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ListLogsRequest {
+    /// The search and filter query settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<LogsQueryFilter>,
+    /// Global query options that are used during the query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<SearchOptions>,
+    /// Paging attributes for listing logs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<SearchPage>,
+    /// Sort parameters when querying logs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<SearchSort>,
+}
+
+/// Query parameters to get a list of logs.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ListLogsGetRequest {
+    /// Search query following logs syntax.
+    #[serde(rename = "filter[query]", skip_serializing_if = "Option::is_none")]
+    pub filter_query: Option<String>,
+    /// For customers with multiple indexes, the indexes to search, comma separated.
+    #[serde(rename = "filter[indexes]", skip_serializing_if = "Option::is_none")]
+    pub filter_indexes: Option<String>,
+    /// Minimum timestamp for requested logs.
+    #[serde(rename = "filter[from]", skip_serializing_if = "Option::is_none")]
+    pub filter_from: Option<String>,
+    /// Maximum timestamp for requested logs.
+    #[serde(rename = "filter[to]", skip_serializing_if = "Option::is_none")]
+    pub filter_to: Option<String>,
+    /// Specifies the storage type to be used.
+    #[serde(
+        rename = "filter[storage_tier]",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub filter_storage_tier: Option<LogsStorageTier>,
+    /// Order of logs in results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<SearchSort>,
+    /// List following results with a cursor provided in the previous query.
+    #[serde(rename = "page[cursor]", skip_serializing_if = "Option::is_none")]
+    pub page_cursor: Option<String>,
+    /// Maximum number of logs in the response.
+    #[serde(rename = "page[limit]", skip_serializing_if = "Option::is_none")]
+    pub page_limit: Option<i32>,
+}
+
+/// An aggregation function.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogsAggregationFunction {
+    /// Equals to `count`.
+    Count,
+    /// Equals to `cardinality`.
+    Cardinality,
+    /// Equals to `pc75`.
+    Pc75,
+    /// Equals to `pc90`.
+    Pc90,
+    /// Equals to `pc95`.
+    Pc95,
+    /// Equals to `pc98`.
+    Pc98,
+    /// Equals to `pc99`.
+    Pc99,
+    /// Equals to `sum`.
+    Sum,
+    /// Equals to `min`.
+    Min,
+    /// Equals to `max`.
+    Max,
+    /// Equals to `avg`.
+    Avg,
+    /// Equals to `median`.
+    Median,
+}
+
+/// The type of compute.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogsComputeType {
+    /// Equals to `timeseries`.
+    Timeseries,
+    /// Equals to `total`.
+    Total,
+}
+
+/// A compute rule to compute metrics or timeseries.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LogsCompute {
+    /// An aggregation function.
+    pub aggregation: LogsAggregationFunction,
+    /// The time buckets' size (only used for type=timeseries).
+    /// Defaults to a resolution of 150 points.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval: Option<String>,
+    /// The metric to use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metric: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    /// The type of compute.
+    pub typ: Option<LogsComputeType>,
+}
+
+/// Used to perform a histogram computation (only for measure facets).
+/// Note: At most 100 buckets are allowed, the number of buckets is (max - min)/interval.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LogsGroupByHistogram {
+    /// The bin size of the histogram buckets.
+    pub interval: f64,
+    /// The maximum value for the measure used in the histogram (values greater than this one are filtered out).
+    pub max: f64,
+    /// The minimum value for the measure used in the histogram (values smaller than this one are filtered out).
+    pub min: f64,
+}
+
+/// A value of a facet, either a string or a number.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum LogsGroupByValue {
+    /// A string value.
+    String(String),
+    /// A numeric value.
+    Number(f64),
+}
+
+/// A resulting object to put the given computes in over all the matching records.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum LogsGroupByTotal {
+    /// If set to true, creates an additional bucket labeled "$facet_total".
+    Boolean(bool),
+    /// A string to use as the key value for the total bucket.
+    String(String),
+    /// A number to use as the key value for the total bucket.
+    Number(f64),
+}
+
+/// The direction to sort by.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogsSortOrder {
+    /// Equals to `asc`.
+    Asc,
+    /// Equals to `desc`.
+    Desc,
+}
+
+/// The type of sorting algorithm.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogsAggregateSortType {
+    /// Equals to `alphabetical`.
+    Alphabetical,
+    /// Equals to `measure`.
+    Measure,
+}
+
+/// A sort rule.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LogsAggregateSort {
+    /// An aggregation function.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aggregation: Option<LogsAggregationFunction>,
+    /// The metric to sort by (only used for `type=measure`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metric: Option<String>,
+    /// The direction to sort by.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<LogsSortOrder>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    /// The type of sorting algorithm.
+    pub typ: Option<LogsAggregateSortType>,
+}
+
+/// A group by rule.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LogsGroupBy {
+    /// The name of the facet to use (required).
+    pub facet: String,
+    /// Used to perform a histogram computation (only for measure facets).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub histogram: Option<LogsGroupByHistogram>,
+    /// The maximum buckets to return for this group by.
+    ///
+    /// default: `10`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// The value to use for logs that don't have the facet used to group by.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub missing: Option<LogsGroupByValue>,
+    /// A sort rule.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<LogsAggregateSort>,
+    /// A resulting object to put the given computes in over all the matching records.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<LogsGroupByTotal>,
+}
+
+/// Paging settings of an aggregation.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LogsAggregateRequestPage {
+    /// The returned paging point to use to get the next results.
+    pub cursor: Option<String>,
+}
+
+/// The object sent with the request to retrieve a list of logs from your organization.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct LogsAggregateRequest {
+    /// The list of metrics or timeseries to compute for the retrieved buckets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compute: Option<Vec<LogsCompute>>,
+    /// The search and filter query settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<LogsQueryFilter>,
+    /// The rules for the group by.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_by: Option<Vec<LogsGroupBy>>,
+    /// Global query options that are used during the query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<SearchOptions>,
+    /// Paging settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<LogsAggregateRequestPage>,
+}
+
+/// A timeseries point.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LogsAggregateBucketValueTimeseriesPoint {
+    /// The time value for this point.
+    pub time: Option<String>,
+    /// The value for this point.
+    pub value: Option<f64>,
+}
+
+/// A bucket value, can be either a timeseries or a single value.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum LogsAggregateBucketValue {
+    /// A single numeric value.
+    Number(f64),
+    /// A single string value.
+    String(String),
+    /// A timeseries array.
+    Timeseries(Vec<LogsAggregateBucketValueTimeseriesPoint>),
+}
+
+/// A bucket values.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LogsAggregateBucket {
+    /// The key, value pairs for each group by.
+    #[serde(default)]
+    pub by: BTreeMap<String, LogsGroupByValue>,
+    /// A map of the metric name -> value for regular compute or list of values for a timeseries.
+    #[serde(default)]
+    pub computes: BTreeMap<String, LogsAggregateBucketValue>,
+}
+
+/// The query results.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LogsAggregateResponseData {
+    /// The list of matching buckets, one item per bucket.
+    pub buckets: Vec<LogsAggregateBucket>,
+}
+
+/// The response object for the logs aggregate API endpoint.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LogsAggregateResponse {
+    /// The query results.
+    pub data: Option<LogsAggregateResponseData>,
+    /// The metadata associated with a request.
+    pub meta: Option<Meta>,
+}
+
+/// Response object with all logs matching the request and pagination information.
+pub type ListLogsResponse = PagedResponse<Log>;
@@ -0,0 +1,300 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::client::{EmptyResponse, Request, Response};
+use super::roles::RelationshipToRoleData;
+
+/// The type of the resource. The value should always be archives.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum LogsArchiveType {
+    /// Equals to `archives`.
+    #[serde(rename = "archives")]
+    Archives,
+}
+
+/// The state of the archive.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum LogsArchiveState {
+    /// Equals to `UNKNOWN`.
+    Unknown,
+    /// Equals to `WORKING`.
+    Working,
+    /// Equals to `FAILING`.
+    Failing,
+    /// Equals to `WORKING_AUTH_LEGACY`.
+    #[serde(rename = "WORKING_AUTH_LEGACY")]
+    WorkingAuthLegacy,
+}
+
+/// The Azure archive's integration destination.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsArchiveIntegrationAzure {
+    /// A client ID.
+    pub client_id: String,
+    /// A tenant ID.
+    pub tenant_id: String,
+}
+
+/// The GCS archive's integration destination.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsArchiveIntegrationGcs {
+    /// A client email.
+    pub client_email: String,
+    /// A project ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+}
+
+/// The S3 Archive's integration destination.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsArchiveIntegrationS3 {
+    /// The account ID for the integration.
+    pub account_id: String,
+    /// The path of the integration.
+    pub role_name: String,
+}
+
+/// Type of S3 encryption for a destination.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LogsArchiveEncryptionS3Type {
+    /// Equals to `NO_OVERRIDE`.
+    NoOverride,
+    /// Equals to `SSE_S3`.
+    SseS3,
+    /// Equals to `SSE_KMS`.
+    SseKms,
+}
+
+/// The S3 encryption settings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsArchiveEncryptionS3 {
+    /// An Amazon Resource Name (ARN) used to identify an AWS KMS key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    #[serde(rename = "type")]
+    /// Type of S3 encryption for a destination.
+    pub typ: LogsArchiveEncryptionS3Type,
+}
+
+/// The storage class where the archive will be stored.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LogsArchiveStorageClassS3 {
+    /// Equals to `STANDARD`.
+    Standard,
+    /// Equals to `STANDARD_IA`.
+    StandardIa,
+    /// Equals to `ONEZONE_IA`.
+    OnezoneIa,
+    /// Equals to `INTELLIGENT_TIERING`.
+    IntelligentTiering,
+    /// Equals to `GLACIER_IR`.
+    GlacierIr,
+}
+
+/// The Azure archive destination.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsArchiveDestinationAzure {
+    /// The container where the archive will be stored.
+    pub container: String,
+    /// The Azure archive's integration destination.
+    pub integration: LogsArchiveIntegrationAzure,
+    /// The archive path.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// The region where the archive will be stored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    /// The associated storage account.
+    pub storage_account: String,
+}
+
+/// The GCS archive destination.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsArchiveDestinationGcs {
+    /// The bucket where the archive will be stored.
+    pub bucket: String,
+    /// The GCS archive's integration destination.
+    pub integration: LogsArchiveIntegrationGcs,
+    /// The archive path.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+/// The S3 archive destination.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsArchiveDestinationS3 {
+    /// The bucket where the archive will be stored.
+    pub bucket: String,
+    /// The S3 encryption settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encryption: Option<LogsArchiveEncryptionS3>,
+    /// The S3 Archive's integration destination.
+    pub integration: LogsArchiveIntegrationS3,
+    /// The archive path.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// The storage class where the archive will be stored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_class: Option<LogsArchiveStorageClassS3>,
+}
+
+/// An archive's destination, tagged by its `type`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum LogsArchiveDestination {
+    /// Equals to `azure`.
+    #[serde(rename = "azure")]
+    Azure(LogsArchiveDestinationAzure),
+    /// Equals to `gcs`.
+    #[serde(rename = "gcs")]
+    Gcs(LogsArchiveDestinationGcs),
+    /// Equals to `s3`.
+    #[serde(rename = "s3")]
+    S3(LogsArchiveDestinationS3),
+}
+
+/// The attributes associated with the archive.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LogsArchiveAttributes {
+    /// An archive's destination.
+    /// Null when the destination is not readable, for example when the integration was removed.
+    pub destination: Option<LogsArchiveDestination>,
+    /// To store the tags in the archive, set the value "true".
+    /// If it is set to "false", the tags will be deleted when the logs are sent to the archive.
+    pub include_tags: Option<bool>,
+    /// The archive name.
+    pub name: String,
+    /// The archive query/filter. Logs matching this query are included in the archive.
+    pub query: String,
+    /// Maximum scan size for rehydration from this archive.
+    pub rehydration_max_scan_size_in_gb: Option<i64>,
+    /// An array of tags to add to rehydrated logs from an archive.
+    pub rehydration_tags: Option<Vec<String>>,
+    /// The state of the archive.
+    pub state: Option<LogsArchiveState>,
+}
+
+/// The definition of an archive.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LogsArchiveDefinition {
+    /// The attributes associated with the archive.
+    pub attributes: Option<LogsArchiveAttributes>,
+    /// The archive ID.
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    /// The type of the resource. The value should always be archives.
+    pub typ: LogsArchiveType,
+}
+
+/// The attributes associated with the archive to create or update.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LogsArchiveCreateRequestAttributes {
+    /// An archive's destination.
+    pub destination: LogsArchiveDestination,
+    /// To store the tags in the archive, set the value "true".
+    /// If it is set to "false", the tags will be deleted when the logs are sent to the archive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_tags: Option<bool>,
+    /// The archive name.
+    pub name: String,
+    /// The archive query/filter. Logs matching this query are included in the archive.
+    pub query: String,
+    /// Maximum scan size for rehydration from this archive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rehydration_max_scan_size_in_gb: Option<i64>,
+    /// An array of tags to add to rehydrated logs from an archive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rehydration_tags: Option<Vec<String>>,
+}
+
+/// The definition of an archive to create or update.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LogsArchiveCreateRequestDefinition {
+    /// The attributes associated with the archive.
+    pub attributes: LogsArchiveCreateRequestAttributes,
+    #[serde(rename = "type")]
+    /// The type of the resource. The value should always be archives.
+    pub typ: LogsArchiveType,
+}
+
+/// Path parameters to get an archive.
+#[derive(Debug)]
+pub struct GetLogsArchiveRequest {
+    /// The ID of the archive.
+    pub archive_id: String,
+}
+
+/// Path parameters and body to update an archive.
+#[derive(Debug)]
+pub struct UpdateLogsArchiveRequest {
+    /// The ID of the archive.
+    pub archive_id: String,
+    /// New definition of the archive.
+    pub archive: Request<LogsArchiveCreateRequestDefinition>,
+}
+
+/// Path parameters to delete an archive.
+#[derive(Debug)]
+pub struct DeleteLogsArchiveRequest {
+    /// The ID of the archive.
+    pub archive_id: String,
+}
+
+/// Path parameters to list the roles with read access to an archive.
+#[derive(Debug)]
+pub struct ListLogsArchiveReadRolesRequest {
+    /// The ID of the archive.
+    pub archive_id: String,
+}
+
+/// Path parameters and role to grant or revoke read access to an archive.
+#[derive(Debug)]
+pub struct LogsArchiveReadRoleRequest {
+    /// The ID of the archive.
+    pub archive_id: String,
+    /// The role to grant or revoke read access to.
+    pub role: RelationshipToRoleData,
+}
+
+/// Type of the archive order definition.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum LogsArchiveOrderDefinitionType {
+    /// Equals to `archive_order`.
+    #[serde(rename = "archive_order")]
+    ArchiveOrder,
+}
+
+/// The attributes associated with the archive order.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LogsArchiveOrderAttributes {
+    /// An ordered array of `<ARCHIVE_ID>` strings, the order of archive IDs in the array
+    /// define the overall archives order for Datadog.
+    pub archive_ids: Vec<String>,
+}
+
+/// The definition of an archive order.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LogsArchiveOrderDefinition {
+    /// The attributes associated with the archive order.
+    pub attributes: LogsArchiveOrderAttributes,
+    #[serde(rename = "type")]
+    /// Type of the archive order definition.
+    pub typ: LogsArchiveOrderDefinitionType,
+}
+
+// Responses and requests
+/// The available archives.
+pub type ListLogsArchivesResponse = Response<Vec<LogsArchiveDefinition>>;
+/// The logs archive.
+pub type LogsArchiveResponse = Response<LogsArchiveDefinition>;
+/// Response to the deletion of an archive.
+pub type DeleteLogsArchiveResponse = EmptyResponse;
+/// An ordered list of archives.
+pub type LogsArchiveOrder = Response<LogsArchiveOrderDefinition>;
+
+/// The logs archive to create.
+pub type CreateLogsArchiveRequest = Request<LogsArchiveCreateRequestDefinition>;
+/// The new order of the archives.
+pub type UpdateLogsArchiveOrderRequest = Request<LogsArchiveOrderDefinition>;
@@ -0,0 +1,259 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::client::{EmptyResponse, Request, Response};
+use crate::secret::{serialize_exposed_option, SecretString};
+
+/// The type of the resource. The value should always be `custom_destination`.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum CustomDestinationType {
+    /// Equals to `custom_destination`.
+    #[serde(rename = "custom_destination")]
+    CustomDestination,
+}
+
+/// How `forward_tags_restriction_list` parameter should be interpreted.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CustomDestinationAttributeTagsRestrictionListType {
+    /// Only the tags in the list are forwarded.
+    AllowList,
+    /// All tags but the ones in the list are forwarded.
+    BlockList,
+}
+
+/// Authentication of an HTTP destination, tagged by its `type`.
+/// Credentials are write-only: the API does not return them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum CustomDestinationHttpDestinationAuth {
+    /// Equals to `basic`.
+    #[serde(rename = "basic")]
+    Basic {
+        /// The username of the authentication.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        username: Option<String>,
+        /// The password of the authentication.
+        #[serde(
+            default,
+            serialize_with = "serialize_exposed_option",
+            skip_serializing_if = "Option::is_none"
+        )]
+        password: Option<SecretString>,
+    },
+    /// Equals to `custom_header`.
+    #[serde(rename = "custom_header")]
+    CustomHeader {
+        /// The header name of the authentication.
+        header_name: String,
+        /// The header value of the authentication.
+        #[serde(
+            default,
+            serialize_with = "serialize_exposed_option",
+            skip_serializing_if = "Option::is_none"
+        )]
+        header_value: Option<SecretString>,
+    },
+}
+
+/// Basic access authentication of an Elasticsearch destination.
+/// Credentials are write-only: the API does not return them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomDestinationElasticsearchDestinationAuth {
+    /// The username of the authentication.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// The password of the authentication.
+    #[serde(
+        default,
+        serialize_with = "serialize_exposed_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub password: Option<SecretString>,
+}
+
+/// An HTTP destination.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomDestinationHttpDestination {
+    /// Authentication method of the HTTP requests.
+    pub auth: CustomDestinationHttpDestinationAuth,
+    /// The destination for which logs will be forwarded to. Must have HTTPS scheme.
+    pub endpoint: String,
+}
+
+/// A Splunk HTTP Event Collector (HEC) destination.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomDestinationSplunkDestination {
+    /// Access token of the Splunk HTTP Event Collector. This field is not returned by the API.
+    #[serde(
+        default,
+        serialize_with = "serialize_exposed_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub access_token: Option<SecretString>,
+    /// The destination for which logs will be forwarded to. Must have HTTPS scheme.
+    pub endpoint: String,
+}
+
+/// An Elasticsearch destination.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomDestinationElasticsearchDestination {
+    /// Basic access authentication.
+    pub auth: CustomDestinationElasticsearchDestinationAuth,
+    /// The destination for which logs will be forwarded to. Must have HTTPS scheme.
+    pub endpoint: String,
+    /// Name of the Elasticsearch index (must follow Elasticsearch's criteria).
+    pub index_name: String,
+    /// Date pattern with US locale and UTC timezone to be appended to the index name after adding `-`
+    /// (that is, `${index_name}-${indexPattern}`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index_rotation: Option<String>,
+}
+
+/// A custom destination's location to forward logs, tagged by its `type`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum CustomDestinationForwardDestination {
+    /// Equals to `http`.
+    #[serde(rename = "http")]
+    Http(CustomDestinationHttpDestination),
+    /// Equals to `splunk_hec`.
+    #[serde(rename = "splunk_hec")]
+    SplunkHec(CustomDestinationSplunkDestination),
+    /// Equals to `elasticsearch`.
+    #[serde(rename = "elasticsearch")]
+    Elasticsearch(CustomDestinationElasticsearchDestination),
+}
+
+/// The attributes associated with the custom destination.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CustomDestinationAttributes {
+    /// Whether logs matching this custom destination should be forwarded or not.
+    pub enabled: Option<bool>,
+    /// Whether tags from the forwarded logs should be forwarded or not.
+    pub forward_tags: Option<bool>,
+    /// List of keys of tags to be filtered.
+    pub forward_tags_restriction_list: Option<Vec<String>>,
+    /// How `forward_tags_restriction_list` parameter should be interpreted.
+    pub forward_tags_restriction_list_type:
+        Option<CustomDestinationAttributeTagsRestrictionListType>,
+    /// A custom destination's location to forward logs.
+    pub forwarder_destination: Option<CustomDestinationForwardDestination>,
+    /// The custom destination name.
+    pub name: Option<String>,
+    /// The custom destination query and filter. Logs matching this query are forwarded to the destination.
+    pub query: Option<String>,
+}
+
+/// The definition of a custom destination.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CustomDestination {
+    /// The attributes associated with the custom destination.
+    pub attributes: Option<CustomDestinationAttributes>,
+    /// The custom destination ID.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// The type of the resource. The value should always be `custom_destination`.
+    pub typ: CustomDestinationType,
+}
+
+/// The attributes associated with the custom destination to create.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CustomDestinationCreateRequestAttributes {
+    /// Whether logs matching this custom destination should be forwarded or not.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Whether tags from the forwarded logs should be forwarded or not.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_tags: Option<bool>,
+    /// List of keys of tags to be filtered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_tags_restriction_list: Option<Vec<String>>,
+    /// How `forward_tags_restriction_list` parameter should be interpreted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_tags_restriction_list_type:
+        Option<CustomDestinationAttributeTagsRestrictionListType>,
+    /// A custom destination's location to forward logs.
+    pub forwarder_destination: CustomDestinationForwardDestination,
+    /// The custom destination name.
+    pub name: String,
+    /// The custom destination query and filter. Logs matching this query are forwarded to the destination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+}
+
+/// The definition of a custom destination to create.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CustomDestinationCreateRequestDefinition {
+    /// The attributes associated with the custom destination.
+    pub attributes: CustomDestinationCreateRequestAttributes,
+    #[serde(rename = "type")]
+    /// The type of the resource. The value should always be `custom_destination`.
+    pub typ: CustomDestinationType,
+}
+
+/// The attributes associated with the custom destination to update.
+/// Only the given attributes are changed.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct CustomDestinationUpdateRequestAttributes {
+    /// Whether logs matching this custom destination should be forwarded or not.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Whether tags from the forwarded logs should be forwarded or not.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_tags: Option<bool>,
+    /// List of keys of tags to be filtered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_tags_restriction_list: Option<Vec<String>>,
+    /// How `forward_tags_restriction_list` parameter should be interpreted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_tags_restriction_list_type:
+        Option<CustomDestinationAttributeTagsRestrictionListType>,
+    /// A custom destination's location to forward logs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forwarder_destination: Option<CustomDestinationForwardDestination>,
+    /// The custom destination name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The custom destination query and filter. Logs matching this query are forwarded to the destination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+}
+
+/// The definition of a custom destination to update.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CustomDestinationUpdateRequestDefinition {
+    /// The attributes associated with the custom destination.
+    pub attributes: CustomDestinationUpdateRequestAttributes,
+    /// The custom destination ID.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// The type of the resource. The value should always be `custom_destination`.
+    pub typ: CustomDestinationType,
+}
+
+/// Path parameters to get a custom destination.
+#[derive(Debug)]
+pub struct GetLogsCustomDestinationRequest {
+    /// The ID of the custom destination.
+    pub custom_destination_id: String,
+}
+
+/// Path parameters to delete a custom destination.
+#[derive(Debug)]
+pub struct DeleteLogsCustomDestinationRequest {
+    /// The ID of the custom destination.
+    pub custom_destination_id: String,
+}
+
+// Responses and requests
+/// The available custom destinations.
+pub type ListLogsCustomDestinationsResponse = Response<Vec<CustomDestination>>;
+/// The custom destination.
+pub type LogsCustomDestinationResponse = Response<CustomDestination>;
+/// Response to the deletion of a custom destination.
+pub type DeleteLogsCustomDestinationResponse = EmptyResponse;
+
+/// The custom destination to create.
+pub type CreateLogsCustomDestinationRequest = Request<CustomDestinationCreateRequestDefinition>;
+/// The custom destination to update.
+pub type UpdateLogsCustomDestinationRequest = Request<CustomDestinationUpdateRequestDefinition>;
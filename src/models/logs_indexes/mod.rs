@@ -0,0 +1,146 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::client::EmptyResponse;
+use super::logs::LogsFilter;
+
+/// Exclusion filter is defined by a query, a sampling rule, and a active/inactive toggle.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsExclusionFilter {
+    /// Default query is `*`, meaning all logs flowing in the index would be excluded.
+    /// Scope down exclusion filter to only a subset of logs with a log query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// Sample rate to apply to logs going through this exclusion filter,
+    /// a value of 1.0 excludes all logs matching the query.
+    pub sample_rate: f64,
+}
+
+/// Represents the index exclusion filter object from configuration API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsExclusion {
+    /// Exclusion filter is defined by a query, a sampling rule, and a active/inactive toggle.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<LogsExclusionFilter>,
+    /// Whether or not the exclusion filter is active.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_enabled: Option<bool>,
+    /// Name of the index exclusion filter.
+    pub name: String,
+}
+
+/// Object containing options to override the default daily limit reset time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsDailyLimitReset {
+    /// String in `HH:00` format representing the time of day the daily limit should be reset.
+    /// The hours must be between 00 and 23 (inclusive).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reset_time: Option<String>,
+    /// String in `(-|+)HH:00` format representing the UTC offset to apply to the given reset time.
+    /// The hours must be between -12 and +14 (inclusive).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reset_utc_offset: Option<String>,
+}
+
+/// Object describing a Datadog Log index.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsIndex {
+    /// The number of log events you can send in this index per day before you are rate-limited.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub daily_limit: Option<i64>,
+    /// Object containing options to override the default daily limit reset time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub daily_limit_reset: Option<LogsDailyLimitReset>,
+    /// A percentage threshold of the daily quota at which a Datadog warning event is generated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub daily_limit_warning_threshold_percentage: Option<f64>,
+    /// An array of exclusion objects. The logs are tested against the query of each filter,
+    /// following the order of the array. Only the first matching active exclusion matters,
+    /// others (if any) are ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclusion_filters: Option<Vec<LogsExclusion>>,
+    /// Filter for logs.
+    pub filter: LogsFilter,
+    /// A boolean stating if the index is rate limited, meaning more logs than the daily limit have been sent.
+    /// Rate limit is reset every-day at 2pm UTC.
+    /// This value is set by the API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_rate_limited: Option<bool>,
+    /// The name of the index.
+    pub name: String,
+    /// The number of days before logs are deleted from this index.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_retention_days: Option<i64>,
+}
+
+/// Object for updating a Datadog Log index.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsIndexUpdate {
+    /// The number of log events you can send in this index per day before you are rate-limited.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub daily_limit: Option<i64>,
+    /// Object containing options to override the default daily limit reset time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub daily_limit_reset: Option<LogsDailyLimitReset>,
+    /// A percentage threshold of the daily quota at which a Datadog warning event is generated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub daily_limit_warning_threshold_percentage: Option<f64>,
+    /// If true, sets the `daily_limit` value to null and the index is not limited on a daily basis
+    /// (any specified `daily_limit` value in the request is ignored).
+    /// If false or omitted, the index's current `daily_limit` is maintained.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_daily_limit: Option<bool>,
+    /// An array of exclusion objects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclusion_filters: Option<Vec<LogsExclusion>>,
+    /// Filter for logs.
+    pub filter: LogsFilter,
+    /// The number of days before logs are deleted from this index.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_retention_days: Option<i64>,
+}
+
+/// Path parameters to get an index.
+#[derive(Debug)]
+pub struct GetLogsIndexRequest {
+    /// Name of the log index.
+    pub name: String,
+}
+
+/// Path parameters and body to update an index.
+#[derive(Debug)]
+pub struct UpdateLogsIndexRequest {
+    /// Name of the log index.
+    pub name: String,
+    /// New definition of the index.
+    pub index: LogsIndexUpdate,
+}
+
+/// Path parameters to delete an index.
+#[derive(Debug)]
+pub struct DeleteLogsIndexRequest {
+    /// Name of the log index.
+    pub name: String,
+}
+
+/// Object with all Index configurations for a given organization.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListLogsIndexesResponse {
+    /// Array of Log index configurations.
+    pub indexes: Vec<LogsIndex>,
+}
+
+/// Object containing the ordered list of log index names.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LogsIndexesOrder {
+    /// Array of strings identifying by their name(s) the index(es) of your organization.
+    /// Logs are tested against the query filter of each index one by one, following the order of the array.
+    /// Logs are eventually stored in the first matching index.
+    pub index_names: Vec<String>,
+}
+
+/// Request to create an index.
+pub type CreateLogsIndexRequest = LogsIndex;
+/// Response to the deletion of an index.
+pub type DeleteLogsIndexResponse = EmptyResponse;
+/// Request to update the order of the indexes.
+pub type UpdateLogsIndexOrderRequest = LogsIndexesOrder;
@@ -0,0 +1,46 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::client::{EmptyResponse, Request, Response};
+use super::generated_metrics::{
+    GeneratedMetric, GeneratedMetricAttributes, GeneratedMetricCreateAttributes,
+    GeneratedMetricCreateData, GeneratedMetricIdRequest, GeneratedMetricUpdateAttributes,
+    GeneratedMetricUpdateData, UpdateGeneratedMetricRequest,
+};
+
+/// The type of the resource. The value should always be `logs_metrics`.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum LogsMetricType {
+    /// Equals to `logs_metrics`.
+    #[serde(rename = "logs_metrics")]
+    LogsMetrics,
+}
+
+/// The object describing a Datadog log-based metric.
+pub type LogsMetricAttributes = GeneratedMetricAttributes;
+/// The log-based metric properties.
+pub type LogsMetric = GeneratedMetric<LogsMetricType>;
+/// The object describing the Datadog log-based metric to create.
+pub type LogsMetricCreateAttributes = GeneratedMetricCreateAttributes;
+/// The new log-based metric properties.
+pub type LogsMetricCreateData = GeneratedMetricCreateData<LogsMetricType>;
+/// The log-based metric properties to update. Only the given attributes are changed.
+pub type LogsMetricUpdateAttributes = GeneratedMetricUpdateAttributes;
+/// The new log-based metric properties.
+pub type LogsMetricUpdateData = GeneratedMetricUpdateData<LogsMetricType>;
+/// Path parameters to get a log-based metric.
+pub type GetLogsMetricRequest = GeneratedMetricIdRequest;
+/// Path parameters and body to update a log-based metric.
+pub type UpdateLogsMetricRequest = UpdateGeneratedMetricRequest<LogsMetricType>;
+/// Path parameters to delete a log-based metric.
+pub type DeleteLogsMetricRequest = GeneratedMetricIdRequest;
+
+// Responses and requests
+/// All the available log-based metric objects.
+pub type ListLogsMetricsResponse = Response<Vec<LogsMetric>>;
+/// The log-based metric object.
+pub type LogsMetricResponse = Response<LogsMetric>;
+/// Response to the deletion of a log-based metric.
+pub type DeleteLogsMetricResponse = EmptyResponse;
+
+/// The log-based metric to create.
+pub type CreateLogsMetricRequest = Request<LogsMetricCreateData>;
@@ -0,0 +1,481 @@
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use super::client::EmptyResponse;
+use super::logs::LogsFilter;
+
+/// Set of rules for the grok parser.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsGrokParserRules {
+    /// List of match rules for the grok parser, separated by a new line.
+    pub match_rules: String,
+    /// List of support rules for the grok parser, separated by a new line.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub support_rules: Option<String>,
+}
+
+/// Create custom grok rules to parse the full message or a specific attribute of your raw event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsGrokParser {
+    /// Set of rules for the grok parser.
+    pub grok: LogsGrokParserRules,
+    /// Whether or not the processor is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_enabled: Option<bool>,
+    /// Name of the processor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// List of sample logs to test this grok parser.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub samples: Option<Vec<String>>,
+    /// Name of the log attribute to parse.
+    pub source: String,
+}
+
+/// Processor promoting the first matching attribute of `sources` to a reserved attribute:
+/// the official date, status, service, message or trace ID of the log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsSourcesRemapper {
+    /// Whether or not the processor is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_enabled: Option<bool>,
+    /// Name of the processor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Array of source attributes.
+    #[serde(default)]
+    pub sources: Vec<String>,
+}
+
+/// The processor remaps any source attribute(s) or tag to another target attribute or tag.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsAttributeRemapper {
+    /// Whether or not the processor is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_enabled: Option<bool>,
+    /// Name of the processor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Override or not the target element if already set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub override_on_conflict: Option<bool>,
+    /// Remove or preserve the remapped source element.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preserve_source: Option<bool>,
+    /// Defines if the sources are from log `attribute` or `tag`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_type: Option<String>,
+    /// Array of source attributes.
+    pub sources: Vec<String>,
+    /// Final attribute or tag name to remap the sources to.
+    pub target: String,
+    /// If the `target_type` of the remapper is `attribute`, try to cast the value to a new specific type.
+    /// If the cast is not possible, the original type is kept.
+    /// `string`, `integer`, or `double` are the possible types.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_format: Option<String>,
+    /// Defines if the final attribute or tag name is from log `attribute` or `tag`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_type: Option<String>,
+}
+
+/// This processor extracts query parameters and other important parameters from a URL.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsUrlParser {
+    /// Whether or not the processor is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_enabled: Option<bool>,
+    /// Name of the processor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Normalize the ending slashes or not.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub normalize_ending_slashes: Option<bool>,
+    /// Array of source attributes.
+    pub sources: Vec<String>,
+    /// Name of the parent attribute that contains all the extracted details from the `sources`.
+    pub target: String,
+}
+
+/// The User-Agent parser takes a User-Agent attribute and extracts the OS, browser, device, and other user data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsUserAgentParser {
+    /// Whether or not the processor is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_enabled: Option<bool>,
+    /// Define if the source attribute is URL encoded or not.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_encoded: Option<bool>,
+    /// Name of the processor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Array of source attributes.
+    pub sources: Vec<String>,
+    /// Name of the parent attribute that contains all the extracted details from the `sources`.
+    pub target: String,
+}
+
+/// Object describing the logs filter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsCategoryProcessorCategory {
+    /// Filter for logs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<LogsFilter>,
+    /// Value to assign to the target attribute.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Use the Category Processor to add a new attribute (without spaces or special characters in the new attribute name)
+/// to a log matching a provided search query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsCategoryProcessor {
+    /// Array of filters to match or not a log and their corresponding `name` to assign a custom value to the log.
+    pub categories: Vec<LogsCategoryProcessorCategory>,
+    /// Whether or not the processor is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_enabled: Option<bool>,
+    /// Name of the processor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Name of the target attribute which value is defined by the matching category.
+    pub target: String,
+}
+
+/// Use the Arithmetic Processor to add a new attribute (without spaces or special characters
+/// in the new attribute name) to a log with the result of the provided formula.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsArithmeticProcessor {
+    /// Arithmetic operation between one or more log attributes.
+    pub expression: String,
+    /// Whether or not the processor is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_enabled: Option<bool>,
+    /// If `true`, it replaces all missing attributes of expression by `0`, `false`
+    /// skip the operation if an attribute is missing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_replace_missing: Option<bool>,
+    /// Name of the processor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Name of the attribute that contains the result of the arithmetic operation.
+    pub target: String,
+}
+
+/// Use the string builder processor to add a new attribute (without spaces or special characters)
+/// to a log with the result of the provided template.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsStringBuilderProcessor {
+    /// Whether or not the processor is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_enabled: Option<bool>,
+    /// If true, it replaces all missing attributes of `template` by an empty string.
+    /// If `false` (default), skips the operation for missing attributes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_replace_missing: Option<bool>,
+    /// Name of the processor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The name of the attribute that contains the result of the template.
+    pub target: String,
+    /// A formula with one or more attributes and raw text.
+    pub template: String,
+}
+
+/// The GeoIP parser takes an IP address attribute and extracts if available
+/// the Continent, Country, Subdivision, and City information in the target attribute path.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsGeoIpParser {
+    /// Whether or not the processor is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_enabled: Option<bool>,
+    /// Name of the processor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Array of source attributes.
+    pub sources: Vec<String>,
+    /// Name of the parent attribute that contains all the extracted details from the `sources`.
+    pub target: String,
+}
+
+/// Use the Lookup Processor to define a mapping between a log attribute
+/// and a human readable value saved in the processors mapping table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsLookupProcessor {
+    /// Value to set the target attribute if the source value is not found in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_lookup: Option<String>,
+    /// Whether or not the processor is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_enabled: Option<bool>,
+    /// Mapping table of values for the source attribute and their associated target attribute values,
+    /// formatted as `["source_key1,target_value1", "source_key2,target_value2"]`
+    pub lookup_table: Vec<String>,
+    /// Name of the processor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Source attribute used to perform the lookup.
+    pub source: String,
+    /// Name of the attribute that contains the corresponding value in the mapping list
+    /// or the `default_lookup` if not found in the mapping list.
+    pub target: String,
+}
+
+/// Use the Lookup Processor to define a mapping between a log attribute
+/// and a human readable value saved in a Reference Table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsReferenceTableLookupProcessor {
+    /// Whether or not the processor is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_enabled: Option<bool>,
+    /// Name of the Reference Table for the source attribute and their associated target attribute values.
+    pub lookup_enrichment_table: String,
+    /// Name of the processor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Source attribute used to perform the lookup.
+    pub source: String,
+    /// Name of the attribute that contains the corresponding value in the mapping list.
+    pub target: String,
+}
+
+/// Nested Pipelines are pipelines within a pipeline.
+/// Use Nested Pipelines to split the processing into two steps.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsNestedPipeline {
+    /// Filter for logs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<LogsFilter>,
+    /// Whether or not the processor is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_enabled: Option<bool>,
+    /// Name of the pipeline.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Ordered list of processors in this pipeline.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub processors: Option<Vec<LogsProcessor>>,
+}
+
+/// Definition of a logs processor, tagged by its `type`.
+///
+/// Processor types this client does not know about are kept as [LogsProcessor::Unknown],
+/// holding the raw definition so that it is sent back unchanged when updating a pipeline.
+#[derive(Debug, Clone)]
+pub enum LogsProcessor {
+    /// Equals to `grok-parser`.
+    GrokParser(LogsGrokParser),
+    /// Equals to `date-remapper`.
+    DateRemapper(LogsSourcesRemapper),
+    /// Equals to `status-remapper`.
+    StatusRemapper(LogsSourcesRemapper),
+    /// Equals to `service-remapper`.
+    ServiceRemapper(LogsSourcesRemapper),
+    /// Equals to `message-remapper`.
+    MessageRemapper(LogsSourcesRemapper),
+    /// Equals to `trace-id-remapper`.
+    TraceIdRemapper(LogsSourcesRemapper),
+    /// Equals to `attribute-remapper`.
+    AttributeRemapper(LogsAttributeRemapper),
+    /// Equals to `url-parser`.
+    UrlParser(LogsUrlParser),
+    /// Equals to `user-agent-parser`.
+    UserAgentParser(LogsUserAgentParser),
+    /// Equals to `category-processor`.
+    CategoryProcessor(LogsCategoryProcessor),
+    /// Equals to `arithmetic-processor`.
+    ArithmeticProcessor(LogsArithmeticProcessor),
+    /// Equals to `string-builder-processor`.
+    StringBuilderProcessor(LogsStringBuilderProcessor),
+    /// Equals to `geo-ip-parser`.
+    GeoIpParser(LogsGeoIpParser),
+    /// Equals to `lookup-processor`.
+    LookupProcessor(LogsLookupProcessor),
+    /// Equals to `reference-table-lookup-processor`.
+    ReferenceTableLookupProcessor(LogsReferenceTableLookupProcessor),
+    /// Equals to `pipeline`.
+    Pipeline(LogsNestedPipeline),
+    /// Any other processor, as returned by the API, `type` included.
+    Unknown(Value),
+}
+
+impl LogsProcessor {
+    /// The `type` of the processor.
+    pub fn processor_type(&self) -> Option<&str> {
+        match self {
+            LogsProcessor::GrokParser(_) => Some("grok-parser"),
+            LogsProcessor::DateRemapper(_) => Some("date-remapper"),
+            LogsProcessor::StatusRemapper(_) => Some("status-remapper"),
+            LogsProcessor::ServiceRemapper(_) => Some("service-remapper"),
+            LogsProcessor::MessageRemapper(_) => Some("message-remapper"),
+            LogsProcessor::TraceIdRemapper(_) => Some("trace-id-remapper"),
+            LogsProcessor::AttributeRemapper(_) => Some("attribute-remapper"),
+            LogsProcessor::UrlParser(_) => Some("url-parser"),
+            LogsProcessor::UserAgentParser(_) => Some("user-agent-parser"),
+            LogsProcessor::CategoryProcessor(_) => Some("category-processor"),
+            LogsProcessor::ArithmeticProcessor(_) => Some("arithmetic-processor"),
+            LogsProcessor::StringBuilderProcessor(_) => Some("string-builder-processor"),
+            LogsProcessor::GeoIpParser(_) => Some("geo-ip-parser"),
+            LogsProcessor::LookupProcessor(_) => Some("lookup-processor"),
+            LogsProcessor::ReferenceTableLookupProcessor(_) => {
+                Some("reference-table-lookup-processor")
+            }
+            LogsProcessor::Pipeline(_) => Some("pipeline"),
+            LogsProcessor::Unknown(value) => value.get("type").and_then(Value::as_str),
+        }
+    }
+}
+
+impl Serialize for LogsProcessor {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let value = match self {
+            LogsProcessor::GrokParser(processor) => serde_json::to_value(processor),
+            LogsProcessor::DateRemapper(processor)
+            | LogsProcessor::StatusRemapper(processor)
+            | LogsProcessor::ServiceRemapper(processor)
+            | LogsProcessor::MessageRemapper(processor)
+            | LogsProcessor::TraceIdRemapper(processor) => serde_json::to_value(processor),
+            LogsProcessor::AttributeRemapper(processor) => serde_json::to_value(processor),
+            LogsProcessor::UrlParser(processor) => serde_json::to_value(processor),
+            LogsProcessor::UserAgentParser(processor) => serde_json::to_value(processor),
+            LogsProcessor::CategoryProcessor(processor) => serde_json::to_value(processor),
+            LogsProcessor::ArithmeticProcessor(processor) => serde_json::to_value(processor),
+            LogsProcessor::StringBuilderProcessor(processor) => serde_json::to_value(processor),
+            LogsProcessor::GeoIpParser(processor) => serde_json::to_value(processor),
+            LogsProcessor::LookupProcessor(processor) => serde_json::to_value(processor),
+            LogsProcessor::ReferenceTableLookupProcessor(processor) => {
+                serde_json::to_value(processor)
+            }
+            LogsProcessor::Pipeline(processor) => serde_json::to_value(processor),
+            LogsProcessor::Unknown(value) => return value.serialize(serializer),
+        };
+
+        let mut value = value.map_err(S::Error::custom)?;
+        if let (Value::Object(fields), Some(typ)) = (&mut value, self.processor_type()) {
+            fields.insert("type".to_string(), Value::String(typ.to_string()));
+        }
+        value.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for LogsProcessor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        let typ = value
+            .get("type")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+
+        let processor = match typ {
+            "grok-parser" => serde_json::from_value(value).map(LogsProcessor::GrokParser),
+            "date-remapper" => serde_json::from_value(value).map(LogsProcessor::DateRemapper),
+            "status-remapper" => serde_json::from_value(value).map(LogsProcessor::StatusRemapper),
+            "service-remapper" => serde_json::from_value(value).map(LogsProcessor::ServiceRemapper),
+            "message-remapper" => serde_json::from_value(value).map(LogsProcessor::MessageRemapper),
+            "trace-id-remapper" => {
+                serde_json::from_value(value).map(LogsProcessor::TraceIdRemapper)
+            }
+            "attribute-remapper" => {
+                serde_json::from_value(value).map(LogsProcessor::AttributeRemapper)
+            }
+            "url-parser" => serde_json::from_value(value).map(LogsProcessor::UrlParser),
+            "user-agent-parser" => {
+                serde_json::from_value(value).map(LogsProcessor::UserAgentParser)
+            }
+            "category-processor" => {
+                serde_json::from_value(value).map(LogsProcessor::CategoryProcessor)
+            }
+            "arithmetic-processor" => {
+                serde_json::from_value(value).map(LogsProcessor::ArithmeticProcessor)
+            }
+            "string-builder-processor" => {
+                serde_json::from_value(value).map(LogsProcessor::StringBuilderProcessor)
+            }
+            "geo-ip-parser" => serde_json::from_value(value).map(LogsProcessor::GeoIpParser),
+            "lookup-processor" => serde_json::from_value(value).map(LogsProcessor::LookupProcessor),
+            "reference-table-lookup-processor" => {
+                serde_json::from_value(value).map(LogsProcessor::ReferenceTableLookupProcessor)
+            }
+            "pipeline" => serde_json::from_value(value).map(LogsProcessor::Pipeline),
+            _ => return Ok(LogsProcessor::Unknown(value)),
+        };
+
+        processor.map_err(D::Error::custom)
+    }
+}
+
+/// Pipelines and processors operate on incoming logs, parsing and transforming them into structured attributes for easier querying.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsPipeline {
+    /// Filter for logs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<LogsFilter>,
+    /// ID of the pipeline.
+    /// This value is set by the API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Whether or not the pipeline is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_enabled: Option<bool>,
+    /// Whether or not the pipeline can be edited.
+    /// This value is set by the API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_read_only: Option<bool>,
+    /// Name of the pipeline.
+    pub name: String,
+    /// Ordered list of processors in this pipeline.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub processors: Option<Vec<LogsProcessor>>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    /// Type of pipeline.
+    /// This value is set by the API.
+    pub typ: Option<String>,
+}
+
+/// Path parameters to get a pipeline.
+#[derive(Debug)]
+pub struct GetLogsPipelineRequest {
+    /// ID of the pipeline to get.
+    pub pipeline_id: String,
+}
+
+/// Path parameters and body to update a pipeline.
+#[derive(Debug)]
+pub struct UpdateLogsPipelineRequest {
+    /// ID of the pipeline to update.
+    pub pipeline_id: String,
+    /// New definition of the pipeline.
+    pub pipeline: LogsPipeline,
+}
+
+/// Path parameters to delete a pipeline.
+#[derive(Debug)]
+pub struct DeleteLogsPipelineRequest {
+    /// ID of the pipeline to delete.
+    pub pipeline_id: String,
+}
+
+/// Object containing the ordered list of pipeline IDs.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LogsPipelinesOrder {
+    /// Ordered Array of `<PIPELINE_ID>` strings, the order of pipeline IDs in the array
+    /// define the overall Pipelines order for Datadog.
+    pub pipeline_ids: Vec<String>,
+}
+
+/// Array of all log pipeline objects of your organization.
+pub type ListLogsPipelinesResponse = Vec<LogsPipeline>;
+/// Request to create a pipeline.
+pub type CreateLogsPipelineRequest = LogsPipeline;
+/// Response to the deletion of a pipeline.
+pub type DeleteLogsPipelineResponse = EmptyResponse;
+/// Request to update the order of the pipelines.
+pub type UpdateLogsPipelineOrderRequest = LogsPipelinesOrder;
@@ -0,0 +1,388 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::client::{Request, Response};
+
+/// Query parameters to list active metrics.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListActiveMetricsRequest {
+    /// Seconds since the Unix epoch.
+    pub from: i64,
+    /// Hostname for filtering the list of metrics returned.
+    /// If set, metrics retrieved are those with the corresponding hostname tag.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    /// Filter metrics that have been submitted with the given tags.
+    /// Supports boolean and wildcard expressions. Cannot be combined with other filters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag_filter: Option<String>,
+}
+
+/// Object listing all metric names stored by Datadog since a given time.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListActiveMetricsResponse {
+    /// Time when the metrics were active, seconds since the Unix epoch.
+    pub from: Option<String>,
+    /// List of metric names.
+    pub metrics: Vec<String>,
+}
+
+/// Type of a metric as reported by the metadata endpoint.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MetricMetadataType {
+    /// Equals to `count`.
+    Count,
+    /// Equals to `rate`.
+    Rate,
+    /// Equals to `gauge`.
+    Gauge,
+    /// Equals to `set`.
+    Set,
+    /// Equals to `histogram`.
+    Histogram,
+    /// Equals to `distribution`.
+    Distribution,
+}
+
+/// Object with all metric related metadata.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MetricMetadata {
+    /// Metric description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Name of the integration that sent the metric if applicable.
+    /// This value is set by the API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integration: Option<String>,
+    /// Per unit of the metric such as `second` in `bytes per second`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_unit: Option<String>,
+    /// A more human-readable and abbreviated version of the metric name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub short_name: Option<String>,
+    /// StatsD flush interval of the metric in seconds if applicable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statsd_interval: Option<i64>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    /// Metric type such as `gauge` or `rate`.
+    pub typ: Option<MetricMetadataType>,
+    /// Primary unit of the metric such as `byte` or `operation`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+}
+
+/// Path parameters to get the metadata of a metric.
+#[derive(Debug)]
+pub struct GetMetricMetadataRequest {
+    /// Name of the metric for which to get metadata.
+    pub metric_name: String,
+}
+
+/// Path parameters and body to edit the metadata of a metric.
+#[derive(Debug)]
+pub struct UpdateMetricMetadataRequest {
+    /// Name of the metric for which to edit metadata.
+    pub metric_name: String,
+    /// New metadata.
+    pub metadata: MetricMetadata,
+}
+
+/// The metric tag configuration resource type.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum MetricTagConfigurationType {
+    /// Equals to `manage_tags`.
+    #[serde(rename = "manage_tags")]
+    ManageTags,
+}
+
+/// The metric's type.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MetricTagConfigurationMetricTypes {
+    /// Equals to `gauge`.
+    Gauge,
+    /// Equals to `count`.
+    Count,
+    /// Equals to `rate`.
+    Rate,
+    /// Equals to `distribution`.
+    Distribution,
+}
+
+/// A space aggregation for use in query.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MetricCustomSpaceAggregation {
+    /// Equals to `avg`.
+    Avg,
+    /// Equals to `max`.
+    Max,
+    /// Equals to `min`.
+    Min,
+    /// Equals to `sum`.
+    Sum,
+}
+
+/// A time aggregation for use in query.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MetricCustomTimeAggregation {
+    /// Equals to `avg`.
+    Avg,
+    /// Equals to `count`.
+    Count,
+    /// Equals to `max`.
+    Max,
+    /// Equals to `min`.
+    Min,
+    /// Equals to `sum`.
+    Sum,
+}
+
+/// A time and space aggregation combination for use in query.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MetricCustomAggregation {
+    /// A space aggregation for use in query.
+    pub space: MetricCustomSpaceAggregation,
+    /// A time aggregation for use in query.
+    pub time: MetricCustomTimeAggregation,
+}
+
+/// Object containing the definition of a metric tag configuration attributes.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MetricTagConfigurationAttributes {
+    /// A list of queryable aggregation combinations for a count, rate, or gauge metric.
+    pub aggregations: Option<Vec<MetricCustomAggregation>>,
+    /// Timestamp when the tag configuration was created.
+    pub created_at: Option<String>,
+    /// When set to true, the configuration will exclude the configured tags and include any other submitted tags.
+    pub exclude_tags_mode: Option<bool>,
+    /// Toggle to include or exclude percentile aggregations for distribution metrics.
+    pub include_percentiles: Option<bool>,
+    /// The metric's type.
+    pub metric_type: Option<MetricTagConfigurationMetricTypes>,
+    /// Timestamp when the tag configuration was last modified.
+    pub modified_at: Option<String>,
+    /// List of tag keys on which to group.
+    pub tags: Vec<String>,
+}
+
+/// Object for a single metric tag configuration.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MetricTagConfiguration {
+    /// Object containing the definition of a metric tag configuration attributes.
+    pub attributes: MetricTagConfigurationAttributes,
+    /// The metric name for this resource.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// The metric tag configuration resource type.
+    pub typ: MetricTagConfigurationType,
+}
+
+/// Path parameters to get the tag configuration of a metric.
+#[derive(Debug)]
+pub struct GetMetricTagConfigurationRequest {
+    /// The name of the metric.
+    pub metric_name: String,
+}
+
+/// Object containing the definition of a metric tag configuration to be created.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateMetricTagConfigurationAttributes {
+    /// A list of queryable aggregation combinations for a count, rate, or gauge metric.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aggregations: Option<Vec<MetricCustomAggregation>>,
+    /// When set to true, the configuration will exclude the configured tags and include any other submitted tags.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude_tags_mode: Option<bool>,
+    /// Toggle to include or exclude percentile aggregations for distribution metrics.
+    /// Only present when the `metric_type` is `distribution`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_percentiles: Option<bool>,
+    /// The metric's type.
+    pub metric_type: MetricTagConfigurationMetricTypes,
+    /// A list of tag keys that will be queryable for your metric.
+    pub tags: Vec<String>,
+}
+
+/// Object for a single metric to be configured.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateMetricTagConfigurationRequestData {
+    /// Object containing the definition of a metric tag configuration to be created.
+    pub attributes: CreateMetricTagConfigurationAttributes,
+    /// The metric name for this resource.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// The metric tag configuration resource type.
+    pub typ: MetricTagConfigurationType,
+}
+
+/// Object containing the definition of a metric tag configuration to be updated.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateMetricTagConfigurationAttributes {
+    /// A list of queryable aggregation combinations for a count, rate, or gauge metric.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aggregations: Option<Vec<MetricCustomAggregation>>,
+    /// When set to true, the configuration will exclude the configured tags and include any other submitted tags.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude_tags_mode: Option<bool>,
+    /// Toggle to include or exclude percentile aggregations for distribution metrics.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_percentiles: Option<bool>,
+    /// A list of tag keys that will be queryable for your metric.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+}
+
+/// Object for a single tag configuration to be edited.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateMetricTagConfigurationRequestData {
+    /// Object containing the definition of a metric tag configuration to be updated.
+    pub attributes: UpdateMetricTagConfigurationAttributes,
+    /// The metric name for this resource.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// The metric tag configuration resource type.
+    pub typ: MetricTagConfigurationType,
+}
+
+/// Path parameters to delete the tag configuration of a metric.
+#[derive(Debug)]
+pub struct DeleteMetricTagConfigurationRequest {
+    /// The name of the metric.
+    pub metric_name: String,
+}
+
+/// Path and query parameters to estimate the cardinality of a metric with a given tag,
+/// percentile and number of aggregations configuration.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EstimateMetricCardinalityRequest {
+    /// The name of the metric.
+    #[serde(skip)]
+    pub metric_name: String,
+    /// Filtered tag keys that the metric is configured to query with, comma separated.
+    #[serde(rename = "filter[groups]", skip_serializing_if = "Option::is_none")]
+    pub filter_groups: Option<String>,
+    /// The number of hours of look back (from now) to estimate cardinality with.
+    #[serde(rename = "filter[hours_ago]", skip_serializing_if = "Option::is_none")]
+    pub filter_hours_ago: Option<i32>,
+    /// The number of aggregations that a count, rate, or gauge metric is configured to use.
+    #[serde(
+        rename = "filter[num_aggregations]",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub filter_num_aggregations: Option<i32>,
+    /// A boolean, for distribution metrics only, to estimate cardinality if the metric includes additional percentile aggregators.
+    #[serde(rename = "filter[pct]", skip_serializing_if = "Option::is_none")]
+    pub filter_pct: Option<bool>,
+    /// A window, in hours, from the look back to estimate cardinality with.
+    #[serde(rename = "filter[timespan_h]", skip_serializing_if = "Option::is_none")]
+    pub filter_timespan_h: Option<i32>,
+}
+
+/// Estimate type based on the queried configuration.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MetricEstimateType {
+    /// Equals to `count_or_gauge`.
+    CountOrGauge,
+    /// Equals to `distribution`.
+    Distribution,
+    /// Equals to `percentile`.
+    Percentile,
+}
+
+/// The metric estimate resource type.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum MetricEstimateResourceType {
+    /// Equals to `metric_cardinality_estimate`.
+    #[serde(rename = "metric_cardinality_estimate")]
+    MetricCardinalityEstimate,
+}
+
+/// Object containing the definition of a metric estimate attribute.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MetricEstimateAttributes {
+    /// Estimate type based on the queried configuration.
+    pub estimate_type: Option<MetricEstimateType>,
+    /// Timestamp when the cardinality estimate was requested.
+    pub estimated_at: Option<String>,
+    /// Estimated cardinality of the metric based on the queried configuration.
+    pub estimated_output_series: Option<i64>,
+}
+
+/// Object for a metric cardinality estimate.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MetricEstimate {
+    /// Object containing the definition of a metric estimate attribute.
+    pub attributes: MetricEstimateAttributes,
+    /// The metric name for this resource.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// The metric estimate resource type.
+    pub typ: MetricEstimateResourceType,
+}
+
+/// Path parameters to view distinct metrics volumes for the given metric name.
+#[derive(Debug)]
+pub struct GetMetricVolumesRequest {
+    /// The name of the metric.
+    pub metric_name: String,
+}
+
+/// Object containing the definition of a metric's distinct volume.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MetricDistinctVolumeAttributes {
+    /// Distinct volume for the given metric.
+    pub distinct_volume: Option<i64>,
+}
+
+/// Object containing the definition of a metric's ingested and indexed volume.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MetricIngestedIndexedVolumeAttributes {
+    /// Indexed volume for the given metric.
+    pub indexed_volume: Option<i64>,
+    /// Ingested volume for the given metric.
+    pub ingested_volume: Option<i64>,
+}
+
+/// Possible response objects for a metric's volume.
+/// Distribution metrics return a distinct volume, any other metric type
+/// returns its ingested and indexed volume.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum MetricVolumes {
+    /// Equals to `distinct_metric_volumes`.
+    #[serde(rename = "distinct_metric_volumes")]
+    Distinct {
+        /// Object containing the definition of a metric's distinct volume.
+        attributes: MetricDistinctVolumeAttributes,
+        /// The metric name for this resource.
+        id: String,
+    },
+    /// Equals to `metric_volumes`.
+    #[serde(rename = "metric_volumes")]
+    IngestedIndexed {
+        /// Object containing the definition of a metric's ingested and indexed volume.
+        attributes: MetricIngestedIndexedVolumeAttributes,
+        /// The metric name for this resource.
+        id: String,
+    },
+}
+
+// Responses and requests
+/// Response object which includes a single metric's tag configuration.
+pub type MetricTagConfigurationResponse = Response<MetricTagConfiguration>;
+/// Response object that includes a single metric's tag configuration.
+pub type CreateMetricTagConfigurationResponse = MetricTagConfigurationResponse;
+/// Response object that includes the updated metric's tag configuration.
+pub type UpdateMetricTagConfigurationResponse = MetricTagConfigurationResponse;
+/// Response object for a metric cardinality estimate.
+pub type MetricEstimateResponse = Response<MetricEstimate>;
+/// Response object which includes a single metric's volume.
+pub type MetricVolumesResponse = Response<MetricVolumes>;
+
+/// Request object that includes the metric that you would like to configure tags for.
+pub type CreateMetricTagConfigurationRequest = Request<CreateMetricTagConfigurationRequestData>;
+/// Request object that includes the metric that you would like to edit the tag configuration on.
+pub type UpdateMetricTagConfigurationRequest = Request<UpdateMetricTagConfigurationRequestData>;
@@ -0,0 +1,612 @@
+use std::collections::BTreeMap;
+
+use serde_derive::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use super::client::EmptyStruct;
+
+/// The type of the monitor.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum MonitorType {
+    /// Equals to `composite`.
+    #[serde(rename = "composite")]
+    Composite,
+    /// Equals to `event alert`.
+    #[serde(rename = "event alert")]
+    EventAlert,
+    /// Equals to `log alert`.
+    #[serde(rename = "log alert")]
+    LogAlert,
+    /// Equals to `metric alert`.
+    #[serde(rename = "metric alert")]
+    MetricAlert,
+    /// Equals to `process alert`.
+    #[serde(rename = "process alert")]
+    ProcessAlert,
+    /// Equals to `query alert`.
+    #[serde(rename = "query alert")]
+    QueryAlert,
+    /// Equals to `rum alert`.
+    #[serde(rename = "rum alert")]
+    RumAlert,
+    /// Equals to `service check`.
+    #[serde(rename = "service check")]
+    ServiceCheck,
+    /// Equals to `synthetics alert`.
+    #[serde(rename = "synthetics alert")]
+    SyntheticsAlert,
+    /// Equals to `trace-analytics alert`.
+    #[serde(rename = "trace-analytics alert")]
+    TraceAnalyticsAlert,
+    /// Equals to `slo alert`.
+    #[serde(rename = "slo alert")]
+    SloAlert,
+    /// Equals to `event-v2 alert`.
+    #[serde(rename = "event-v2 alert")]
+    EventV2Alert,
+    /// Equals to `audit alert`.
+    #[serde(rename = "audit alert")]
+    AuditAlert,
+    /// Equals to `ci-pipelines alert`.
+    #[serde(rename = "ci-pipelines alert")]
+    CiPipelinesAlert,
+    /// Equals to `ci-tests alert`.
+    #[serde(rename = "ci-tests alert")]
+    CiTestsAlert,
+    /// Equals to `error-tracking alert`.
+    #[serde(rename = "error-tracking alert")]
+    ErrorTrackingAlert,
+    /// Equals to `database-monitoring alert`.
+    #[serde(rename = "database-monitoring alert")]
+    DatabaseMonitoringAlert,
+    /// Equals to `network-performance alert`.
+    #[serde(rename = "network-performance alert")]
+    NetworkPerformanceAlert,
+    /// Any other monitor type, kept as is so that it is sent back unchanged.
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// The different states your monitor can be in.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum MonitorOverallStates {
+    /// Equals to `Alert`.
+    Alert,
+    /// Equals to `Ignored`.
+    Ignored,
+    /// Equals to `No Data`.
+    #[serde(rename = "No Data")]
+    NoData,
+    /// Equals to `OK`.
+    #[serde(rename = "OK")]
+    Ok,
+    /// Equals to `Skipped`.
+    Skipped,
+    /// Equals to `Unknown`.
+    Unknown,
+    /// Equals to `Warn`.
+    Warn,
+}
+
+/// The alert thresholds of a monitor.
+/// Which of them are accepted depends on the monitor type and query.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MonitorThresholds {
+    /// The monitor `CRITICAL` threshold.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub critical: Option<f64>,
+    /// The monitor `CRITICAL` recovery threshold.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub critical_recovery: Option<f64>,
+    /// The monitor `OK` threshold.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ok: Option<f64>,
+    /// The monitor `UNKNOWN` threshold.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unknown: Option<f64>,
+    /// The monitor `WARNING` threshold.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<f64>,
+    /// The monitor `WARNING` recovery threshold.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning_recovery: Option<f64>,
+}
+
+/// Alerting time window options of anomaly monitors.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MonitorThresholdWindowOptions {
+    /// Describes how long an anomalous metric must be normal before the alert recovers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recovery_window: Option<String>,
+    /// Describes how long a metric must be anomalous before an alert triggers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_window: Option<String>,
+}
+
+/// Toggles the display of additional content sent in the monitor notification.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MonitorNotificationPreset {
+    /// Equals to `show_all`.
+    ShowAll,
+    /// Equals to `hide_query`.
+    HideQuery,
+    /// Equals to `hide_handles`.
+    HideHandles,
+    /// Equals to `hide_all`.
+    HideAll,
+}
+
+/// Controls how groups or monitors are treated if an evaluation does not return any data points.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MonitorOnMissingData {
+    /// Equals to `default`.
+    Default,
+    /// Equals to `show_no_data`.
+    ShowNoData,
+    /// Equals to `show_and_notify_no_data`.
+    ShowAndNotifyNoData,
+    /// Equals to `resolve`.
+    Resolve,
+}
+
+/// The state a monitor renotifies from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum MonitorRenotifyStatus {
+    /// Equals to `alert`.
+    #[serde(rename = "alert")]
+    Alert,
+    /// Equals to `warn`.
+    #[serde(rename = "warn")]
+    Warn,
+    /// Equals to `no data`.
+    #[serde(rename = "no data")]
+    NoData,
+}
+
+/// List of options associated with your monitor.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MonitorOptions {
+    /// Whether or not to send a log sample when the log monitor triggers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_logs_sample: Option<bool>,
+    /// Whether or not to send a list of samples when the monitor triggers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_samples: Option<bool>,
+    /// A message to include with a re-notification. Kept as raw text, like the monitor message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub escalation_message: Option<String>,
+    /// Time (in seconds) to delay evaluation, as a non-negative integer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evaluation_delay: Option<i64>,
+    /// The time span after which groups with missing data are dropped from the monitor state.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_retention_duration: Option<String>,
+    /// Whether the log alert monitor triggers a single alert or multiple alerts when any group breaches a threshold.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groupby_simple_monitor: Option<bool>,
+    /// A Boolean indicating whether notifications from this monitor automatically insert its triggering tags into the title.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_tags: Option<bool>,
+    /// How long the test should be in failure before alerting (integer, number of seconds, max 7200).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_failure_duration: Option<i64>,
+    /// The minimum number of locations in failure at the same time during
+    /// at least one moment in the `min_failure_duration` period.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_location_failed: Option<i64>,
+    /// Time (in seconds) to skip evaluations for new groups.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_group_delay: Option<i64>,
+    /// Time (in seconds) to allow a host to boot and applications to fully start before starting the evaluation.
+    /// Deprecated in favor of `new_group_delay`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_host_delay: Option<i64>,
+    /// The number of minutes before a monitor notifies after data stops reporting.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_data_timeframe: Option<i64>,
+    /// Toggles the display of additional content sent in the monitor notification.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notification_preset_name: Option<MonitorNotificationPreset>,
+    /// A Boolean indicating whether tagged users is notified on changes to this monitor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_audit: Option<bool>,
+    /// Controls what granularity a monitor alerts on, as a list of tag keys.
+    /// `["*"]` configures the monitor to notify as a simple-alert.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_by: Option<Vec<String>>,
+    /// A Boolean indicating whether this monitor notifies when data stops reporting.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_no_data: Option<bool>,
+    /// Controls how groups or monitors are treated if an evaluation does not return any data points.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_missing_data: Option<MonitorOnMissingData>,
+    /// The number of minutes after the last notification before a monitor re-notifies on the current status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub renotify_interval: Option<i64>,
+    /// The number of times re-notification messages should be sent on the current status at the provided re-notification interval.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub renotify_occurrences: Option<i64>,
+    /// The types of monitor statuses for which re-notification messages are sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub renotify_statuses: Option<Vec<MonitorRenotifyStatus>>,
+    /// A Boolean indicating whether this monitor needs a full window of data before it's evaluated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_full_window: Option<bool>,
+    /// ID of the corresponding Synthetic check.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub synthetics_check_id: Option<String>,
+    /// Alerting time window options of anomaly monitors.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub threshold_windows: Option<MonitorThresholdWindowOptions>,
+    /// The alert thresholds of the monitor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thresholds: Option<MonitorThresholds>,
+    /// The number of hours of the monitor not reporting data before it automatically resolves from a triggered state.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_h: Option<i64>,
+    /// Any other option, such as `aggregation`, `scheduling_options` or `variables`.
+    /// They are kept untouched so that definitions round-trip without loss.
+    #[serde(flatten)]
+    pub additional_properties: Map<String, Value>,
+}
+
+/// Object describing the creator of a monitor.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MonitorCreator {
+    /// Email of the creator.
+    pub email: Option<String>,
+    /// Handle of the creator.
+    pub handle: Option<String>,
+    /// Name of the creator.
+    pub name: Option<String>,
+}
+
+/// A downtime matching a monitor.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MatchingDowntime {
+    /// POSIX timestamp to end the downtime.
+    pub end: Option<i64>,
+    /// The downtime ID.
+    pub id: i64,
+    /// The scope(s) to which the downtime applies.
+    pub scope: Option<Vec<String>>,
+    /// POSIX timestamp to start the downtime.
+    pub start: Option<i64>,
+}
+
+/// Monitor state for a single group.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MonitorStateGroup {
+    /// Latest timestamp the monitor was in NO_DATA state.
+    pub last_nodata_ts: Option<i64>,
+    /// Latest timestamp of the notification sent for this monitor group.
+    pub last_notified_ts: Option<i64>,
+    /// Latest timestamp the monitor group was resolved.
+    pub last_resolved_ts: Option<i64>,
+    /// Latest timestamp the monitor group triggered.
+    pub last_triggered_ts: Option<i64>,
+    /// The name of the monitor group.
+    pub name: Option<String>,
+    /// The state of the monitor group.
+    pub status: Option<MonitorOverallStates>,
+}
+
+/// Wrapper object with the different monitor states.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MonitorState {
+    /// Dictionary where the keys are groups (comma separated lists of tags) and the values are the group states.
+    pub groups: Option<BTreeMap<String, MonitorStateGroup>>,
+}
+
+/// Object describing a monitor.
+///
+/// Fields set by the API are skipped when the monitor is sent back, so that a monitor read
+/// from the API or from a definition file can be created, updated or validated as is.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Monitor {
+    /// Timestamp of the monitor creation.
+    /// This value is set by the API.
+    #[serde(default, skip_serializing)]
+    pub created: Option<String>,
+    /// Object describing the creator of the monitor.
+    /// This value is set by the API.
+    #[serde(default, skip_serializing)]
+    pub creator: Option<MonitorCreator>,
+    /// Whether or not the monitor is deleted.
+    /// This value is set by the API.
+    #[serde(default, skip_serializing)]
+    pub deleted: Option<String>,
+    /// ID of this monitor.
+    /// This value is set by the API.
+    #[serde(default, skip_serializing)]
+    pub id: Option<i64>,
+    /// A list of active downtimes that match this monitor.
+    /// This value is set by the API.
+    #[serde(default, skip_serializing)]
+    pub matching_downtimes: Option<Vec<MatchingDowntime>>,
+    /// The notification message of the monitor.
+    ///
+    /// Kept as raw text: template variables such as `{{#is_alert}}` and `@` notification handles
+    /// are neither parsed nor escaped.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Last timestamp when the monitor was edited.
+    /// This value is set by the API.
+    #[serde(default, skip_serializing)]
+    pub modified: Option<String>,
+    /// Whether or not the monitor is broken down on different groups.
+    /// This value is set by the API.
+    #[serde(default, skip_serializing)]
+    pub multi: Option<bool>,
+    /// The monitor name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// List of options associated with the monitor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<MonitorOptions>,
+    /// Current state of the monitor.
+    /// This value is set by the API.
+    #[serde(default, skip_serializing)]
+    pub overall_state: Option<MonitorOverallStates>,
+    /// Integer from 1 (high) to 5 (low) indicating alert severity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i64>,
+    /// The monitor query.
+    pub query: String,
+    /// A list of unique role identifiers to define which roles are allowed to edit the monitor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restricted_roles: Option<Vec<String>>,
+    /// Wrapper object with the different monitor states.
+    /// This value is set by the API.
+    #[serde(default, skip_serializing)]
+    pub state: Option<MonitorState>,
+    /// Tags associated to the monitor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    #[serde(rename = "type")]
+    /// The type of the monitor.
+    pub typ: MonitorType,
+}
+
+/// The monitor properties to update. Only the given properties are changed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MonitorUpdate {
+    /// The notification message of the monitor, kept as raw text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// The monitor name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// List of options associated with the monitor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<MonitorOptions>,
+    /// Integer from 1 (high) to 5 (low) indicating alert severity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i64>,
+    /// The monitor query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// A list of unique role identifiers to define which roles are allowed to edit the monitor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restricted_roles: Option<Vec<String>>,
+    /// Tags associated to the monitor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    /// The type of the monitor.
+    pub typ: Option<MonitorType>,
+}
+
+/// Query parameters to list monitors.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ListMonitorsRequest {
+    /// When specified, shows additional information about the group states.
+    /// Choose one or more from `all`, `alert`, `warn`, and `no data`, comma separated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_states: Option<String>,
+    /// A string to filter monitors by name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// A comma separated list indicating what scope tags should be used to filter the list of monitors.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<String>,
+    /// A comma separated list indicating what service and/or custom tags should be used to filter the list of monitors.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub monitor_tags: Option<String>,
+    /// If this argument is set to true, then the returned data includes all current active downtimes for each monitor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub with_downtimes: Option<bool>,
+    /// Use this parameter for paginating through large sets of monitors.
+    /// Start with a value of zero, make a request, set the value to the last ID of result set, and then repeat until the response is empty.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_offset: Option<i64>,
+    /// The page to start paginating from. If this argument is not specified, the request returns all monitors without pagination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<i64>,
+    /// Number of monitors to return per page. Maximum 1000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<i32>,
+}
+
+/// Path and query parameters to get a monitor.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetMonitorRequest {
+    /// The ID of the monitor.
+    #[serde(skip)]
+    pub monitor_id: i64,
+    /// When specified, shows additional information about the group states.
+    /// Choose one or more from `all`, `alert`, `warn`, and `no data`, comma separated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_states: Option<String>,
+    /// If this argument is set to true, then the returned data includes all current active downtimes for the monitor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub with_downtimes: Option<bool>,
+}
+
+/// Path parameters and body to update a monitor.
+#[derive(Debug)]
+pub struct UpdateMonitorRequest {
+    /// The ID of the monitor.
+    pub monitor_id: i64,
+    /// The monitor properties to update.
+    pub monitor: MonitorUpdate,
+}
+
+/// Path and query parameters to delete a monitor.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DeleteMonitorRequest {
+    /// The ID of the monitor.
+    #[serde(skip)]
+    pub monitor_id: i64,
+    /// Delete the monitor even if it's referenced by other resources (for example SLO, composite monitor).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
+}
+
+/// Response to the deletion of a monitor.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DeletedMonitor {
+    /// ID of the deleted monitor.
+    pub deleted_monitor_id: Option<i64>,
+}
+
+/// Query parameters to search monitors.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SearchMonitorsRequest {
+    /// After entering a search query in your Manage Monitor page, use the query parameter value in the URL of the page as value for this parameter.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// Page to start paginating from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<i64>,
+    /// Number of monitors to return per page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<i64>,
+    /// String for sort order, composed of field and sort order separate by a comma, for example `name,asc`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+}
+
+/// A facet item of a monitors search.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MonitorSearchCountItem {
+    /// The number of found monitors with the listed value.
+    pub count: Option<i64>,
+    /// The facet value, a string or a Boolean depending on the facet.
+    pub name: Option<Value>,
+}
+
+/// The counts of monitors per different criteria.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MonitorSearchCounts {
+    /// Search facets for the muted state.
+    pub muted: Option<Vec<MonitorSearchCountItem>>,
+    /// Search facets for the status.
+    pub status: Option<Vec<MonitorSearchCountItem>>,
+    /// Search facets for the tags.
+    pub tag: Option<Vec<MonitorSearchCountItem>>,
+    #[serde(rename = "type")]
+    /// Search facets for the monitor types.
+    pub typ: Option<Vec<MonitorSearchCountItem>>,
+}
+
+/// Metadata about the response.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MonitorSearchResponseMetadata {
+    /// The page to start paginating from.
+    pub page: Option<i64>,
+    /// The number of pages.
+    pub page_count: Option<i64>,
+    /// The number of monitors to return per page.
+    pub per_page: Option<i64>,
+    /// The total number of monitors.
+    pub total_count: Option<i64>,
+}
+
+/// A notification triggered by the monitor.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MonitorSearchResultNotification {
+    /// The email address that received the notification.
+    pub handle: Option<String>,
+    /// The username receiving the notification.
+    pub name: Option<String>,
+}
+
+/// Holds search results.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MonitorSearchResult {
+    /// Classification of the monitor.
+    pub classification: Option<String>,
+    /// Object describing the creator of the monitor.
+    pub creator: Option<MonitorCreator>,
+    /// ID of the monitor.
+    pub id: Option<i64>,
+    /// Latest timestamp the monitor triggered.
+    pub last_triggered_ts: Option<i64>,
+    /// Metrics used by the monitor.
+    pub metrics: Option<Vec<String>>,
+    /// The monitor name.
+    pub name: Option<String>,
+    /// The notification triggered by the monitor.
+    pub notifications: Option<Vec<MonitorSearchResultNotification>>,
+    /// The ID of the organization.
+    pub org_id: Option<i64>,
+    /// The monitor query.
+    pub query: Option<String>,
+    /// The scope(s) to which the downtime applies.
+    pub scopes: Option<Vec<String>>,
+    /// The different states your monitor can be in.
+    pub status: Option<MonitorOverallStates>,
+    /// Tags associated with the monitor.
+    pub tags: Option<Vec<String>>,
+    #[serde(rename = "type")]
+    /// The type of the monitor.
+    pub typ: Option<MonitorType>,
+}
+
+/// The response from a monitor search.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MonitorSearchResponse {
+    /// The counts of monitors per different criteria.
+    pub counts: Option<MonitorSearchCounts>,
+    /// Metadata about the response.
+    pub metadata: Option<MonitorSearchResponseMetadata>,
+    /// The list of found monitors.
+    pub monitors: Option<Vec<MonitorSearchResult>>,
+}
+
+/// Query parameters to check if monitors can be deleted.
+#[derive(Debug)]
+pub struct CheckCanDeleteMonitorRequest {
+    /// The IDs of the monitors to check.
+    pub monitor_ids: Vec<i64>,
+}
+
+/// Wrapper object with the list of monitor IDs.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CheckCanDeleteMonitorResponseData {
+    /// An array of monitor IDs that can be deleted.
+    pub ok: Option<Vec<i64>>,
+}
+
+/// Response of a monitor deletion check.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CheckCanDeleteMonitorResponse {
+    /// Wrapper object with the list of monitor IDs.
+    pub data: CheckCanDeleteMonitorResponseData,
+    /// A mapping of the monitor IDs that cannot be deleted to the reasons why.
+    pub errors: Option<BTreeMap<String, Vec<String>>>,
+}
+
+// Responses and requests
+/// The monitors.
+pub type ListMonitorsResponse = Vec<Monitor>;
+/// Response to the validation of a monitor. Invalid monitors are reported through [Error::InvalidRequest](crate::error::Error::InvalidRequest).
+pub type ValidateMonitorResponse = EmptyStruct;
+
+/// The monitor to create.
+pub type CreateMonitorRequest = Monitor;
+/// The monitor to validate.
+pub type ValidateMonitorRequest = Monitor;
@@ -0,0 +1,741 @@
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+
+use super::client::{EmptyResponse, Request, Response};
+
+/// Type of the Notebook resource.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum NotebookResourceType {
+    /// Equals to `notebooks`.
+    #[serde(rename = "notebooks")]
+    Notebooks,
+}
+
+/// Type of the Notebook cell resource.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum NotebookCellResourceType {
+    /// Equals to `notebook_cells`.
+    #[serde(rename = "notebook_cells")]
+    NotebookCells,
+}
+
+/// The available timeframes depend on the widget you are using.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum WidgetLiveSpan {
+    /// Equals to `1m`.
+    #[serde(rename = "1m")]
+    PastOneMinute,
+    /// Equals to `5m`.
+    #[serde(rename = "5m")]
+    PastFiveMinutes,
+    /// Equals to `10m`.
+    #[serde(rename = "10m")]
+    PastTenMinutes,
+    /// Equals to `15m`.
+    #[serde(rename = "15m")]
+    PastFifteenMinutes,
+    /// Equals to `30m`.
+    #[serde(rename = "30m")]
+    PastThirtyMinutes,
+    /// Equals to `1h`.
+    #[serde(rename = "1h")]
+    PastOneHour,
+    /// Equals to `4h`.
+    #[serde(rename = "4h")]
+    PastFourHours,
+    /// Equals to `1d`.
+    #[serde(rename = "1d")]
+    PastOneDay,
+    /// Equals to `2d`.
+    #[serde(rename = "2d")]
+    PastTwoDays,
+    /// Equals to `1w`.
+    #[serde(rename = "1w")]
+    PastOneWeek,
+    /// Equals to `1mo`.
+    #[serde(rename = "1mo")]
+    PastOneMonth,
+    /// Equals to `3mo`.
+    #[serde(rename = "3mo")]
+    PastThreeMonths,
+    /// Equals to `6mo`.
+    #[serde(rename = "6mo")]
+    PastSixMonths,
+    /// Equals to `1y`.
+    #[serde(rename = "1y")]
+    PastOneYear,
+    /// Equals to `alert`.
+    #[serde(rename = "alert")]
+    Alert,
+}
+
+/// Relative timeframe.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NotebookRelativeTime {
+    /// The available timeframes depend on the widget you are using.
+    pub live_span: WidgetLiveSpan,
+}
+
+/// Absolute timeframe.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NotebookAbsoluteTime {
+    /// The end time, as an ISO 8601 timestamp.
+    pub end: String,
+    /// Indicates whether the timeframe should be shifted to end at the current time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live: Option<bool>,
+    /// The start time, as an ISO 8601 timestamp.
+    pub start: String,
+}
+
+/// Timeframe of a notebook or of one of its cells, either relative or absolute.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum NotebookTime {
+    /// Relative timeframe.
+    Relative(NotebookRelativeTime),
+    /// Absolute timeframe.
+    Absolute(NotebookAbsoluteTime),
+}
+
+/// Publication status of the notebook. For now, always `published`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum NotebookStatus {
+    /// Equals to `published`.
+    #[serde(rename = "published")]
+    Published,
+}
+
+/// Metadata type of the notebook.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NotebookMetadataType {
+    /// Equals to `postmortem`.
+    Postmortem,
+    /// Equals to `runbook`.
+    Runbook,
+    /// Equals to `investigation`.
+    Investigation,
+    /// Equals to `documentation`.
+    Documentation,
+    /// Equals to `report`.
+    Report,
+}
+
+/// Metadata associated with the notebook.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NotebookMetadata {
+    /// Whether or not the notebook is a template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_template: Option<bool>,
+    /// Whether or not the notebook takes snapshot image backups of the notebook's fixed-time graphs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub take_snapshots: Option<bool>,
+    /// Metadata type of the notebook.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub typ: Option<NotebookMetadataType>,
+}
+
+/// The size of the graph.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NotebookGraphSize {
+    /// Equals to `xs`.
+    XS,
+    /// Equals to `s`.
+    S,
+    /// Equals to `m`.
+    M,
+    /// Equals to `l`.
+    L,
+    /// Equals to `xl`.
+    XL,
+}
+
+/// Object describing how to split the graph to display multiple visualizations per request.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NotebookSplitBy {
+    /// Keys to split on.
+    pub keys: Vec<String>,
+    /// Tags to split on.
+    pub tags: Vec<String>,
+}
+
+/// A formula that specifies how to combine the results of multiple queries.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WidgetFormula {
+    /// Expression alias.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    /// String expression built from queries, formulas, and functions.
+    pub formula: String,
+}
+
+/// A query that can be used in formulas of a widget request.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WidgetQueryDefinition {
+    /// Data source of the query, such as `metrics`, `logs` or `rum`.
+    pub data_source: String,
+    /// Name of the query for use in formulas.
+    pub name: String,
+    /// Query definition, for the data sources using a query string, such as `metrics`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// The aggregation method to reduce a timeseries to a single value, such as `avg` or `sum`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aggregator: Option<String>,
+    /// The other fields of the query, which depend on the data source, such as `compute` and
+    /// `search` for the events data sources.
+    #[serde(flatten)]
+    pub additional_properties: Map<String, Value>,
+}
+
+/// Timeseries, scalar, or event list response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WidgetResponseFormat {
+    /// Equals to `timeseries`.
+    Timeseries,
+    /// Equals to `scalar`.
+    Scalar,
+    /// Equals to `event_list`.
+    EventList,
+}
+
+/// Type of display to use for the request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WidgetDisplayType {
+    /// Equals to `area`.
+    Area,
+    /// Equals to `bars`.
+    Bars,
+    /// Equals to `line`.
+    Line,
+}
+
+/// A request of a widget, either a single metric query `q` or a list of `queries`
+/// combined by `formulas`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WidgetRequest {
+    /// Type of display to use for the request. Only used by the timeseries widget.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_type: Option<WidgetDisplayType>,
+    /// List of formulas that operate on queries.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formulas: Option<Vec<WidgetFormula>>,
+    /// Widget query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub q: Option<String>,
+    /// List of queries that can be returned directly or used in formulas.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queries: Option<Vec<WidgetQueryDefinition>>,
+    /// Timeseries, scalar, or event list response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<WidgetResponseFormat>,
+}
+
+/// Axis controls for the widget.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WidgetAxis {
+    /// Set to `true` to include zero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_zero: Option<bool>,
+    /// The label of the axis to display on the graph.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// Specifies maximum numeric value to show on the axis. Defaults to `auto`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<String>,
+    /// Specifies minimum numeric value to show on the axis. Defaults to `auto`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<String>,
+    /// Specifies the scale type. Possible values are `linear`, `log`, `sqrt`, and `pow##`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scale: Option<String>,
+}
+
+/// The Markdown cell is used to add text to a notebook.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarkdownCellDefinition {
+    /// The markdown content.
+    pub text: String,
+}
+
+/// The timeseries visualization shows the evolution of one or more metrics, log events,
+/// or Indexed Spans over time.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TimeseriesWidgetDefinition {
+    /// List of timeseries widget requests.
+    pub requests: Vec<WidgetRequest>,
+    /// Whether or not to display the legend on this widget.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_legend: Option<bool>,
+    /// Title of your widget.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Axis controls for the widget.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub yaxis: Option<WidgetAxis>,
+}
+
+/// The top list visualization displays the top values of a tag or of a metric.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ToplistWidgetDefinition {
+    /// List of top list widget requests.
+    pub requests: Vec<WidgetRequest>,
+    /// Title of your widget.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+/// The heat map visualization shows metrics aggregated across many tags, such as hosts.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HeatMapWidgetDefinition {
+    /// List of heat map widget requests.
+    pub requests: Vec<WidgetRequest>,
+    /// Whether or not to display the legend on this widget.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_legend: Option<bool>,
+    /// Title of your widget.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Axis controls for the widget.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub yaxis: Option<WidgetAxis>,
+}
+
+/// The distribution visualization shows data aggregated across one or several tags,
+/// such as hosts.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DistributionWidgetDefinition {
+    /// List of distribution widget requests.
+    pub requests: Vec<WidgetRequest>,
+    /// Whether or not to display the legend on this widget.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_legend: Option<bool>,
+    /// Title of your widget.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Axis controls for the X axis of the widget.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub xaxis: Option<WidgetAxis>,
+    /// Axis controls for the Y axis of the widget.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub yaxis: Option<WidgetAxis>,
+}
+
+/// Which column and order to sort by.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WidgetFieldSort {
+    /// Facet path for the column.
+    pub column: String,
+    /// Widget sorting methods, `asc` or `desc`.
+    pub order: String,
+}
+
+/// The log stream visualization displays a log flow matching the defined query.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LogStreamWidgetDefinition {
+    /// Which columns to display on the widget.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub columns: Option<Vec<String>>,
+    /// An array of index names to query in the stream. Use `[]` to query all indexes at once.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indexes: Option<Vec<String>>,
+    /// Query to filter the log stream with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// Whether to show the date column or not.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_date_column: Option<bool>,
+    /// Whether to show the message column or not.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_message_column: Option<bool>,
+    /// Which column and order to sort by.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<WidgetFieldSort>,
+    /// Title of the widget.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+/// The definition of a notebook cell, tagged by its `type`.
+#[derive(Debug, Clone)]
+pub enum NotebookCellDefinition {
+    /// Equals to `markdown`.
+    Markdown(MarkdownCellDefinition),
+    /// Equals to `timeseries`.
+    Timeseries(TimeseriesWidgetDefinition),
+    /// Equals to `toplist`.
+    Toplist(ToplistWidgetDefinition),
+    /// Equals to `heatmap`.
+    HeatMap(HeatMapWidgetDefinition),
+    /// Equals to `distribution`.
+    Distribution(DistributionWidgetDefinition),
+    /// Equals to `log_stream`.
+    LogStream(LogStreamWidgetDefinition),
+    /// Any other cell type.
+    Unknown(Value),
+}
+
+impl NotebookCellDefinition {
+    /// The `type` of the cell definition.
+    pub fn cell_type(&self) -> Option<&str> {
+        match self {
+            NotebookCellDefinition::Markdown(_) => Some("markdown"),
+            NotebookCellDefinition::Timeseries(_) => Some("timeseries"),
+            NotebookCellDefinition::Toplist(_) => Some("toplist"),
+            NotebookCellDefinition::HeatMap(_) => Some("heatmap"),
+            NotebookCellDefinition::Distribution(_) => Some("distribution"),
+            NotebookCellDefinition::LogStream(_) => Some("log_stream"),
+            NotebookCellDefinition::Unknown(value) => value.get("type").and_then(Value::as_str),
+        }
+    }
+}
+
+impl Serialize for NotebookCellDefinition {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let value = match self {
+            NotebookCellDefinition::Markdown(definition) => serde_json::to_value(definition),
+            NotebookCellDefinition::Timeseries(definition) => serde_json::to_value(definition),
+            NotebookCellDefinition::Toplist(definition) => serde_json::to_value(definition),
+            NotebookCellDefinition::HeatMap(definition) => serde_json::to_value(definition),
+            NotebookCellDefinition::Distribution(definition) => serde_json::to_value(definition),
+            NotebookCellDefinition::LogStream(definition) => serde_json::to_value(definition),
+            NotebookCellDefinition::Unknown(value) => return value.serialize(serializer),
+        };
+
+        let mut value = value.map_err(S::Error::custom)?;
+        if let (Value::Object(fields), Some(typ)) = (&mut value, self.cell_type()) {
+            fields.insert("type".to_string(), Value::String(typ.to_string()));
+        }
+        value.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for NotebookCellDefinition {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        let typ = value
+            .get("type")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+
+        let definition = match typ {
+            "markdown" => serde_json::from_value(value).map(NotebookCellDefinition::Markdown),
+            "timeseries" => serde_json::from_value(value).map(NotebookCellDefinition::Timeseries),
+            "toplist" => serde_json::from_value(value).map(NotebookCellDefinition::Toplist),
+            "heatmap" => serde_json::from_value(value).map(NotebookCellDefinition::HeatMap),
+            "distribution" => {
+                serde_json::from_value(value).map(NotebookCellDefinition::Distribution)
+            }
+            "log_stream" => serde_json::from_value(value).map(NotebookCellDefinition::LogStream),
+            _ => return Ok(NotebookCellDefinition::Unknown(value)),
+        };
+
+        definition.map_err(D::Error::custom)
+    }
+}
+
+/// Attributes of a notebook cell.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotebookCellAttributes {
+    /// The definition of the cell.
+    pub definition: NotebookCellDefinition,
+    /// The size of the graph. Not used by markdown cells.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub graph_size: Option<NotebookGraphSize>,
+    /// Object describing how to split the graph to display multiple visualizations per request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub split_by: Option<NotebookSplitBy>,
+    /// Timeframe of the cell. The notebook's timeframe is used when it is not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<NotebookTime>,
+}
+
+impl From<NotebookCellDefinition> for NotebookCellAttributes {
+    fn from(definition: NotebookCellDefinition) -> Self {
+        NotebookCellAttributes {
+            definition,
+            graph_size: None,
+            split_by: None,
+            time: None,
+        }
+    }
+}
+
+/// A notebook cell.
+///
+/// Cells without an `id` are created, while cells with the `id` of an existing cell update it.
+/// Existing cells left out of an update are removed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotebookCell {
+    /// Attributes of the cell.
+    pub attributes: NotebookCellAttributes,
+    /// Notebook cell ID, absent for the cells to create.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    /// Type of the Notebook cell resource.
+    pub typ: NotebookCellResourceType,
+}
+
+impl From<NotebookCellDefinition> for NotebookCell {
+    fn from(definition: NotebookCellDefinition) -> Self {
+        NotebookCell {
+            attributes: definition.into(),
+            id: None,
+            typ: NotebookCellResourceType::NotebookCells,
+        }
+    }
+}
+
+/// Attributes of the user who created the notebook.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotebookAuthor {
+    /// Creation time of the user.
+    pub created_at: Option<String>,
+    /// Whether the user is disabled.
+    pub disabled: Option<bool>,
+    /// Email of the user.
+    pub email: Option<String>,
+    /// Handle of the user.
+    pub handle: Option<String>,
+    /// URL of the user's icon.
+    pub icon: Option<String>,
+    /// Name of the user.
+    pub name: Option<String>,
+    /// Status of the user.
+    pub status: Option<String>,
+    /// Title of the user.
+    pub title: Option<String>,
+    /// Whether the user is verified.
+    pub verified: Option<bool>,
+}
+
+/// The attributes of a notebook.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotebookAttributes {
+    /// Attributes of the user who created the notebook.
+    #[serde(default, skip_serializing)]
+    /// This value is set by the API.
+    pub author: Option<NotebookAuthor>,
+    /// List of cells to display in the notebook.
+    pub cells: Vec<NotebookCell>,
+    /// UTC time stamp for when the notebook was created.
+    #[serde(default, skip_serializing)]
+    /// This value is set by the API.
+    pub created: Option<String>,
+    /// Metadata associated with the notebook.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<NotebookMetadata>,
+    /// UTC time stamp for when the notebook was last modified.
+    #[serde(default, skip_serializing)]
+    /// This value is set by the API.
+    pub modified: Option<String>,
+    /// The name of the notebook.
+    pub name: String,
+    /// Publication status of the notebook.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<NotebookStatus>,
+    /// Timeframe of the notebook, used by the cells without their own.
+    pub time: NotebookTime,
+}
+
+impl NotebookAttributes {
+    /// A published notebook with the given `name`, `time` and `cells`.
+    pub fn new(name: &str, time: NotebookTime, cells: Vec<NotebookCell>) -> Self {
+        NotebookAttributes {
+            author: None,
+            cells,
+            created: None,
+            metadata: None,
+            modified: None,
+            name: name.to_string(),
+            status: Some(NotebookStatus::Published),
+            time,
+        }
+    }
+}
+
+/// The data of a notebook.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Notebook {
+    /// The attributes of the notebook.
+    pub attributes: NotebookAttributes,
+    /// Unique notebook ID, assigned when you create the notebook.
+    #[serde(default, skip_serializing)]
+    /// This value is set by the API.
+    pub id: Option<i64>,
+    #[serde(rename = "type")]
+    /// Type of the Notebook resource.
+    pub typ: NotebookResourceType,
+}
+
+impl From<NotebookAttributes> for Notebook {
+    fn from(attributes: NotebookAttributes) -> Self {
+        Notebook {
+            attributes,
+            id: None,
+            typ: NotebookResourceType::Notebooks,
+        }
+    }
+}
+
+/// Field to sort notebooks by.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NotebooksSortField {
+    /// Equals to `modified`.
+    Modified,
+    /// Equals to `name`.
+    Name,
+    /// Equals to `created`.
+    Created,
+}
+
+/// Direction to sort notebooks by.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NotebooksSortDirection {
+    /// Equals to `asc`.
+    Asc,
+    /// Equals to `desc`.
+    Desc,
+}
+
+/// Query parameters to list notebooks.
+#[derive(Debug, Default, Serialize)]
+pub struct ListNotebooksRequest {
+    /// Return notebooks created by the given `author_handle`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_handle: Option<String>,
+    /// Return notebooks not created by the given `author_handle`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude_author_handle: Option<String>,
+    /// The index of the first notebook you want returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<i64>,
+    /// The number of notebooks to be returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    /// Sort by field `modified`, `name`, or `created`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_field: Option<NotebooksSortField>,
+    /// Sort by direction `asc` or `desc`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_dir: Option<NotebooksSortDirection>,
+    /// Return only notebooks with `query` string in notebook name or author handle.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// Value of `false` excludes the `cells` and global `time` for each notebook.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_cells: Option<bool>,
+    /// True value returns only template notebooks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_template: Option<bool>,
+    /// If type is provided, returns only notebooks with that metadata type.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub typ: Option<NotebookMetadataType>,
+}
+
+/// The attributes of a notebook in a list, without its cells and time
+/// unless `include_cells` is set.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NotebooksResponseDataAttributes {
+    /// Attributes of the user who created the notebook.
+    pub author: Option<NotebookAuthor>,
+    /// List of cells to display in the notebook.
+    pub cells: Option<Vec<NotebookCell>>,
+    /// UTC time stamp for when the notebook was created.
+    pub created: Option<String>,
+    /// Metadata associated with the notebook.
+    pub metadata: Option<NotebookMetadata>,
+    /// UTC time stamp for when the notebook was last modified.
+    pub modified: Option<String>,
+    /// The name of the notebook.
+    pub name: String,
+    /// Publication status of the notebook.
+    pub status: Option<NotebookStatus>,
+    /// Timeframe of the notebook.
+    pub time: Option<NotebookTime>,
+}
+
+/// The data of a notebook in a list.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NotebooksResponseData {
+    /// The attributes of the notebook.
+    pub attributes: NotebooksResponseDataAttributes,
+    /// Unique notebook ID.
+    pub id: i64,
+    #[serde(rename = "type")]
+    /// Type of the Notebook resource.
+    pub typ: NotebookResourceType,
+}
+
+/// Pagination metadata returned by the API.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NotebooksResponsePage {
+    /// The total number of notebooks that would be returned if the request was not filtered
+    /// by `start` and `count` parameters.
+    pub total_count: Option<i64>,
+    /// The total number of notebooks returned.
+    pub total_filtered_count: Option<i64>,
+}
+
+/// Searches metadata returned by the API.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NotebooksResponseMeta {
+    /// Pagination metadata returned by the API.
+    pub page: Option<NotebooksResponsePage>,
+}
+
+/// Notebooks get all response.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NotebooksResponse {
+    /// List of notebook definitions.
+    pub data: Vec<NotebooksResponseData>,
+    /// Searches metadata returned by the API.
+    pub meta: Option<NotebooksResponseMeta>,
+}
+
+/// Path parameters to get a notebook.
+#[derive(Debug)]
+pub struct GetNotebookRequest {
+    /// Unique ID, assigned when you create the notebook.
+    pub notebook_id: i64,
+}
+
+/// Path parameters and body to update a notebook.
+#[derive(Debug)]
+pub struct UpdateNotebookRequest {
+    /// Unique ID, assigned when you create the notebook.
+    pub notebook_id: i64,
+    /// The full notebook, replacing the current one.
+    pub notebook: Request<Notebook>,
+}
+
+/// Path parameters to delete a notebook.
+#[derive(Debug)]
+pub struct DeleteNotebookRequest {
+    /// Unique ID, assigned when you create the notebook.
+    pub notebook_id: i64,
+}
+
+// Responses and requests
+/// The description of a notebook response.
+pub type NotebookResponse = Response<Notebook>;
+/// Response to the deletion of a notebook.
+pub type DeleteNotebookResponse = EmptyResponse;
+
+/// The notebook to create.
+pub type CreateNotebookRequest = Request<Notebook>;
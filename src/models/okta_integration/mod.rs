@@ -0,0 +1,152 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::client::{EmptyResponse, Request, Response};
+use crate::secret::{serialize_exposed_option, SecretString};
+
+/// Okta account resource type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum OktaAccountType {
+    /// Equals to `okta-accounts`.
+    #[serde(rename = "okta-accounts")]
+    OktaAccounts,
+}
+
+/// Method used to authenticate to Okta.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum OktaAuthMethod {
+    /// Equals to `oauth`.
+    #[serde(rename = "oauth")]
+    OAuth,
+    /// Equals to `api_key`.
+    #[serde(rename = "api_key")]
+    ApiKey,
+}
+
+/// Attributes of an Okta account. The API key and client secret are never returned.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OktaAccountAttributes {
+    /// The authorization method of the Okta account.
+    pub auth_method: Option<OktaAuthMethod>,
+    /// The client ID of the Okta app integration.
+    pub client_id: Option<String>,
+    /// The domain of the Okta account, such as `https://example.okta.com`.
+    pub domain: Option<String>,
+    /// The name of the Okta account.
+    pub name: Option<String>,
+}
+
+/// An Okta account.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OktaAccount {
+    /// Attributes of the Okta account.
+    pub attributes: Option<OktaAccountAttributes>,
+    /// The ID of the Okta account, a UUID hash of the account name.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// Okta account resource type.
+    pub typ: OktaAccountType,
+}
+
+/// Attributes of the Okta account to add or update.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OktaAccountWriteAttributes {
+    /// The API key of the Okta account. Required with the `api_key` authorization method.
+    #[serde(
+        serialize_with = "serialize_exposed_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub api_key: Option<SecretString>,
+    /// The authorization method of the Okta account.
+    pub auth_method: OktaAuthMethod,
+    /// The client ID of the Okta app integration. Required with the `oauth` authorization
+    /// method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    /// The client secret of the Okta app integration. Required with the `oauth` authorization
+    /// method.
+    #[serde(
+        serialize_with = "serialize_exposed_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub client_secret: Option<SecretString>,
+    /// The domain of the Okta account.
+    pub domain: String,
+    /// The name of the Okta account. Only used when adding an account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl OktaAccountWriteAttributes {
+    /// Attributes of an account authenticated with an API key.
+    pub fn with_api_key(
+        name: impl Into<String>,
+        domain: impl Into<String>,
+        api_key: SecretString,
+    ) -> Self {
+        OktaAccountWriteAttributes {
+            api_key: Some(api_key),
+            auth_method: OktaAuthMethod::ApiKey,
+            client_id: None,
+            client_secret: None,
+            domain: domain.into(),
+            name: Some(name.into()),
+        }
+    }
+
+    /// Attributes of an account authenticated with an OAuth app integration.
+    pub fn with_oauth(
+        name: impl Into<String>,
+        domain: impl Into<String>,
+        client_id: impl Into<String>,
+        client_secret: SecretString,
+    ) -> Self {
+        OktaAccountWriteAttributes {
+            api_key: None,
+            auth_method: OktaAuthMethod::OAuth,
+            client_id: Some(client_id.into()),
+            client_secret: Some(client_secret),
+            domain: domain.into(),
+            name: Some(name.into()),
+        }
+    }
+}
+
+/// Data of the Okta account to add or update.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OktaAccountWriteData {
+    /// Attributes of the Okta account.
+    pub attributes: OktaAccountWriteAttributes,
+    /// The ID of the Okta account. Only used when adding an account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    /// Okta account resource type.
+    pub typ: OktaAccountType,
+}
+
+/// Path parameters to get or delete an Okta account.
+#[derive(Debug)]
+pub struct OktaAccountRequest {
+    /// The ID of the Okta account.
+    pub account_id: String,
+}
+
+/// Path parameters and body to update an Okta account.
+#[derive(Debug)]
+pub struct UpdateOktaAccountRequest {
+    /// The ID of the Okta account.
+    pub account_id: String,
+    /// The changes to the Okta account.
+    pub account: Request<OktaAccountWriteData>,
+}
+
+// Responses and requests
+/// Response containing an Okta account.
+pub type OktaAccountResponse = Response<OktaAccount>;
+/// Response containing all the Okta accounts.
+pub type OktaAccountsResponse = Response<Vec<OktaAccount>>;
+/// Response to the deletion of an Okta account.
+pub type DeleteOktaAccountResponse = EmptyResponse;
+
+/// The Okta account to add.
+pub type AddOktaAccountRequest = Request<OktaAccountWriteData>;
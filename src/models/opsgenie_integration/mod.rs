@@ -0,0 +1,133 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::client::{EmptyResponse, Request, Response};
+use crate::secret::{serialize_exposed, serialize_exposed_option, SecretString};
+
+/// OpsGenie service resource type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum OpsgenieServiceType {
+    /// Equals to `opsgenie-service`.
+    #[serde(rename = "opsgenie-service")]
+    OpsgenieService,
+}
+
+/// The region of the OpsGenie service.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum OpsgenieServiceRegion {
+    /// Equals to `us`.
+    #[serde(rename = "us")]
+    Us,
+    /// Equals to `eu`.
+    #[serde(rename = "eu")]
+    Eu,
+    /// Equals to `custom`.
+    #[serde(rename = "custom")]
+    Custom,
+}
+
+/// Attributes of an OpsGenie service. The API key is never returned.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OpsgenieServiceAttributes {
+    /// The custom URL of the OpsGenie service, set with the `custom` region.
+    pub custom_url: Option<String>,
+    /// The name of the OpsGenie service.
+    pub name: Option<String>,
+    /// The region of the OpsGenie service.
+    pub region: Option<OpsgenieServiceRegion>,
+}
+
+/// An OpsGenie service.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OpsgenieService {
+    /// Attributes of the OpsGenie service.
+    pub attributes: Option<OpsgenieServiceAttributes>,
+    /// The ID of the OpsGenie service.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// OpsGenie service resource type.
+    pub typ: OpsgenieServiceType,
+}
+
+/// Attributes of the OpsGenie service to add.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OpsgenieServiceCreateAttributes {
+    /// The custom URL of the OpsGenie service. Required with the `custom` region.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_url: Option<String>,
+    /// The name of the OpsGenie service.
+    pub name: String,
+    /// The OpsGenie API key for the service.
+    #[serde(serialize_with = "serialize_exposed")]
+    pub opsgenie_api_key: SecretString,
+    /// The region of the OpsGenie service.
+    pub region: OpsgenieServiceRegion,
+}
+
+/// Data of the OpsGenie service to add.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OpsgenieServiceCreateData {
+    /// Attributes of the OpsGenie service.
+    pub attributes: OpsgenieServiceCreateAttributes,
+    #[serde(rename = "type")]
+    /// OpsGenie service resource type.
+    pub typ: OpsgenieServiceType,
+}
+
+/// Attributes of the OpsGenie service to update.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct OpsgenieServiceUpdateAttributes {
+    /// The custom URL of the OpsGenie service.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_url: Option<String>,
+    /// The name of the OpsGenie service.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The new OpsGenie API key for the service.
+    #[serde(
+        serialize_with = "serialize_exposed_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub opsgenie_api_key: Option<SecretString>,
+    /// The region of the OpsGenie service.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<OpsgenieServiceRegion>,
+}
+
+/// Data of the OpsGenie service to update.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OpsgenieServiceUpdateData {
+    /// Attributes of the OpsGenie service.
+    pub attributes: OpsgenieServiceUpdateAttributes,
+    /// The ID of the OpsGenie service.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// OpsGenie service resource type.
+    pub typ: OpsgenieServiceType,
+}
+
+/// Path parameters to get or delete an OpsGenie service.
+#[derive(Debug)]
+pub struct OpsgenieServiceRequest {
+    /// The ID of the OpsGenie service.
+    pub integration_service_id: String,
+}
+
+/// Path parameters and body to update an OpsGenie service.
+#[derive(Debug)]
+pub struct UpdateOpsgenieServiceRequest {
+    /// The ID of the OpsGenie service.
+    pub integration_service_id: String,
+    /// The changes to the OpsGenie service.
+    pub service: Request<OpsgenieServiceUpdateData>,
+}
+
+// Responses and requests
+/// Response containing an OpsGenie service.
+pub type OpsgenieServiceResponse = Response<OpsgenieService>;
+/// Response containing all the OpsGenie services.
+pub type OpsgenieServicesResponse = Response<Vec<OpsgenieService>>;
+/// Response to the deletion of an OpsGenie service.
+pub type DeleteOpsgenieServiceResponse = EmptyResponse;
+
+/// The OpsGenie service to add.
+pub type AddOpsgenieServiceRequest = Request<OpsgenieServiceCreateData>;
@@ -0,0 +1,337 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::client::{EmptyResponse, Request, Response};
+use super::users::{RelationshipToUserData, RelationshipToUsers, UsersResponseMeta};
+
+/// Roles type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RolesType {
+    /// Equals to `roles`.
+    #[serde(rename = "roles")]
+    Roles,
+}
+
+/// Permissions resource type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum PermissionsType {
+    /// Equals to `permissions`.
+    #[serde(rename = "permissions")]
+    Permissions,
+}
+
+/// Attributes of the role.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RoleAttributes {
+    /// Creation time of the role.
+    pub created_at: Option<String>,
+    /// Time of last role modification.
+    pub modified_at: Option<String>,
+    /// The name of the role. The name is neither unique nor a stable identifier of the role.
+    pub name: Option<String>,
+    /// Number of users with that role.
+    pub user_count: Option<i64>,
+}
+
+/// Relationship to permission object.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelationshipToPermissionData {
+    /// ID of the permission.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// Permissions resource type.
+    pub typ: PermissionsType,
+}
+
+/// Relationship to multiple permissions objects.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelationshipToPermissions {
+    /// Relationships to permission objects.
+    pub data: Vec<RelationshipToPermissionData>,
+}
+
+/// Relationships of the role object returned by the API.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RoleResponseRelationships {
+    /// Relationship to multiple permissions objects.
+    pub permissions: Option<RelationshipToPermissions>,
+    /// Relationship to multiple users objects.
+    pub users: Option<RelationshipToUsers>,
+}
+
+/// Role object returned by the API.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Role {
+    /// Attributes of the role.
+    pub attributes: Option<RoleAttributes>,
+    /// The unique identifier of the role.
+    pub id: String,
+    /// Relationships of the role object returned by the API.
+    pub relationships: Option<RoleResponseRelationships>,
+    #[serde(rename = "type")]
+    /// Roles type.
+    pub typ: RolesType,
+}
+
+/// Relationship to role object.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelationshipToRoleData {
+    /// The unique identifier of the role.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// Roles type.
+    pub typ: RolesType,
+}
+
+/// Relationship to role.
+pub type RelationshipToRole = Response<RelationshipToRoleData>;
+
+/// Relationship to multiple roles.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelationshipToRoles {
+    /// An array containing type and the unique identifier of a role.
+    pub data: Vec<RelationshipToRoleData>,
+}
+
+/// Response containing information about multiple roles.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RolesResponse {
+    /// Array of returned roles.
+    pub data: Vec<Role>,
+    /// Object describing meta attributes of the response.
+    pub meta: Option<UsersResponseMeta>,
+}
+
+/// Response containing information about a single role.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RoleResponse {
+    /// Role object returned by the API.
+    pub data: Role,
+}
+
+/// Sorting options for roles.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum RolesSort {
+    /// Equals to `name`.
+    #[serde(rename = "name")]
+    NameAscending,
+    /// Equals to `-name`.
+    #[serde(rename = "-name")]
+    NameDescending,
+    /// Equals to `modified_at`.
+    #[serde(rename = "modified_at")]
+    ModifiedAtAscending,
+    /// Equals to `-modified_at`.
+    #[serde(rename = "-modified_at")]
+    ModifiedAtDescending,
+    /// Equals to `user_count`.
+    #[serde(rename = "user_count")]
+    UserCountAscending,
+    /// Equals to `-user_count`.
+    #[serde(rename = "-user_count")]
+    UserCountDescending,
+}
+
+/// Query parameters to list roles.
+#[derive(Debug, Default, Serialize)]
+pub struct ListRolesRequest {
+    /// Size for a given page. The maximum allowed value is 100.
+    #[serde(rename = "page[size]", skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<i64>,
+    /// Specific page number to return.
+    #[serde(rename = "page[number]", skip_serializing_if = "Option::is_none")]
+    pub page_number: Option<i64>,
+    /// Sort roles depending on the given field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<RolesSort>,
+    /// Filter all roles by the given string.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    /// Filter all roles by the given list of role IDs, separated by commas.
+    #[serde(rename = "filter[id]", skip_serializing_if = "Option::is_none")]
+    pub filter_id: Option<String>,
+}
+
+/// Attributes of the role to create.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RoleCreateAttributes {
+    /// Name of the role.
+    pub name: String,
+}
+
+/// Relationships of the role to create or update.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct RoleRelationships {
+    /// Relationship to multiple permissions objects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<RelationshipToPermissions>,
+}
+
+/// Data related to the creation of a role.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RoleCreateData {
+    /// Attributes of the role.
+    pub attributes: RoleCreateAttributes,
+    /// Relationships of the role.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationships: Option<RoleRelationships>,
+    #[serde(rename = "type")]
+    /// Roles type.
+    pub typ: RolesType,
+}
+
+/// Attributes of the role to update.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct RoleUpdateAttributes {
+    /// Name of the role.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Data related to the update of a role.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RoleUpdateData {
+    /// Attributes of the role.
+    pub attributes: RoleUpdateAttributes,
+    /// The unique identifier of the role.
+    pub id: String,
+    /// Relationships of the role. The given permissions replace the current ones.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationships: Option<RoleRelationships>,
+    #[serde(rename = "type")]
+    /// Roles type.
+    pub typ: RolesType,
+}
+
+/// Attributes of the clone of a role.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RoleCloneAttributes {
+    /// Name of the new role that is cloned.
+    pub name: String,
+}
+
+/// Data for the clone of a role.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RoleCloneData {
+    /// Attributes of the clone.
+    pub attributes: RoleCloneAttributes,
+    #[serde(rename = "type")]
+    /// Roles type.
+    pub typ: RolesType,
+}
+
+/// Path parameters to get a role.
+#[derive(Debug)]
+pub struct GetRoleRequest {
+    /// The unique identifier of the role.
+    pub role_id: String,
+}
+
+/// Path parameters and body to update a role.
+#[derive(Debug)]
+pub struct UpdateRoleRequest {
+    /// The unique identifier of the role.
+    pub role_id: String,
+    /// The changes to the role.
+    pub role: Request<RoleUpdateData>,
+}
+
+/// Path parameters to delete a role.
+#[derive(Debug)]
+pub struct DeleteRoleRequest {
+    /// The unique identifier of the role.
+    pub role_id: String,
+}
+
+/// Path parameters and body to clone a role.
+#[derive(Debug)]
+pub struct CloneRoleRequest {
+    /// The unique identifier of the role to clone.
+    pub role_id: String,
+    /// The clone to create.
+    pub role: Request<RoleCloneData>,
+}
+
+/// Query parameters to list the users of a role.
+#[derive(Debug, Serialize)]
+pub struct ListRoleUsersRequest {
+    /// The unique identifier of the role.
+    #[serde(skip)]
+    pub role_id: String,
+    /// Size for a given page. The maximum allowed value is 100.
+    #[serde(rename = "page[size]", skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<i64>,
+    /// Specific page number to return.
+    #[serde(rename = "page[number]", skip_serializing_if = "Option::is_none")]
+    pub page_number: Option<i64>,
+    /// User attribute to order results by, such as `name` or `-email`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+    /// Filter all users by the given string.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+}
+
+/// Path parameters and user to add to or remove from a role.
+#[derive(Debug)]
+pub struct RoleUserRequest {
+    /// The unique identifier of the role.
+    pub role_id: String,
+    /// The user to add or remove.
+    pub user: RelationshipToUserData,
+}
+
+/// Path parameters to list the permissions of a role.
+#[derive(Debug)]
+pub struct ListRolePermissionsRequest {
+    /// The unique identifier of the role.
+    pub role_id: String,
+}
+
+/// Path parameters and permission to grant to or revoke from a role.
+#[derive(Debug)]
+pub struct RolePermissionRequest {
+    /// The unique identifier of the role.
+    pub role_id: String,
+    /// The permission to grant or revoke.
+    pub permission: RelationshipToPermissionData,
+}
+
+/// Attributes of a permission.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PermissionAttributes {
+    /// Creation time of the permission.
+    pub created: Option<String>,
+    /// Description of the permission.
+    pub description: Option<String>,
+    /// Displayed name for the permission.
+    pub display_name: Option<String>,
+    /// Display type.
+    pub display_type: Option<String>,
+    /// Name of the permission group.
+    pub group_name: Option<String>,
+    /// Name of the permission.
+    pub name: Option<String>,
+    /// Whether or not the permission is restricted.
+    pub restricted: Option<bool>,
+}
+
+/// Permission object.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Permission {
+    /// Attributes of the permission.
+    pub attributes: Option<PermissionAttributes>,
+    /// ID of the permission.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// Permissions resource type.
+    pub typ: PermissionsType,
+}
+
+// Responses and requests
+/// Payload with API-returned permissions.
+pub type PermissionsResponse = Response<Vec<Permission>>;
+/// Response to the deletion of a role.
+pub type DeleteRoleResponse = EmptyResponse;
+
+/// The role to create.
+pub type CreateRoleRequest = Request<RoleCreateData>;
@@ -0,0 +1,370 @@
+use serde_derive::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use super::client::{EmptyResponse, Link, Meta, PagedResponse, Request, Response};
+use super::logs::{
+    LogsAggregateBucket, LogsAggregateRequestPage, LogsAggregateSort, LogsAggregationFunction,
+    LogsCompute, LogsGroupBy, LogsGroupByHistogram,
+};
+use super::search::{SearchOptions, SearchPage, SearchSort};
+
+/// RUM application resource type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RumApplicationType {
+    /// Equals to `rum_application`.
+    #[serde(rename = "rum_application")]
+    RumApplication,
+}
+
+/// RUM application creation resource type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RumApplicationCreateType {
+    /// Equals to `rum_application_create`.
+    #[serde(rename = "rum_application_create")]
+    RumApplicationCreate,
+}
+
+/// RUM application update resource type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RumApplicationUpdateType {
+    /// Equals to `rum_application_update`.
+    #[serde(rename = "rum_application_update")]
+    RumApplicationUpdate,
+}
+
+/// The platform of a RUM application.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RumApplicationPlatform {
+    /// Equals to `browser`.
+    #[serde(rename = "browser")]
+    Browser,
+    /// Equals to `ios`.
+    #[serde(rename = "ios")]
+    Ios,
+    /// Equals to `android`.
+    #[serde(rename = "android")]
+    Android,
+    /// Equals to `react-native`.
+    #[serde(rename = "react-native")]
+    ReactNative,
+    /// Equals to `flutter`.
+    #[serde(rename = "flutter")]
+    Flutter,
+    /// Equals to `roku`.
+    #[serde(rename = "roku")]
+    Roku,
+    /// Equals to `electron`.
+    #[serde(rename = "electron")]
+    Electron,
+    /// Equals to `unity`.
+    #[serde(rename = "unity")]
+    Unity,
+    /// Equals to `kotlin-multiplatform`.
+    #[serde(rename = "kotlin-multiplatform")]
+    KotlinMultiplatform,
+}
+
+/// RUM application attributes, as returned when listing applications.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RumApplicationListAttributes {
+    /// ID of the RUM application.
+    pub application_id: String,
+    /// Timestamp in ms of the creation date.
+    pub created_at: i64,
+    /// Handle of the creator user.
+    pub created_by_handle: String,
+    /// Hash of the RUM application.
+    pub hash: Option<String>,
+    /// Indicates if the RUM application is active.
+    pub is_active: Option<bool>,
+    /// Name of the RUM application.
+    pub name: String,
+    /// Org ID of the RUM application.
+    pub org_id: i64,
+    #[serde(rename = "type")]
+    /// Platform of the RUM application.
+    pub typ: RumApplicationPlatform,
+    /// Timestamp in ms of the last update date.
+    pub updated_at: i64,
+    /// Handle of the updater user.
+    pub updated_by_handle: String,
+}
+
+/// RUM application, as returned when listing applications. It does not hold the client token.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RumApplicationList {
+    /// RUM application attributes.
+    pub attributes: RumApplicationListAttributes,
+    /// RUM application ID.
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    /// RUM application resource type.
+    pub typ: RumApplicationType,
+}
+
+/// RUM application attributes.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RumApplicationAttributes {
+    /// ID of the RUM application.
+    pub application_id: String,
+    /// Client token of the RUM application, used to configure the RUM SDKs.
+    pub client_token: String,
+    /// Timestamp in ms of the creation date.
+    pub created_at: i64,
+    /// Handle of the creator user.
+    pub created_by_handle: String,
+    /// Hash of the RUM application.
+    pub hash: Option<String>,
+    /// Indicates if the RUM application is active.
+    pub is_active: Option<bool>,
+    /// Name of the RUM application.
+    pub name: String,
+    /// Org ID of the RUM application.
+    pub org_id: i64,
+    #[serde(rename = "type")]
+    /// Platform of the RUM application.
+    pub typ: RumApplicationPlatform,
+    /// Timestamp in ms of the last update date.
+    pub updated_at: i64,
+    /// Handle of the updater user.
+    pub updated_by_handle: String,
+}
+
+/// RUM application.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RumApplication {
+    /// RUM application attributes.
+    pub attributes: RumApplicationAttributes,
+    /// RUM application ID.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// RUM application resource type.
+    pub typ: RumApplicationType,
+}
+
+/// RUM application creation attributes.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RumApplicationCreateAttributes {
+    /// Name of the RUM application.
+    pub name: String,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    /// Platform of the RUM application. Defaults to `browser`.
+    pub typ: Option<RumApplicationPlatform>,
+}
+
+/// RUM application creation.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RumApplicationCreateData {
+    /// RUM application creation attributes.
+    pub attributes: RumApplicationCreateAttributes,
+    #[serde(rename = "type")]
+    /// RUM application creation resource type.
+    pub typ: RumApplicationCreateType,
+}
+
+/// RUM application update attributes.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct RumApplicationUpdateAttributes {
+    /// Name of the RUM application.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    /// Platform of the RUM application.
+    pub typ: Option<RumApplicationPlatform>,
+}
+
+/// RUM application update.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RumApplicationUpdateData {
+    /// RUM application update attributes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<RumApplicationUpdateAttributes>,
+    /// RUM application ID.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// RUM application update resource type.
+    pub typ: RumApplicationUpdateType,
+}
+
+/// Path parameters to get a RUM application.
+#[derive(Debug)]
+pub struct GetRumApplicationRequest {
+    /// RUM application ID.
+    pub id: String,
+}
+
+/// Path parameters and body to update a RUM application.
+#[derive(Debug)]
+pub struct UpdateRumApplicationRequest {
+    /// RUM application ID.
+    pub id: String,
+    /// The changes to the RUM application.
+    pub application: Request<RumApplicationUpdateData>,
+}
+
+/// Path parameters to delete a RUM application.
+#[derive(Debug)]
+pub struct DeleteRumApplicationRequest {
+    /// RUM application ID.
+    pub id: String,
+}
+
+/// Type of the RUM event.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum RumEventType {
+    /// Equals to `rum`.
+    #[serde(rename = "rum")]
+    Rum,
+}
+
+/// JSON object containing all event attributes and their associated values.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RumEventAttributes {
+    /// JSON object of attributes from RUM events.
+    pub attributes: Option<Map<String, Value>>,
+    /// The name of the application or service generating RUM events.
+    pub service: Option<String>,
+    /// Array of tags associated with your event.
+    pub tags: Option<Vec<String>>,
+    /// Timestamp of your event.
+    // TODO: Use a datetime type.
+    pub timestamp: Option<String>,
+}
+
+/// Object description of a RUM event after being processed and stored by Datadog.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RumEvent {
+    /// JSON object containing all event attributes and their associated values.
+    pub attributes: RumEventAttributes,
+    /// Unique ID of the event.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// Type of the event.
+    pub typ: RumEventType,
+}
+
+/// The search and filter query settings.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct RumQueryFilter {
+    /// The minimum time for the requested events. Supports date math and regular timestamps in milliseconds.
+    ///
+    /// default: `now-15m`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    /// The search query following the RUM search syntax.
+    ///
+    /// default: `*`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// The maximum time for the requested events. Supports date math and regular timestamps in milliseconds.
+    ///
+    /// default: `now`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+}
+
+/// The request for a RUM events list.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SearchRumEventsRequest {
+    /// The search and filter query settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<RumQueryFilter>,
+    /// Global query options that are used during the query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<SearchOptions>,
+    /// Paging attributes for listing events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<SearchPage>,
+    /// Sort parameters when querying events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<SearchSort>,
+}
+
+/// Query parameters to get a list of RUM events.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ListRumEventsRequest {
+    /// Search query following RUM syntax.
+    #[serde(rename = "filter[query]", skip_serializing_if = "Option::is_none")]
+    pub filter_query: Option<String>,
+    /// Minimum timestamp for requested events.
+    #[serde(rename = "filter[from]", skip_serializing_if = "Option::is_none")]
+    pub filter_from: Option<String>,
+    /// Maximum timestamp for requested events.
+    #[serde(rename = "filter[to]", skip_serializing_if = "Option::is_none")]
+    pub filter_to: Option<String>,
+    /// Order of events in results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<SearchSort>,
+    /// List following results with a cursor provided in the previous query.
+    #[serde(rename = "page[cursor]", skip_serializing_if = "Option::is_none")]
+    pub page_cursor: Option<String>,
+    /// Maximum number of events in the response.
+    #[serde(rename = "page[limit]", skip_serializing_if = "Option::is_none")]
+    pub page_limit: Option<i32>,
+}
+
+/// The object sent with the request to aggregate RUM events into buckets.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct RumAggregateRequest {
+    /// The list of metrics or timeseries to compute for the retrieved buckets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compute: Option<Vec<RumCompute>>,
+    /// The search and filter query settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<RumQueryFilter>,
+    /// The rules for the group by.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_by: Option<Vec<RumGroupBy>>,
+    /// Global query options that are used during the query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<SearchOptions>,
+    /// Paging settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<RumAggregateRequestPage>,
+}
+
+/// The query results.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RumAggregateResponseData {
+    /// The list of matching buckets, one item per bucket.
+    pub buckets: Vec<RumAggregateBucket>,
+}
+
+/// The response object for the RUM events aggregate API endpoint.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RumAggregateResponse {
+    /// The query results.
+    pub data: Option<RumAggregateResponseData>,
+    /// Links attributes.
+    pub links: Option<Link>,
+    /// The metadata associated with a request.
+    pub meta: Option<Meta>,
+}
+
+/// A compute rule to compute metrics or timeseries.
+pub type RumCompute = LogsCompute;
+/// An aggregation function.
+pub type RumAggregationFunction = LogsAggregationFunction;
+/// A group by rule.
+pub type RumGroupBy = LogsGroupBy;
+/// Used to perform a histogram computation (only for measure facets).
+pub type RumGroupByHistogram = LogsGroupByHistogram;
+/// A sort rule.
+pub type RumAggregateSort = LogsAggregateSort;
+/// Paging settings of an aggregation.
+pub type RumAggregateRequestPage = LogsAggregateRequestPage;
+/// A bucket values.
+pub type RumAggregateBucket = LogsAggregateBucket;
+
+// Responses and requests
+/// All RUM applications of the organization.
+pub type RumApplicationsResponse = Response<Vec<RumApplicationList>>;
+/// A RUM application, with its client token.
+pub type RumApplicationResponse = Response<RumApplication>;
+/// Response to the deletion of a RUM application.
+pub type DeleteRumApplicationResponse = EmptyResponse;
+/// The RUM events matching a query, with the cursor to the next results.
+pub type ListRumEventsResponse = PagedResponse<RumEvent>;
+
+/// The RUM application to create.
+pub type CreateRumApplicationRequest = Request<RumApplicationCreateData>;
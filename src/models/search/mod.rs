@@ -0,0 +1,37 @@
+use serde_derive::{Deserialize, Serialize};
+
+/// Global query options that are used during the query.
+///
+/// Note: Specify either timezone or time offset, not both. Otherwise, the query fails.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SearchOptions {
+    /// Time offset (in seconds) to apply to the query.
+    #[serde(rename = "timeOffset", skip_serializing_if = "Option::is_none")]
+    pub time_offset: Option<i64>,
+    /// The timezone can be specified as GMT, UTC, an offset from UTC (like UTC+1), or as a Timezone Database identifier (like America/New_York).
+    ///
+    /// default: `UTC`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+}
+
+/// Paging attributes for listing events.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SearchPage {
+    /// List following results with a cursor provided in the previous query.
+    pub cursor: Option<String>,
+    /// Maximum number of events in the response.
+    /// default: `10`
+    pub limit: i32,
+}
+
+/// Sort parameters when querying events.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum SearchSort {
+    /// Equals to `timestamp`.
+    #[serde(rename = "timestamp")]
+    Timestamp,
+    /// Equals to `-timestamp`.
+    #[serde(rename = "-timestamp")]
+    NegativeTimestamp,
+}
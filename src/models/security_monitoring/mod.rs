@@ -0,0 +1,1217 @@
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+
+use super::client::{EmptyResponse, PagedResponse, Request, Response};
+use super::search::{SearchPage, SearchSort};
+
+/// Severity of a security monitoring signal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SecurityMonitoringRuleSeverity {
+    /// Equals to `info`.
+    Info,
+    /// Equals to `low`.
+    Low,
+    /// Equals to `medium`.
+    Medium,
+    /// Equals to `high`.
+    High,
+    /// Equals to `critical`.
+    Critical,
+}
+
+/// Case when a signal is generated.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecurityMonitoringRuleCase {
+    /// A rule case contains logical operations (`>`,`>=`, `&&`, `||`) to determine if a signal should be generated
+    /// based on the event counts in the previously defined queries.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>,
+    /// Name of the case.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Notification targets for each rule case.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notifications: Option<Vec<String>>,
+    /// Severity of the security signal.
+    pub status: SecurityMonitoringRuleSeverity,
+}
+
+/// The action to perform when a filter matches.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SecurityMonitoringFilterAction {
+    /// Equals to `require`.
+    Require,
+    /// Equals to `suppress`.
+    Suppress,
+}
+
+/// The rule's suppression filter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecurityMonitoringFilter {
+    /// The type of filtering action.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<SecurityMonitoringFilterAction>,
+    /// Query for selecting logs to apply the filtering action.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+}
+
+/// The detection method.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SecurityMonitoringRuleDetectionMethod {
+    /// Equals to `threshold`.
+    Threshold,
+    /// Equals to `new_value`.
+    NewValue,
+    /// Equals to `anomaly_detection`.
+    AnomalyDetection,
+    /// Equals to `impossible_travel`.
+    ImpossibleTravel,
+    /// Equals to `hardcoded`.
+    Hardcoded,
+    /// Equals to `third_party`.
+    ThirdParty,
+}
+
+/// The learning method used to determine when signals should be generated for values that weren't learned.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SecurityMonitoringRuleNewValueOptionsLearningMethod {
+    /// Equals to `duration`.
+    Duration,
+    /// Equals to `threshold`.
+    Threshold,
+}
+
+/// Options on new value detection method.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityMonitoringRuleNewValueOptions {
+    /// The duration in days after which a learned value is forgotten.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forget_after: Option<i64>,
+    /// The duration in days during which values are learned, and after which signals will be generated for values that
+    /// weren't learned. If set to 0, a signal will be generated for all new values after the first value is learned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub learning_duration: Option<i64>,
+    /// The learning method used to determine when signals should be generated for values that weren't learned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub learning_method: Option<SecurityMonitoringRuleNewValueOptionsLearningMethod>,
+    /// A number of occurrences after which signals will be generated for values that weren't learned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub learning_threshold: Option<i64>,
+}
+
+/// Options on impossible travel detection method.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityMonitoringRuleImpossibleTravelOptions {
+    /// If true, signals are suppressed for the first 24 hours. In that time, Datadog learns the user's regular
+    /// access locations. This can be helpful to reduce noise and infer VPN usage or credentialed API access.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub baseline_user_locations: Option<bool>,
+}
+
+/// Rego rule of a cloud configuration rule.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloudConfigurationRegoRule {
+    /// The policy written in `rego`.
+    pub policy: String,
+    /// List of resource types that will be evaluated upon. Must have at least one element.
+    pub resource_types: Vec<String>,
+}
+
+/// Options for cloud configuration rules.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloudConfigurationComplianceRuleOptions {
+    /// Whether the rule is a complex one. Must be set to true if `regoRule.resourceTypes` contains more than one item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub complex_rule: Option<bool>,
+    /// Rule details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rego_rule: Option<CloudConfigurationRegoRule>,
+    /// Main resource type to be checked by the rule.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_type: Option<String>,
+}
+
+/// Options on rules.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityMonitoringRuleOptions {
+    /// Options for cloud configuration rules.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compliance_rule_options: Option<CloudConfigurationComplianceRuleOptions>,
+    /// If true, signals in non-production environments have a lower severity than what is defined by the rule case.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decrease_criticality_based_on_env: Option<bool>,
+    /// The detection method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detection_method: Option<SecurityMonitoringRuleDetectionMethod>,
+    /// A time window in seconds that is specified to match events, such as `300`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evaluation_window: Option<i64>,
+    /// Options on impossible travel detection method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impossible_travel_options: Option<SecurityMonitoringRuleImpossibleTravelOptions>,
+    /// Once a signal is generated, the signal will remain "open" if a case is matched at least once within
+    /// this keep alive window, in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keep_alive: Option<i64>,
+    /// A signal will "close" regardless of the query being matched once the time exceeds the maximum duration,
+    /// in seconds. This time is calculated from the first seen timestamp.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_signal_duration: Option<i64>,
+    /// Options on new value detection method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_value_options: Option<SecurityMonitoringRuleNewValueOptions>,
+}
+
+/// The aggregation type of a rule query.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SecurityMonitoringRuleQueryAggregation {
+    /// Equals to `count`.
+    Count,
+    /// Equals to `cardinality`.
+    Cardinality,
+    /// Equals to `sum`.
+    Sum,
+    /// Equals to `max`.
+    Max,
+    /// Equals to `new_value`.
+    NewValue,
+    /// Equals to `geo_data`.
+    GeoData,
+    /// Equals to `event_count`.
+    EventCount,
+    /// Equals to `none`.
+    None,
+}
+
+/// Source of events of a rule query.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SecurityMonitoringStandardDataSource {
+    /// Equals to `logs`.
+    Logs,
+    /// Equals to `audit`.
+    Audit,
+    /// Equals to `app_sec_spans`.
+    AppSecSpans,
+    /// Equals to `spans`.
+    Spans,
+    /// Equals to `security_runtime`.
+    SecurityRuntime,
+    /// Equals to `network`.
+    Network,
+    /// Equals to `events`.
+    Events,
+}
+
+/// Query for matching rule.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityMonitoringStandardRuleQuery {
+    /// The aggregation type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aggregation: Option<SecurityMonitoringRuleQueryAggregation>,
+    /// Source of events, either logs, audit trail, or Datadog events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_source: Option<SecurityMonitoringStandardDataSource>,
+    /// Field for which the cardinality is measured. Sent as an array.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distinct_fields: Option<Vec<String>>,
+    /// Fields to group by.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_by_fields: Option<Vec<String>>,
+    /// When false, events without a group-by value are ignored by the rule.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_optional_group_by_fields: Option<bool>,
+    /// Group of target fields to aggregate over when using the sum, max, geo data, or new value aggregations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<Vec<String>>,
+    /// Name of the query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Query to run on logs.
+    pub query: String,
+}
+
+/// Query for matching rule on signals.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityMonitoringSignalRuleQuery {
+    /// The aggregation type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aggregation: Option<SecurityMonitoringRuleQueryAggregation>,
+    /// Fields to correlate by.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlated_by_fields: Option<Vec<String>>,
+    /// Index of the rule query used to retrieve the correlated field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlated_query_index: Option<i32>,
+    /// Group of target fields to aggregate over.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<Vec<String>>,
+    /// Name of the query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Rule ID to match on signals.
+    pub rule_id: String,
+}
+
+/// Attributes of a rule that are set by the API.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityMonitoringRuleMetadata {
+    /// The ID of the rule.
+    #[serde(default, skip_serializing)]
+    /// This value is set by the API.
+    pub id: Option<String>,
+    /// When the rule was created, timestamp in milliseconds.
+    #[serde(default, skip_serializing)]
+    /// This value is set by the API.
+    pub created_at: Option<i64>,
+    /// User ID of the user who created the rule.
+    #[serde(default, skip_serializing)]
+    /// This value is set by the API.
+    pub creation_author_id: Option<i64>,
+    /// Default tags for default rules, included in tags.
+    #[serde(default, skip_serializing)]
+    /// This value is set by the API.
+    pub default_tags: Option<Vec<String>>,
+    /// When the rule will be deprecated, timestamp in milliseconds.
+    #[serde(default, skip_serializing)]
+    /// This value is set by the API.
+    pub deprecation_date: Option<i64>,
+    /// Whether the rule is included by default.
+    #[serde(default, skip_serializing)]
+    /// This value is set by the API.
+    pub is_default: Option<bool>,
+    /// Whether the rule has been deleted.
+    #[serde(default, skip_serializing)]
+    /// This value is set by the API.
+    pub is_deleted: Option<bool>,
+    /// User ID of the user who updated the rule.
+    #[serde(default, skip_serializing)]
+    /// This value is set by the API.
+    pub update_author_id: Option<i64>,
+    /// The version of the rule.
+    #[serde(default, skip_serializing)]
+    /// This value is set by the API.
+    pub version: Option<i64>,
+}
+
+/// A detection rule on logs, audit trail, workload or application security events.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityMonitoringStandardRule {
+    /// Cases for generating signals.
+    pub cases: Vec<SecurityMonitoringRuleCase>,
+    /// Additional queries to filter matched events before they are processed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filters: Option<Vec<SecurityMonitoringFilter>>,
+    /// Whether the notifications include the triggering group-by values in their title.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_extended_title: Option<bool>,
+    /// Whether the rule is enabled.
+    pub is_enabled: bool,
+    /// Message for generated signals.
+    pub message: String,
+    /// The name of the rule.
+    pub name: String,
+    /// Options on rules.
+    pub options: SecurityMonitoringRuleOptions,
+    /// Queries for selecting logs which are part of the rule.
+    pub queries: Vec<SecurityMonitoringStandardRuleQuery>,
+    /// Tags for generated signals.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// Attributes of the rule that are set by the API.
+    #[serde(flatten)]
+    pub metadata: SecurityMonitoringRuleMetadata,
+}
+
+/// A rule correlating the signals of other rules.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityMonitoringSignalRule {
+    /// Cases for generating signals.
+    pub cases: Vec<SecurityMonitoringRuleCase>,
+    /// Additional queries to filter matched events before they are processed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filters: Option<Vec<SecurityMonitoringFilter>>,
+    /// Whether the notifications include the triggering group-by values in their title.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_extended_title: Option<bool>,
+    /// Whether the rule is enabled.
+    pub is_enabled: bool,
+    /// Message for generated signals.
+    pub message: String,
+    /// The name of the rule.
+    pub name: String,
+    /// Options on rules.
+    pub options: SecurityMonitoringRuleOptions,
+    /// Queries for selecting signals which are part of the rule.
+    pub queries: Vec<SecurityMonitoringSignalRuleQuery>,
+    /// Tags for generated signals.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// Attributes of the rule that are set by the API.
+    #[serde(flatten)]
+    pub metadata: SecurityMonitoringRuleMetadata,
+}
+
+/// The case of a cloud configuration rule.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CloudConfigurationRuleCase {
+    /// Notification targets for each rule case.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notifications: Option<Vec<String>>,
+    /// Severity of the security signal.
+    pub status: SecurityMonitoringRuleSeverity,
+}
+
+/// How to generate compliance signals. Useful for cloud configuration rules only.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloudConfigurationRuleComplianceSignalOptions {
+    /// The default activation status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_activation_status: Option<bool>,
+    /// The default group by fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_group_by_fields: Option<Vec<String>>,
+    /// Whether signals will be sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_activation_status: Option<bool>,
+    /// Fields to use to group findings by when sending signals.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_group_by_fields: Option<Vec<String>>,
+}
+
+/// Options on cloud configuration rules.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloudConfigurationRuleOptions {
+    /// Options for cloud configuration rules.
+    pub compliance_rule_options: CloudConfigurationComplianceRuleOptions,
+}
+
+/// A rule evaluating the configuration of cloud resources.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloudConfigurationRule {
+    /// Description of generated findings and signals (severity and channels to be notified in case of a signal).
+    /// Must contain exactly one item.
+    pub cases: Vec<CloudConfigurationRuleCase>,
+    /// How to generate compliance signals.
+    pub compliance_signal_options: CloudConfigurationRuleComplianceSignalOptions,
+    /// Additional queries to filter matched events before they are processed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filters: Option<Vec<SecurityMonitoringFilter>>,
+    /// Whether the rule is enabled.
+    pub is_enabled: bool,
+    /// Message in markdown format for generated findings and signals.
+    pub message: String,
+    /// The name of the rule.
+    pub name: String,
+    /// Options on cloud configuration rules.
+    pub options: CloudConfigurationRuleOptions,
+    /// Tags for generated findings and signals.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// Attributes of the rule that are set by the API.
+    #[serde(flatten)]
+    pub metadata: SecurityMonitoringRuleMetadata,
+}
+
+/// A security monitoring rule, tagged by its `type`.
+///
+/// Rule types this client does not model are kept as [SecurityMonitoringRule::Unknown],
+/// holding the raw definition so that it is sent back unchanged when updating a rule.
+#[derive(Debug, Clone)]
+pub enum SecurityMonitoringRule {
+    /// Equals to `log_detection`.
+    LogDetection(SecurityMonitoringStandardRule),
+    /// Equals to `workload_security`.
+    WorkloadSecurity(SecurityMonitoringStandardRule),
+    /// Equals to `application_security`.
+    ApplicationSecurity(SecurityMonitoringStandardRule),
+    /// Equals to `cloud_configuration`.
+    CloudConfiguration(CloudConfigurationRule),
+    /// Equals to `signal_correlation`.
+    SignalCorrelation(SecurityMonitoringSignalRule),
+    /// Any other rule type.
+    Unknown(Value),
+}
+
+impl SecurityMonitoringRule {
+    /// The `type` of the rule.
+    pub fn rule_type(&self) -> Option<&str> {
+        match self {
+            SecurityMonitoringRule::LogDetection(_) => Some("log_detection"),
+            SecurityMonitoringRule::WorkloadSecurity(_) => Some("workload_security"),
+            SecurityMonitoringRule::ApplicationSecurity(_) => Some("application_security"),
+            SecurityMonitoringRule::CloudConfiguration(_) => Some("cloud_configuration"),
+            SecurityMonitoringRule::SignalCorrelation(_) => Some("signal_correlation"),
+            SecurityMonitoringRule::Unknown(value) => value.get("type").and_then(Value::as_str),
+        }
+    }
+
+    /// The ID of the rule, if it was returned by the API.
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            SecurityMonitoringRule::LogDetection(rule)
+            | SecurityMonitoringRule::WorkloadSecurity(rule)
+            | SecurityMonitoringRule::ApplicationSecurity(rule) => rule.metadata.id.as_deref(),
+            SecurityMonitoringRule::CloudConfiguration(rule) => rule.metadata.id.as_deref(),
+            SecurityMonitoringRule::SignalCorrelation(rule) => rule.metadata.id.as_deref(),
+            SecurityMonitoringRule::Unknown(value) => value.get("id").and_then(Value::as_str),
+        }
+    }
+}
+
+impl Serialize for SecurityMonitoringRule {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let value = match self {
+            SecurityMonitoringRule::LogDetection(rule)
+            | SecurityMonitoringRule::WorkloadSecurity(rule)
+            | SecurityMonitoringRule::ApplicationSecurity(rule) => serde_json::to_value(rule),
+            SecurityMonitoringRule::CloudConfiguration(rule) => serde_json::to_value(rule),
+            SecurityMonitoringRule::SignalCorrelation(rule) => serde_json::to_value(rule),
+            SecurityMonitoringRule::Unknown(value) => return value.serialize(serializer),
+        };
+
+        let mut value = value.map_err(S::Error::custom)?;
+        if let (Value::Object(fields), Some(typ)) = (&mut value, self.rule_type()) {
+            fields.insert("type".to_string(), Value::String(typ.to_string()));
+        }
+        value.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SecurityMonitoringRule {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        let typ = value
+            .get("type")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+
+        let rule = match typ {
+            "log_detection" => {
+                serde_json::from_value(value).map(SecurityMonitoringRule::LogDetection)
+            }
+            "workload_security" => {
+                serde_json::from_value(value).map(SecurityMonitoringRule::WorkloadSecurity)
+            }
+            "application_security" => {
+                serde_json::from_value(value).map(SecurityMonitoringRule::ApplicationSecurity)
+            }
+            "cloud_configuration" => {
+                serde_json::from_value(value).map(SecurityMonitoringRule::CloudConfiguration)
+            }
+            "signal_correlation" => {
+                serde_json::from_value(value).map(SecurityMonitoringRule::SignalCorrelation)
+            }
+            _ => return Ok(SecurityMonitoringRule::Unknown(value)),
+        };
+
+        rule.map_err(D::Error::custom)
+    }
+}
+
+/// Query parameters to list rules.
+#[derive(Debug, Default, Serialize)]
+pub struct ListSecurityMonitoringRulesRequest {
+    /// Size for a given page. The maximum allowed value is 100.
+    #[serde(rename = "page[size]", skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<i64>,
+    /// Specific page number to return.
+    #[serde(rename = "page[number]", skip_serializing_if = "Option::is_none")]
+    pub page_number: Option<i64>,
+}
+
+/// Page information of a list of rules.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityMonitoringRulesPage {
+    /// Total count.
+    pub total_count: Option<i64>,
+    /// Total count of elements matching the filter.
+    pub total_filtered_count: Option<i64>,
+}
+
+/// Object describing meta attributes of a list of rules.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityMonitoringRulesMeta {
+    /// Page information.
+    pub page: Option<SecurityMonitoringRulesPage>,
+}
+
+/// List of rules.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityMonitoringListRulesResponse {
+    /// Array containing the list of rules.
+    pub data: Vec<SecurityMonitoringRule>,
+    /// Object describing meta attributes of the response.
+    pub meta: Option<SecurityMonitoringRulesMeta>,
+}
+
+/// Path parameters to get a rule.
+#[derive(Debug)]
+pub struct GetSecurityMonitoringRuleRequest {
+    /// The ID of the rule.
+    pub rule_id: String,
+}
+
+/// Path parameters and body to update a rule.
+///
+/// Default rules can only be updated to be enabled, to change notifications, or to update the tags.
+#[derive(Debug)]
+pub struct UpdateSecurityMonitoringRuleRequest {
+    /// The ID of the rule.
+    pub rule_id: String,
+    /// The new definition of the rule.
+    pub rule: SecurityMonitoringRule,
+}
+
+/// Path parameters to delete a rule.
+#[derive(Debug)]
+pub struct DeleteSecurityMonitoringRuleRequest {
+    /// The ID of the rule.
+    pub rule_id: String,
+}
+
+/// The type of event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SecurityMonitoringSignalType {
+    /// Equals to `signal`.
+    #[serde(rename = "signal")]
+    Signal,
+}
+
+/// The attributes of a security signal.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityMonitoringSignalAttributes {
+    /// A JSON object of attributes in the security signal, including the triage state of the signal.
+    pub custom: Option<Map<String, Value>>,
+    /// The message in the security signal defined by the rule that generated the signal.
+    pub message: Option<String>,
+    /// An array of tags associated with the security signal.
+    pub tags: Option<Vec<String>>,
+    /// The timestamp of the security signal.
+    // TODO: Use a datetime type.
+    pub timestamp: Option<String>,
+}
+
+/// Object description of a security signal.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityMonitoringSignal {
+    /// The attributes of a security signal.
+    pub attributes: Option<SecurityMonitoringSignalAttributes>,
+    /// The unique ID of the security signal.
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    /// The type of event.
+    pub typ: Option<SecurityMonitoringSignalType>,
+}
+
+/// Search filters for listing security signals.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SecurityMonitoringSignalListRequestFilter {
+    /// The minimum timestamp for requested security signals.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    /// Search query for listing security signals.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// The maximum timestamp for requested security signals.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+}
+
+/// The request for a security signal list.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SearchSecurityMonitoringSignalsRequest {
+    /// Search filters for listing security signals.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<SecurityMonitoringSignalListRequestFilter>,
+    /// The paging attributes for listing security signals.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<SearchPage>,
+    /// The sort parameters used for querying security signals.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<SearchSort>,
+}
+
+/// Query parameters to get a list of security signals.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ListSecurityMonitoringSignalsRequest {
+    /// The search query for security signals.
+    #[serde(rename = "filter[query]", skip_serializing_if = "Option::is_none")]
+    pub filter_query: Option<String>,
+    /// The minimum timestamp for requested security signals.
+    #[serde(rename = "filter[from]", skip_serializing_if = "Option::is_none")]
+    pub filter_from: Option<String>,
+    /// The maximum timestamp for requested security signals.
+    #[serde(rename = "filter[to]", skip_serializing_if = "Option::is_none")]
+    pub filter_to: Option<String>,
+    /// The order of the security signals in results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<SearchSort>,
+    /// A list of results using the cursor provided in the previous query.
+    #[serde(rename = "page[cursor]", skip_serializing_if = "Option::is_none")]
+    pub page_cursor: Option<String>,
+    /// The maximum number of security signals in the response.
+    #[serde(rename = "page[limit]", skip_serializing_if = "Option::is_none")]
+    pub page_limit: Option<i32>,
+}
+
+/// Path parameters to get a security signal.
+#[derive(Debug)]
+pub struct GetSecurityMonitoringSignalRequest {
+    /// The ID of the signal.
+    pub signal_id: String,
+}
+
+/// The new triage state of the signal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SecurityMonitoringSignalState {
+    /// Equals to `open`.
+    Open,
+    /// Equals to `archived`.
+    Archived,
+    /// Equals to `under_review`.
+    UnderReview,
+}
+
+/// Reason a signal is archived.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SecurityMonitoringSignalArchiveReason {
+    /// Equals to `none`.
+    None,
+    /// Equals to `false_positive`.
+    FalsePositive,
+    /// Equals to `testing_or_maintenance`.
+    TestingOrMaintenance,
+    /// Equals to `investigated_case_opened`.
+    InvestigatedCaseOpened,
+    /// Equals to `other`.
+    Other,
+}
+
+/// Object representing a given user entity.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SecurityMonitoringTriageUser {
+    /// The handle for this user account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub handle: Option<String>,
+    /// Gravatar icon associated to the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// Numerical ID assigned by Datadog to this user account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    /// The name for this user account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// UUID assigned by Datadog to this user account. An empty UUID unassigns the signal.
+    pub uuid: String,
+}
+
+/// Attributes describing the new assignee of a security signal.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityMonitoringSignalAssigneeUpdateAttributes {
+    /// Object representing a given user entity.
+    pub assignee: SecurityMonitoringTriageUser,
+    /// Version of the updated signal. If server side version is higher, update will be rejected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<i64>,
+}
+
+/// Data containing the patch for changing the assignee of a signal.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityMonitoringSignalAssigneeUpdateData {
+    /// Attributes describing the new assignee of a security signal.
+    pub attributes: SecurityMonitoringSignalAssigneeUpdateAttributes,
+}
+
+/// Attributes describing the new list of related signals for a security signal.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityMonitoringSignalIncidentsUpdateAttributes {
+    /// Array of incidents that are associated with this signal.
+    pub incident_ids: Vec<i64>,
+    /// Version of the updated signal. If server side version is higher, update will be rejected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<i64>,
+}
+
+/// Data containing the patch for changing the related incidents of a signal.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityMonitoringSignalIncidentsUpdateData {
+    /// Attributes describing the new list of related signals for a security signal.
+    pub attributes: SecurityMonitoringSignalIncidentsUpdateAttributes,
+}
+
+/// Attributes describing the change of state of a security signal.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityMonitoringSignalStateUpdateAttributes {
+    /// Optional comment to display on archived signals.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archive_comment: Option<String>,
+    /// Reason a signal is archived.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archive_reason: Option<SecurityMonitoringSignalArchiveReason>,
+    /// The new triage state of the signal.
+    pub state: SecurityMonitoringSignalState,
+    /// Version of the updated signal. If server side version is higher, update will be rejected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<i64>,
+}
+
+/// Data containing the patch for changing the state of a signal.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityMonitoringSignalStateUpdateData {
+    /// Attributes describing the change of state of a security signal.
+    pub attributes: SecurityMonitoringSignalStateUpdateAttributes,
+}
+
+/// Path parameters and body to change the assignee of a signal.
+#[derive(Debug)]
+pub struct EditSecurityMonitoringSignalAssigneeRequest {
+    /// The ID of the signal.
+    pub signal_id: String,
+    /// The new assignee.
+    pub assignee: Request<SecurityMonitoringSignalAssigneeUpdateData>,
+}
+
+/// Path parameters and body to change the related incidents of a signal.
+#[derive(Debug)]
+pub struct EditSecurityMonitoringSignalIncidentsRequest {
+    /// The ID of the signal.
+    pub signal_id: String,
+    /// The new related incidents.
+    pub incidents: Request<SecurityMonitoringSignalIncidentsUpdateData>,
+}
+
+/// Path parameters and body to change the triage state of a signal.
+#[derive(Debug)]
+pub struct EditSecurityMonitoringSignalStateRequest {
+    /// The ID of the signal.
+    pub signal_id: String,
+    /// The new triage state.
+    pub state: Request<SecurityMonitoringSignalStateUpdateData>,
+}
+
+/// The type of event of a signal metadata.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SecurityMonitoringSignalMetadataType {
+    /// Equals to `signal_metadata`.
+    #[serde(rename = "signal_metadata")]
+    SignalMetadata,
+}
+
+/// Attributes describing a triage state update operation over a security signal.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityMonitoringSignalTriageAttributes {
+    /// Optional comment to display on archived signals.
+    pub archive_comment: Option<String>,
+    /// Timestamp of the last edit to the comment.
+    pub archive_comment_timestamp: Option<i64>,
+    /// Object representing a given user entity.
+    pub archive_comment_user: Option<SecurityMonitoringTriageUser>,
+    /// Reason a signal is archived.
+    pub archive_reason: Option<SecurityMonitoringSignalArchiveReason>,
+    /// Object representing a given user entity.
+    pub assignee: SecurityMonitoringTriageUser,
+    /// Array of incidents that are associated with this signal.
+    pub incident_ids: Vec<i64>,
+    /// The new triage state of the signal.
+    pub state: SecurityMonitoringSignalState,
+    /// Timestamp of the last update to the signal state.
+    pub state_update_timestamp: Option<i64>,
+    /// Object representing a given user entity.
+    pub state_update_user: Option<SecurityMonitoringTriageUser>,
+}
+
+/// Data containing the updated triage attributes of the signal.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityMonitoringSignalTriageUpdateData {
+    /// Attributes describing a triage state update operation over a security signal.
+    pub attributes: Option<SecurityMonitoringSignalTriageAttributes>,
+    /// The unique ID of the security signal.
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    /// The type of event.
+    pub typ: Option<SecurityMonitoringSignalMetadataType>,
+}
+
+/// The type of the resource of a suppression rule.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SecurityMonitoringSuppressionType {
+    /// Equals to `suppressions`.
+    #[serde(rename = "suppressions")]
+    Suppressions,
+}
+
+/// A user.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityMonitoringUser {
+    /// The handle of the user.
+    pub handle: Option<String>,
+    /// The name of the user.
+    pub name: Option<String>,
+}
+
+/// The attributes of the suppression rule.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityMonitoringSuppressionAttributes {
+    /// A Unix millisecond timestamp given the creation date of the suppression rule.
+    pub creation_date: Option<i64>,
+    /// A user.
+    pub creator: Option<SecurityMonitoringUser>,
+    /// An exclusion query on the input data of the security rules, which could be logs, Agent events, or other types
+    /// of data based on the security rule. Events matching this query are ignored by any security rules referenced in
+    /// the suppression rule.
+    pub data_exclusion_query: Option<String>,
+    /// A description for the suppression rule.
+    pub description: Option<String>,
+    /// Whether the suppression rule is editable.
+    pub editable: Option<bool>,
+    /// Whether the suppression rule is enabled.
+    pub enabled: Option<bool>,
+    /// A Unix millisecond timestamp giving an expiration date for the suppression rule. After this date, it won't
+    /// suppress signals anymore.
+    pub expiration_date: Option<i64>,
+    /// The name of the suppression rule.
+    pub name: Option<String>,
+    /// The rule query of the suppression rule, with the same syntax as the search bar for detection rules.
+    pub rule_query: Option<String>,
+    /// The suppression query of the suppression rule. If a signal matches this query, it is suppressed and not
+    /// triggered.
+    pub suppression_query: Option<String>,
+    /// A Unix millisecond timestamp given the update date of the suppression rule.
+    pub update_date: Option<i64>,
+    /// A user.
+    pub updater: Option<SecurityMonitoringUser>,
+    /// The version of the suppression rule; it starts at 1, and is incremented at each update.
+    pub version: Option<i32>,
+}
+
+/// The suppression rule's properties.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityMonitoringSuppression {
+    /// The attributes of the suppression rule.
+    pub attributes: Option<SecurityMonitoringSuppressionAttributes>,
+    /// The ID of the suppression rule.
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    /// The type of the resource.
+    pub typ: Option<SecurityMonitoringSuppressionType>,
+}
+
+/// Object containing the attributes of the suppression rule to be created.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityMonitoringSuppressionCreateAttributes {
+    /// An exclusion query on the input data of the security rules. Either this field or `suppression_query` must
+    /// be set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_exclusion_query: Option<String>,
+    /// A description for the suppression rule.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Whether the suppression rule is enabled.
+    pub enabled: bool,
+    /// A Unix millisecond timestamp giving an expiration date for the suppression rule.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration_date: Option<i64>,
+    /// The name of the suppression rule.
+    pub name: String,
+    /// The rule query of the suppression rule, with the same syntax as the search bar for detection rules.
+    pub rule_query: String,
+    /// The suppression query of the suppression rule. Either this field or `data_exclusion_query` must be set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suppression_query: Option<String>,
+}
+
+/// Object for a single suppression rule.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityMonitoringSuppressionCreateData {
+    /// Object containing the attributes of the suppression rule to be created.
+    pub attributes: SecurityMonitoringSuppressionCreateAttributes,
+    #[serde(rename = "type")]
+    /// The type of the resource.
+    pub typ: SecurityMonitoringSuppressionType,
+}
+
+/// The suppression rule properties to be updated.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SecurityMonitoringSuppressionUpdateAttributes {
+    /// An exclusion query on the input data of the security rules.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_exclusion_query: Option<String>,
+    /// A description for the suppression rule.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Whether the suppression rule is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// A Unix millisecond timestamp giving an expiration date for the suppression rule.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration_date: Option<i64>,
+    /// The name of the suppression rule.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The rule query of the suppression rule.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule_query: Option<String>,
+    /// The suppression query of the suppression rule.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suppression_query: Option<String>,
+    /// The current version of the suppression. This is optional, but it can help prevent concurrent modifications.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<i32>,
+}
+
+/// The new suppression properties; partial updates are supported.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityMonitoringSuppressionUpdateData {
+    /// The suppression rule properties to be updated.
+    pub attributes: SecurityMonitoringSuppressionUpdateAttributes,
+    #[serde(rename = "type")]
+    /// The type of the resource.
+    pub typ: SecurityMonitoringSuppressionType,
+}
+
+/// Path parameters to get a suppression rule.
+#[derive(Debug)]
+pub struct GetSecurityMonitoringSuppressionRequest {
+    /// The ID of the suppression rule.
+    pub suppression_id: String,
+}
+
+/// Path parameters and body to update a suppression rule.
+#[derive(Debug)]
+pub struct UpdateSecurityMonitoringSuppressionRequest {
+    /// The ID of the suppression rule.
+    pub suppression_id: String,
+    /// The changes to the suppression rule.
+    pub suppression: Request<SecurityMonitoringSuppressionUpdateData>,
+}
+
+/// Path parameters to delete a suppression rule.
+#[derive(Debug)]
+pub struct DeleteSecurityMonitoringSuppressionRequest {
+    /// The ID of the suppression rule.
+    pub suppression_id: String,
+}
+
+/// The type of the resource of a security filter.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SecurityFilterType {
+    /// Equals to `security_filters`.
+    #[serde(rename = "security_filters")]
+    SecurityFilters,
+}
+
+/// The filtered data type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SecurityFilterFilteredDataType {
+    /// Equals to `logs`.
+    #[serde(rename = "logs")]
+    Logs,
+}
+
+/// Exclusion filter for the security filter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecurityFilterExclusionFilter {
+    /// Exclusion filter name.
+    pub name: String,
+    /// Exclusion filter query. Logs that match this query are excluded from the security filter.
+    pub query: String,
+}
+
+/// The object describing a security filter.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityFilterAttributes {
+    /// The list of exclusion filters applied in this security filter.
+    pub exclusion_filters: Option<Vec<SecurityFilterExclusionFilter>>,
+    /// The filtered data type.
+    pub filtered_data_type: Option<SecurityFilterFilteredDataType>,
+    /// Whether the security filter is the built-in filter.
+    pub is_builtin: Option<bool>,
+    /// Whether the security filter is enabled.
+    pub is_enabled: Option<bool>,
+    /// The security filter name.
+    pub name: Option<String>,
+    /// The security filter query. Logs accepted by this query will be accepted by this filter.
+    pub query: Option<String>,
+    /// The version of the security filter.
+    pub version: Option<i32>,
+}
+
+/// The security filter's properties.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityFilter {
+    /// The object describing a security filter.
+    pub attributes: Option<SecurityFilterAttributes>,
+    /// The ID of the security filter.
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    /// The type of the resource.
+    pub typ: Option<SecurityFilterType>,
+}
+
+/// Optional metadata associated to the response.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityFilterMeta {
+    /// A warning message.
+    pub warning: Option<String>,
+}
+
+/// Response object which includes a single security filter.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityFilterResponse {
+    /// The security filter's properties.
+    pub data: Option<SecurityFilter>,
+    /// Optional metadata associated to the response.
+    pub meta: Option<SecurityFilterMeta>,
+}
+
+/// All the available security filters objects.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityFiltersResponse {
+    /// A list of security filters objects.
+    pub data: Option<Vec<SecurityFilter>>,
+    /// Optional metadata associated to the response.
+    pub meta: Option<SecurityFilterMeta>,
+}
+
+/// Object containing the attributes of the security filter to be created.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityFilterCreateAttributes {
+    /// Exclusion filters to exclude some logs from the security filter.
+    pub exclusion_filters: Vec<SecurityFilterExclusionFilter>,
+    /// The filtered data type.
+    pub filtered_data_type: SecurityFilterFilteredDataType,
+    /// Whether the security filter is enabled.
+    pub is_enabled: bool,
+    /// The name of the security filter.
+    pub name: String,
+    /// The query of the security filter.
+    pub query: String,
+}
+
+/// Object for a single security filter.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityFilterCreateData {
+    /// Object containing the attributes of the security filter to be created.
+    pub attributes: SecurityFilterCreateAttributes,
+    #[serde(rename = "type")]
+    /// The type of the resource.
+    pub typ: SecurityFilterType,
+}
+
+/// The security filters properties to be updated.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SecurityFilterUpdateAttributes {
+    /// Exclusion filters to exclude some logs from the security filter.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclusion_filters: Option<Vec<SecurityFilterExclusionFilter>>,
+    /// The filtered data type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filtered_data_type: Option<SecurityFilterFilteredDataType>,
+    /// Whether the security filter is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_enabled: Option<bool>,
+    /// The name of the security filter.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The query of the security filter.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// The version of the security filter to update.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<i32>,
+}
+
+/// The new security filter properties.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityFilterUpdateData {
+    /// The security filters properties to be updated.
+    pub attributes: SecurityFilterUpdateAttributes,
+    #[serde(rename = "type")]
+    /// The type of the resource.
+    pub typ: SecurityFilterType,
+}
+
+/// Path parameters to get a security filter.
+#[derive(Debug)]
+pub struct GetSecurityFilterRequest {
+    /// The ID of the security filter.
+    pub security_filter_id: String,
+}
+
+/// Path parameters and body to update a security filter.
+#[derive(Debug)]
+pub struct UpdateSecurityFilterRequest {
+    /// The ID of the security filter.
+    pub security_filter_id: String,
+    /// The changes to the security filter.
+    pub security_filter: Request<SecurityFilterUpdateData>,
+}
+
+/// Path parameters to delete a security filter.
+#[derive(Debug)]
+pub struct DeleteSecurityFilterRequest {
+    /// The ID of the security filter.
+    pub security_filter_id: String,
+}
+
+// Responses and requests
+/// The security signals matching a query, with the cursor to the next results.
+pub type ListSecurityMonitoringSignalsResponse = PagedResponse<SecurityMonitoringSignal>;
+/// A single security signal.
+pub type SecurityMonitoringSignalResponse = Response<SecurityMonitoringSignal>;
+/// The updated triage attributes of a signal.
+pub type SecurityMonitoringSignalTriageUpdateResponse =
+    Response<SecurityMonitoringSignalTriageUpdateData>;
+/// All the suppression rules.
+pub type SecurityMonitoringSuppressionsResponse = Response<Vec<SecurityMonitoringSuppression>>;
+/// A single suppression rule.
+pub type SecurityMonitoringSuppressionResponse = Response<SecurityMonitoringSuppression>;
+/// Response to the deletion of a rule.
+pub type DeleteSecurityMonitoringRuleResponse = EmptyResponse;
+/// Response to the validation of a rule.
+pub type ValidateSecurityMonitoringRuleResponse = EmptyResponse;
+/// Response to the deletion of a suppression rule.
+pub type DeleteSecurityMonitoringSuppressionResponse = EmptyResponse;
+/// Response to the deletion of a security filter.
+pub type DeleteSecurityFilterResponse = EmptyResponse;
+
+/// The rule to create.
+pub type CreateSecurityMonitoringRuleRequest = SecurityMonitoringRule;
+/// The rule to validate.
+pub type ValidateSecurityMonitoringRuleRequest = SecurityMonitoringRule;
+/// The suppression rule to create.
+pub type CreateSecurityMonitoringSuppressionRequest =
+    Request<SecurityMonitoringSuppressionCreateData>;
+/// The security filter to create.
+pub type CreateSecurityFilterRequest = Request<SecurityFilterCreateData>;
@@ -0,0 +1,618 @@
+use serde_derive::{Deserialize, Serialize};
+
+/// Sensitive Data Scanner configuration type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SensitiveDataScannerConfigurationType {
+    /// Equals to `sensitive_data_scanner_configuration`.
+    #[serde(rename = "sensitive_data_scanner_configuration")]
+    SensitiveDataScannerConfiguration,
+}
+
+/// Sensitive Data Scanner group type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SensitiveDataScannerGroupType {
+    /// Equals to `sensitive_data_scanner_group`.
+    #[serde(rename = "sensitive_data_scanner_group")]
+    SensitiveDataScannerGroup,
+}
+
+/// Sensitive Data Scanner rule type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SensitiveDataScannerRuleType {
+    /// Equals to `sensitive_data_scanner_rule`.
+    #[serde(rename = "sensitive_data_scanner_rule")]
+    SensitiveDataScannerRule,
+}
+
+/// Sensitive Data Scanner standard pattern type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SensitiveDataScannerStandardPatternType {
+    /// Equals to `sensitive_data_scanner_standard_pattern`.
+    #[serde(rename = "sensitive_data_scanner_standard_pattern")]
+    SensitiveDataScannerStandardPattern,
+}
+
+/// Datadog product onto which Sensitive Data Scanner can be activated.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SensitiveDataScannerProduct {
+    /// Equals to `logs`.
+    Logs,
+    /// Equals to `rum`.
+    Rum,
+    /// Equals to `events`.
+    Events,
+    /// Equals to `apm`.
+    Apm,
+}
+
+/// Meta carrying the version of the configuration.
+///
+/// Every change to the scanning groups or rules increments the version. Changes must send the
+/// version they were based on, and Datadog rejects them with
+/// [Error::VersionConflict](crate::error::Error::VersionConflict) when it is no longer current.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SensitiveDataScannerMetaVersionOnly {
+    /// Version of the API.
+    pub version: i64,
+}
+
+/// Filter for the scanning group.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SensitiveDataScannerFilter {
+    /// Query to filter the events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+}
+
+/// Attributes of the Sensitive Data Scanner group.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SensitiveDataScannerGroupAttributes {
+    /// Description of the group.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Filter for the scanning group.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<SensitiveDataScannerFilter>,
+    /// Whether or not the group is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_enabled: Option<bool>,
+    /// Name of the group.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// List of products the scanning group applies.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_list: Option<Vec<SensitiveDataScannerProduct>>,
+}
+
+/// A configuration object.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SensitiveDataScannerConfiguration {
+    /// ID of the configuration.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// Sensitive Data Scanner configuration type.
+    pub typ: SensitiveDataScannerConfigurationType,
+}
+
+/// A Sensitive Data Scanner configuration data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SensitiveDataScannerConfigurationData {
+    /// A Sensitive Data Scanner configuration data.
+    pub data: SensitiveDataScannerConfiguration,
+}
+
+/// Relationship to a scanning group.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SensitiveDataScannerGroup {
+    /// ID of the group.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// Sensitive Data Scanner group type.
+    pub typ: SensitiveDataScannerGroupType,
+}
+
+/// A scanning group data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SensitiveDataScannerGroupData {
+    /// A scanning group.
+    pub data: SensitiveDataScannerGroup,
+}
+
+/// Relationship to a list of scanning groups, in the order they are applied.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SensitiveDataScannerGroupList {
+    /// List of groups, ordered.
+    pub data: Vec<SensitiveDataScannerGroup>,
+}
+
+/// Relationship to a scanning rule.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SensitiveDataScannerRule {
+    /// ID of the rule.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// Sensitive Data Scanner rule type.
+    pub typ: SensitiveDataScannerRuleType,
+}
+
+/// Relationship to a list of scanning rules, in the order they are applied.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SensitiveDataScannerRuleData {
+    /// Rules included in the group.
+    pub data: Vec<SensitiveDataScannerRule>,
+}
+
+/// Relationships of the configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SensitiveDataScannerConfigurationRelationships {
+    /// List of groups, ordered.
+    pub groups: SensitiveDataScannerGroupList,
+}
+
+/// Relationships of the group.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SensitiveDataScannerGroupRelationships {
+    /// A Sensitive Data Scanner configuration data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub configuration: Option<SensitiveDataScannerConfigurationData>,
+    /// Rules included in the group.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rules: Option<SensitiveDataScannerRuleData>,
+}
+
+/// Data related to a Sensitive Data Scanner configuration, listing its scanning groups.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SensitiveDataScannerGetConfigResponseData {
+    /// ID of the configuration.
+    pub id: String,
+    /// Relationships of the configuration.
+    pub relationships: Option<SensitiveDataScannerConfigurationRelationships>,
+    #[serde(rename = "type")]
+    /// Sensitive Data Scanner configuration type.
+    pub typ: SensitiveDataScannerConfigurationType,
+}
+
+/// A scanning group, included in the configuration.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SensitiveDataScannerGroupIncludedItem {
+    /// Attributes of the group.
+    pub attributes: SensitiveDataScannerGroupAttributes,
+    /// ID of the group.
+    pub id: String,
+    /// Relationships of the group.
+    pub relationships: Option<SensitiveDataScannerGroupRelationships>,
+}
+
+/// A scanning rule, included in the configuration.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SensitiveDataScannerRuleIncludedItem {
+    /// Attributes of the rule.
+    pub attributes: SensitiveDataScannerRuleAttributes,
+    /// ID of the rule.
+    pub id: String,
+    /// Relationships of the rule.
+    pub relationships: Option<SensitiveDataScannerRuleRelationships>,
+}
+
+/// An object related to the configuration, tagged by its `type`.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum SensitiveDataScannerGetConfigIncludedItem {
+    /// Equals to `sensitive_data_scanner_group`.
+    #[serde(rename = "sensitive_data_scanner_group")]
+    Group(SensitiveDataScannerGroupIncludedItem),
+    /// Equals to `sensitive_data_scanner_rule`.
+    #[serde(rename = "sensitive_data_scanner_rule")]
+    Rule(SensitiveDataScannerRuleIncludedItem),
+}
+
+/// Meta of the configuration, describing the organization's limits and the current version.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SensitiveDataScannerMeta {
+    /// Maximum number of scanning rules allowed for the org.
+    pub count_limit: Option<i64>,
+    /// Maximum number of scanning groups allowed for the org.
+    pub group_count_limit: Option<i64>,
+    /// Whether or not scanned events are highlighted in Logs or RUM for the org.
+    pub has_highlight_enabled: Option<bool>,
+    /// Whether or not scanned events have multi-pass enabled.
+    pub has_multi_pass_enabled: Option<bool>,
+    /// Whether or not the org is compliant to the payment card industry standard.
+    pub is_pci_compliant: Option<bool>,
+    /// Version of the API.
+    pub version: i64,
+}
+
+/// Get scanning groups response.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SensitiveDataScannerGetConfigResponse {
+    /// Data related to the configuration.
+    pub data: SensitiveDataScannerGetConfigResponseData,
+    /// Included objects from relationships.
+    pub included: Option<Vec<SensitiveDataScannerGetConfigIncludedItem>>,
+    /// Meta of the configuration.
+    pub meta: SensitiveDataScannerMeta,
+}
+
+impl SensitiveDataScannerGetConfigResponse {
+    /// The current version of the configuration, to send along with the next change.
+    pub fn version(&self) -> i64 {
+        self.meta.version
+    }
+
+    /// The scanning groups of the configuration, in the order they are applied.
+    pub fn groups(&self) -> impl Iterator<Item = &SensitiveDataScannerGroupIncludedItem> {
+        self.included
+            .iter()
+            .flatten()
+            .filter_map(|item| match item {
+                SensitiveDataScannerGetConfigIncludedItem::Group(group) => Some(group),
+                SensitiveDataScannerGetConfigIncludedItem::Rule(_) => None,
+            })
+    }
+
+    /// The scanning rules of the configuration.
+    pub fn rules(&self) -> impl Iterator<Item = &SensitiveDataScannerRuleIncludedItem> {
+        self.included
+            .iter()
+            .flatten()
+            .filter_map(|item| match item {
+                SensitiveDataScannerGetConfigIncludedItem::Rule(rule) => Some(rule),
+                SensitiveDataScannerGetConfigIncludedItem::Group(_) => None,
+            })
+    }
+}
+
+/// Data related to the reordering of scanning groups.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SensitiveDataScannerReorderConfig {
+    /// ID of the configuration.
+    pub id: String,
+    /// Relationships of the configuration, with the groups in their new order.
+    pub relationships: SensitiveDataScannerConfigurationRelationships,
+    #[serde(rename = "type")]
+    /// Sensitive Data Scanner configuration type.
+    pub typ: SensitiveDataScannerConfigurationType,
+}
+
+/// Reorder scanning groups request.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SensitiveDataScannerConfigRequest {
+    /// Data related to the reordering of scanning groups.
+    pub data: SensitiveDataScannerReorderConfig,
+    /// Meta carrying the version of the configuration.
+    pub meta: SensitiveDataScannerMetaVersionOnly,
+}
+
+/// Response carrying only the new version of the configuration.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SensitiveDataScannerVersionResponse {
+    /// Meta carrying the version of the configuration.
+    pub meta: Option<SensitiveDataScannerMetaVersionOnly>,
+}
+
+/// Data related to the creation of a scanning group.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SensitiveDataScannerGroupCreate {
+    /// Attributes of the group.
+    pub attributes: SensitiveDataScannerGroupAttributes,
+    /// Relationships of the group, such as the configuration it belongs to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationships: Option<SensitiveDataScannerGroupRelationships>,
+    #[serde(rename = "type")]
+    /// Sensitive Data Scanner group type.
+    pub typ: SensitiveDataScannerGroupType,
+}
+
+/// Create scanning group request.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SensitiveDataScannerGroupCreateRequest {
+    /// Data related to the creation of a group.
+    pub data: SensitiveDataScannerGroupCreate,
+    /// Meta carrying the version of the configuration.
+    pub meta: SensitiveDataScannerMetaVersionOnly,
+}
+
+/// A scanning group returned by the API.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SensitiveDataScannerGroupResponse {
+    /// Attributes of the group.
+    pub attributes: Option<SensitiveDataScannerGroupAttributes>,
+    /// ID of the group.
+    pub id: Option<String>,
+    /// Relationships of the group.
+    pub relationships: Option<SensitiveDataScannerGroupRelationships>,
+    #[serde(rename = "type")]
+    /// Sensitive Data Scanner group type.
+    pub typ: Option<SensitiveDataScannerGroupType>,
+}
+
+/// Create scanning group response.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SensitiveDataScannerCreateGroupResponse {
+    /// The created group.
+    pub data: Option<SensitiveDataScannerGroupResponse>,
+    /// Meta carrying the new version of the configuration.
+    pub meta: Option<SensitiveDataScannerMetaVersionOnly>,
+}
+
+/// Data related to the update of a scanning group.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SensitiveDataScannerGroupUpdate {
+    /// Attributes of the group.
+    pub attributes: SensitiveDataScannerGroupAttributes,
+    /// ID of the group.
+    pub id: String,
+    /// Relationships of the group. The given rules replace the current ones, in that order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationships: Option<SensitiveDataScannerGroupRelationships>,
+    #[serde(rename = "type")]
+    /// Sensitive Data Scanner group type.
+    pub typ: SensitiveDataScannerGroupType,
+}
+
+/// Update scanning group request.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SensitiveDataScannerGroupUpdateRequest {
+    /// Data related to the update of a group.
+    pub data: SensitiveDataScannerGroupUpdate,
+    /// Meta carrying the version of the configuration.
+    pub meta: SensitiveDataScannerMetaVersionOnly,
+}
+
+/// Delete scanning group or rule request.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SensitiveDataScannerDeleteRequest {
+    /// Meta carrying the version of the configuration.
+    pub meta: SensitiveDataScannerMetaVersionOnly,
+}
+
+/// Path parameters and body to update a scanning group.
+#[derive(Debug)]
+pub struct UpdateScanningGroupRequest {
+    /// The ID of a group of rules.
+    pub group_id: String,
+    /// The changes to the group.
+    pub group: SensitiveDataScannerGroupUpdateRequest,
+}
+
+/// Path parameters and body to delete a scanning group.
+#[derive(Debug)]
+pub struct DeleteScanningGroupRequest {
+    /// The ID of a group of rules.
+    pub group_id: String,
+    /// The version of the configuration the deletion is based on.
+    pub body: SensitiveDataScannerDeleteRequest,
+}
+
+/// Type of the replacement text.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SensitiveDataScannerTextReplacementType {
+    /// Equals to `none`.
+    #[serde(rename = "none")]
+    None,
+    /// Equals to `hash`.
+    #[serde(rename = "hash")]
+    Hash,
+    /// Equals to `replacement_string`.
+    #[serde(rename = "replacement_string")]
+    ReplacementString,
+    /// Equals to `partial_replacement_from_beginning`.
+    #[serde(rename = "partial_replacement_from_beginning")]
+    PartialReplacementFromBeginning,
+    /// Equals to `partial_replacement_from_end`.
+    #[serde(rename = "partial_replacement_from_end")]
+    PartialReplacementFromEnd,
+}
+
+/// Object describing how the scanned event is replaced.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SensitiveDataScannerTextReplacement {
+    /// Required if type == `partial_replacement_from_beginning` or
+    /// `partial_replacement_from_end`. It must be > 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number_of_chars: Option<i64>,
+    /// Required if type == `replacement_string`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replacement_string: Option<String>,
+    #[serde(rename = "type")]
+    /// Type of the replacement text.
+    pub typ: SensitiveDataScannerTextReplacementType,
+}
+
+/// Attributes of the Sensitive Data Scanner rule.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SensitiveDataScannerRuleAttributes {
+    /// Description of the rule.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Attributes excluded from the scan. If namespaces is provided, it has to be a sub-path of
+    /// the namespaces array.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excluded_namespaces: Option<Vec<String>>,
+    /// Whether or not the rule is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_enabled: Option<bool>,
+    /// Name of the rule.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Attributes included in the scan. If namespaces is empty or missing, all attributes except
+    /// excluded_namespaces are scanned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespaces: Option<Vec<String>>,
+    /// Not included if there is a relationship to a standard pattern.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    /// Integer from 1 (high) to 5 (low) indicating rule issue severity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i64>,
+    /// List of tags.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// Object describing how the scanned event is replaced.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_replacement: Option<SensitiveDataScannerTextReplacement>,
+}
+
+/// Relationship to a standard pattern.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SensitiveDataScannerStandardPattern {
+    /// ID of the standard pattern.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// Sensitive Data Scanner standard pattern type.
+    pub typ: SensitiveDataScannerStandardPatternType,
+}
+
+/// A standard pattern data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SensitiveDataScannerStandardPatternData {
+    /// A standard pattern.
+    pub data: SensitiveDataScannerStandardPattern,
+}
+
+/// Relationships of the rule.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SensitiveDataScannerRuleRelationships {
+    /// The group the rule belongs to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<SensitiveDataScannerGroupData>,
+    /// The standard pattern the rule is based on, in place of its own `pattern`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub standard_pattern: Option<SensitiveDataScannerStandardPatternData>,
+}
+
+/// Data related to the creation of a rule.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SensitiveDataScannerRuleCreate {
+    /// Attributes of the rule.
+    pub attributes: SensitiveDataScannerRuleAttributes,
+    /// Relationships of the rule. The group is required.
+    pub relationships: SensitiveDataScannerRuleRelationships,
+    #[serde(rename = "type")]
+    /// Sensitive Data Scanner rule type.
+    pub typ: SensitiveDataScannerRuleType,
+}
+
+/// Create rule request.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SensitiveDataScannerRuleCreateRequest {
+    /// Data related to the creation of a rule.
+    pub data: SensitiveDataScannerRuleCreate,
+    /// Meta carrying the version of the configuration.
+    pub meta: SensitiveDataScannerMetaVersionOnly,
+}
+
+/// A scanning rule returned by the API.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SensitiveDataScannerRuleResponse {
+    /// Attributes of the rule.
+    pub attributes: Option<SensitiveDataScannerRuleAttributes>,
+    /// ID of the rule.
+    pub id: Option<String>,
+    /// Relationships of the rule.
+    pub relationships: Option<SensitiveDataScannerRuleRelationships>,
+    #[serde(rename = "type")]
+    /// Sensitive Data Scanner rule type.
+    pub typ: Option<SensitiveDataScannerRuleType>,
+}
+
+/// Create rule response.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SensitiveDataScannerCreateRuleResponse {
+    /// The created rule.
+    pub data: Option<SensitiveDataScannerRuleResponse>,
+    /// Meta carrying the new version of the configuration.
+    pub meta: Option<SensitiveDataScannerMetaVersionOnly>,
+}
+
+/// Data related to the update of a rule.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SensitiveDataScannerRuleUpdate {
+    /// Attributes of the rule.
+    pub attributes: SensitiveDataScannerRuleAttributes,
+    /// ID of the rule.
+    pub id: String,
+    /// Relationships of the rule. The group of a rule cannot be changed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationships: Option<SensitiveDataScannerRuleRelationships>,
+    #[serde(rename = "type")]
+    /// Sensitive Data Scanner rule type.
+    pub typ: SensitiveDataScannerRuleType,
+}
+
+/// Update rule request.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SensitiveDataScannerRuleUpdateRequest {
+    /// Data related to the update of a rule.
+    pub data: SensitiveDataScannerRuleUpdate,
+    /// Meta carrying the version of the configuration.
+    pub meta: SensitiveDataScannerMetaVersionOnly,
+}
+
+/// Path parameters and body to update a scanning rule.
+#[derive(Debug)]
+pub struct UpdateScanningRuleRequest {
+    /// The ID of the rule.
+    pub rule_id: String,
+    /// The changes to the rule.
+    pub rule: SensitiveDataScannerRuleUpdateRequest,
+}
+
+/// Path parameters and body to delete a scanning rule.
+#[derive(Debug)]
+pub struct DeleteScanningRuleRequest {
+    /// The ID of the rule.
+    pub rule_id: String,
+    /// The version of the configuration the deletion is based on.
+    pub body: SensitiveDataScannerDeleteRequest,
+}
+
+/// Attributes of the Sensitive Data Scanner standard pattern.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SensitiveDataScannerStandardPatternAttributes {
+    /// Description of the standard pattern.
+    pub description: Option<String>,
+    /// List of included keywords.
+    pub included_keywords: Option<Vec<String>>,
+    /// Name of the standard pattern.
+    pub name: Option<String>,
+    /// Regex to match.
+    pub pattern: Option<String>,
+    /// Integer from 1 (high) to 5 (low) indicating standard pattern issue severity.
+    pub priority: Option<i64>,
+    /// List of tags.
+    pub tags: Option<Vec<String>>,
+}
+
+/// A standard pattern of the library.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SensitiveDataScannerStandardPatternsResponseItem {
+    /// Attributes of the standard pattern.
+    pub attributes: Option<SensitiveDataScannerStandardPatternAttributes>,
+    /// ID of the standard pattern.
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    /// Sensitive Data Scanner standard pattern type.
+    pub typ: Option<SensitiveDataScannerStandardPatternType>,
+}
+
+/// List standard patterns response.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SensitiveDataScannerStandardPatternsResponse {
+    /// List of standard patterns.
+    pub data: Vec<SensitiveDataScannerStandardPatternsResponseItem>,
+}
+
+// Responses and requests
+/// Reorder scanning groups response.
+pub type SensitiveDataScannerReorderGroupsResponse = SensitiveDataScannerVersionResponse;
+/// Update scanning group response.
+pub type SensitiveDataScannerGroupUpdateResponse = SensitiveDataScannerVersionResponse;
+/// Delete scanning group response.
+pub type SensitiveDataScannerGroupDeleteResponse = SensitiveDataScannerVersionResponse;
+/// Update rule response.
+pub type SensitiveDataScannerRuleUpdateResponse = SensitiveDataScannerVersionResponse;
+/// Delete rule response.
+pub type SensitiveDataScannerRuleDeleteResponse = SensitiveDataScannerVersionResponse;
@@ -0,0 +1,502 @@
+use std::collections::BTreeMap;
+
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use super::client::EmptyResponse;
+use crate::error::{Error, YamlError};
+
+/// Service definition type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ServiceDefinitionType {
+    /// Equals to `service-definition`.
+    #[serde(rename = "service-definition")]
+    ServiceDefinition,
+}
+
+/// Schema versions of a service definition.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ServiceDefinitionSchemaVersion {
+    /// Equals to `v1`.
+    #[serde(rename = "v1")]
+    V1,
+    /// Equals to `v2`.
+    #[serde(rename = "v2")]
+    V2,
+    /// Equals to `v2.1`.
+    #[serde(rename = "v2.1")]
+    V2Dot1,
+    /// Equals to `v2.2`.
+    #[serde(rename = "v2.2")]
+    V2Dot2,
+}
+
+/// Contact type of a service definition.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ServiceDefinitionContactType {
+    /// Equals to `email`.
+    #[serde(rename = "email")]
+    Email,
+    /// Equals to `slack`.
+    #[serde(rename = "slack")]
+    Slack,
+    /// Equals to `microsoft-teams`.
+    #[serde(rename = "microsoft-teams")]
+    MicrosoftTeams,
+}
+
+/// Service owner's contacts information, for schemas v2 and v2.1.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceDefinitionContact {
+    /// Contact value, such as an email address, a Slack channel URL or a Microsoft Teams URL.
+    pub contact: String,
+    /// Contact name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "type")]
+    /// Contact type.
+    pub typ: ServiceDefinitionContactType,
+}
+
+/// Service owner's contacts information, for schema v2.2.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceDefinitionV2Dot2Contact {
+    /// Contact value.
+    pub contact: String,
+    /// Contact name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "type")]
+    /// Contact type, such as `email`, `slack` or `microsoft-teams`. Any value is accepted.
+    pub typ: String,
+}
+
+/// Service documents or repositories, for schema v2.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceDefinitionV2Doc {
+    /// Document or repository name.
+    pub name: String,
+    /// Document or repository provider, such as `Confluence` or `GitHub`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider: Option<String>,
+    /// Document or repository URL.
+    pub url: String,
+}
+
+/// Service's external links.
+///
+/// The accepted `type` values depend on the schema version: v2 accepts `doc`, `wiki`, `runbook`,
+/// `url`, `repo`, `dashboard`, `oncall`, `code` and `link`; v2.1 accepts `doc`, `repo`,
+/// `runbook`, `dashboard` and `other`; v2.2 accepts any value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceDefinitionLink {
+    /// Link name.
+    pub name: String,
+    /// Link provider. Not part of schema v2.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider: Option<String>,
+    #[serde(rename = "type")]
+    /// Link type.
+    pub typ: String,
+    /// Link URL.
+    pub url: String,
+}
+
+/// Opsgenie integration for the service.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceDefinitionOpsgenie {
+    /// Opsgenie instance region, `US` or `EU`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    /// Opsgenie service URL.
+    #[serde(rename = "service-url")]
+    pub service_url: String,
+}
+
+/// PagerDuty integration for the service, for schemas v2.1 and v2.2.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceDefinitionPagerduty {
+    /// PagerDuty service URL.
+    #[serde(rename = "service-url", skip_serializing_if = "Option::is_none")]
+    pub service_url: Option<String>,
+}
+
+/// Third party integrations that Datadog supports, for schema v2.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ServiceDefinitionV2Integrations {
+    /// Opsgenie integration for the service.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub opsgenie: Option<ServiceDefinitionOpsgenie>,
+    /// PagerDuty service URL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pagerduty: Option<String>,
+}
+
+/// Third party integrations that Datadog supports, for schemas v2.1 and v2.2.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ServiceDefinitionV2Dot1Integrations {
+    /// Opsgenie integration for the service.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub opsgenie: Option<ServiceDefinitionOpsgenie>,
+    /// PagerDuty integration for the service.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pagerduty: Option<ServiceDefinitionPagerduty>,
+}
+
+/// Service definition v2 for providing service metadata and integrations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceDefinitionV2 {
+    /// A list of contacts related to the services.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contacts: Option<Vec<ServiceDefinitionContact>>,
+    /// Unique identifier of the service. Must be unique across all services and is used to match with a service in Datadog.
+    #[serde(rename = "dd-service")]
+    pub dd_service: String,
+    /// Experimental feature. A Team handle that matches a Team in the Datadog Teams product.
+    #[serde(rename = "dd-team", skip_serializing_if = "Option::is_none")]
+    pub dd_team: Option<String>,
+    /// A list of documentation related to the services.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub docs: Option<Vec<ServiceDefinitionV2Doc>>,
+    /// Extensions to the schema, for custom metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<BTreeMap<String, Value>>,
+    /// Third party integrations that Datadog supports.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integrations: Option<ServiceDefinitionV2Integrations>,
+    /// A list of links related to the services.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub links: Option<Vec<ServiceDefinitionLink>>,
+    /// A list of code repositories related to the services.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repos: Option<Vec<ServiceDefinitionV2Doc>>,
+    /// A set of custom tags.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// Team that owns the service.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team: Option<String>,
+}
+
+/// Service definition v2.1 for providing service metadata and integrations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceDefinitionV2Dot1 {
+    /// Identifier for a group of related services serving a product feature, which the service is a part of.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application: Option<String>,
+    /// A list of contacts related to the services.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contacts: Option<Vec<ServiceDefinitionContact>>,
+    /// Unique identifier of the service. Must be unique across all services and is used to match with a service in Datadog.
+    #[serde(rename = "dd-service")]
+    pub dd_service: String,
+    /// A short description of the service.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Extensions to the schema, for custom metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<BTreeMap<String, Value>>,
+    /// Third party integrations that Datadog supports.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integrations: Option<ServiceDefinitionV2Dot1Integrations>,
+    /// The current life cycle phase of the service, such as `production`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lifecycle: Option<String>,
+    /// A list of links related to the services.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub links: Option<Vec<ServiceDefinitionLink>>,
+    /// A set of custom tags.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// Team that owns the service.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team: Option<String>,
+    /// Importance of the service.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tier: Option<String>,
+}
+
+/// The type of service, for schema v2.2.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ServiceDefinitionV2Dot2Type {
+    /// Equals to `web`.
+    Web,
+    /// Equals to `db`.
+    Db,
+    /// Equals to `cache`.
+    Cache,
+    /// Equals to `function`.
+    Function,
+    /// Equals to `browser`.
+    Browser,
+    /// Equals to `mobile`.
+    Mobile,
+    /// Equals to `custom`.
+    Custom,
+}
+
+/// Service definition v2.2 for providing service metadata and integrations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceDefinitionV2Dot2 {
+    /// Identifier for a group of related services serving a product feature, which the service is a part of.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application: Option<String>,
+    /// A set of CI fingerprints.
+    #[serde(
+        rename = "ci-pipeline-fingerprints",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ci_pipeline_fingerprints: Option<Vec<String>>,
+    /// A list of contacts related to the services.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contacts: Option<Vec<ServiceDefinitionV2Dot2Contact>>,
+    /// Unique identifier of the service. Must be unique across all services and is used to match with a service in Datadog.
+    #[serde(rename = "dd-service")]
+    pub dd_service: String,
+    /// A short description of the service.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Extensions to the schema, for custom metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<BTreeMap<String, Value>>,
+    /// Third party integrations that Datadog supports.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integrations: Option<ServiceDefinitionV2Dot1Integrations>,
+    /// The service's programming language, such as `go` or `python`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub languages: Option<Vec<String>>,
+    /// The current life cycle phase of the service, such as `production`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lifecycle: Option<String>,
+    /// A list of links related to the services.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub links: Option<Vec<ServiceDefinitionLink>>,
+    /// A set of custom tags.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// Team that owns the service.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team: Option<String>,
+    /// Importance of the service.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tier: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    /// The type of service.
+    pub typ: Option<ServiceDefinitionV2Dot2Type>,
+}
+
+/// A service definition, tagged by its `schema-version`.
+///
+/// Schema versions this client does not model, such as `v1`, are kept as
+/// [ServiceDefinitionSchema::Unknown] with the raw definition.
+#[derive(Debug, Clone)]
+pub enum ServiceDefinitionSchema {
+    /// Equals to `v2`.
+    V2(ServiceDefinitionV2),
+    /// Equals to `v2.1`.
+    V2Dot1(ServiceDefinitionV2Dot1),
+    /// Equals to `v2.2`.
+    V2Dot2(ServiceDefinitionV2Dot2),
+    /// Any other schema version.
+    Unknown(Value),
+}
+
+impl ServiceDefinitionSchema {
+    /// The `schema-version` of the definition.
+    pub fn schema_version(&self) -> Option<&str> {
+        match self {
+            ServiceDefinitionSchema::V2(_) => Some("v2"),
+            ServiceDefinitionSchema::V2Dot1(_) => Some("v2.1"),
+            ServiceDefinitionSchema::V2Dot2(_) => Some("v2.2"),
+            ServiceDefinitionSchema::Unknown(value) => {
+                value.get("schema-version").and_then(Value::as_str)
+            }
+        }
+    }
+
+    /// The `dd-service` of the definition.
+    pub fn dd_service(&self) -> Option<&str> {
+        match self {
+            ServiceDefinitionSchema::V2(schema) => Some(&schema.dd_service),
+            ServiceDefinitionSchema::V2Dot1(schema) => Some(&schema.dd_service),
+            ServiceDefinitionSchema::V2Dot2(schema) => Some(&schema.dd_service),
+            ServiceDefinitionSchema::Unknown(value) => {
+                value.get("dd-service").and_then(Value::as_str)
+            }
+        }
+    }
+
+    /// Parses a service definition, such as the content of a `service.datadog.yaml` file.
+    ///
+    /// JSON being a subset of YAML, JSON definitions are accepted as well.
+    pub fn from_yaml(definition: &str) -> Result<Self, Error> {
+        serde_norway::from_str(definition)
+            .map_err(|err| Error::YamlDeserializationError(YamlError(err)))
+    }
+
+    /// Parses a JSON service definition.
+    pub fn from_json(definition: &str) -> Result<Self, Error> {
+        Ok(serde_json::from_str(definition)?)
+    }
+}
+
+impl Serialize for ServiceDefinitionSchema {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let value = match self {
+            ServiceDefinitionSchema::V2(schema) => serde_json::to_value(schema),
+            ServiceDefinitionSchema::V2Dot1(schema) => serde_json::to_value(schema),
+            ServiceDefinitionSchema::V2Dot2(schema) => serde_json::to_value(schema),
+            ServiceDefinitionSchema::Unknown(value) => return value.serialize(serializer),
+        };
+
+        let mut value = value.map_err(S::Error::custom)?;
+        if let (Value::Object(fields), Some(version)) = (&mut value, self.schema_version()) {
+            fields.insert(
+                "schema-version".to_string(),
+                Value::String(version.to_string()),
+            );
+        }
+        value.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ServiceDefinitionSchema {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        let version = value
+            .get("schema-version")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+
+        let schema = match version {
+            "v2" => serde_json::from_value(value).map(ServiceDefinitionSchema::V2),
+            "v2.1" => serde_json::from_value(value).map(ServiceDefinitionSchema::V2Dot1),
+            "v2.2" => serde_json::from_value(value).map(ServiceDefinitionSchema::V2Dot2),
+            _ => return Ok(ServiceDefinitionSchema::Unknown(value)),
+        };
+
+        schema.map_err(D::Error::custom)
+    }
+}
+
+/// Service definition schema warning.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ServiceDefinitionMetaWarnings {
+    /// The warning instance location.
+    #[serde(rename = "instance-location")]
+    pub instance_location: Option<String>,
+    /// The warning keyword location.
+    #[serde(rename = "keyword-location")]
+    pub keyword_location: Option<String>,
+    /// The warning message.
+    pub message: Option<String>,
+}
+
+/// Metadata about a service definition.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ServiceDefinitionMeta {
+    /// GitHub HTML URL.
+    #[serde(rename = "github-html-url")]
+    pub github_html_url: Option<String>,
+    /// Ingestion schema version.
+    #[serde(rename = "ingested-schema-version")]
+    pub ingested_schema_version: Option<String>,
+    /// Ingestion source of the service definition.
+    #[serde(rename = "ingestion-source")]
+    pub ingestion_source: Option<String>,
+    /// Last modified time of the service definition.
+    #[serde(rename = "last-modified-time")]
+    pub last_modified_time: Option<String>,
+    /// User defined origin of the service definition.
+    pub origin: Option<String>,
+    /// User defined origin's detail of the service definition.
+    #[serde(rename = "origin-detail")]
+    pub origin_detail: Option<String>,
+    /// A list of schema validation warnings.
+    pub warnings: Option<Vec<ServiceDefinitionMetaWarnings>>,
+}
+
+/// Service definition attributes.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ServiceDefinitionDataAttributes {
+    /// Metadata about a service definition.
+    pub meta: Option<ServiceDefinitionMeta>,
+    /// The service definition.
+    pub schema: Option<ServiceDefinitionSchema>,
+}
+
+/// Service definition data.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ServiceDefinitionData {
+    /// Service definition attributes.
+    pub attributes: Option<ServiceDefinitionDataAttributes>,
+    /// Service definition id.
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    /// Service definition type.
+    pub typ: Option<ServiceDefinitionType>,
+}
+
+/// Query parameters to list service definitions.
+#[derive(Debug, Default, Serialize)]
+pub struct ListServiceDefinitionsRequest {
+    /// Size for a given page. The maximum allowed value is 100.
+    #[serde(rename = "page[size]", skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<i64>,
+    /// Specific page number to return.
+    #[serde(rename = "page[number]", skip_serializing_if = "Option::is_none")]
+    pub page_number: Option<i64>,
+    /// The schema version desired in the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_version: Option<ServiceDefinitionSchemaVersion>,
+}
+
+/// Path and query parameters to get a service definition.
+#[derive(Debug, Serialize)]
+pub struct GetServiceDefinitionRequest {
+    /// The name of the service.
+    #[serde(skip)]
+    pub service_name: String,
+    /// The schema version desired in the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_version: Option<ServiceDefinitionSchemaVersion>,
+}
+
+/// Path parameters to delete a service definition.
+#[derive(Debug)]
+pub struct DeleteServiceDefinitionRequest {
+    /// The name of the service.
+    pub service_name: String,
+}
+
+/// Response with a single service definition.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ServiceDefinitionGetResponse {
+    /// Service definition data.
+    pub data: Option<ServiceDefinitionData>,
+}
+
+/// Response with multiple service definitions.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ServiceDefinitionsListResponse {
+    /// Data representing service definitions.
+    pub data: Option<Vec<ServiceDefinitionData>>,
+}
+
+// Responses and requests
+/// Response with the created or updated service definitions.
+pub type ServiceDefinitionCreateResponse = ServiceDefinitionsListResponse;
+/// Response to the deletion of a service definition.
+pub type DeleteServiceDefinitionResponse = EmptyResponse;
+
+/// The service definition to create or update.
+pub type CreateOrUpdateServiceDefinitionRequest = ServiceDefinitionSchema;
@@ -0,0 +1,233 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::client::{EmptyResponse, Request, Response};
+
+/// SLO correction resource type.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SloCorrectionType {
+    /// Equals to `correction`.
+    #[serde(rename = "correction")]
+    Correction,
+}
+
+/// Category the SLO correction belongs to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SloCorrectionCategory {
+    /// Equals to `Scheduled Maintenance`.
+    #[serde(rename = "Scheduled Maintenance")]
+    ScheduledMaintenance,
+    /// Equals to `Outside Business Hours`.
+    #[serde(rename = "Outside Business Hours")]
+    OutsideBusinessHours,
+    /// Equals to `Deployment`.
+    Deployment,
+    /// Equals to `Other`.
+    Other,
+}
+
+/// Attributes of the user who created or modified an SLO correction.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SloCorrectionUserAttributes {
+    /// Email of the user.
+    pub email: Option<String>,
+    /// Handle of the user.
+    pub handle: Option<String>,
+    /// Name of the user.
+    pub name: Option<String>,
+}
+
+/// The user who created or modified an SLO correction.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SloCorrectionUser {
+    /// Attributes of the user.
+    pub attributes: Option<SloCorrectionUserAttributes>,
+    /// ID of the user.
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    /// Users type. Always `users`.
+    pub typ: Option<String>,
+}
+
+/// The attribute object associated with the SLO correction.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SloCorrectionAttributes {
+    /// Category the SLO correction belongs to.
+    pub category: Option<SloCorrectionCategory>,
+    /// The epoch timestamp of when the correction was created at.
+    pub created_at: Option<i64>,
+    /// The user who created the correction.
+    pub creator: Option<SloCorrectionUser>,
+    /// Description of the correction being made.
+    pub description: Option<String>,
+    /// Length of time (in seconds) for a specified `rrule` recurring SLO correction.
+    pub duration: Option<i64>,
+    /// Ending time of the correction in epoch seconds.
+    pub end: Option<i64>,
+    /// The epoch timestamp of when the correction was modified at.
+    pub modified_at: Option<i64>,
+    /// The user who last modified the correction.
+    pub modifier: Option<SloCorrectionUser>,
+    /// The recurrence rules as defined in the iCalendar RFC 5545.
+    /// The supported rules for SLO corrections are `FREQ`, `INTERVAL`, `COUNT`, and `UNTIL`.
+    pub rrule: Option<String>,
+    /// ID of the SLO that this correction applies to.
+    pub slo_id: Option<String>,
+    /// Starting time of the correction in epoch seconds.
+    pub start: Option<i64>,
+    /// The timezone to display in the UI for the correction times (defaults to "UTC").
+    pub timezone: Option<String>,
+}
+
+/// The response object of a list of SLO corrections.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SloCorrection {
+    /// The attribute object associated with the SLO correction.
+    pub attributes: Option<SloCorrectionAttributes>,
+    /// The ID of the SLO correction.
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    /// SLO correction resource type.
+    pub typ: Option<SloCorrectionType>,
+}
+
+/// The attribute object associated with the SLO correction to be created.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SloCorrectionCreateAttributes {
+    /// Category the SLO correction belongs to.
+    pub category: SloCorrectionCategory,
+    /// Description of the correction being made.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Length of time (in seconds) for a specified `rrule` recurring SLO correction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    /// Ending time of the correction in epoch seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<i64>,
+    /// The recurrence rules as defined in the iCalendar RFC 5545.
+    /// The supported rules for SLO corrections are `FREQ`, `INTERVAL`, `COUNT`, and `UNTIL`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rrule: Option<String>,
+    /// ID of the SLO that this correction applies to.
+    pub slo_id: String,
+    /// Starting time of the correction in epoch seconds.
+    pub start: i64,
+    /// The timezone to display in the UI for the correction times (defaults to "UTC").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+}
+
+/// The data object associated with the SLO correction to be created.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SloCorrectionCreateData {
+    /// The attribute object associated with the SLO correction to be created.
+    pub attributes: SloCorrectionCreateAttributes,
+    #[serde(rename = "type")]
+    /// SLO correction resource type.
+    pub typ: SloCorrectionType,
+}
+
+/// The attribute object associated with the SLO correction to be updated.
+/// Only the given attributes are changed.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SloCorrectionUpdateAttributes {
+    /// Category the SLO correction belongs to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<SloCorrectionCategory>,
+    /// Description of the correction being made.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Length of time (in seconds) for a specified `rrule` recurring SLO correction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    /// Ending time of the correction in epoch seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<i64>,
+    /// The recurrence rules as defined in the iCalendar RFC 5545.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rrule: Option<String>,
+    /// Starting time of the correction in epoch seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<i64>,
+    /// The timezone to display in the UI for the correction times (defaults to "UTC").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+}
+
+/// The data object associated with the SLO correction to be updated.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SloCorrectionUpdateData {
+    /// The attribute object associated with the SLO correction to be updated.
+    pub attributes: SloCorrectionUpdateAttributes,
+    #[serde(rename = "type")]
+    /// SLO correction resource type.
+    pub typ: SloCorrectionType,
+}
+
+/// Query parameters to list SLO corrections.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ListSloCorrectionsRequest {
+    /// The specific offset to use as the beginning of the returned response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i64>,
+    /// The number of SLO corrections to return in the response. Default is 25.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+}
+
+/// Pagination metadata of a list of SLO corrections.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SloCorrectionListMetaPage {
+    /// The total number of resources that could be retrieved ignoring the parameters and filters in the request.
+    pub total_count: Option<i64>,
+    /// The total number of resources that match the parameters and filters in the request.
+    pub total_filtered_count: Option<i64>,
+}
+
+/// The metadata object containing additional information about the list of SLO corrections.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SloCorrectionListMeta {
+    /// Pagination metadata of a list of SLO corrections.
+    pub page: Option<SloCorrectionListMetaPage>,
+}
+
+/// A list of SLO correction objects.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListSloCorrectionsResponse {
+    /// The list of SLO corrections objects.
+    pub data: Option<Vec<SloCorrection>>,
+    /// The metadata object containing additional information about the list of SLO corrections.
+    pub meta: Option<SloCorrectionListMeta>,
+}
+
+/// Path parameters to get an SLO correction.
+#[derive(Debug)]
+pub struct GetSloCorrectionRequest {
+    /// The ID of the SLO correction object.
+    pub slo_correction_id: String,
+}
+
+/// Path parameters and body to update an SLO correction.
+#[derive(Debug)]
+pub struct UpdateSloCorrectionRequest {
+    /// The ID of the SLO correction object.
+    pub slo_correction_id: String,
+    /// The SLO correction attributes to update.
+    pub correction: Request<SloCorrectionUpdateData>,
+}
+
+/// Path parameters to delete an SLO correction.
+#[derive(Debug)]
+pub struct DeleteSloCorrectionRequest {
+    /// The ID of the SLO correction object.
+    pub slo_correction_id: String,
+}
+
+// Responses and requests
+/// The SLO correction.
+pub type SloCorrectionResponse = Response<SloCorrection>;
+/// Response to the deletion of an SLO correction.
+pub type DeleteSloCorrectionResponse = EmptyResponse;
+
+/// The SLO correction to create.
+pub type CreateSloCorrectionRequest = Request<SloCorrectionCreateData>;
@@ -0,0 +1,674 @@
+use std::collections::BTreeMap;
+
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The SLO time window options.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum SloTimeframe {
+    /// Equals to `7d`.
+    #[serde(rename = "7d")]
+    SevenDays,
+    /// Equals to `30d`.
+    #[serde(rename = "30d")]
+    ThirtyDays,
+    /// Equals to `90d`.
+    #[serde(rename = "90d")]
+    NinetyDays,
+    /// Equals to `custom`.
+    #[serde(rename = "custom")]
+    Custom,
+}
+
+/// The type of the service level objective.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SloType {
+    /// Equals to `metric`.
+    Metric,
+    /// Equals to `monitor`.
+    Monitor,
+    /// Equals to `time_slice`.
+    TimeSlice,
+}
+
+/// SLO thresholds (target and optionally warning) for a single time window.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SloThreshold {
+    /// The target value for the service level indicator within the corresponding timeframe.
+    pub target: f64,
+    /// A string representation of the target that indicates its precision.
+    /// It uses trailing zeros to show significant decimal places (for example `98.00`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_display: Option<String>,
+    /// The SLO time window options.
+    pub timeframe: SloTimeframe,
+    /// The warning value for the service level objective.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<f64>,
+    /// A string representation of the warning target (see the description of the `target_display` field for details).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning_display: Option<String>,
+}
+
+/// A metric-based SLO. The ratio of the sum of the numerator and denominator is used as the SLI.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SloMetricQuery {
+    /// A Datadog metric query for total (valid) events.
+    pub denominator: String,
+    /// A Datadog metric query for good events.
+    pub numerator: String,
+}
+
+/// The comparator used to compare the SLI value to the threshold.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SloTimeSliceComparator {
+    /// Equals to `>`.
+    #[serde(rename = ">")]
+    Greater,
+    /// Equals to `>=`.
+    #[serde(rename = ">=")]
+    GreaterEqual,
+    /// Equals to `<`.
+    #[serde(rename = "<")]
+    Less,
+    /// Equals to `<=`.
+    #[serde(rename = "<=")]
+    LessEqual,
+}
+
+/// A formula that specifies how to combine the results of multiple queries.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SloFormula {
+    /// The formula string, which is an expression involving named queries.
+    pub formula: String,
+}
+
+/// A metric query of a time-slice SLO.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SloDataSourceQueryDefinition {
+    /// Data source for metrics queries. Should always be `metrics`.
+    pub data_source: String,
+    /// Name of the query for use in formulas.
+    pub name: String,
+    /// Metrics query definition.
+    pub query: String,
+}
+
+/// The queries and formula used to calculate the SLI value of a time-slice SLO.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SloTimeSliceQuery {
+    /// A list that contains exactly one formula, as only a single formula may be used in a time slice SLO.
+    pub formulas: Vec<SloFormula>,
+    /// A list of queries that are used to calculate the SLI value.
+    pub queries: Vec<SloDataSourceQueryDefinition>,
+}
+
+/// The time-slice condition, composed of 3 parts: the metric timeseries query,
+/// the comparator, and the threshold.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SloTimeSliceCondition {
+    /// The comparator used to compare the SLI value to the threshold.
+    pub comparator: SloTimeSliceComparator,
+    /// The queries and formula used to calculate the SLI value.
+    pub query: SloTimeSliceQuery,
+    /// The interval used when querying data, in seconds: `60` or `300`. Defaults to `300`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_interval_seconds: Option<i64>,
+    /// The threshold value to which each SLI value will be compared.
+    pub threshold: f64,
+}
+
+/// A generic SLI specification. This is currently used for time-slice SLOs only.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SloSliSpecification {
+    /// The time-slice condition.
+    pub time_slice: SloTimeSliceCondition,
+}
+
+/// How the SLI of a service level objective is computed, tagged by its `type`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum SloDefinition {
+    /// Equals to `metric`.
+    #[serde(rename = "metric")]
+    Metric {
+        /// The good and total events queries.
+        query: SloMetricQuery,
+    },
+    /// Equals to `monitor`.
+    #[serde(rename = "monitor")]
+    Monitor {
+        /// A list of monitor IDs that defines the scope of a monitor service level objective.
+        monitor_ids: Vec<i64>,
+        /// A list of (up to 100) monitor groups that narrow the scope of a monitor service level objective.
+        /// Included in service level objective responses if it is not empty.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        groups: Option<Vec<String>>,
+    },
+    /// Equals to `time_slice`.
+    #[serde(rename = "time_slice")]
+    TimeSlice {
+        /// The time-slice condition.
+        sli_specification: SloSliSpecification,
+    },
+}
+
+/// Object describing the creator of a service level objective.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SloCreator {
+    /// Email of the creator.
+    pub email: Option<String>,
+    /// Handle of the creator.
+    pub handle: Option<String>,
+    /// Name of the creator.
+    pub name: Option<String>,
+}
+
+/// A service level objective object.
+///
+/// Fields set by the API are skipped when the SLO is sent back, so that an SLO read
+/// from the API can be created or updated as is.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ServiceLevelObjective {
+    /// A list of SLO monitors IDs that reference this SLO.
+    /// Only returned when `with_configured_alert_ids` is set.
+    #[serde(default, skip_serializing)]
+    pub configured_alert_ids: Option<Vec<i64>>,
+    /// Creation timestamp (UNIX time in seconds).
+    /// This value is set by the API.
+    #[serde(default, skip_serializing)]
+    pub created_at: Option<i64>,
+    /// Object describing the creator of the SLO.
+    /// This value is set by the API.
+    #[serde(default, skip_serializing)]
+    pub creator: Option<SloCreator>,
+    /// A user-defined description of the service level objective.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// A unique identifier for the service level objective object.
+    /// This value is set by the API.
+    #[serde(default, skip_serializing)]
+    pub id: Option<String>,
+    /// Modification timestamp (UNIX time in seconds).
+    /// This value is set by the API.
+    #[serde(default, skip_serializing)]
+    pub modified_at: Option<i64>,
+    /// The name of the service level objective object.
+    pub name: String,
+    /// A list of tags associated with this service level objective.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// The target threshold such that when the service level indicator is above this threshold
+    /// over the given timeframe, the objective is being met.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_threshold: Option<f64>,
+    /// The thresholds (timeframes and associated targets) for this service level objective object.
+    pub thresholds: Vec<SloThreshold>,
+    /// The SLO time window options.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeframe: Option<SloTimeframe>,
+    /// The optional warning threshold such that when the service level indicator is below this value
+    /// for the given threshold, but above the target threshold, the objective appears in a "warning" state.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning_threshold: Option<f64>,
+    /// How the SLI is computed: from metrics, monitors or time slices.
+    #[serde(flatten)]
+    pub definition: SloDefinition,
+}
+
+/// Pagination metadata of a list of SLOs.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SloListResponseMetadataPage {
+    /// The total number of resources that could be retrieved ignoring the parameters and filters in the request.
+    pub total_count: Option<i64>,
+    /// The total number of resources that match the parameters and filters in the request.
+    pub total_filtered_count: Option<i64>,
+}
+
+/// The metadata object containing additional information about the list of SLOs.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SloListResponseMetadata {
+    /// The object containing information about the pages of the list of SLOs.
+    pub page: Option<SloListResponseMetadataPage>,
+}
+
+/// A response with one or more service level objective.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SloListResponse {
+    /// An array of service level objective objects.
+    pub data: Option<Vec<ServiceLevelObjective>>,
+    /// An array of error messages.
+    pub errors: Option<Vec<String>>,
+    /// The metadata object containing additional information about the list of SLOs.
+    pub metadata: Option<SloListResponseMetadata>,
+}
+
+/// A service level objective response containing a single service level objective.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SloResponse {
+    /// A service level objective object.
+    pub data: Option<ServiceLevelObjective>,
+    /// An array of error messages.
+    pub errors: Option<Vec<String>>,
+}
+
+/// Query parameters to list SLOs.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ListSlosRequest {
+    /// A comma separated list of the IDs of the service level objectives objects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ids: Option<String>,
+    /// The query string to filter results based on SLO names.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// The query string to filter results based on a single SLO tag.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags_query: Option<String>,
+    /// The query string to filter results based on SLO numerator and denominator.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metrics_query: Option<String>,
+    /// The number of SLOs to return in the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// The specific offset to use as the beginning of the returned response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i64>,
+}
+
+/// Path and query parameters to get an SLO.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetSloRequest {
+    /// The ID of the service level objective object.
+    #[serde(skip)]
+    pub slo_id: String,
+    /// Get the IDs of SLO monitors that reference this SLO.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub with_configured_alert_ids: Option<bool>,
+}
+
+/// Path parameters and body to update an SLO.
+#[derive(Debug)]
+pub struct UpdateSloRequest {
+    /// The ID of the service level objective object.
+    pub slo_id: String,
+    /// The edited service level objective.
+    pub slo: ServiceLevelObjective,
+}
+
+/// Path and query parameters to delete an SLO.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DeleteSloRequest {
+    /// The ID of the service level objective.
+    #[serde(skip)]
+    pub slo_id: String,
+    /// Delete the SLO even if it's referenced by other resources (for example SLO widgets on dashboards).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
+}
+
+/// A response list of all service level objective deleted.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SloDeleteResponse {
+    /// An array containing the ID of the deleted service level objective object.
+    pub data: Option<Vec<String>>,
+    /// An dictionary containing the ID of the SLO as key and a deletion error as value.
+    pub errors: Option<BTreeMap<String, String>>,
+}
+
+/// An array of all SLO timeframes to delete, keyed by SLO ID.
+/// All the thresholds of an SLO deleted this way delete the SLO itself.
+pub type SloBulkDeleteRequest = BTreeMap<String, Vec<SloTimeframe>>;
+
+/// An array of service level objective objects.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SloBulkDeleteResponseData {
+    /// An array of service level objective object IDs that indicates which objects that were completely deleted.
+    pub deleted: Option<Vec<String>>,
+    /// An array of service level objective object IDs that indicates which objects that were modified
+    /// (objects for which at least one threshold was deleted, but that were not completely deleted).
+    pub updated: Option<Vec<String>>,
+}
+
+/// Object describing the error of a bulk deletion.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SloBulkDeleteError {
+    /// The ID of the service level objective object associated with this error.
+    pub id: String,
+    /// The error message.
+    pub message: String,
+    /// The timeframe of the threshold associated with this error or "all" if all thresholds are affected.
+    pub timeframe: String,
+}
+
+/// The bulk partial delete service level objective object endpoint response.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SloBulkDeleteResponse {
+    /// An array of service level objective objects.
+    pub data: Option<SloBulkDeleteResponseData>,
+    /// Array of errors object returned.
+    pub errors: Option<Vec<SloBulkDeleteError>>,
+}
+
+/// Query parameters to check if SLOs can be deleted.
+#[derive(Debug)]
+pub struct CheckCanDeleteSloRequest {
+    /// The IDs of the service level objectives to check.
+    pub ids: Vec<String>,
+}
+
+/// An array of service level objective objects.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CheckCanDeleteSloResponseData {
+    /// An array of service level objective IDs that can be deleted.
+    pub ok: Option<Vec<String>>,
+}
+
+/// A service level objective response containing the requested object.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CheckCanDeleteSloResponse {
+    /// An array of service level objective objects.
+    pub data: Option<CheckCanDeleteSloResponseData>,
+    /// A mapping of SLO ID to the reason why it cannot be deleted.
+    pub errors: Option<BTreeMap<String, String>>,
+}
+
+/// Path and query parameters to get the history of an SLO.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetSloHistoryRequest {
+    /// The ID of the service level objective object.
+    #[serde(skip)]
+    pub slo_id: String,
+    /// The `from` timestamp for the query window in epoch seconds.
+    pub from_ts: i64,
+    /// The `to` timestamp for the query window in epoch seconds.
+    pub to_ts: i64,
+    /// The SLO target. If `target` is passed in, the response includes the remaining error budget
+    /// and a timeframe `custom`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<f64>,
+    /// Defaults to `true`. If any SLO corrections are applied and this parameter is set to `false`,
+    /// then the corrections will not be applied and the SLI values will not be affected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub apply_correction: Option<bool>,
+}
+
+/// An object describing the error with its type.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SloHistoryResponseErrorWithType {
+    /// A message with more details about the error.
+    pub error_message: String,
+    /// Type of the error.
+    pub error_type: String,
+}
+
+/// The SLI values of the SLO, of one of its groups or of one of its monitors.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SloHistorySliData {
+    /// A mapping of threshold timeframe to the remaining error budget.
+    /// The value is null when the error budget cannot be computed.
+    pub error_budget_remaining: Option<BTreeMap<String, Option<f64>>>,
+    /// An array of error objects returned while querying the history data.
+    pub errors: Option<Vec<SloHistoryResponseErrorWithType>>,
+    /// For groups in a grouped SLO, this is the group name.
+    pub group: Option<String>,
+    /// For `monitor` based SLOs, this includes the aggregated history as arrays
+    /// of `[timestamp, state]` pairs, state being 0 (up) or 1 (down).
+    pub history: Option<Vec<Vec<f64>>>,
+    /// For `monitor` based SLOs, this is the last modified timestamp in epoch seconds of the monitor.
+    pub monitor_modified: Option<i64>,
+    /// For `monitor` based SLOs, this describes the type of monitor.
+    pub monitor_type: Option<String>,
+    /// For groups in a grouped SLO, this is the group name.
+    /// For monitors in a multi-monitor SLO, this is the monitor name.
+    pub name: Option<String>,
+    /// A mapping of threshold timeframe to number of accurate decimals, regardless of the from && to timestamp.
+    pub precision: Option<BTreeMap<String, f64>>,
+    /// For `monitor` based SLOs, when `true` this indicates that a replay is in progress
+    /// to give an accurate uptime calculation.
+    pub preview: Option<bool>,
+    /// The current SLI value of the SLO over the history window.
+    pub sli_value: Option<f64>,
+    /// The amount of decimal places the SLI value is accurate to for the given from && to timestamp.
+    pub span_precision: Option<f64>,
+}
+
+/// A representation of `metric` based SLO time series for the provided queries.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SloHistoryMetricsSeries {
+    /// Count of submitted metrics.
+    pub count: i64,
+    /// Query metadata.
+    pub metadata: Option<Value>,
+    /// Total sum of the query.
+    pub sum: f64,
+    /// The query values for each metric.
+    pub values: Vec<f64>,
+}
+
+/// A `metric` based SLO history response.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SloHistoryMetrics {
+    /// The time series of the total events query.
+    pub denominator: SloHistoryMetricsSeries,
+    /// The aggregated query interval for the series data.
+    pub interval: i64,
+    /// Optional message if there are specific query issues/warnings.
+    pub message: Option<String>,
+    /// The time series of the good events query.
+    pub numerator: SloHistoryMetricsSeries,
+    /// The combined numerator and denominator query CSV.
+    pub query: String,
+    /// The series result type. This mimics `batch_query` response type.
+    pub res_type: String,
+    /// The series response version type. This mimics `batch_query` response type.
+    pub resp_version: i64,
+    /// An array of query timestamps in EPOCH milliseconds.
+    pub times: Vec<f64>,
+}
+
+/// The history data of an SLO.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SloHistoryResponseData {
+    /// The `from` timestamp in epoch seconds.
+    pub from_ts: Option<i64>,
+    /// For `metric` based SLOs where the query includes a group-by clause, this represents the list of grouping parameters.
+    /// This is not included in responses for `monitor` based SLOs.
+    pub group_by: Option<Vec<String>>,
+    /// For grouped SLOs, this represents SLI data for specific groups.
+    /// This is not included in the responses for `metric` based SLOs.
+    pub groups: Option<Vec<SloHistorySliData>>,
+    /// For multi-monitor SLOs, this represents SLI data for specific monitors.
+    /// This is not included in the responses for `metric` based SLOs.
+    pub monitors: Option<Vec<SloHistorySliData>>,
+    /// The overall SLI data of the SLO.
+    pub overall: Option<SloHistorySliData>,
+    /// A `metric` based SLO history response.
+    /// This is not included in responses for `monitor` based SLOs.
+    pub series: Option<SloHistoryMetrics>,
+    /// Mapping of string timeframe to the SLO threshold.
+    pub thresholds: Option<BTreeMap<String, SloThreshold>>,
+    /// The `to` timestamp in epoch seconds.
+    pub to_ts: Option<i64>,
+    #[serde(rename = "type")]
+    /// The type of the service level objective.
+    pub typ: Option<SloType>,
+    /// A numeric representation of the type of the service level objective
+    /// (`0` for monitor, `1` for metric). Always included in service level objective responses.
+    pub type_id: Option<i64>,
+}
+
+/// A service level objective history error.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SloHistoryResponseError {
+    /// Human readable error.
+    pub error: Option<String>,
+}
+
+/// A service level objective history response.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SloHistoryResponse {
+    /// The history data of the SLO.
+    pub data: Option<SloHistoryResponseData>,
+    /// A list of errors while querying the history data for the service level objective.
+    pub errors: Option<Vec<SloHistoryResponseError>>,
+}
+
+/// Query parameters to search SLOs.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SearchSlosRequest {
+    /// The query string to filter results based on SLO names.
+    /// Some examples of queries include `service:<service-name>` and `<slo-name>`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// The number of files to return in the response `[default=10]`.
+    #[serde(rename = "page[size]", skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<i64>,
+    /// The identifier of the first page to return.
+    /// This parameter is used for the pagination feature `[default=0]`.
+    #[serde(rename = "page[number]", skip_serializing_if = "Option::is_none")]
+    pub page_number: Option<i64>,
+    /// Whether or not to return facet information in the response `[default=false]`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_facets: Option<bool>,
+}
+
+/// Status of an SLO over one of its timeframes.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SearchSloOverallStatus {
+    /// Error budget remaining for an SLO.
+    pub error_budget_remaining: Option<f64>,
+    /// timestamp (UNIX time in seconds) of when the SLO status and error budget were calculated.
+    pub indexed_at: Option<i64>,
+    /// The amount of decimal places the SLI value is accurate to.
+    pub span_precision: Option<f64>,
+    /// State of the SLO.
+    pub state: Option<String>,
+    /// The status of the SLO.
+    pub status: Option<f64>,
+    /// The target of the SLO.
+    pub target: Option<f64>,
+    /// The SLO time window options.
+    pub timeframe: Option<SloTimeframe>,
+}
+
+/// A metric SLI query as returned by a search.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SearchSloQuery {
+    /// A Datadog metric query for total (valid) events.
+    pub denominator: Option<String>,
+    /// Metric names used in the query's numerator and denominator.
+    pub metrics: Option<Vec<String>>,
+    /// A Datadog metric query for good events.
+    pub numerator: Option<String>,
+}
+
+/// A service level objective as returned by a search.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SearchSloAttributes {
+    /// A list of tags associated with this service level objective.
+    pub all_tags: Option<Vec<String>>,
+    /// Creation timestamp (UNIX time in seconds).
+    pub created_at: Option<i64>,
+    /// A user-defined description of the service level objective.
+    pub description: Option<String>,
+    /// Tags with the `env` tag key.
+    pub env_tags: Option<Vec<String>>,
+    /// A list of (up to 100) monitor groups that narrow the scope of a monitor service level objective.
+    pub groups: Option<Vec<String>>,
+    /// Modification timestamp (UNIX time in seconds).
+    pub modified_at: Option<i64>,
+    /// A list of monitor IDs that defines the scope of a monitor service level objective.
+    pub monitor_ids: Option<Vec<i64>>,
+    /// The name of the service level objective object.
+    pub name: Option<String>,
+    /// Calculated status and error budget remaining.
+    pub overall_status: Option<Vec<SearchSloOverallStatus>>,
+    /// The metric query of good / total events.
+    pub query: Option<SearchSloQuery>,
+    /// Tags with the `service` tag key.
+    pub service_tags: Option<Vec<String>>,
+    /// The type of the service level objective.
+    pub slo_type: Option<SloType>,
+    /// Tags with the `team` tag key.
+    pub team_tags: Option<Vec<String>>,
+    /// The thresholds (timeframes and associated targets) for this service level objective object.
+    pub thresholds: Option<Vec<SloThreshold>>,
+}
+
+/// Data from a service level objective search.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SearchSloData {
+    /// A service level objective as returned by a search.
+    pub attributes: Option<SearchSloAttributes>,
+    /// A unique identifier for the service level objective object.
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    /// Service level objective type. Always `slo`.
+    pub typ: Option<String>,
+}
+
+/// A service level objective found by a search.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SearchServiceLevelObjective {
+    /// Data from a service level objective search.
+    pub data: Option<SearchSloData>,
+}
+
+/// Attributes of the search results.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SearchSloResponseDataAttributes {
+    /// Facets of the found SLOs, when requested with `include_facets`.
+    pub facets: Option<Value>,
+    /// SLOs matching the search query.
+    pub slos: Option<Vec<SearchServiceLevelObjective>>,
+}
+
+/// Data from the search results.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SearchSloResponseData {
+    /// Attributes of the search results.
+    pub attributes: Option<SearchSloResponseDataAttributes>,
+    #[serde(rename = "type")]
+    /// Type of service level objective result.
+    pub typ: Option<String>,
+}
+
+/// Pagination metadata of the search results.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SearchSloResponseMetaPage {
+    /// The first number of the page.
+    pub first_number: Option<i64>,
+    /// The last number of the page.
+    pub last_number: Option<i64>,
+    /// The next number of the page.
+    pub next_number: Option<i64>,
+    /// The page number.
+    pub number: Option<i64>,
+    /// The previous number of the page.
+    pub prev_number: Option<i64>,
+    /// The size of the page.
+    pub size: Option<i64>,
+    /// The total number of SLOs in the response.
+    pub total: Option<i64>,
+}
+
+/// Searches metadata returned by the API.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SearchSloResponseMeta {
+    /// Pagination metadata of the search results.
+    pub pagination: Option<SearchSloResponseMetaPage>,
+}
+
+/// A search SLO response containing results from the search query.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SearchSloResponse {
+    /// Data from the search results.
+    pub data: Option<SearchSloResponseData>,
+    /// Searches metadata returned by the API.
+    pub meta: Option<SearchSloResponseMeta>,
+}
+
+// Responses and requests
+/// The service level objective to create.
+pub type CreateSloRequest = ServiceLevelObjective;
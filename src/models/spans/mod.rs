@@ -0,0 +1,277 @@
+use std::collections::BTreeMap;
+
+use serde_derive::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use super::client::{Meta, PagedResponse, Request};
+use super::logs::{
+    LogsAggregateBucketValue, LogsAggregateSort, LogsAggregationFunction, LogsCompute, LogsGroupBy,
+    LogsGroupByHistogram, LogsGroupByValue,
+};
+use super::search::{SearchOptions, SearchSort};
+
+/// Type of the span.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum SpansType {
+    /// Equals to `spans`.
+    #[serde(rename = "spans")]
+    Spans,
+}
+
+/// The type of resource of a spans list request.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum SpansListRequestType {
+    /// Equals to `search_request`.
+    #[serde(rename = "search_request")]
+    SearchRequest,
+}
+
+/// The type of resource of a spans aggregate request.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum SpansAggregateRequestType {
+    /// Equals to `aggregate_request`.
+    #[serde(rename = "aggregate_request")]
+    AggregateRequest,
+}
+
+/// The type of resource of a spans aggregation bucket.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum SpansAggregationBucketType {
+    /// Equals to `bucket`.
+    #[serde(rename = "bucket")]
+    Bucket,
+}
+
+/// JSON object containing all span attributes and their associated values.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SpansAttributes {
+    /// JSON object of attributes from your span.
+    pub attributes: Option<Map<String, Value>>,
+    /// JSON object of custom spans data.
+    pub custom: Option<Map<String, Value>>,
+    /// End timestamp of your span.
+    // TODO: Use a datetime type.
+    pub end_timestamp: Option<String>,
+    /// Name of the environment from where the spans are being sent.
+    pub env: Option<String>,
+    /// Name of the machine from where the spans are being sent.
+    pub host: Option<String>,
+    /// The reason why the span was ingested.
+    pub ingestion_reason: Option<String>,
+    /// Id of the span that's parent of this span.
+    pub parent_id: Option<String>,
+    /// Unique identifier of the resource.
+    pub resource_hash: Option<String>,
+    /// The name of the resource.
+    pub resource_name: Option<String>,
+    /// The reason why the span was indexed.
+    pub retained_by: Option<String>,
+    /// The name of the application or service generating the span events.
+    pub service: Option<String>,
+    /// Whether or not the span was collected as a stand-alone span.
+    pub single_span: Option<bool>,
+    /// Id of the span.
+    pub span_id: Option<String>,
+    /// Start timestamp of your span.
+    // TODO: Use a datetime type.
+    pub start_timestamp: Option<String>,
+    /// Array of tags associated with your span.
+    pub tags: Option<Vec<String>>,
+    /// Id of the trace to which the span belongs.
+    pub trace_id: Option<String>,
+    #[serde(rename = "type")]
+    /// The type of the span, such as `web` or `db`.
+    pub typ: Option<String>,
+}
+
+/// Object description of a spans after being processed and stored by Datadog.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Span {
+    /// JSON object containing all span attributes and their associated values.
+    pub attributes: Option<SpansAttributes>,
+    /// Unique ID of the Span.
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    /// Type of the span.
+    pub typ: Option<SpansType>,
+}
+
+/// The search and filter query settings.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SpansQueryFilter {
+    /// The minimum time for the requested spans, supports date-time ISO8601, date math, and regular timestamps (milliseconds).
+    ///
+    /// default: `now-15m`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    /// The search query - following the span search syntax.
+    ///
+    /// default: `*`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// The maximum time for the requested spans, supports date-time ISO8601, date math, and regular timestamps (milliseconds).
+    ///
+    /// default: `now`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+}
+
+/// Paging attributes for listing spans.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SpansListRequestPage {
+    /// List following results with a cursor provided in the previous query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    /// Maximum number of spans in the response.
+    ///
+    /// default: `10`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+}
+
+/// The object containing all the query parameters.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SpansListRequestAttributes {
+    /// The search and filter query settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<SpansQueryFilter>,
+    /// Global query options that are used during the query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<SearchOptions>,
+    /// Paging attributes for listing spans.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<SpansListRequestPage>,
+    /// Sort parameters when querying spans.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<SearchSort>,
+}
+
+/// Data of a spans list request.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SpansListRequestData {
+    /// The object containing all the query parameters.
+    pub attributes: SpansListRequestAttributes,
+    #[serde(rename = "type")]
+    /// The type of resource.
+    pub typ: SpansListRequestType,
+}
+
+impl From<SpansListRequestAttributes> for SpansListRequestData {
+    fn from(attributes: SpansListRequestAttributes) -> Self {
+        SpansListRequestData {
+            attributes,
+            typ: SpansListRequestType::SearchRequest,
+        }
+    }
+}
+
+/// Query parameters to get a list of spans.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ListSpansGetRequest {
+    /// Search query following spans syntax.
+    #[serde(rename = "filter[query]", skip_serializing_if = "Option::is_none")]
+    pub filter_query: Option<String>,
+    /// Minimum timestamp for requested spans.
+    #[serde(rename = "filter[from]", skip_serializing_if = "Option::is_none")]
+    pub filter_from: Option<String>,
+    /// Maximum timestamp for requested spans.
+    #[serde(rename = "filter[to]", skip_serializing_if = "Option::is_none")]
+    pub filter_to: Option<String>,
+    /// Order of spans in results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<SearchSort>,
+    /// List following results with a cursor provided in the previous query.
+    #[serde(rename = "page[cursor]", skip_serializing_if = "Option::is_none")]
+    pub page_cursor: Option<String>,
+    /// Maximum number of spans in the response.
+    #[serde(rename = "page[limit]", skip_serializing_if = "Option::is_none")]
+    pub page_limit: Option<i32>,
+}
+
+/// The object containing the query content of an aggregation.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SpansAggregateRequestAttributes {
+    /// The list of metrics or timeseries to compute for the retrieved buckets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compute: Option<Vec<SpansCompute>>,
+    /// The search and filter query settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<SpansQueryFilter>,
+    /// The rules for the group by.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_by: Option<Vec<SpansGroupBy>>,
+    /// Global query options that are used during the query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<SearchOptions>,
+}
+
+/// Data of a spans aggregate request.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SpansAggregateRequestData {
+    /// The object containing the query content of an aggregation.
+    pub attributes: SpansAggregateRequestAttributes,
+    #[serde(rename = "type")]
+    /// The type of resource.
+    pub typ: SpansAggregateRequestType,
+}
+
+impl From<SpansAggregateRequestAttributes> for SpansAggregateRequestData {
+    fn from(attributes: SpansAggregateRequestAttributes) -> Self {
+        SpansAggregateRequestData {
+            attributes,
+            typ: SpansAggregateRequestType::AggregateRequest,
+        }
+    }
+}
+
+/// A bucket values.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SpansAggregateBucketAttributes {
+    /// The key, value pairs for each group by.
+    #[serde(default)]
+    pub by: BTreeMap<String, LogsGroupByValue>,
+    /// A map of the metric name -> value for regular compute or list of values for a timeseries.
+    #[serde(default)]
+    pub computes: BTreeMap<String, LogsAggregateBucketValue>,
+}
+
+/// Spans aggregate.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SpansAggregateBucket {
+    /// A bucket values.
+    pub attributes: Option<SpansAggregateBucketAttributes>,
+    /// ID of the spans aggregate.
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    /// The spans aggregate bucket type.
+    pub typ: Option<SpansAggregationBucketType>,
+}
+
+/// The response object for the spans aggregate API endpoint.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SpansAggregateResponse {
+    /// The list of matching buckets, one item per bucket.
+    pub data: Option<Vec<SpansAggregateBucket>>,
+    /// The metadata associated with a request.
+    pub meta: Option<Meta>,
+}
+
+/// A compute rule to compute metrics or timeseries.
+pub type SpansCompute = LogsCompute;
+/// An aggregation function.
+pub type SpansAggregationFunction = LogsAggregationFunction;
+/// A group by rule.
+pub type SpansGroupBy = LogsGroupBy;
+/// Used to perform a histogram computation (only for measure facets).
+pub type SpansGroupByHistogram = LogsGroupByHistogram;
+/// A sort rule.
+pub type SpansAggregateSort = LogsAggregateSort;
+
+// Responses and requests
+/// The spans matching a query, with the cursor to the next results.
+pub type ListSpansResponse = PagedResponse<Span>;
+
+/// The request for a spans list.
+pub type ListSpansRequest = Request<SpansListRequestData>;
+/// The request to aggregate spans.
+pub type AggregateSpansRequest = Request<SpansAggregateRequestData>;
@@ -0,0 +1,46 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::client::{EmptyResponse, Request, Response};
+use super::generated_metrics::{
+    GeneratedMetric, GeneratedMetricAttributes, GeneratedMetricCreateAttributes,
+    GeneratedMetricCreateData, GeneratedMetricIdRequest, GeneratedMetricUpdateAttributes,
+    GeneratedMetricUpdateData, UpdateGeneratedMetricRequest,
+};
+
+/// The type of the resource. The value should always be `spans_metrics`.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum SpansMetricType {
+    /// Equals to `spans_metrics`.
+    #[serde(rename = "spans_metrics")]
+    SpansMetrics,
+}
+
+/// The object describing a Datadog span-based metric.
+pub type SpansMetricAttributes = GeneratedMetricAttributes;
+/// The span-based metric properties.
+pub type SpansMetric = GeneratedMetric<SpansMetricType>;
+/// The object describing the Datadog span-based metric to create.
+pub type SpansMetricCreateAttributes = GeneratedMetricCreateAttributes;
+/// The new span-based metric properties.
+pub type SpansMetricCreateData = GeneratedMetricCreateData<SpansMetricType>;
+/// The span-based metric properties to update. Only the given attributes are changed.
+pub type SpansMetricUpdateAttributes = GeneratedMetricUpdateAttributes;
+/// The new span-based metric properties.
+pub type SpansMetricUpdateData = GeneratedMetricUpdateData<SpansMetricType>;
+/// Path parameters to get a span-based metric.
+pub type GetSpansMetricRequest = GeneratedMetricIdRequest;
+/// Path parameters and body to update a span-based metric.
+pub type UpdateSpansMetricRequest = UpdateGeneratedMetricRequest<SpansMetricType>;
+/// Path parameters to delete a span-based metric.
+pub type DeleteSpansMetricRequest = GeneratedMetricIdRequest;
+
+// Responses and requests
+/// All the available span-based metric objects.
+pub type ListSpansMetricsResponse = Response<Vec<SpansMetric>>;
+/// The span-based metric object.
+pub type SpansMetricResponse = Response<SpansMetric>;
+/// Response to the deletion of a span-based metric.
+pub type DeleteSpansMetricResponse = EmptyResponse;
+
+/// The span-based metric to create.
+pub type CreateSpansMetricRequest = Request<SpansMetricCreateData>;
@@ -0,0 +1,1595 @@
+use std::collections::BTreeMap;
+
+use serde_derive::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use super::client::EmptyResponse;
+use crate::secret::{serialize_exposed, serialize_exposed_option, SecretString};
+
+/// Type of the Synthetic API test. The value should always be `api`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SyntheticsApiTestType {
+    /// Equals to `api`.
+    #[serde(rename = "api")]
+    Api,
+}
+
+/// Type of the Synthetic browser test. The value should always be `browser`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SyntheticsBrowserTestType {
+    /// Equals to `browser`.
+    #[serde(rename = "browser")]
+    Browser,
+}
+
+/// The subtype of the Synthetic API test.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SyntheticsTestDetailsSubType {
+    /// Equals to `http`.
+    Http,
+    /// Equals to `ssl`.
+    Ssl,
+    /// Equals to `tcp`.
+    Tcp,
+    /// Equals to `dns`.
+    Dns,
+    /// Equals to `multi`.
+    Multi,
+    /// Equals to `icmp`.
+    Icmp,
+    /// Equals to `udp`.
+    Udp,
+    /// Equals to `websocket`.
+    Websocket,
+    /// Equals to `grpc`.
+    Grpc,
+}
+
+/// Whether a test is running or paused.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SyntheticsTestPauseStatus {
+    /// Equals to `live`.
+    Live,
+    /// Equals to `paused`.
+    Paused,
+}
+
+/// Type of the assertion.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SyntheticsAssertionType {
+    /// Equals to `body`.
+    Body,
+    /// Equals to `header`.
+    Header,
+    /// Equals to `statusCode`.
+    StatusCode,
+    /// Equals to `certificate`.
+    Certificate,
+    /// Equals to `responseTime`.
+    ResponseTime,
+    /// Equals to `property`.
+    Property,
+    /// Equals to `recordEvery`.
+    RecordEvery,
+    /// Equals to `recordSome`.
+    RecordSome,
+    /// Equals to `tlsVersion`.
+    TlsVersion,
+    /// Equals to `minTlsVersion`.
+    MinTlsVersion,
+    /// Equals to `latency`.
+    Latency,
+    /// Equals to `packetLossPercentage`.
+    PacketLossPercentage,
+    /// Equals to `packetsReceived`.
+    PacketsReceived,
+    /// Equals to `networkHop`.
+    NetworkHop,
+    /// Equals to `receivedMessage`.
+    ReceivedMessage,
+    /// Equals to `grpcHealthcheckStatus`.
+    GrpcHealthcheckStatus,
+    /// Equals to `grpcMetadata`.
+    GrpcMetadata,
+    /// Equals to `grpcProto`.
+    GrpcProto,
+    /// Equals to `connection`.
+    Connection,
+    /// Equals to `bodyHash`.
+    BodyHash,
+    /// Equals to `javascript`.
+    Javascript,
+}
+
+/// Assertion operator to apply.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SyntheticsAssertionOperator {
+    /// Equals to `contains`.
+    Contains,
+    /// Equals to `doesNotContain`.
+    DoesNotContain,
+    /// Equals to `is`.
+    Is,
+    /// Equals to `isNot`.
+    IsNot,
+    /// Equals to `lessThan`.
+    LessThan,
+    /// Equals to `lessThanOrEqual`.
+    LessThanOrEqual,
+    /// Equals to `moreThan`.
+    MoreThan,
+    /// Equals to `moreThanOrEqual`.
+    MoreThanOrEqual,
+    /// Equals to `matches`.
+    Matches,
+    /// Equals to `doesNotMatch`.
+    DoesNotMatch,
+    /// Equals to `validates`.
+    Validates,
+    /// Equals to `isInMoreThan`.
+    IsInMoreThan,
+    /// Equals to `isInLessThan`.
+    IsInLessThan,
+    /// Equals to `doesNotExist`.
+    DoesNotExist,
+    /// Equals to `isUndefined`.
+    IsUndefined,
+}
+
+/// The operator of a JSON path assertion. The value should always be `validatesJSONPath`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SyntheticsAssertionJsonPathOperator {
+    /// Equals to `validatesJSONPath`.
+    #[serde(rename = "validatesJSONPath")]
+    ValidatesJsonPath,
+}
+
+/// The operator of an XPath assertion. The value should always be `validatesXPath`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SyntheticsAssertionXPathOperator {
+    /// Equals to `validatesXPath`.
+    #[serde(rename = "validatesXPath")]
+    ValidatesXPath,
+}
+
+/// Value an assertion compares against. Depending on the assertion type,
+/// Datadog expects a number (status codes, response times) or a string.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SyntheticsAssertionTargetValue {
+    /// A numeric value.
+    Number(f64),
+    /// A string value, which may be a regular expression or a template.
+    String(String),
+}
+
+/// An assertion comparing a property of the response to a target value.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyntheticsAssertionTarget {
+    /// Assertion operator to apply.
+    pub operator: SyntheticsAssertionOperator,
+    /// The associated assertion property, such as a header name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub property: Option<String>,
+    /// Value used by the operator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<SyntheticsAssertionTargetValue>,
+    /// Timings scope for response time assertions: `all` or `withoutDNS`.
+    #[serde(
+        default,
+        rename = "timingsScope",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub timings_scope: Option<String>,
+    #[serde(rename = "type")]
+    /// Type of the assertion.
+    pub typ: SyntheticsAssertionType,
+}
+
+/// Target of a JSON path assertion.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyntheticsAssertionJsonPathTargetTarget {
+    /// The JSON path to assert.
+    #[serde(rename = "jsonPath")]
+    pub json_path: String,
+    /// The specific operator to use on the path.
+    pub operator: SyntheticsAssertionOperator,
+    /// The path target value to compare to.
+    #[serde(
+        default,
+        rename = "targetValue",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub target_value: Option<SyntheticsAssertionTargetValue>,
+}
+
+/// An assertion on a JSON path of the response body.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyntheticsAssertionJsonPathTarget {
+    /// The operator of a JSON path assertion.
+    pub operator: SyntheticsAssertionJsonPathOperator,
+    /// The associated assertion property.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub property: Option<String>,
+    /// Target of the JSON path assertion.
+    pub target: SyntheticsAssertionJsonPathTargetTarget,
+    #[serde(rename = "type")]
+    /// Type of the assertion.
+    pub typ: SyntheticsAssertionType,
+}
+
+/// Target of an XPath assertion.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyntheticsAssertionXPathTargetTarget {
+    /// The specific operator to use on the path.
+    pub operator: SyntheticsAssertionOperator,
+    /// The path target value to compare to.
+    #[serde(
+        default,
+        rename = "targetValue",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub target_value: Option<SyntheticsAssertionTargetValue>,
+    /// The XPath to assert.
+    #[serde(rename = "xPath")]
+    pub x_path: String,
+}
+
+/// An assertion on an XPath of the response body.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyntheticsAssertionXPathTarget {
+    /// The operator of an XPath assertion.
+    pub operator: SyntheticsAssertionXPathOperator,
+    /// The associated assertion property.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub property: Option<String>,
+    /// Target of the XPath assertion.
+    pub target: SyntheticsAssertionXPathTargetTarget,
+    #[serde(rename = "type")]
+    /// Type of the assertion.
+    pub typ: SyntheticsAssertionType,
+}
+
+/// Object describing the assertions of a test, told apart by their `operator`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SyntheticsAssertion {
+    /// An assertion on a JSON path, with the `validatesJSONPath` operator.
+    JsonPath(SyntheticsAssertionJsonPathTarget),
+    /// An assertion on an XPath, with the `validatesXPath` operator.
+    XPath(SyntheticsAssertionXPathTarget),
+    /// Any other assertion.
+    Target(SyntheticsAssertionTarget),
+}
+
+/// Authentication used by the request of a test, tagged by its `type`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum SyntheticsBasicAuth {
+    /// Equals to `web`.
+    #[serde(rename = "web")]
+    Web {
+        /// Username to use for the basic authentication.
+        username: String,
+        /// Password to use for the basic authentication.
+        #[serde(serialize_with = "serialize_exposed")]
+        password: SecretString,
+    },
+    /// Equals to `digest`.
+    #[serde(rename = "digest")]
+    Digest {
+        /// Username to use for the digest authentication.
+        username: String,
+        /// Password to use for the digest authentication.
+        #[serde(serialize_with = "serialize_exposed")]
+        password: SecretString,
+    },
+    /// Equals to `ntlm`.
+    #[serde(rename = "ntlm")]
+    Ntlm {
+        /// Domain for the authentication to use when performing the test.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        domain: Option<String>,
+        /// Password for the authentication to use when performing the test.
+        #[serde(
+            default,
+            serialize_with = "serialize_exposed_option",
+            skip_serializing_if = "Option::is_none"
+        )]
+        password: Option<SecretString>,
+        /// Username for the authentication to use when performing the test.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        username: Option<String>,
+        /// Workstation for the authentication to use when performing the test.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        workstation: Option<String>,
+    },
+    /// Equals to `sigv4`.
+    #[serde(rename = "sigv4")]
+    Sigv4 {
+        /// Access key for the `SIGV4` authentication.
+        #[serde(rename = "accessKey")]
+        access_key: String,
+        /// Region for the `SIGV4` authentication.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        region: Option<String>,
+        /// Secret key for the `SIGV4` authentication.
+        #[serde(rename = "secretKey", serialize_with = "serialize_exposed")]
+        secret_key: SecretString,
+        /// Service name for the `SIGV4` authentication.
+        #[serde(
+            default,
+            rename = "serviceName",
+            skip_serializing_if = "Option::is_none"
+        )]
+        service_name: Option<String>,
+        /// Session token for the `SIGV4` authentication.
+        #[serde(
+            default,
+            rename = "sessionToken",
+            serialize_with = "serialize_exposed_option",
+            skip_serializing_if = "Option::is_none"
+        )]
+        session_token: Option<SecretString>,
+    },
+}
+
+/// Port to use when performing the test.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SyntheticsTestRequestPort {
+    /// A port number.
+    Number(i64),
+    /// A templated port, such as `{{ PORT }}`.
+    Template(String),
+}
+
+/// The proxy to perform the test.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SyntheticsTestRequestProxy {
+    /// Headers to include when performing the test.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub headers: Option<BTreeMap<String, String>>,
+    /// URL of the proxy to perform the test.
+    pub url: String,
+}
+
+/// Object describing the request performed by a test.
+/// Which fields apply depends on the subtype of the test.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SyntheticsTestRequest {
+    /// Allows loading insecure content for an HTTP request in a multistep test step.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_insecure: Option<bool>,
+    /// Authentication used by the request.
+    #[serde(default, rename = "basicAuth", skip_serializing_if = "Option::is_none")]
+    pub basic_auth: Option<SyntheticsBasicAuth>,
+    /// Body to include in the test.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// Type of the request body, such as `application/json`.
+    #[serde(default, rename = "bodyType", skip_serializing_if = "Option::is_none")]
+    pub body_type: Option<String>,
+    /// DNS server to use for DNS tests.
+    #[serde(default, rename = "dnsServer", skip_serializing_if = "Option::is_none")]
+    pub dns_server: Option<String>,
+    /// DNS server port to use for DNS tests.
+    #[serde(
+        default,
+        rename = "dnsServerPort",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub dns_server_port: Option<String>,
+    /// Specifies whether or not the request follows redirects.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub follow_redirects: Option<bool>,
+    /// Headers to include when performing the test.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub headers: Option<BTreeMap<String, String>>,
+    /// Host name to perform the test with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    /// Message to send for UDP or WebSocket tests.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// The HTTP method, such as `GET` or `POST`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    /// Determines whether or not to save the response body.
+    #[serde(
+        default,
+        rename = "noSavingResponseBody",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub no_saving_response_body: Option<bool>,
+    /// Number of pings to use per test.
+    #[serde(
+        default,
+        rename = "numberOfPackets",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub number_of_packets: Option<i32>,
+    /// Persist cookies across redirects.
+    #[serde(
+        default,
+        rename = "persistCookies",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub persist_cookies: Option<bool>,
+    /// Port to use when performing the test.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port: Option<SyntheticsTestRequestPort>,
+    /// The proxy to perform the test.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<SyntheticsTestRequestProxy>,
+    /// Query to use for the test.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query: Option<Value>,
+    /// For SSL tests, it specifies on which server you want to initiate the TLS handshake.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub servername: Option<String>,
+    /// The gRPC service on which you want to perform the gRPC call.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
+    /// Turns on a traceroute probe to discover all gateways along the path to the host destination.
+    #[serde(
+        default,
+        rename = "shouldTrackHops",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub should_track_hops: Option<bool>,
+    /// Timeout in seconds for the test.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<f64>,
+    /// URL to perform the test with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Type of a configuration variable.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SyntheticsConfigVariableType {
+    /// Equals to `global`.
+    Global,
+    /// Equals to `text`.
+    Text,
+    /// Equals to `email`.
+    Email,
+}
+
+/// Object defining a variable that can be used in your test configuration.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyntheticsConfigVariable {
+    /// Example for the variable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub example: Option<String>,
+    /// ID of the variable for global variables.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Name of the variable.
+    pub name: String,
+    /// Pattern of the variable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    /// Whether the value of this variable will be obfuscated in test results.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secure: Option<bool>,
+    #[serde(rename = "type")]
+    /// Type of the configuration variable.
+    pub typ: SyntheticsConfigVariableType,
+}
+
+/// Type of the parser to use to extract a value.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyntheticsVariableParserType {
+    /// Equals to `raw`.
+    Raw,
+    /// Equals to `json_path`.
+    JsonPath,
+    /// Equals to `regex`.
+    Regex,
+    /// Equals to `x_path`.
+    XPath,
+}
+
+/// Details of the parser to use to extract a value.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyntheticsVariableParser {
+    #[serde(rename = "type")]
+    /// Type of the parser to use.
+    pub typ: SyntheticsVariableParserType,
+    /// Regex, JSON path or XPath used by the parser. Not used with `raw`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+/// Where the value of a variable is extracted from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyntheticsParsingSource {
+    /// Equals to `http_body`.
+    HttpBody,
+    /// Equals to `http_header`.
+    HttpHeader,
+    /// Equals to `local_variable`.
+    LocalVariable,
+}
+
+/// Parsing options for variables to extract.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyntheticsParsingOptions {
+    /// When type is `http_header`, name of the header to use to extract the value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+    /// Name of the variable to extract.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Details of the parser to use.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parser: Option<SyntheticsVariableParser>,
+    /// Determines whether or not the extracted value will be obfuscated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secure: Option<bool>,
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    /// Where the value is extracted from.
+    pub typ: Option<SyntheticsParsingSource>,
+}
+
+/// Object describing the retry strategy to apply to a test or a step.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SyntheticsTestOptionsRetry {
+    /// Number of times a test needs to be retried before marking a location as failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    /// Time interval between retries (in milliseconds).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interval: Option<f64>,
+}
+
+/// The subtype of a multistep API test step. The value should always be `http`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SyntheticsApiStepSubtype {
+    /// Equals to `http`.
+    #[serde(rename = "http")]
+    Http,
+}
+
+/// A step of a multistep API test.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyntheticsApiStep {
+    /// Determines whether or not to continue with test if this step fails.
+    #[serde(
+        default,
+        rename = "allowFailure",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub allow_failure: Option<bool>,
+    /// Array of assertions used for the test.
+    pub assertions: Vec<SyntheticsAssertion>,
+    /// Array of values to parse and save as variables from the response.
+    #[serde(
+        default,
+        rename = "extractedValues",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub extracted_values: Option<Vec<SyntheticsParsingOptions>>,
+    /// Determines whether or not to consider the entire test as failed if this step fails.
+    #[serde(
+        default,
+        rename = "isCritical",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub is_critical: Option<bool>,
+    /// The name of the step.
+    pub name: String,
+    /// The request performed by the step.
+    pub request: SyntheticsTestRequest,
+    /// The retry strategy of the step.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry: Option<SyntheticsTestOptionsRetry>,
+    /// The subtype of the step.
+    pub subtype: SyntheticsApiStepSubtype,
+}
+
+/// Configuration object for a Synthetic API test.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SyntheticsApiTestConfig {
+    /// Array of assertions used for the test. Required for single API tests.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub assertions: Vec<SyntheticsAssertion>,
+    /// Array of variables used for the test.
+    #[serde(
+        default,
+        rename = "configVariables",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub config_variables: Option<Vec<SyntheticsConfigVariable>>,
+    /// The request performed by single API tests.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request: Option<SyntheticsTestRequest>,
+    /// When the test subtype is `multi`, the steps of the test.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub steps: Option<Vec<SyntheticsApiStep>>,
+}
+
+/// Type of a browser test step.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SyntheticsStepType {
+    /// Equals to `assertCurrentUrl`.
+    AssertCurrentUrl,
+    /// Equals to `assertElementAttribute`.
+    AssertElementAttribute,
+    /// Equals to `assertElementContent`.
+    AssertElementContent,
+    /// Equals to `assertElementPresent`.
+    AssertElementPresent,
+    /// Equals to `assertEmail`.
+    AssertEmail,
+    /// Equals to `assertFileDownload`.
+    AssertFileDownload,
+    /// Equals to `assertFromJavascript`.
+    AssertFromJavascript,
+    /// Equals to `assertPageContains`.
+    AssertPageContains,
+    /// Equals to `assertPageLacks`.
+    AssertPageLacks,
+    /// Equals to `click`.
+    Click,
+    /// Equals to `extractFromJavascript`.
+    ExtractFromJavascript,
+    /// Equals to `extractVariable`.
+    ExtractVariable,
+    /// Equals to `goToEmailLink`.
+    GoToEmailLink,
+    /// Equals to `goToUrl`.
+    GoToUrl,
+    /// Equals to `goToUrlAndMeasureTti`.
+    GoToUrlAndMeasureTti,
+    /// Equals to `hover`.
+    Hover,
+    /// Equals to `playSubTest`.
+    PlaySubTest,
+    /// Equals to `pressKey`.
+    PressKey,
+    /// Equals to `refresh`.
+    Refresh,
+    /// Equals to `runApiTest`.
+    RunApiTest,
+    /// Equals to `scroll`.
+    Scroll,
+    /// Equals to `selectOption`.
+    SelectOption,
+    /// Equals to `typeText`.
+    TypeText,
+    /// Equals to `uploadFiles`.
+    UploadFiles,
+    /// Equals to `wait`.
+    Wait,
+}
+
+/// A step of a browser test.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyntheticsStep {
+    /// A boolean set to allow this step to fail.
+    #[serde(
+        default,
+        rename = "allowFailure",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub allow_failure: Option<bool>,
+    /// A boolean to use in addition to `allowFailure` to determine if the test should be marked as failed when the step fails.
+    #[serde(
+        default,
+        rename = "isCritical",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub is_critical: Option<bool>,
+    /// The name of the step.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// A boolean set to not take a screenshot for the step.
+    #[serde(
+        default,
+        rename = "noScreenshot",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub no_screenshot: Option<bool>,
+    /// The parameters of the step. Their shape depends on the step type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub params: Option<Value>,
+    /// The time before declaring a step failed, in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<i64>,
+    #[serde(rename = "type")]
+    /// Type of the step.
+    pub typ: SyntheticsStepType,
+}
+
+/// Type of a browser test variable.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SyntheticsBrowserVariableType {
+    /// Equals to `element`.
+    Element,
+    /// Equals to `email`.
+    Email,
+    /// Equals to `global`.
+    Global,
+    /// Equals to `javascript`.
+    Javascript,
+    /// Equals to `text`.
+    Text,
+}
+
+/// Object defining a variable that can be used in a browser test.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyntheticsBrowserVariable {
+    /// Example for the variable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub example: Option<String>,
+    /// ID for the variable. Global variables require an ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Name of the variable.
+    pub name: String,
+    /// Pattern of the variable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    /// Whether the value of this variable will be obfuscated in test results.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secure: Option<bool>,
+    #[serde(rename = "type")]
+    /// Type of browser test variable.
+    pub typ: SyntheticsBrowserVariableType,
+}
+
+/// Configuration object for a Synthetic browser test.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SyntheticsBrowserTestConfig {
+    /// Array of assertions used for the test.
+    #[serde(default)]
+    pub assertions: Vec<SyntheticsAssertion>,
+    /// Array of variables used for the test.
+    #[serde(
+        default,
+        rename = "configVariables",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub config_variables: Option<Vec<SyntheticsConfigVariable>>,
+    /// The request performed to load the starting page.
+    pub request: SyntheticsTestRequest,
+    /// Cookies to be used for the request, using the `Set-Cookie` syntax.
+    #[serde(default, rename = "setCookie", skip_serializing_if = "Option::is_none")]
+    pub set_cookie: Option<String>,
+    /// Array of variables used in the test steps.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variables: Option<Vec<SyntheticsBrowserVariable>>,
+}
+
+/// Object containing the options of the monitor attached to a test.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SyntheticsTestOptionsMonitorOptions {
+    /// Time interval before renotifying if the test is still failing (in minutes).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub renotify_interval: Option<i64>,
+}
+
+/// Object describing the extra options of a test.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SyntheticsTestOptions {
+    /// For SSL tests, whether or not the test should allow self signed certificates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accept_self_signed: Option<bool>,
+    /// Allows loading insecure content for an HTTP request in an API test.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_insecure: Option<bool>,
+    /// For SSL tests, whether or not the test should fail on revoked certificates in stapled OCSP.
+    #[serde(
+        default,
+        rename = "checkCertificateRevocation",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub check_certificate_revocation: Option<bool>,
+    /// For browser tests, the devices on which the test runs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub device_ids: Option<Vec<String>>,
+    /// Whether or not to disable CORS mechanism.
+    #[serde(
+        default,
+        rename = "disableCors",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub disable_cors: Option<bool>,
+    /// Specifies whether or not the request follows redirects.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub follow_redirects: Option<bool>,
+    /// HTTP version to use for a Synthetic test: `http1`, `http2` or `any`.
+    #[serde(
+        default,
+        rename = "httpVersion",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub http_version: Option<String>,
+    /// Minimum amount of time in failure required to trigger an alert (in seconds).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_failure_duration: Option<i64>,
+    /// Minimum number of locations in failure required to trigger an alert.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_location_failed: Option<i64>,
+    /// The monitor name is used for the alert title as well as for all monitor dashboard widgets and SLOs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub monitor_name: Option<String>,
+    /// Object containing the options of the monitor attached to the test.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub monitor_options: Option<SyntheticsTestOptionsMonitorOptions>,
+    /// Integer from 1 (high) to 5 (low) indicating alert severity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub monitor_priority: Option<i32>,
+    /// Prevents saving screenshots of the steps.
+    #[serde(
+        default,
+        rename = "noScreenshot",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub no_screenshot: Option<bool>,
+    /// The retry strategy of the test.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry: Option<SyntheticsTestOptionsRetry>,
+    /// The frequency at which to run the test (in seconds).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tick_every: Option<i64>,
+    /// Options not modelled above, such as `ci`, `rumSettings` or `scheduling`.
+    #[serde(flatten)]
+    pub additional_properties: Map<String, Value>,
+}
+
+/// Object describing the creator of a test.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyntheticsTestCreator {
+    /// Email of the creator.
+    pub email: Option<String>,
+    /// Handle of the creator.
+    pub handle: Option<String>,
+    /// Name of the creator.
+    pub name: Option<String>,
+}
+
+/// Object containing details about a Synthetic API test.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyntheticsApiTest {
+    /// Configuration object for a Synthetic API test.
+    pub config: SyntheticsApiTestConfig,
+    /// Object describing the creator of the test.
+    /// This value is set by the API.
+    #[serde(default, skip_serializing)]
+    pub creator: Option<SyntheticsTestCreator>,
+    /// Array of locations used to run the test, such as `aws:eu-central-1` or `pl:my-private-location-id`.
+    pub locations: Vec<String>,
+    /// Notification message associated with the test.
+    pub message: String,
+    /// The associated monitor ID.
+    /// This value is set by the API.
+    #[serde(default, skip_serializing)]
+    pub monitor_id: Option<i64>,
+    /// Name of the test.
+    pub name: String,
+    /// Object describing the extra options of the test.
+    pub options: SyntheticsTestOptions,
+    /// The public ID of the test.
+    /// This value is set by the API.
+    #[serde(default, skip_serializing)]
+    pub public_id: Option<String>,
+    /// Whether the test is running or paused.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<SyntheticsTestPauseStatus>,
+    /// The subtype of the Synthetic API test. Defaults to `http`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtype: Option<SyntheticsTestDetailsSubType>,
+    /// Array of tags attached to the test.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    #[serde(rename = "type")]
+    /// Type of the Synthetic test. The value should always be `api`.
+    pub typ: SyntheticsApiTestType,
+}
+
+/// Object containing details about a Synthetic browser test.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyntheticsBrowserTest {
+    /// Configuration object for a Synthetic browser test.
+    pub config: SyntheticsBrowserTestConfig,
+    /// Object describing the creator of the test.
+    /// This value is set by the API.
+    #[serde(default, skip_serializing)]
+    pub creator: Option<SyntheticsTestCreator>,
+    /// Array of locations used to run the test.
+    pub locations: Vec<String>,
+    /// Notification message associated with the test.
+    pub message: String,
+    /// The associated monitor ID.
+    /// This value is set by the API.
+    #[serde(default, skip_serializing)]
+    pub monitor_id: Option<i64>,
+    /// Name of the test.
+    pub name: String,
+    /// Object describing the extra options of the test.
+    pub options: SyntheticsTestOptions,
+    /// The public ID of the test.
+    /// This value is set by the API.
+    #[serde(default, skip_serializing)]
+    pub public_id: Option<String>,
+    /// Whether the test is running or paused.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<SyntheticsTestPauseStatus>,
+    /// Array of steps for the test.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub steps: Option<Vec<SyntheticsStep>>,
+    /// Array of tags attached to the test.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    #[serde(rename = "type")]
+    /// Type of the Synthetic test. The value should always be `browser`.
+    pub typ: SyntheticsBrowserTestType,
+}
+
+/// A Synthetic test, told apart by its `type`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SyntheticsTestDetails {
+    /// An API test.
+    Api(SyntheticsApiTest),
+    /// A browser test.
+    Browser(SyntheticsBrowserTest),
+    /// A test of another type, such as a mobile test, kept as raw JSON.
+    Other(Value),
+}
+
+/// Query parameters to list the Synthetic tests.
+#[derive(Debug, Default, Serialize)]
+pub struct ListSyntheticsTestsRequest {
+    /// Used for pagination. The number of tests returned in the page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<i64>,
+    /// Used for pagination. Which page you want to retrieve. Starts at zero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_number: Option<i64>,
+}
+
+/// Object containing an array of Synthetic tests configuration.
+#[derive(Debug, Deserialize)]
+pub struct ListSyntheticsTestsResponse {
+    /// Array of Synthetic tests configuration.
+    pub tests: Option<Vec<SyntheticsTestDetails>>,
+}
+
+/// Path parameters to get a Synthetic test.
+#[derive(Debug)]
+pub struct GetSyntheticsTestRequest {
+    /// The public ID of the test.
+    pub public_id: String,
+}
+
+/// Path parameters and body to update a Synthetic API test.
+#[derive(Debug)]
+pub struct UpdateSyntheticsApiTestRequest {
+    /// The public ID of the test.
+    pub public_id: String,
+    /// New definition of the test.
+    pub test: SyntheticsApiTest,
+}
+
+/// Path parameters and body to update a Synthetic browser test.
+#[derive(Debug)]
+pub struct UpdateSyntheticsBrowserTestRequest {
+    /// The public ID of the test.
+    pub public_id: String,
+    /// New definition of the test.
+    pub test: SyntheticsBrowserTest,
+}
+
+/// Path parameters and body to pause or resume a Synthetic test.
+#[derive(Debug, Serialize)]
+pub struct UpdateSyntheticsTestStatusRequest {
+    /// The public ID of the test.
+    #[serde(skip)]
+    pub public_id: String,
+    /// The new status of the test.
+    pub new_status: SyntheticsTestPauseStatus,
+}
+
+/// The Synthetic tests to delete.
+#[derive(Debug, Serialize)]
+pub struct DeleteSyntheticsTestsRequest {
+    /// Delete the tests even if they are referenced by other resources, such as SLOs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force_delete_dependencies: Option<bool>,
+    /// An array of public IDs of the tests to delete.
+    pub public_ids: Vec<String>,
+}
+
+/// Object containing a deleted Synthetic test ID with the associated deletion timestamp.
+#[derive(Debug, Deserialize)]
+pub struct SyntheticsDeletedTest {
+    /// Deletion timestamp of the test.
+    pub deleted_at: Option<String>,
+    /// The public ID of the deleted test.
+    pub public_id: Option<String>,
+}
+
+/// Response object for deleting Synthetic tests.
+#[derive(Debug, Deserialize)]
+pub struct DeleteSyntheticsTestsResponse {
+    /// Array of objects containing a deleted test ID with the associated deletion timestamp.
+    pub deleted_tests: Option<Vec<SyntheticsDeletedTest>>,
+}
+
+/// Metadata of the CI provider and the commit a test is triggered from.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SyntheticsCiTestMetadata {
+    /// Description of the CI provider.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ci: Option<SyntheticsCiTestMetadataCi>,
+    /// Git information.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git: Option<SyntheticsCiTestMetadataGit>,
+}
+
+/// Description of the CI provider.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SyntheticsCiTestMetadataCi {
+    /// URL of the pipeline.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pipeline_url: Option<String>,
+    /// Name of the CI provider.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider_name: Option<String>,
+}
+
+/// Git information.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SyntheticsCiTestMetadataGit {
+    /// Branch name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    /// The commit SHA.
+    #[serde(default, rename = "commitSha", skip_serializing_if = "Option::is_none")]
+    pub commit_sha: Option<String>,
+}
+
+/// A test to trigger.
+#[derive(Debug, Clone, Serialize)]
+pub struct SyntheticsTriggerTest {
+    /// Metadata for the triggered test.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<SyntheticsCiTestMetadata>,
+    /// The public ID of the test to trigger.
+    pub public_id: String,
+}
+
+/// The tests to trigger.
+#[derive(Debug, Serialize)]
+pub struct TriggerSyntheticsTestsRequest {
+    /// List of tests to trigger.
+    pub tests: Vec<SyntheticsTriggerTest>,
+}
+
+/// A test to trigger from a CI pipeline, with the options overriding its configuration for this run.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SyntheticsCiTest {
+    /// Disable certificate checks in API tests.
+    #[serde(
+        rename = "allowInsecureCertificates",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub allow_insecure_certificates: Option<bool>,
+    /// Authentication used by the request.
+    #[serde(rename = "basicAuth", skip_serializing_if = "Option::is_none")]
+    pub basic_auth: Option<SyntheticsBasicAuth>,
+    /// Body to include in the test.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// Type of the data sent in a Synthetic API test.
+    #[serde(rename = "bodyType", skip_serializing_if = "Option::is_none")]
+    pub body_type: Option<String>,
+    /// Cookies for the request, using the `Set-Cookie` syntax.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cookies: Option<String>,
+    /// For browser tests, the devices on which the test runs.
+    #[serde(rename = "deviceIds", skip_serializing_if = "Option::is_none")]
+    pub device_ids: Option<Vec<String>>,
+    /// For API HTTP tests, whether to follow redirects.
+    #[serde(rename = "followRedirects", skip_serializing_if = "Option::is_none")]
+    pub follow_redirects: Option<bool>,
+    /// Headers to include when performing the test.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<BTreeMap<String, String>>,
+    /// Array of locations used to run the test.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locations: Option<Vec<String>>,
+    /// Metadata of the CI pipeline and commit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<SyntheticsCiTestMetadata>,
+    /// The public ID of the test to trigger.
+    pub public_id: String,
+    /// The retry strategy of the run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry: Option<SyntheticsTestOptionsRetry>,
+    /// Starting URL for the browser test.
+    #[serde(rename = "startUrl", skip_serializing_if = "Option::is_none")]
+    pub start_url: Option<String>,
+    /// Variables to replace in the test.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variables: Option<BTreeMap<String, String>>,
+}
+
+/// The tests to trigger from a CI pipeline.
+#[derive(Debug, Serialize)]
+pub struct TriggerCiSyntheticsTestsRequest {
+    /// List of tests to trigger.
+    pub tests: Vec<SyntheticsCiTest>,
+}
+
+/// Synthetic location that a test was triggered on.
+#[derive(Debug, Deserialize)]
+pub struct SyntheticsTriggerCiTestLocation {
+    /// Unique identifier of the location.
+    pub id: Option<i64>,
+    /// Name of the location.
+    pub name: Option<String>,
+}
+
+/// Information about a single test run.
+#[derive(Debug, Deserialize)]
+pub struct SyntheticsTriggerCiTestRunResult {
+    /// The device ID, for browser tests.
+    pub device: Option<String>,
+    /// The location ID of the test run.
+    pub location: Option<i64>,
+    /// The public ID of the test.
+    pub public_id: Option<String>,
+    /// ID of the result, to fetch it once the run is finished.
+    pub result_id: Option<String>,
+}
+
+/// Object containing information about the triggered tests.
+#[derive(Debug, Deserialize)]
+pub struct SyntheticsTriggerCiTestsResponse {
+    /// The ID of the batch of tests, to poll with [get_synthetics_ci_batch](crate::client::Client::get_synthetics_ci_batch).
+    pub batch_id: Option<String>,
+    /// List of Synthetic locations.
+    pub locations: Option<Vec<SyntheticsTriggerCiTestLocation>>,
+    /// Information about the tests runs.
+    pub results: Option<Vec<SyntheticsTriggerCiTestRunResult>>,
+    /// The public IDs of the triggered tests.
+    pub triggered_check_ids: Option<Vec<String>>,
+}
+
+/// Path parameters to get a batch of tests triggered from CI.
+#[derive(Debug)]
+pub struct GetSyntheticsCiBatchRequest {
+    /// The ID of the batch.
+    pub batch_id: String,
+}
+
+/// Determines the status of a batch or of one of its results.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyntheticsStatus {
+    /// Equals to `passed`.
+    Passed,
+    /// Equals to `skipped`.
+    Skipped,
+    /// Equals to `failed`.
+    Failed,
+    /// Equals to `in_progress`.
+    InProgress,
+}
+
+/// Execution rule of a test in a batch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyntheticsTestExecutionRule {
+    /// Equals to `blocking`.
+    Blocking,
+    /// Equals to `non_blocking`.
+    NonBlocking,
+    /// Equals to `skipped`.
+    Skipped,
+}
+
+/// Object with the results of a test in a batch.
+#[derive(Debug, Deserialize)]
+pub struct SyntheticsBatchResult {
+    /// The device ID, for browser tests.
+    pub device: Option<String>,
+    /// Total duration in millisecond of the test.
+    pub duration: Option<f64>,
+    /// Execution rule for a Synthetic test.
+    pub execution_rule: Option<SyntheticsTestExecutionRule>,
+    /// Name of the location.
+    pub location: Option<String>,
+    /// The ID of the result.
+    pub result_id: Option<String>,
+    /// Number of times this result has been retried.
+    pub retries: Option<f64>,
+    /// Status of the result.
+    pub status: Option<SyntheticsStatus>,
+    /// Name of the test.
+    pub test_name: Option<String>,
+    /// The public ID of the test.
+    pub test_public_id: Option<String>,
+    /// Type of the test: `api` or `browser`.
+    pub test_type: Option<String>,
+}
+
+/// Details of a batch of tests triggered from CI.
+#[derive(Debug, Deserialize)]
+pub struct SyntheticsBatchDetailsData {
+    /// Metadata of the CI pipeline and commit.
+    pub metadata: Option<SyntheticsCiTestMetadata>,
+    /// List of results for the batch.
+    pub results: Option<Vec<SyntheticsBatchResult>>,
+    /// Status of the batch. Stays `in_progress` until all its results are known.
+    pub status: Option<SyntheticsStatus>,
+}
+
+/// Details about a batch of tests triggered from CI.
+#[derive(Debug, Deserialize)]
+pub struct SyntheticsBatchDetails {
+    /// Details of the batch.
+    pub data: Option<SyntheticsBatchDetailsData>,
+}
+
+/// Query parameters to list the latest results of a Synthetic test.
+#[derive(Debug, Serialize)]
+pub struct ListSyntheticsTestResultsRequest {
+    /// The public ID of the test.
+    #[serde(skip)]
+    pub public_id: String,
+    /// Timestamp in milliseconds from which to start querying results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_ts: Option<i64>,
+    /// Timestamp in milliseconds up to which to query results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to_ts: Option<i64>,
+}
+
+/// Path parameters to get a result of a Synthetic test.
+#[derive(Debug)]
+pub struct GetSyntheticsTestResultRequest {
+    /// The public ID of the test.
+    pub public_id: String,
+    /// The ID of the result.
+    pub result_id: String,
+}
+
+/// Failure details of a test result.
+#[derive(Debug, Deserialize)]
+pub struct SyntheticsTestResultFailure {
+    /// Error code that can be returned by a Synthetic test.
+    pub code: Option<String>,
+    /// The failure message.
+    pub message: Option<String>,
+}
+
+/// Details of a test result.
+/// The fields not modelled here depend on the type of the test.
+#[derive(Debug, Deserialize)]
+pub struct SyntheticsTestResultData {
+    /// The failure details, when the test failed.
+    pub failure: Option<SyntheticsTestResultFailure>,
+    /// Whether the test passed.
+    pub passed: Option<bool>,
+    /// Time in milliseconds spent in each phase of the test.
+    pub timings: Option<BTreeMap<String, f64>>,
+    /// Other fields of the result.
+    #[serde(flatten)]
+    pub additional_properties: Map<String, Value>,
+}
+
+/// Object with a short version of a test result.
+#[derive(Debug, Deserialize)]
+pub struct SyntheticsTestResultShort {
+    /// Last time the test was performed.
+    pub check_time: Option<f64>,
+    /// Location from which the test was performed.
+    pub probe_dc: Option<String>,
+    /// Details of the result.
+    pub result: Option<SyntheticsTestResultData>,
+    /// ID of the result.
+    pub result_id: Option<String>,
+    /// The status of the test: `0` for passed, `1` for timed out and `2` for failed.
+    pub status: Option<i64>,
+}
+
+/// Object with the latest results of a Synthetic test.
+#[derive(Debug, Deserialize)]
+pub struct ListSyntheticsTestResultsResponse {
+    /// Timestamp of the latest result fetched.
+    pub last_timestamp_fetched: Option<i64>,
+    /// The public ID of the test.
+    pub public_id: Option<String>,
+    /// Result of the latest runs.
+    pub results: Option<Vec<SyntheticsTestResultShort>>,
+}
+
+/// Object returned describing a full test result.
+#[derive(Debug, Deserialize)]
+pub struct SyntheticsTestResult {
+    /// The test configuration at the time of the run.
+    pub check: Option<Value>,
+    /// When the test was performed.
+    pub check_time: Option<f64>,
+    /// Version of the test at the time of the run.
+    pub check_version: Option<i64>,
+    /// Location from which the test was performed.
+    pub probe_dc: Option<String>,
+    /// Details of the result.
+    pub result: Option<SyntheticsTestResultData>,
+    /// ID of the result.
+    pub result_id: Option<String>,
+    /// The status of the test: `0` for passed, `1` for timed out and `2` for failed.
+    pub status: Option<i64>,
+}
+
+/// Attributes of a global variable.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SyntheticsGlobalVariableAttributes {
+    /// A list of role identifiers that can be pulled from the Roles API, for restricting read and write access.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub restricted_roles: Option<Vec<String>>,
+}
+
+/// Parameters for a TOTP or MFA global variable.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SyntheticsGlobalVariableTotpParameters {
+    /// Number of digits for the OTP code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub digits: Option<i32>,
+    /// Interval for which to refresh the token (in seconds).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_interval: Option<i32>,
+}
+
+/// Options for the global variable value.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SyntheticsGlobalVariableOptions {
+    /// Parameters for the TOTP or MFA global variable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub totp_parameters: Option<SyntheticsGlobalVariableTotpParameters>,
+}
+
+/// Value of the global variable.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SyntheticsGlobalVariableValue {
+    /// Options for the global variable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options: Option<SyntheticsGlobalVariableOptions>,
+    /// Whether the value of the variable is hidden. The API does not return secure values.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secure: Option<bool>,
+    /// Value of the global variable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+/// Parser options to use for retrieving a global variable value from a test.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyntheticsGlobalVariableParseTestOptions {
+    /// When type is `http_header`, name of the header to use to extract the value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+    /// When type is `local_variable`, name of the local variable to use to extract the value.
+    #[serde(
+        default,
+        rename = "localVariableName",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub local_variable_name: Option<String>,
+    /// Details of the parser to use.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parser: Option<SyntheticsVariableParser>,
+    #[serde(rename = "type")]
+    /// Where the value is extracted from.
+    pub typ: SyntheticsParsingSource,
+}
+
+/// Synthetic global variable.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyntheticsGlobalVariable {
+    /// Attributes of the global variable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<SyntheticsGlobalVariableAttributes>,
+    /// Description of the global variable.
+    pub description: String,
+    /// Unique identifier of the global variable.
+    /// This value is set by the API.
+    #[serde(default, skip_serializing)]
+    pub id: Option<String>,
+    /// Determines if the global variable is a FIDO variable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_fido: Option<bool>,
+    /// Determines if the global variable is a TOTP/MFA variable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_totp: Option<bool>,
+    /// Name of the global variable. Unique across Synthetic global variables.
+    pub name: String,
+    /// Parser options to use for retrieving the value from a test.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parse_test_options: Option<SyntheticsGlobalVariableParseTestOptions>,
+    /// A Synthetic test ID to use as a test to generate the variable value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parse_test_public_id: Option<String>,
+    /// Tags of the global variable.
+    pub tags: Vec<String>,
+    /// Value of the global variable.
+    pub value: SyntheticsGlobalVariableValue,
+}
+
+/// Response object with the Synthetic global variables.
+#[derive(Debug, Deserialize)]
+pub struct ListSyntheticsGlobalVariablesResponse {
+    /// Array of Synthetic global variables.
+    pub variables: Option<Vec<SyntheticsGlobalVariable>>,
+}
+
+/// Path parameters to get a global variable.
+#[derive(Debug)]
+pub struct GetSyntheticsGlobalVariableRequest {
+    /// The ID of the global variable.
+    pub variable_id: String,
+}
+
+/// Path parameters and body to update a global variable.
+#[derive(Debug)]
+pub struct UpdateSyntheticsGlobalVariableRequest {
+    /// The ID of the global variable.
+    pub variable_id: String,
+    /// New definition of the global variable.
+    pub variable: SyntheticsGlobalVariable,
+}
+
+/// Path parameters to delete a global variable.
+#[derive(Debug)]
+pub struct DeleteSyntheticsGlobalVariableRequest {
+    /// The ID of the global variable.
+    pub variable_id: String,
+}
+
+/// Metadata of a private location.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SyntheticsPrivateLocationMetadata {
+    /// A list of role identifiers that can be pulled from the Roles API, for restricting read and write access.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub restricted_roles: Option<Vec<String>>,
+}
+
+/// Authentication part of the secrets of a private location.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SyntheticsPrivateLocationSecretsAuthentication {
+    /// Access key for the private location.
+    pub id: Option<String>,
+    /// Secret access key for the private location.
+    pub key: Option<SecretString>,
+}
+
+/// Private key for the private location.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SyntheticsPrivateLocationSecretsConfigDecryption {
+    /// Private key for the private location.
+    pub key: Option<SecretString>,
+}
+
+/// Secrets for the private location. Only present in the response when creating the private location.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SyntheticsPrivateLocationSecrets {
+    /// Authentication part of the secrets.
+    pub authentication: Option<SyntheticsPrivateLocationSecretsAuthentication>,
+    /// Private key for the private location.
+    pub config_decryption: Option<SyntheticsPrivateLocationSecretsConfigDecryption>,
+}
+
+/// Object containing information about the private location to create.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyntheticsPrivateLocation {
+    /// Description of the private location.
+    pub description: String,
+    /// Unique identifier of the private location.
+    /// This value is set by the API.
+    #[serde(default, skip_serializing)]
+    pub id: Option<String>,
+    /// Object containing metadata about the private location.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<SyntheticsPrivateLocationMetadata>,
+    /// Name of the private location.
+    pub name: String,
+    /// Secrets for the private location.
+    /// This value is set by the API.
+    #[serde(default, skip_serializing)]
+    pub secrets: Option<SyntheticsPrivateLocationSecrets>,
+    /// Array of tags attached to the private location.
+    pub tags: Vec<String>,
+}
+
+/// Public key for the result encryption.
+#[derive(Debug, Deserialize)]
+pub struct SyntheticsPrivateLocationCreationResponseResultEncryption {
+    /// Fingerprint for the encryption key.
+    pub id: Option<String>,
+    /// Public key for result encryption.
+    pub key: Option<String>,
+}
+
+/// Object that contains the new private location, its configuration and its result encryption key.
+#[derive(Debug, Deserialize)]
+pub struct SyntheticsPrivateLocationCreationResponse {
+    /// Configuration skeleton for the private location worker.
+    pub config: Option<Value>,
+    /// The new private location.
+    pub private_location: Option<SyntheticsPrivateLocation>,
+    /// Public key for the result encryption.
+    pub result_encryption: Option<SyntheticsPrivateLocationCreationResponseResultEncryption>,
+}
+
+/// Path parameters to get a private location.
+#[derive(Debug)]
+pub struct GetSyntheticsPrivateLocationRequest {
+    /// The ID of the private location.
+    pub location_id: String,
+}
+
+/// Path parameters and body to update a private location.
+#[derive(Debug)]
+pub struct UpdateSyntheticsPrivateLocationRequest {
+    /// The ID of the private location.
+    pub location_id: String,
+    /// New definition of the private location.
+    pub location: SyntheticsPrivateLocation,
+}
+
+/// Path parameters to delete a private location.
+#[derive(Debug)]
+pub struct DeleteSyntheticsPrivateLocationRequest {
+    /// The ID of the private location.
+    pub location_id: String,
+}
+
+/// Synthetic location that can be used when creating or editing a test.
+#[derive(Debug, Deserialize)]
+pub struct SyntheticsLocation {
+    /// Unique identifier of the location, such as `aws:eu-central-1` or `pl:my-private-location-id`.
+    pub id: Option<String>,
+    /// Name of the location.
+    pub name: Option<String>,
+}
+
+/// List of Synthetic locations.
+#[derive(Debug, Deserialize)]
+pub struct ListSyntheticsLocationsResponse {
+    /// List of Synthetic locations.
+    pub locations: Option<Vec<SyntheticsLocation>>,
+}
+
+// Responses and requests
+/// Whether the status of the test was updated.
+pub type UpdateSyntheticsTestStatusResponse = bool;
+/// Response to the deletion of a global variable.
+pub type DeleteSyntheticsGlobalVariableResponse = EmptyResponse;
+/// Response to the deletion of a private location.
+pub type DeleteSyntheticsPrivateLocationResponse = EmptyResponse;
+
+/// The Synthetic API test to create.
+pub type CreateSyntheticsApiTestRequest = SyntheticsApiTest;
+/// The Synthetic browser test to create.
+pub type CreateSyntheticsBrowserTestRequest = SyntheticsBrowserTest;
+/// The global variable to create.
+pub type CreateSyntheticsGlobalVariableRequest = SyntheticsGlobalVariable;
+/// The private location to create.
+pub type CreateSyntheticsPrivateLocationRequest = SyntheticsPrivateLocation;
@@ -0,0 +1,62 @@
+use std::collections::BTreeMap;
+
+use serde_derive::{Deserialize, Serialize};
+
+use super::client::EmptyResponse;
+
+/// Query parameters to get all host tags.
+#[derive(Debug, Default, Serialize)]
+pub struct ListHostTagsRequest {
+    /// When specified, filters host list to those tags with the specified source.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+
+/// In this object, the key is the tag, the value is a list of host names that are reporting
+/// that tag.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TagToHosts {
+    /// A list of tags to apply to the host.
+    pub tags: BTreeMap<String, Vec<String>>,
+}
+
+/// Path and query parameters to get or delete the tags of a host.
+#[derive(Debug, Serialize)]
+pub struct HostTagsRequest {
+    /// The host name.
+    #[serde(skip)]
+    pub host_name: String,
+    /// The source of the tags, such as `users` or `chef`.
+    /// [Complete list of source attribute values](https://docs.datadoghq.com/integrations/faq/list-of-api-source-attribute-value).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+
+/// Set of tags to associate with your host.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct HostTags {
+    /// Your host name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    /// A list of tags to apply to the host.
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// Path and query parameters and tags to add to or replace on a host.
+#[derive(Debug, Serialize)]
+pub struct UpdateHostTagsRequest {
+    /// The host name.
+    #[serde(skip)]
+    pub host_name: String,
+    /// The source of the tags, such as `users` or `chef`. Defaults to `users`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// The tags to add or replace.
+    #[serde(skip)]
+    pub tags: HostTags,
+}
+
+// Responses and requests
+/// Response to the deletion of the tags of a host.
+pub type DeleteHostTagsResponse = EmptyResponse;
@@ -0,0 +1,794 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::client::{EmptyResponse, Request, Response};
+use super::users::{RelationshipToUserData, RelationshipToUsers, UserAttributes};
+
+/// Team type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TeamType {
+    /// Equals to `team`.
+    #[serde(rename = "team")]
+    Team,
+}
+
+/// Team membership type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum UserTeamType {
+    /// Equals to `team_memberships`.
+    #[serde(rename = "team_memberships")]
+    TeamMemberships,
+}
+
+/// Team link type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TeamLinkType {
+    /// Equals to `team_links`.
+    #[serde(rename = "team_links")]
+    TeamLinks,
+}
+
+/// Team permission setting type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TeamPermissionSettingType {
+    /// Equals to `team_permission_settings`.
+    #[serde(rename = "team_permission_settings")]
+    TeamPermissionSettings,
+}
+
+/// User team permission type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum UserTeamPermissionType {
+    /// Equals to `user_team_permissions`.
+    #[serde(rename = "user_team_permissions")]
+    UserTeamPermissions,
+}
+
+/// The user's role within the team.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum UserTeamRole {
+    /// Equals to `admin`.
+    #[serde(rename = "admin")]
+    Admin,
+}
+
+/// Links attributes of a relationship.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TeamRelationshipsLinks {
+    /// Related link.
+    pub related: Option<String>,
+}
+
+/// Relationship between a team and a team link.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelationshipToTeamLinkData {
+    /// The team link's identifier.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// Team link type.
+    pub typ: TeamLinkType,
+}
+
+/// Relationship between a team and its links.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RelationshipToTeamLinks {
+    /// Related team links.
+    pub data: Option<Vec<RelationshipToTeamLinkData>>,
+    /// Links attributes.
+    pub links: Option<TeamRelationshipsLinks>,
+}
+
+/// Related user team permission data.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RelationshipToUserTeamPermissionData {
+    /// The ID of the user team permission.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// User team permission type.
+    pub typ: UserTeamPermissionType,
+}
+
+/// Relationship between a user team permission and a team.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RelationshipToUserTeamPermission {
+    /// Related user team permission data.
+    pub data: Option<RelationshipToUserTeamPermissionData>,
+    /// Links attributes.
+    pub links: Option<TeamRelationshipsLinks>,
+}
+
+/// Team attributes.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TeamAttributes {
+    /// Unicode representation of the avatar for the team, limited to a single grapheme.
+    pub avatar: Option<String>,
+    /// Banner selection for the team.
+    pub banner: Option<i64>,
+    /// Creation date of the team.
+    pub created_at: Option<String>,
+    /// Free-form markdown description/content for the team's homepage.
+    pub description: Option<String>,
+    /// The team's identifier.
+    pub handle: String,
+    /// Collection of hidden modules for the team.
+    pub hidden_modules: Option<Vec<String>>,
+    /// The number of links belonging to the team.
+    #[serde(default, skip_serializing)]
+    /// This value is set by the API.
+    pub link_count: Option<i32>,
+    /// Modification date of the team.
+    pub modified_at: Option<String>,
+    /// The name of the team.
+    pub name: String,
+    /// A brief summary of the team, derived from the `description`.
+    #[serde(default, skip_serializing)]
+    /// This value is set by the API.
+    pub summary: Option<String>,
+    /// The number of users belonging to the team.
+    #[serde(default, skip_serializing)]
+    /// This value is set by the API.
+    pub user_count: Option<i32>,
+    /// Collection of visible modules for the team.
+    pub visible_modules: Option<Vec<String>>,
+}
+
+/// Team relationships.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TeamRelationships {
+    /// Relationship between a team and its links.
+    pub team_links: Option<RelationshipToTeamLinks>,
+    /// Relationship between a user team permission and a team.
+    pub user_team_permissions: Option<RelationshipToUserTeamPermission>,
+}
+
+/// A team.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Team {
+    /// Team attributes.
+    pub attributes: TeamAttributes,
+    /// The team's identifier.
+    pub id: String,
+    /// Team relationships.
+    pub relationships: Option<TeamRelationships>,
+    #[serde(rename = "type")]
+    /// Team type.
+    pub typ: TeamType,
+}
+
+/// Team link attributes.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TeamLinkAttributes {
+    /// The link's label.
+    pub label: String,
+    /// The link's position, used to sort links for the team.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<i32>,
+    /// ID of the team the link is associated with.
+    #[serde(default, skip_serializing)]
+    /// This value is set by the API.
+    pub team_id: Option<String>,
+    /// The URL for the link.
+    pub url: String,
+}
+
+/// A team link.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TeamLink {
+    /// Team link attributes.
+    pub attributes: TeamLinkAttributes,
+    /// The team link's identifier.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// Team link type.
+    pub typ: TeamLinkType,
+}
+
+/// User team permission attributes.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserTeamPermissionAttributes {
+    /// Object of team permission actions and boolean values that a logged in user can perform on this team.
+    #[serde(default, skip_serializing)]
+    /// This value is set by the API.
+    pub permissions: Option<serde_json::Value>,
+}
+
+/// A user related to a team, included through `include=users`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TeamIncludedUser {
+    /// Attributes of the user.
+    pub attributes: Option<UserAttributes>,
+    /// ID of the user.
+    pub id: String,
+}
+
+/// A link of a team, included through `include=team_links`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TeamIncludedLink {
+    /// Team link attributes.
+    pub attributes: TeamLinkAttributes,
+    /// The team link's identifier.
+    pub id: String,
+}
+
+/// The permissions of the current user on a team, included through `include=user_team_permissions`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TeamIncludedUserTeamPermission {
+    /// User team permission attributes.
+    pub attributes: Option<UserTeamPermissionAttributes>,
+    /// The user team permission's identifier.
+    pub id: String,
+}
+
+/// An object related to a team, tagged by its `type`.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum TeamIncluded {
+    /// Equals to `users`.
+    #[serde(rename = "users")]
+    User(TeamIncludedUser),
+    /// Equals to `team_links`.
+    #[serde(rename = "team_links")]
+    TeamLink(TeamIncludedLink),
+    /// Equals to `user_team_permissions`.
+    #[serde(rename = "user_team_permissions")]
+    UserTeamPermission(TeamIncludedUserTeamPermission),
+}
+
+/// Teams response links.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TeamsResponseLinks {
+    /// First link.
+    pub first: Option<String>,
+    /// Last link.
+    pub last: Option<String>,
+    /// Next link.
+    pub next: Option<String>,
+    /// Previous link.
+    pub prev: Option<String>,
+    /// Current link.
+    #[serde(rename = "self")]
+    pub self_link: Option<String>,
+}
+
+/// Teams response metadata pagination.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TeamsResponseMetaPagination {
+    /// The first offset.
+    pub first_offset: Option<i64>,
+    /// The last offset.
+    pub last_offset: Option<i64>,
+    /// Pagination limit.
+    pub limit: Option<i64>,
+    /// The next offset.
+    pub next_offset: Option<i64>,
+    /// The offset.
+    pub offset: Option<i64>,
+    /// The previous offset.
+    pub prev_offset: Option<i64>,
+    /// Total results.
+    pub total: Option<i64>,
+    #[serde(rename = "type")]
+    /// Offset type.
+    pub typ: Option<String>,
+}
+
+/// Teams response metadata.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TeamsResponseMeta {
+    /// Teams response metadata pagination.
+    pub pagination: Option<TeamsResponseMetaPagination>,
+}
+
+/// Response with a team.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TeamResponse {
+    /// A team.
+    pub data: Team,
+    /// Resources related to the team.
+    pub included: Option<Vec<TeamIncluded>>,
+}
+
+/// Response with multiple teams.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TeamsResponse {
+    /// Teams response data.
+    pub data: Vec<Team>,
+    /// Resources related to the teams.
+    pub included: Option<Vec<TeamIncluded>>,
+    /// Teams response links.
+    pub links: Option<TeamsResponseLinks>,
+    /// Teams response metadata.
+    pub meta: Option<TeamsResponseMeta>,
+}
+
+/// Sorting options for teams.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TeamsSort {
+    /// Equals to `name`.
+    #[serde(rename = "name")]
+    NameAscending,
+    /// Equals to `-name`.
+    #[serde(rename = "-name")]
+    NameDescending,
+    /// Equals to `user_count`.
+    #[serde(rename = "user_count")]
+    UserCountAscending,
+    /// Equals to `-user_count`.
+    #[serde(rename = "-user_count")]
+    UserCountDescending,
+}
+
+/// Query parameters to list teams.
+#[derive(Debug, Default, Serialize)]
+pub struct ListTeamsRequest {
+    /// Size for a given page. The maximum allowed value is 100.
+    #[serde(rename = "page[size]", skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<i64>,
+    /// Specific page number to return.
+    #[serde(rename = "page[number]", skip_serializing_if = "Option::is_none")]
+    pub page_number: Option<i64>,
+    /// Specifies the order of the returned teams.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<TeamsSort>,
+    /// Comma-separated list of related objects to include in the response: `team_links`, `user_team_permissions`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include: Option<String>,
+    /// Search query. Can be team name, team handle, or email of team member.
+    #[serde(rename = "filter[keyword]", skip_serializing_if = "Option::is_none")]
+    pub filter_keyword: Option<String>,
+    /// When true, only returns teams the current user belongs to.
+    #[serde(rename = "filter[me]", skip_serializing_if = "Option::is_none")]
+    pub filter_me: Option<bool>,
+    /// Comma-separated list of team fields to return in the response.
+    #[serde(rename = "fields[team]", skip_serializing_if = "Option::is_none")]
+    pub fields_team: Option<String>,
+}
+
+/// Team creation attributes.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct TeamCreateAttributes {
+    /// Unicode representation of the avatar for the team, limited to a single grapheme.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<String>,
+    /// Banner selection for the team.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub banner: Option<i64>,
+    /// Free-form markdown description/content for the team's homepage.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The team's identifier.
+    pub handle: String,
+    /// Collection of hidden modules for the team.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hidden_modules: Option<Vec<String>>,
+    /// The name of the team.
+    pub name: String,
+    /// Collection of visible modules for the team.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visible_modules: Option<Vec<String>>,
+}
+
+/// Relationships formed with the team on creation.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct TeamCreateRelationships {
+    /// Users to add to the team.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub users: Option<RelationshipToUsers>,
+}
+
+/// Team create.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TeamCreateData {
+    /// Team creation attributes.
+    pub attributes: TeamCreateAttributes,
+    /// Relationships formed with the team on creation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationships: Option<TeamCreateRelationships>,
+    #[serde(rename = "type")]
+    /// Team type.
+    pub typ: TeamType,
+}
+
+/// Team update attributes.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct TeamUpdateAttributes {
+    /// Unicode representation of the avatar for the team, limited to a single grapheme.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<String>,
+    /// Banner selection for the team.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub banner: Option<i64>,
+    /// An identifier for the color representing the team.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<i32>,
+    /// Free-form markdown description/content for the team's homepage.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The team's identifier.
+    pub handle: String,
+    /// Collection of hidden modules for the team.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hidden_modules: Option<Vec<String>>,
+    /// The name of the team.
+    pub name: String,
+    /// Collection of visible modules for the team.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visible_modules: Option<Vec<String>>,
+}
+
+/// Team update request.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TeamUpdateData {
+    /// Team update attributes.
+    pub attributes: TeamUpdateAttributes,
+    #[serde(rename = "type")]
+    /// Team type.
+    pub typ: TeamType,
+}
+
+/// Path parameters to get a team.
+#[derive(Debug)]
+pub struct GetTeamRequest {
+    /// The team's identifier.
+    pub team_id: String,
+}
+
+/// Path parameters and body to update a team.
+#[derive(Debug)]
+pub struct UpdateTeamRequest {
+    /// The team's identifier.
+    pub team_id: String,
+    /// The changes to the team.
+    pub team: Request<TeamUpdateData>,
+}
+
+/// Path parameters to delete a team.
+#[derive(Debug)]
+pub struct DeleteTeamRequest {
+    /// The team's identifier.
+    pub team_id: String,
+}
+
+/// Team membership attributes.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct UserTeamAttributes {
+    /// The user's role within the team. Regular members have no role.
+    pub role: Option<UserTeamRole>,
+    /// The ID of the team the membership belongs to.
+    #[serde(default, skip_serializing)]
+    /// This value is set by the API.
+    pub team_id: Option<String>,
+}
+
+/// Relationship between a team membership and a team.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RelationshipToUserTeamTeamData {
+    /// The ID of the team associated with the membership.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// Team type.
+    pub typ: TeamType,
+}
+
+/// Relationships of a team membership.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserTeamRelationships {
+    /// Relationship between a team membership and a team.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team: Option<Response<RelationshipToUserTeamTeamData>>,
+    /// Relationship between a team membership and a user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<Response<RelationshipToUserData>>,
+}
+
+/// A user's relationship with a team.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserTeam {
+    /// Team membership attributes.
+    pub attributes: Option<UserTeamAttributes>,
+    /// The ID of a user's relationship with a team.
+    pub id: String,
+    /// Relationships of a team membership.
+    pub relationships: Option<UserTeamRelationships>,
+    #[serde(rename = "type")]
+    /// Team membership type.
+    pub typ: UserTeamType,
+}
+
+/// Team membership response.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserTeamResponse {
+    /// A user's relationship with a team.
+    pub data: UserTeam,
+    /// Resources related to the team memberships.
+    pub included: Option<Vec<TeamIncluded>>,
+}
+
+/// Team memberships response.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserTeamsResponse {
+    /// Team memberships response data.
+    pub data: Vec<UserTeam>,
+    /// Resources related to the team memberships.
+    pub included: Option<Vec<TeamIncluded>>,
+    /// Teams response links.
+    pub links: Option<TeamsResponseLinks>,
+    /// Teams response metadata.
+    pub meta: Option<TeamsResponseMeta>,
+}
+
+/// Sorting options for team memberships.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum UserTeamsSort {
+    /// Equals to `manager_name`.
+    #[serde(rename = "manager_name")]
+    ManagerNameAscending,
+    /// Equals to `-manager_name`.
+    #[serde(rename = "-manager_name")]
+    ManagerNameDescending,
+    /// Equals to `name`.
+    #[serde(rename = "name")]
+    NameAscending,
+    /// Equals to `-name`.
+    #[serde(rename = "-name")]
+    NameDescending,
+    /// Equals to `handle`.
+    #[serde(rename = "handle")]
+    HandleAscending,
+    /// Equals to `-handle`.
+    #[serde(rename = "-handle")]
+    HandleDescending,
+    /// Equals to `email`.
+    #[serde(rename = "email")]
+    EmailAscending,
+    /// Equals to `-email`.
+    #[serde(rename = "-email")]
+    EmailDescending,
+}
+
+/// Query parameters to list the memberships of a team.
+#[derive(Debug, Serialize)]
+pub struct ListTeamMembershipsRequest {
+    /// The team's identifier.
+    #[serde(skip)]
+    pub team_id: String,
+    /// Size for a given page. The maximum allowed value is 100.
+    #[serde(rename = "page[size]", skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<i64>,
+    /// Specific page number to return.
+    #[serde(rename = "page[number]", skip_serializing_if = "Option::is_none")]
+    pub page_number: Option<i64>,
+    /// Specifies the order of the returned team memberships.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<UserTeamsSort>,
+    /// Search query, can be user email or name.
+    #[serde(rename = "filter[keyword]", skip_serializing_if = "Option::is_none")]
+    pub filter_keyword: Option<String>,
+}
+
+/// Team membership creation data.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserTeamCreateData {
+    /// Team membership attributes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<UserTeamAttributes>,
+    /// Relationships of a team membership.
+    pub relationships: UserTeamRelationships,
+    #[serde(rename = "type")]
+    /// Team membership type.
+    pub typ: UserTeamType,
+}
+
+/// Path parameters and body to add a user to a team.
+#[derive(Debug)]
+pub struct CreateTeamMembershipRequest {
+    /// The team's identifier.
+    pub team_id: String,
+    /// The membership to create.
+    pub membership: Request<UserTeamCreateData>,
+}
+
+/// Team membership update data.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserTeamUpdateData {
+    /// Team membership attributes.
+    pub attributes: UserTeamAttributes,
+    #[serde(rename = "type")]
+    /// Team membership type.
+    pub typ: UserTeamType,
+}
+
+/// Path parameters and body to update the role of a user in a team.
+#[derive(Debug)]
+pub struct UpdateTeamMembershipRequest {
+    /// The team's identifier.
+    pub team_id: String,
+    /// The ID of the user.
+    pub user_id: String,
+    /// The changes to the membership.
+    pub membership: Request<UserTeamUpdateData>,
+}
+
+/// Path parameters to remove a user from a team.
+#[derive(Debug)]
+pub struct DeleteTeamMembershipRequest {
+    /// The team's identifier.
+    pub team_id: String,
+    /// The ID of the user.
+    pub user_id: String,
+}
+
+/// Team link creation data.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TeamLinkData {
+    /// Team link attributes.
+    pub attributes: TeamLinkAttributes,
+    #[serde(rename = "type")]
+    /// Team link type.
+    pub typ: TeamLinkType,
+}
+
+/// Path parameters to list the links of a team.
+#[derive(Debug)]
+pub struct ListTeamLinksRequest {
+    /// The team's identifier.
+    pub team_id: String,
+}
+
+/// Path parameters and body to create a team link.
+#[derive(Debug)]
+pub struct CreateTeamLinkRequest {
+    /// The team's identifier.
+    pub team_id: String,
+    /// The link to create.
+    pub link: Request<TeamLinkData>,
+}
+
+/// Path parameters to get or delete a team link.
+#[derive(Debug)]
+pub struct TeamLinkRequest {
+    /// The team's identifier.
+    pub team_id: String,
+    /// The link's identifier.
+    pub link_id: String,
+}
+
+/// Path parameters and body to update a team link.
+#[derive(Debug)]
+pub struct UpdateTeamLinkRequest {
+    /// The team's identifier.
+    pub team_id: String,
+    /// The link's identifier.
+    pub link_id: String,
+    /// The changes to the link.
+    pub link: Request<TeamLinkData>,
+}
+
+/// The action a team permission setting controls.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TeamPermissionSettingAction {
+    /// Equals to `manage_membership`.
+    #[serde(rename = "manage_membership")]
+    ManageMembership,
+    /// Equals to `edit`.
+    #[serde(rename = "edit")]
+    Edit,
+}
+
+impl TeamPermissionSettingAction {
+    /// The path segment of the action.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TeamPermissionSettingAction::ManageMembership => "manage_membership",
+            TeamPermissionSettingAction::Edit => "edit",
+        }
+    }
+}
+
+/// Who is allowed to perform the action of a team permission setting.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TeamPermissionSettingValue {
+    /// Equals to `admins`.
+    #[serde(rename = "admins")]
+    Admins,
+    /// Equals to `members`.
+    #[serde(rename = "members")]
+    Members,
+    /// Equals to `organization`.
+    #[serde(rename = "organization")]
+    Organization,
+    /// Equals to `user_access_manage`.
+    #[serde(rename = "user_access_manage")]
+    UserAccessManage,
+    /// Equals to `teams_manage`.
+    #[serde(rename = "teams_manage")]
+    TeamsManage,
+}
+
+/// Team permission setting attributes.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TeamPermissionSettingAttributes {
+    /// The action the permission setting controls.
+    #[serde(default, skip_serializing)]
+    /// This value is set by the API.
+    pub action: Option<TeamPermissionSettingAction>,
+    /// Whether or not the permission setting is editable by the current user.
+    #[serde(default, skip_serializing)]
+    /// This value is set by the API.
+    pub editable: Option<bool>,
+    /// Possible values for the action.
+    #[serde(default, skip_serializing)]
+    /// This value is set by the API.
+    pub options: Option<Vec<TeamPermissionSettingValue>>,
+    /// The team permission name.
+    #[serde(default, skip_serializing)]
+    /// This value is set by the API.
+    pub title: Option<String>,
+    /// Who is allowed to perform the action.
+    pub value: Option<TeamPermissionSettingValue>,
+}
+
+/// Team permission setting.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TeamPermissionSetting {
+    /// Team permission setting attributes.
+    pub attributes: Option<TeamPermissionSettingAttributes>,
+    /// The identifier of the permission setting.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// Team permission setting type.
+    pub typ: TeamPermissionSettingType,
+}
+
+/// Team permission setting update attributes.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TeamPermissionSettingUpdateAttributes {
+    /// Who is allowed to perform the action.
+    pub value: TeamPermissionSettingValue,
+}
+
+/// Team permission setting update data.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TeamPermissionSettingUpdateData {
+    /// Team permission setting update attributes.
+    pub attributes: TeamPermissionSettingUpdateAttributes,
+    #[serde(rename = "type")]
+    /// Team permission setting type.
+    pub typ: TeamPermissionSettingType,
+}
+
+/// Path parameters to list the permission settings of a team.
+#[derive(Debug)]
+pub struct ListTeamPermissionSettingsRequest {
+    /// The team's identifier.
+    pub team_id: String,
+}
+
+/// Path parameters and body to update a team permission setting.
+#[derive(Debug)]
+pub struct UpdateTeamPermissionSettingRequest {
+    /// The team's identifier.
+    pub team_id: String,
+    /// The action the permission setting controls.
+    pub action: TeamPermissionSettingAction,
+    /// The new value of the permission setting.
+    pub setting: Request<TeamPermissionSettingUpdateData>,
+}
+
+// Responses and requests
+/// Team link response.
+pub type TeamLinkResponse = Response<TeamLink>;
+/// Team links response.
+pub type TeamLinksResponse = Response<Vec<TeamLink>>;
+/// Team permission setting response.
+pub type TeamPermissionSettingResponse = Response<TeamPermissionSetting>;
+/// Team permission settings response.
+pub type TeamPermissionSettingsResponse = Response<Vec<TeamPermissionSetting>>;
+/// Response to the deletion of a team.
+pub type DeleteTeamResponse = EmptyResponse;
+/// Response to the removal of a user from a team.
+pub type DeleteTeamMembershipResponse = EmptyResponse;
+/// Response to the deletion of a team link.
+pub type DeleteTeamLinkResponse = EmptyResponse;
+
+/// The team to create.
+pub type CreateTeamRequest = Request<TeamCreateData>;
@@ -0,0 +1,596 @@
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, Duration, Months, NaiveDate, NaiveDateTime};
+use serde_derive::{Deserialize, Serialize};
+
+use super::client::Response;
+
+/// Format of the hours accepted by the usage endpoints, `[YYYY-MM-DDThh]`.
+const HOUR_FORMAT: &str = "%Y-%m-%dT%H";
+/// Format of the days accepted by the usage endpoints, `[YYYY-MM-DD]`.
+const DAY_FORMAT: &str = "%Y-%m-%d";
+/// Format of the months accepted by the usage endpoints, `[YYYY-MM]`.
+const MONTH_FORMAT: &str = "%Y-%m";
+
+/// Type of usage data.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum UsageTimeSeriesType {
+    /// Equals to `usage_timeseries`.
+    #[serde(rename = "usage_timeseries")]
+    UsageTimeseries,
+}
+
+/// Usage of a given product, for a given hour.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HourlyUsageMeasurement {
+    /// Type of usage, such as `apm_host_count`.
+    pub usage_type: String,
+    /// Contains the number measured for the given `usage_type` during the hour.
+    pub value: Option<i64>,
+}
+
+/// Attributes of hourly usage for a product family for an org for a time period.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HourlyUsageAttributes {
+    /// The account name.
+    pub account_name: Option<String>,
+    /// The account public ID.
+    pub account_public_id: Option<String>,
+    /// List of the measured usage values for the product family for the org for the time period.
+    pub measurements: Option<Vec<HourlyUsageMeasurement>>,
+    /// The organization name.
+    pub org_name: Option<String>,
+    /// The product for which usage is being reported.
+    pub product_family: Option<String>,
+    /// The organization public ID.
+    pub public_id: Option<String>,
+    /// The region of the Datadog instance that the organization belongs to.
+    pub region: Option<String>,
+    /// Datetime in ISO-8601 format, UTC. The hour for the usage.
+    pub timestamp: Option<String>,
+}
+
+/// Hourly usage for a product family for an org.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HourlyUsage {
+    /// Attributes of hourly usage for a product family for an org for a time period.
+    pub attributes: Option<HourlyUsageAttributes>,
+    /// Unique ID of the response.
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    /// Type of usage data.
+    pub typ: Option<UsageTimeSeriesType>,
+}
+
+/// The pagination metadata of the hourly usage.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HourlyUsagePagination {
+    /// The cursor to get the next results (if any).
+    pub next_record_id: Option<String>,
+}
+
+/// The object containing document metadata.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HourlyUsageMetadata {
+    /// The pagination metadata of the hourly usage.
+    pub pagination: Option<HourlyUsagePagination>,
+}
+
+/// Hourly usage response.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HourlyUsageResponse {
+    /// Response containing hourly usage.
+    pub data: Vec<HourlyUsage>,
+    /// The object containing document metadata.
+    pub meta: Option<HourlyUsageMetadata>,
+}
+
+impl HourlyUsageResponse {
+    /// The record ID to request the next results with, if there are any.
+    pub fn next_record_id(&self) -> Option<&str> {
+        self.meta
+            .as_ref()
+            .and_then(|meta| meta.pagination.as_ref())
+            .and_then(|pagination| pagination.next_record_id.as_deref())
+    }
+}
+
+/// Query parameters to get hourly usage by product family.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct GetHourlyUsageRequest {
+    /// Datetime in ISO-8601 format, UTC, precise to hour: `[YYYY-MM-DDThh]`.
+    #[serde(rename = "filter[timestamp][start]")]
+    pub filter_timestamp_start: String,
+    /// Datetime in ISO-8601 format, UTC, precise to hour: `[YYYY-MM-DDThh]`, exclusive.
+    #[serde(
+        rename = "filter[timestamp][end]",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub filter_timestamp_end: Option<String>,
+    /// Comma separated list of product families to retrieve, such as `infra_hosts,logs`,
+    /// or `all`.
+    #[serde(rename = "filter[product_families]")]
+    pub filter_product_families: String,
+    /// Include child org usage in the response.
+    #[serde(
+        rename = "filter[include_descendants]",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub filter_include_descendants: Option<bool>,
+    /// Include breakdown of usage by subcategories where applicable.
+    #[serde(
+        rename = "filter[include_breakdown]",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub filter_include_breakdown: Option<bool>,
+    /// Comma separated list of product family versions to use in the format
+    /// `product_family:version`.
+    #[serde(rename = "filter[versions]", skip_serializing_if = "Option::is_none")]
+    pub filter_versions: Option<String>,
+    /// Maximum number of results to return (between 1 and 500) - defaults to 500.
+    #[serde(rename = "page[limit]", skip_serializing_if = "Option::is_none")]
+    pub page_limit: Option<i32>,
+    /// List following results with a next_record_id provided in the previous query.
+    #[serde(
+        rename = "page[next_record_id]",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub page_next_record_id: Option<String>,
+}
+
+/// A range of time to get usage for, from `start` included to `end` excluded, in UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UsageDateRange {
+    /// Start of the range, included.
+    pub start: NaiveDateTime,
+    /// End of the range, excluded.
+    pub end: NaiveDateTime,
+}
+
+impl UsageDateRange {
+    /// The range from the start of `start` to the start of `end`.
+    pub fn from_dates(start: NaiveDate, end: NaiveDate) -> Self {
+        UsageDateRange {
+            start: start.and_time(Default::default()),
+            end: end.and_time(Default::default()),
+        }
+    }
+
+    /// Splits the range into consecutive windows no longer than `max_window`.
+    /// An empty range has no windows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_window` is zero or negative.
+    pub fn windows(&self, max_window: Duration) -> Vec<UsageDateRange> {
+        assert!(
+            max_window > Duration::zero(),
+            "the maximum window must be positive"
+        );
+
+        let mut windows = Vec::new();
+        let mut start = self.start;
+        while start < self.end {
+            let end = (start + max_window).min(self.end);
+            windows.push(UsageDateRange { start, end });
+            start = end;
+        }
+        windows
+    }
+
+    /// Splits the range into the calendar months it covers. Every window but the first one
+    /// starts on the first day of a month.
+    pub fn month_windows(&self) -> Vec<UsageDateRange> {
+        let mut windows = Vec::new();
+        let mut start = self.start;
+        while start < self.end {
+            let month = start
+                .date()
+                .with_day0(0)
+                .expect("the first day of a month is valid");
+            let next_month = (month + Months::new(1)).and_time(Default::default());
+            let end = next_month.min(self.end);
+            windows.push(UsageDateRange { start, end });
+            start = end;
+        }
+        windows
+    }
+
+    /// The start of the range, formatted as `[YYYY-MM-DDThh]`.
+    pub fn start_hour(&self) -> String {
+        self.start.format(HOUR_FORMAT).to_string()
+    }
+
+    /// The end of the range, formatted as `[YYYY-MM-DDThh]`.
+    pub fn end_hour(&self) -> String {
+        self.end.format(HOUR_FORMAT).to_string()
+    }
+
+    /// The start of the range, formatted as `[YYYY-MM-DD]`.
+    pub fn start_day(&self) -> String {
+        self.start.format(DAY_FORMAT).to_string()
+    }
+
+    /// The end of the range, formatted as `[YYYY-MM-DD]`.
+    pub fn end_day(&self) -> String {
+        self.end.format(DAY_FORMAT).to_string()
+    }
+
+    /// The month of the start of the range, formatted as `[YYYY-MM]`.
+    pub fn start_month(&self) -> String {
+        self.start.format(MONTH_FORMAT).to_string()
+    }
+}
+
+/// Sums the measurements of hourly usages by product family, then by usage type.
+///
+/// Hours without a value for a usage type are skipped.
+pub fn total_hourly_usage<'a>(
+    usages: impl IntoIterator<Item = &'a HourlyUsage>,
+) -> BTreeMap<String, BTreeMap<String, i64>> {
+    let mut totals: BTreeMap<String, BTreeMap<String, i64>> = BTreeMap::new();
+    for attributes in usages
+        .into_iter()
+        .filter_map(|usage| usage.attributes.as_ref())
+    {
+        let product_family = attributes.product_family.clone().unwrap_or_default();
+        let product_totals = totals.entry(product_family).or_default();
+        for measurement in attributes.measurements.iter().flatten() {
+            if let Some(value) = measurement.value {
+                *product_totals
+                    .entry(measurement.usage_type.clone())
+                    .or_default() += value;
+            }
+        }
+    }
+    totals
+}
+
+/// View of the costs, either for the parent organization only or broken down by sub-organization.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CostView {
+    /// Equals to `summary`.
+    #[serde(rename = "summary")]
+    Summary,
+    /// Equals to `sub-org`.
+    #[serde(rename = "sub-org")]
+    SubOrg,
+}
+
+/// Query parameters to get the estimated cost across multi-org and single root-org accounts.
+///
+/// Either the months or the days of the range must be given, up to the current day.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct GetEstimatedCostRequest {
+    /// Cost breakdown view, `summary` by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub view: Option<CostView>,
+    /// Datetime in ISO-8601 format, UTC, precise to month: `[YYYY-MM]` for cost beginning
+    /// this month.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_month: Option<String>,
+    /// Datetime in ISO-8601 format, UTC, precise to month: `[YYYY-MM]` for cost ending
+    /// this month.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_month: Option<String>,
+    /// Datetime in ISO-8601 format, UTC, precise to day: `[YYYY-MM-DD]` for cost beginning
+    /// this day.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    /// Datetime in ISO-8601 format, UTC, precise to day: `[YYYY-MM-DD]` for cost ending
+    /// this day.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    /// Boolean to specify whether to include accounts connected to the current account as
+    /// partner customers in the Datadog partner network program.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_connected_accounts: Option<bool>,
+}
+
+/// Query parameters to get the historical cost across multi-org and single root-org accounts.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct GetHistoricalCostRequest {
+    /// Cost breakdown view, `summary` by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub view: Option<CostView>,
+    /// Datetime in ISO-8601 format, UTC, precise to month: `[YYYY-MM]` for cost beginning
+    /// this month.
+    pub start_month: String,
+    /// Datetime in ISO-8601 format, UTC, precise to month: `[YYYY-MM]` for cost ending
+    /// this month.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_month: Option<String>,
+    /// Boolean to specify whether to include accounts connected to the current account as
+    /// partner customers in the Datadog partner network program.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_connected_accounts: Option<bool>,
+}
+
+/// Query parameters to get the projected cost of the current month.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct GetProjectedCostRequest {
+    /// Cost breakdown view, `summary` by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub view: Option<CostView>,
+    /// Boolean to specify whether to include accounts connected to the current account as
+    /// partner customers in the Datadog partner network program.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_connected_accounts: Option<bool>,
+}
+
+/// Type of cost data.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CostByOrgType {
+    /// Equals to `cost_by_org`.
+    #[serde(rename = "cost_by_org")]
+    CostByOrg,
+}
+
+/// Type of projected cost data.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ProjectedCostType {
+    /// Equals to `projected_cost`.
+    #[serde(rename = "projected_cost")]
+    ProjectedCost,
+}
+
+/// Charges breakdown.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChargebackBreakdown {
+    /// The type of charge for a particular product, such as `committed` or `on_demand`.
+    pub charge_type: Option<String>,
+    /// The cost for a particular product and charge type during a given month.
+    pub cost: Option<f64>,
+    /// The product for which cost is being reported.
+    pub product_name: Option<String>,
+}
+
+/// Cost attributes data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostByOrgAttributes {
+    /// The account name.
+    pub account_name: Option<String>,
+    /// The account public ID.
+    pub account_public_id: Option<String>,
+    /// List of charges data reported for the requested month.
+    pub charges: Option<Vec<ChargebackBreakdown>>,
+    /// The month requested.
+    pub date: Option<String>,
+    /// The organization name.
+    pub org_name: Option<String>,
+    /// The organization public ID.
+    pub public_id: Option<String>,
+    /// The region of the Datadog instance that the organization belongs to.
+    pub region: Option<String>,
+    /// The total cost of products for the month.
+    pub total_cost: Option<f64>,
+}
+
+/// Cost data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostByOrg {
+    /// Cost attributes data.
+    pub attributes: Option<CostByOrgAttributes>,
+    /// Unique ID of the response.
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    /// Type of cost data.
+    pub typ: Option<CostByOrgType>,
+}
+
+/// Projected Cost attributes data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectedCostAttributes {
+    /// The account name.
+    pub account_name: Option<String>,
+    /// The account public ID.
+    pub account_public_id: Option<String>,
+    /// List of charges data reported for the requested month.
+    pub charges: Option<Vec<ChargebackBreakdown>>,
+    /// The month requested.
+    pub date: Option<String>,
+    /// The organization name.
+    pub org_name: Option<String>,
+    /// The total projected cost of products for the month.
+    pub projected_total_cost: Option<f64>,
+    /// The organization public ID.
+    pub public_id: Option<String>,
+    /// The region of the Datadog instance that the organization belongs to.
+    pub region: Option<String>,
+}
+
+/// Projected Cost data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectedCost {
+    /// Projected Cost attributes data.
+    pub attributes: Option<ProjectedCostAttributes>,
+    /// Unique ID of the response.
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    /// Type of projected cost data.
+    pub typ: Option<ProjectedCostType>,
+}
+
+/// Direction to sort the usage attribution by.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UsageSortDirection {
+    /// Equals to `asc`.
+    Asc,
+    /// Equals to `desc`.
+    Desc,
+}
+
+/// Query parameters to get the monthly usage attribution by tag.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct GetMonthlyUsageAttributionRequest {
+    /// Datetime in ISO-8601 format, UTC, precise to hour: `[YYYY-MM]` for usage beginning
+    /// in this month.
+    pub start_month: String,
+    /// Datetime in ISO-8601 format, UTC, precise to hour: `[YYYY-MM]` for usage ending this
+    /// month.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_month: Option<String>,
+    /// Comma-separated list of usage types to return, such as `api_usage,infra_host_usage`,
+    /// or `*` for all of them.
+    pub fields: String,
+    /// The direction to sort by: `desc` or `asc`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_direction: Option<UsageSortDirection>,
+    /// The field to sort by.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_name: Option<String>,
+    /// Comma separated list of tag keys used to group usage. If no value is provided the
+    /// usage is not broken down by tags.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag_breakdown_keys: Option<String>,
+    /// List following results with a next_record_id provided in the previous query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_record_id: Option<String>,
+    /// Include child org usage in the response. Defaults to `true`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_descendants: Option<bool>,
+}
+
+/// The object containing the aggregates.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsageAttributionAggregatesBody {
+    /// The aggregate type.
+    pub agg_type: Option<String>,
+    /// The field.
+    pub field: Option<String>,
+    /// The value for a given field.
+    pub value: Option<f64>,
+}
+
+/// The metadata for the current pagination.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MonthlyUsageAttributionPagination {
+    /// The cursor to get the next results (if any).
+    pub next_record_id: Option<String>,
+}
+
+/// The object containing document metadata.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MonthlyUsageAttributionMetadata {
+    /// An array of available aggregates.
+    pub aggregates: Option<Vec<UsageAttributionAggregatesBody>>,
+    /// The metadata for the current pagination.
+    pub pagination: Option<MonthlyUsageAttributionPagination>,
+}
+
+/// Usage Summary by tag for a given organization.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonthlyUsageAttributionBody {
+    /// Datetime in ISO-8601 format, UTC, precise to hour: `[YYYY-MM-DDThh]`.
+    pub month: Option<String>,
+    /// The name of the organization.
+    pub org_name: Option<String>,
+    /// The organization public ID.
+    pub public_id: Option<String>,
+    /// The region of the Datadog instance that the organization belongs to.
+    pub region: Option<String>,
+    /// The source of the usage attribution tag configuration and the selected tags in the
+    /// format `<source_org_name>:::<selected tag 1>///<selected tag 2>`.
+    pub tag_config_source: Option<String>,
+    /// Tag keys and values. A `null` value indicates the usage is not attributed to a value
+    /// for that tag key.
+    pub tags: Option<BTreeMap<String, Option<Vec<String>>>>,
+    /// Datetime of the most recent update to the usage values.
+    pub updated_at: Option<String>,
+    /// The usage of each requested field, such as `infra_host_usage` or
+    /// `infra_host_percentage`, keyed by field name.
+    #[serde(default)]
+    pub values: BTreeMap<String, f64>,
+}
+
+/// Response containing the monthly Usage Summary by tag(s).
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MonthlyUsageAttributionResponse {
+    /// The object containing document metadata.
+    pub metadata: Option<MonthlyUsageAttributionMetadata>,
+    /// Get usage summary by tag(s).
+    #[serde(default)]
+    pub usage: Vec<MonthlyUsageAttributionBody>,
+}
+
+impl MonthlyUsageAttributionResponse {
+    /// The record ID to request the next results with, if there are any.
+    pub fn next_record_id(&self) -> Option<&str> {
+        self.metadata
+            .as_ref()
+            .and_then(|metadata| metadata.pagination.as_ref())
+            .and_then(|pagination| pagination.next_record_id.as_deref())
+    }
+}
+
+/// Query parameters to get the billable usage summary.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct GetUsageBillableSummaryRequest {
+    /// Datetime in ISO-8601 format, UTC, precise to month: `[YYYY-MM]` for usage starting
+    /// this month. Defaults to the current month.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub month: Option<String>,
+}
+
+/// Response with properties for each aggregated usage type.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsageBillableSummaryBody {
+    /// The total account usage.
+    pub account_billable_usage: Option<i64>,
+    /// Elapsed usage hours for some billable product.
+    pub elapsed_usage_hours: Option<i64>,
+    /// The first billable hour for the org.
+    pub first_billable_usage_hour: Option<String>,
+    /// The last billable hour for the org.
+    pub last_billable_usage_hour: Option<String>,
+    /// The number of units used within the billable timeframe.
+    pub org_billable_usage: Option<i64>,
+    /// The percentage of account usage the org represents.
+    pub percentage_in_account: Option<f64>,
+    /// Units pertaining to the usage.
+    pub usage_unit: Option<String>,
+}
+
+/// Response with monthly summary of data billed by Datadog.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsageBillableSummaryHour {
+    /// The account name.
+    pub account_name: Option<String>,
+    /// The account public ID.
+    pub account_public_id: Option<String>,
+    /// The billing plan.
+    pub billing_plan: Option<String>,
+    /// Shows the last date of usage.
+    pub end_date: Option<String>,
+    /// The number of organizations.
+    pub num_orgs: Option<i64>,
+    /// The organization name.
+    pub org_name: Option<String>,
+    /// The organization public ID.
+    pub public_id: Option<String>,
+    /// Shows usage aggregation for a billing period.
+    pub ratio_in_month: Option<f64>,
+    /// The region of the Datadog instance that the organization belongs to.
+    pub region: Option<String>,
+    /// Shows the first date of usage.
+    pub start_date: Option<String>,
+    /// The billable usage of each product, such as `apm_host_top99p` or `logs_indexed_sum`,
+    /// keyed by usage type.
+    #[serde(default)]
+    pub usage: BTreeMap<String, UsageBillableSummaryBody>,
+}
+
+/// Response with monthly summary of data billed by Datadog.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UsageBillableSummaryResponse {
+    /// An array of objects regarding usage of billable summary.
+    #[serde(default)]
+    pub usage: Vec<UsageBillableSummaryHour>,
+}
+
+// Responses and requests
+/// Chargeback Summary response.
+pub type CostByOrgResponse = Response<Vec<CostByOrg>>;
+/// Projected Cost response.
+pub type ProjectedCostResponse = Response<Vec<ProjectedCost>>;
@@ -0,0 +1,409 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::client::{EmptyResponse, Request, Response};
+use super::roles::{PermissionAttributes, RelationshipToRoles, RoleAttributes};
+
+/// Users resource type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum UsersType {
+    /// Equals to `users`.
+    #[serde(rename = "users")]
+    Users,
+}
+
+/// Organizations resource type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum OrganizationsType {
+    /// Equals to `orgs`.
+    #[serde(rename = "orgs")]
+    Orgs,
+}
+
+/// User invitations type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum UserInvitationsType {
+    /// Equals to `user_invitations`.
+    #[serde(rename = "user_invitations")]
+    UserInvitations,
+}
+
+/// Relationship to user object.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelationshipToUserData {
+    /// A unique identifier that represents the user.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// Users resource type.
+    pub typ: UsersType,
+}
+
+/// Relationship to user.
+pub type RelationshipToUser = Response<RelationshipToUserData>;
+
+/// Relationship to multiple users.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelationshipToUsers {
+    /// Relationships to user objects.
+    pub data: Vec<RelationshipToUserData>,
+}
+
+/// Relationship to organization object.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelationshipToOrganizationData {
+    /// ID of the organization.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// Organizations resource type.
+    pub typ: OrganizationsType,
+}
+
+/// Relationship to an organization.
+pub type RelationshipToOrganization = Response<RelationshipToOrganizationData>;
+
+/// Relationship to multiple organizations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelationshipToOrganizations {
+    /// Relationships to organization objects.
+    pub data: Vec<RelationshipToOrganizationData>,
+}
+
+/// Attributes of the user.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserAttributes {
+    /// Creation time of the user.
+    pub created_at: Option<String>,
+    /// Whether the user is disabled.
+    pub disabled: Option<bool>,
+    /// Email of the user.
+    pub email: Option<String>,
+    /// Handle of the user.
+    pub handle: Option<String>,
+    /// URL of the user's icon.
+    pub icon: Option<String>,
+    /// Time that the user was last modified.
+    pub modified_at: Option<String>,
+    /// Name of the user.
+    pub name: Option<String>,
+    /// Whether the user is a service account.
+    pub service_account: Option<bool>,
+    /// Status of the user: `Active`, `Pending` or `Disabled`.
+    pub status: Option<String>,
+    /// Title of the user.
+    pub title: Option<String>,
+    /// Whether the user is verified.
+    pub verified: Option<bool>,
+}
+
+/// Relationships of the user object returned by the API.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserResponseRelationships {
+    /// Relationship to the organization of the user.
+    pub org: Option<RelationshipToOrganization>,
+    /// Relationship to the other organizations of the user.
+    pub other_orgs: Option<RelationshipToOrganizations>,
+    /// Relationship to other users.
+    pub other_users: Option<RelationshipToUsers>,
+    /// Relationship to the roles of the user.
+    pub roles: Option<RelationshipToRoles>,
+}
+
+/// User object returned by the API.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct User {
+    /// Attributes of the user.
+    pub attributes: Option<UserAttributes>,
+    /// ID of the user.
+    pub id: String,
+    /// Relationships of the user.
+    pub relationships: Option<UserResponseRelationships>,
+    #[serde(rename = "type")]
+    /// Users resource type.
+    pub typ: UsersType,
+}
+
+/// Attributes of an organization.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OrganizationAttributes {
+    /// Creation time of the organization.
+    pub created_at: Option<String>,
+    /// Description of the organization.
+    pub description: Option<String>,
+    /// Whether or not the organization is disabled.
+    pub disabled: Option<bool>,
+    /// Time of last organization modification.
+    pub modified_at: Option<String>,
+    /// Name of the organization.
+    pub name: Option<String>,
+    /// Public ID of the organization.
+    pub public_id: Option<String>,
+    /// Sharing type of the organization.
+    pub sharing: Option<String>,
+    /// URL of the site that this organization exists at.
+    pub url: Option<String>,
+}
+
+/// An organization related to a user.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserIncludedOrganization {
+    /// Attributes of the organization.
+    pub attributes: Option<OrganizationAttributes>,
+    /// ID of the organization.
+    pub id: String,
+}
+
+/// A permission related to a user.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserIncludedPermission {
+    /// Attributes of the permission.
+    pub attributes: Option<PermissionAttributes>,
+    /// ID of the permission.
+    pub id: String,
+}
+
+/// A role related to a user.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserIncludedRole {
+    /// Attributes of the role.
+    pub attributes: Option<RoleAttributes>,
+    /// The unique identifier of the role.
+    pub id: String,
+}
+
+/// An object related to a user, tagged by its `type`.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum UserResponseIncludedItem {
+    /// Equals to `orgs`.
+    #[serde(rename = "orgs")]
+    Organization(UserIncludedOrganization),
+    /// Equals to `permissions`.
+    #[serde(rename = "permissions")]
+    Permission(UserIncludedPermission),
+    /// Equals to `roles`.
+    #[serde(rename = "roles")]
+    Role(UserIncludedRole),
+}
+
+/// Response containing information about a single user.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserResponse {
+    /// User object returned by the API.
+    pub data: User,
+    /// Array of objects related to the user.
+    pub included: Option<Vec<UserResponseIncludedItem>>,
+}
+
+/// Page information of a list of users or roles.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UsersResponsePage {
+    /// Total count.
+    pub total_count: Option<i64>,
+    /// Total count of elements matching the filter.
+    pub total_filtered_count: Option<i64>,
+}
+
+/// Object describing meta attributes of a list of users or roles.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UsersResponseMeta {
+    /// Page information.
+    pub page: Option<UsersResponsePage>,
+}
+
+/// Response containing information about multiple users.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UsersResponse {
+    /// Array of returned users.
+    pub data: Vec<User>,
+    /// Array of objects related to the users.
+    pub included: Option<Vec<UserResponseIncludedItem>>,
+    /// Object describing meta attributes of the response.
+    pub meta: Option<UsersResponseMeta>,
+}
+
+/// Direction of sort.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QuerySortOrder {
+    /// Equals to `asc`.
+    Asc,
+    /// Equals to `desc`.
+    Desc,
+}
+
+/// Query parameters to list users.
+#[derive(Debug, Default, Serialize)]
+pub struct ListUsersRequest {
+    /// Size for a given page. The maximum allowed value is 100.
+    #[serde(rename = "page[size]", skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<i64>,
+    /// Specific page number to return.
+    #[serde(rename = "page[number]", skip_serializing_if = "Option::is_none")]
+    pub page_number: Option<i64>,
+    /// User attribute to order results by, such as `name`, `email` or `status`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+    /// Direction of sort.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_dir: Option<QuerySortOrder>,
+    /// Filter all users by the given string.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    /// Filter on status attribute, as a comma separated list of `Active`, `Pending` and `Disabled`.
+    #[serde(rename = "filter[status]", skip_serializing_if = "Option::is_none")]
+    pub filter_status: Option<String>,
+}
+
+/// Attributes of the user to create.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserCreateAttributes {
+    /// The email of the user.
+    pub email: String,
+    /// The name of the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The title of the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+/// Relationships of the user to create.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct UserRelationships {
+    /// Relationship to the roles of the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub roles: Option<RelationshipToRoles>,
+}
+
+/// Object to create a user.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserCreateData {
+    /// Attributes of the user.
+    pub attributes: UserCreateAttributes,
+    /// Relationships of the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationships: Option<UserRelationships>,
+    #[serde(rename = "type")]
+    /// Users resource type.
+    pub typ: UsersType,
+}
+
+/// Attributes of the user to update.
+/// Only the given attributes are changed.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct UserUpdateAttributes {
+    /// If the user is enabled or disabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
+    /// The email of the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// The name of the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Object to update a user.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserUpdateData {
+    /// Attributes of the user.
+    pub attributes: UserUpdateAttributes,
+    /// ID of the user.
+    pub id: String,
+    #[serde(rename = "type")]
+    /// Users resource type.
+    pub typ: UsersType,
+}
+
+/// Path parameters to get a user.
+#[derive(Debug)]
+pub struct GetUserRequest {
+    /// The ID of the user.
+    pub user_id: String,
+}
+
+/// Path parameters and body to update a user.
+#[derive(Debug)]
+pub struct UpdateUserRequest {
+    /// The ID of the user.
+    pub user_id: String,
+    /// The changes to the user.
+    pub user: Request<UserUpdateData>,
+}
+
+/// Path parameters to disable a user.
+#[derive(Debug)]
+pub struct DisableUserRequest {
+    /// The ID of the user.
+    pub user_id: String,
+}
+
+/// Path parameters to list the permissions of a user.
+#[derive(Debug)]
+pub struct ListUserPermissionsRequest {
+    /// The ID of the user.
+    pub user_id: String,
+}
+
+/// Relationships of a user invitation.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserInvitationRelationships {
+    /// Relationship to the invited user.
+    pub user: RelationshipToUser,
+}
+
+/// Object to create a user invitation.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserInvitationData {
+    /// Relationships of the invitation.
+    pub relationships: UserInvitationRelationships,
+    #[serde(rename = "type")]
+    /// User invitations type.
+    pub typ: UserInvitationsType,
+}
+
+/// Attributes of a user invitation.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserInvitationDataAttributes {
+    /// Creation time of the user invitation.
+    pub created_at: Option<String>,
+    /// Time of invitation expiration.
+    pub expires_at: Option<String>,
+    /// Type of invitation.
+    pub invite_type: Option<String>,
+    /// UUID of the user invitation.
+    pub uuid: Option<String>,
+}
+
+/// Object of a user invitation returned by the API.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserInvitationResponseData {
+    /// Attributes of the invitation.
+    pub attributes: Option<UserInvitationDataAttributes>,
+    /// ID of the user invitation.
+    pub id: Option<String>,
+    /// Relationships of the invitation.
+    pub relationships: Option<UserInvitationRelationships>,
+    #[serde(rename = "type")]
+    /// User invitations type.
+    pub typ: Option<UserInvitationsType>,
+}
+
+/// Path parameters to get a user invitation.
+#[derive(Debug)]
+pub struct GetInvitationRequest {
+    /// The UUID of the user invitation.
+    pub user_invitation_uuid: String,
+}
+
+// Responses and requests
+/// User invitations as returned by the API.
+pub type UserInvitationsResponse = Response<Vec<UserInvitationResponseData>>;
+/// User invitation as returned by the API.
+pub type UserInvitationResponse = Response<UserInvitationResponseData>;
+/// Response to the disabling of a user.
+pub type DisableUserResponse = EmptyResponse;
+
+/// The user to create.
+pub type CreateUserRequest = Request<UserCreateData>;
+/// The user invitations to send.
+pub type SendInvitationsRequest = Request<Vec<UserInvitationData>>;
@@ -7,7 +7,7 @@ use datadog_api_client::{
 };
 use url::Url;
 use wiremock::{
-    matchers::{method, path},
+    matchers::{body_json, method, path},
     Mock, MockServer, ResponseTemplate,
 };
 
@@ -61,6 +61,12 @@ async fn search_audit_logs() {
     let response = ResponseTemplate::new(201).set_body_raw(body, "application/json");
     Mock::given(method("GET"))
         .and(path("api/v2/audit/events/search"))
+        .and(body_json(serde_json::json!({
+            "filter": {"from": "now-15m", "query": "*", "to": "now"},
+            "options": {"timeOffset": 3600},
+            "page": {"cursor": null, "limit": 25},
+            "sort": "timestamp"
+        })))
         .respond_with(response)
         .mount(&mock_server)
         .await;
@@ -73,8 +79,8 @@ async fn search_audit_logs() {
                 to: "now".to_string(),
             },
             options: SearchAuditLogsEventsOptions {
-                time_offset: 0,
-                timezone: "GMT".to_string(),
+                time_offset: Some(3600),
+                timezone: None,
             },
             page: SearchAuditLogsEventsPage {
                 cursor: None,
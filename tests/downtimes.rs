@@ -0,0 +1,402 @@
+use datadog_api_client::{
+    client::ClientBuilder,
+    models::downtimes::{
+        CancelDowntimeRequest, CreateDowntimeRequest, DowntimeCreateRequestAttributes,
+        DowntimeCreateRequestData, DowntimeIncluded, DowntimeMonitorIdentifier,
+        DowntimeNotifyEndState, DowntimeNotifyEndType, DowntimeResourceType, DowntimeSchedule,
+        DowntimeScheduleRecurrence, DowntimeStatus, DowntimeUpdateRequestAttributes,
+        DowntimeUpdateRequestData, GetDowntimeRequest, ListDowntimesRequest,
+        ListMonitorDowntimesRequest, UpdateDowntimeRequest,
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+static DOWNTIME_BODY: &str = r#"
+    {
+        "data": {
+            "attributes": {
+                "canceled": null,
+                "created": "2024-02-01T10:00:00.000000+00:00",
+                "display_timezone": "Europe/Paris",
+                "message": "Weekly maintenance @slack-ops",
+                "modified": "2024-02-01T10:00:00.000000+00:00",
+                "monitor_identifier": {
+                    "monitor_tags": ["service:db"]
+                },
+                "mute_first_recovery_notification": false,
+                "notify_end_states": ["alert", "no data"],
+                "notify_end_types": ["expired"],
+                "schedule": {
+                    "current_downtime": {
+                        "end": "2024-02-04T04:00:00.000000+00:00",
+                        "start": "2024-02-04T02:00:00.000000+00:00"
+                    },
+                    "recurrences": [
+                        {
+                            "duration": "2h",
+                            "rrule": "FREQ=WEEKLY;BYDAY=SU",
+                            "start": "2024-02-04T02:00:00"
+                        }
+                    ],
+                    "timezone": "UTC"
+                },
+                "scope": "env:prod",
+                "status": "scheduled"
+            },
+            "id": "00000000-0000-1234-0000-000000000000",
+            "relationships": {
+                "created_by": {
+                    "data": {
+                        "id": "00000000-0000-0000-0000-000000000001",
+                        "type": "users"
+                    }
+                },
+                "monitor": {
+                    "data": null
+                }
+            },
+            "type": "downtime"
+        },
+        "included": [
+            {
+                "attributes": {
+                    "email": "jane@example.com",
+                    "handle": "jane@example.com",
+                    "name": "Jane",
+                    "status": "Active"
+                },
+                "id": "00000000-0000-0000-0000-000000000001",
+                "type": "users"
+            }
+        ]
+    }
+"#;
+
+#[tokio::test]
+async fn list_downtimes() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": [
+                {
+                    "attributes": {
+                        "monitor_identifier": {"monitor_id": 12345},
+                        "schedule": {
+                            "end": null,
+                            "start": "2024-02-01T10:00:00.000000+00:00"
+                        },
+                        "scope": "host:web-1",
+                        "status": "active"
+                    },
+                    "id": "00000000-0000-1234-0000-000000000001",
+                    "relationships": {
+                        "monitor": {"data": {"id": "12345", "type": "monitors"}}
+                    },
+                    "type": "downtime"
+                }
+            ],
+            "included": [
+                {
+                    "attributes": {"name": "High CPU"},
+                    "id": 12345,
+                    "type": "monitors"
+                }
+            ],
+            "meta": {"page": {"total_filtered_count": 1}}
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v2/downtime"))
+        .and(query_param("current_only", "true"))
+        .and(query_param("include", "monitor"))
+        .and(query_param("page[limit]", "10"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .list_downtimes(ListDowntimesRequest {
+            current_only: Some(true),
+            include: Some("monitor".to_string()),
+            page_limit: Some(10),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let attributes = res.data[0].attributes.as_ref().unwrap();
+    assert_eq!(
+        attributes.monitor_identifier,
+        Some(DowntimeMonitorIdentifier::Id { monitor_id: 12345 })
+    );
+    assert_eq!(
+        attributes.schedule,
+        Some(DowntimeSchedule::OneTime {
+            end: None,
+            start: Some("2024-02-01T10:00:00.000000+00:00".to_string()),
+        })
+    );
+    assert_eq!(attributes.status, Some(DowntimeStatus::Active));
+    match &res.included.unwrap()[0] {
+        DowntimeIncluded::Monitor(monitor) => assert_eq!(monitor.id, Some(12345)),
+        included => panic!("unexpected included resource: {:?}", included),
+    }
+    assert_eq!(
+        res.meta.unwrap().page.unwrap().total_filtered_count,
+        Some(1)
+    );
+}
+
+#[tokio::test]
+async fn create_downtime() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(DOWNTIME_BODY, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/downtime"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {
+                    "display_timezone": "Europe/Paris",
+                    "message": "Weekly maintenance @slack-ops",
+                    "monitor_identifier": {
+                        "monitor_tags": ["service:db"]
+                    },
+                    "notify_end_states": ["alert", "no data"],
+                    "notify_end_types": ["expired"],
+                    "schedule": {
+                        "recurrences": [
+                            {
+                                "duration": "2h",
+                                "rrule": "FREQ=WEEKLY;BYDAY=SU",
+                                "start": "2024-02-04T02:00:00"
+                            }
+                        ],
+                        "timezone": "UTC"
+                    },
+                    "scope": "env:prod"
+                },
+                "type": "downtime"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .create_downtime(CreateDowntimeRequest {
+            data: DowntimeCreateRequestData {
+                attributes: DowntimeCreateRequestAttributes {
+                    display_timezone: Some("Europe/Paris".to_string()),
+                    message: Some("Weekly maintenance @slack-ops".to_string()),
+                    monitor_identifier: DowntimeMonitorIdentifier::Tags {
+                        monitor_tags: vec!["service:db".to_string()],
+                    },
+                    mute_first_recovery_notification: None,
+                    notify_end_states: Some(vec![
+                        DowntimeNotifyEndState::Alert,
+                        DowntimeNotifyEndState::NoData,
+                    ]),
+                    notify_end_types: Some(vec![DowntimeNotifyEndType::Expired]),
+                    schedule: Some(DowntimeSchedule::Recurring {
+                        current_downtime: None,
+                        recurrences: vec![DowntimeScheduleRecurrence {
+                            duration: "2h".to_string(),
+                            rrule: "FREQ=WEEKLY;BYDAY=SU".to_string(),
+                            start: Some("2024-02-04T02:00:00".to_string()),
+                        }],
+                        timezone: Some("UTC".to_string()),
+                    }),
+                    scope: "env:prod".to_string(),
+                },
+                typ: DowntimeResourceType::Downtime,
+            },
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        res.data.id.as_deref(),
+        Some("00000000-0000-1234-0000-000000000000")
+    );
+    match res.data.attributes.unwrap().schedule.unwrap() {
+        DowntimeSchedule::Recurring {
+            current_downtime,
+            recurrences,
+            ..
+        } => {
+            assert!(current_downtime.is_some());
+            assert_eq!(recurrences[0].rrule, "FREQ=WEEKLY;BYDAY=SU");
+        }
+        schedule => panic!("unexpected schedule: {:?}", schedule),
+    }
+}
+
+#[tokio::test]
+async fn get_downtime() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(DOWNTIME_BODY, "application/json");
+    Mock::given(method("GET"))
+        .and(path(
+            "/api/v2/downtime/00000000-0000-1234-0000-000000000000",
+        ))
+        .and(query_param("include", "created_by,monitor"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .get_downtime(GetDowntimeRequest {
+            downtime_id: "00000000-0000-1234-0000-000000000000".to_string(),
+            include: Some("created_by,monitor".to_string()),
+        })
+        .await
+        .unwrap();
+    let relationships = res.data.relationships.unwrap();
+    assert!(relationships.monitor.unwrap().data.is_none());
+    assert_eq!(
+        relationships
+            .created_by
+            .unwrap()
+            .data
+            .unwrap()
+            .id
+            .as_deref(),
+        Some("00000000-0000-0000-0000-000000000001")
+    );
+    match &res.included.unwrap()[0] {
+        DowntimeIncluded::User(user) => assert_eq!(
+            user.attributes.as_ref().unwrap().email.as_deref(),
+            Some("jane@example.com")
+        ),
+        included => panic!("unexpected included resource: {:?}", included),
+    }
+}
+
+#[tokio::test]
+async fn update_downtime() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(DOWNTIME_BODY, "application/json");
+    Mock::given(method("PATCH"))
+        .and(path(
+            "/api/v2/downtime/00000000-0000-1234-0000-000000000000",
+        ))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {
+                    "schedule": {
+                        "end": "2024-02-01T12:00:00Z"
+                    }
+                },
+                "id": "00000000-0000-1234-0000-000000000000",
+                "type": "downtime"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .update_downtime(UpdateDowntimeRequest {
+            data: DowntimeUpdateRequestData {
+                attributes: DowntimeUpdateRequestAttributes {
+                    schedule: Some(DowntimeSchedule::OneTime {
+                        end: Some("2024-02-01T12:00:00Z".to_string()),
+                        start: None,
+                    }),
+                    ..Default::default()
+                },
+                id: "00000000-0000-1234-0000-000000000000".to_string(),
+                typ: DowntimeResourceType::Downtime,
+            },
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn cancel_downtime() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    Mock::given(method("DELETE"))
+        .and(path(
+            "/api/v2/downtime/00000000-0000-1234-0000-000000000000",
+        ))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .cancel_downtime(CancelDowntimeRequest {
+            downtime_id: "00000000-0000-1234-0000-000000000000".to_string(),
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn list_monitor_downtimes() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": [
+                {
+                    "attributes": {
+                        "end": null,
+                        "groups": ["host:web-1"],
+                        "scope": "host:web-1",
+                        "start": "2024-02-01T10:00:00.000000+00:00"
+                    },
+                    "id": "00000000-0000-1234-0000-000000000001",
+                    "type": "downtime_match"
+                }
+            ],
+            "meta": {"page": {"total_filtered_count": 1}}
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v2/monitor/12345/downtime_matches"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .list_monitor_downtimes(ListMonitorDowntimesRequest {
+            monitor_id: 12345,
+            page_offset: None,
+            page_limit: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        res.data[0].attributes.as_ref().unwrap().groups,
+        Some(vec!["host:web-1".to_string()])
+    );
+}
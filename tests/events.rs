@@ -0,0 +1,183 @@
+use datadog_api_client::{
+    client::ClientBuilder,
+    models::{
+        events::{
+            CreateEventRequest, EventAlertType, EventPriority, EventsQueryFilter,
+            ListEventsRequest, SearchEventsRequest,
+        },
+        search::{SearchPage, SearchSort},
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const LIST_EVENTS_BODY: &str = r#"
+    {
+        "data": [
+            {
+                "attributes": {
+                    "attributes": {
+                        "aggregation_key": "deploy-checkout",
+                        "evt": {"id": "7383479348734", "source_id": 1}
+                    },
+                    "message": "Deployed checkout v1.2.3",
+                    "tags": ["service:checkout"],
+                    "timestamp": "2024-02-01T10:00:00Z"
+                },
+                "id": "AAAAAYzXjK2Z6-MwJwAAAABBWXpYaks0cUFBQ1BBQU5Ia1BhcQ",
+                "type": "event"
+            }
+        ],
+        "links": {
+            "next": "https://app.datadoghq.com/api/v2/events?page[cursor]=eyJhZnRlciI6IkFRQUFBWUxrMFBUMTBrS"
+        },
+        "meta": {
+            "elapsed": 24,
+            "page": {
+                "after": "eyJhZnRlciI6IkFRQUFBWUxrMFBUMTBrS"
+            },
+            "request_id": "pddv1ChZPT0hOTlBvWlNVbWZWUE1kZkJCOFJ3Ii0KHdgr",
+            "status": "done"
+        }
+    }
+"#;
+
+#[tokio::test]
+async fn create_event() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "event": {
+                "date_happened": 1706781600,
+                "id": 7383479348734,
+                "id_str": "7383479348734",
+                "priority": "normal",
+                "tags": ["service:checkout"],
+                "text": "Deployed checkout v1.2.3",
+                "title": "Deploy",
+                "url": "https://app.datadoghq.com/event/event?id=7383479348734"
+            },
+            "status": "ok"
+        }
+    "#;
+    let response = ResponseTemplate::new(202).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v1/events"))
+        .and(body_json(serde_json::json!({
+            "aggregation_key": "deploy-checkout",
+            "alert_type": "success",
+            "priority": "low",
+            "tags": ["service:checkout"],
+            "text": "Deployed checkout v1.2.3",
+            "title": "Deploy"
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .create_event(CreateEventRequest {
+            aggregation_key: Some("deploy-checkout".to_string()),
+            alert_type: Some(EventAlertType::Success),
+            priority: Some(EventPriority::Low),
+            tags: Some(vec!["service:checkout".to_string()]),
+            text: "Deployed checkout v1.2.3".to_string(),
+            title: "Deploy".to_string(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.status.as_deref(), Some("ok"));
+    let event = res.event.unwrap();
+    assert_eq!(event.id_str.as_deref(), Some("7383479348734"));
+    assert_eq!(event.priority, Some(EventPriority::Normal));
+}
+
+#[tokio::test]
+async fn list_events() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(LIST_EVENTS_BODY, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v2/events"))
+        .and(query_param("filter[query]", "service:checkout"))
+        .and(query_param("sort", "-timestamp"))
+        .and(query_param(
+            "page[cursor]",
+            "eyJhZnRlciI6IkFRQUFBWUxrMFBUMTBrS",
+        ))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .list_events(ListEventsRequest {
+            filter_query: Some("service:checkout".to_string()),
+            sort: Some(SearchSort::NegativeTimestamp),
+            page_cursor: Some("eyJhZnRlciI6IkFRQUFBWUxrMFBUMTBrS".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.data.len(), 1);
+    assert_eq!(
+        res.data[0].attributes.message.as_deref(),
+        Some("Deployed checkout v1.2.3")
+    );
+    assert_eq!(res.next_cursor(), Some("eyJhZnRlciI6IkFRQUFBWUxrMFBUMTBrS"));
+}
+
+#[tokio::test]
+async fn search_events() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(LIST_EVENTS_BODY, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/events/search"))
+        .and(body_json(serde_json::json!({
+            "filter": {
+                "from": "now-1h",
+                "query": "source:deploy"
+            },
+            "page": {
+                "cursor": null,
+                "limit": 25
+            },
+            "sort": "timestamp"
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .search_events(SearchEventsRequest {
+            filter: Some(EventsQueryFilter {
+                from: Some("now-1h".to_string()),
+                query: Some("source:deploy".to_string()),
+                to: None,
+            }),
+            options: None,
+            page: Some(SearchPage {
+                cursor: None,
+                limit: 25,
+            }),
+            sort: Some(SearchSort::Timestamp),
+        })
+        .await
+        .unwrap();
+    let attributes = res.data[0].attributes.attributes.as_ref().unwrap();
+    assert_eq!(attributes["aggregation_key"], "deploy-checkout");
+}
@@ -0,0 +1,199 @@
+use datadog_api_client::{
+    client::ClientBuilder,
+    models::{
+        client::Request,
+        fastly_integration::{
+            FastlyAccountCreateAttributes, FastlyAccountCreateData, FastlyAccountRequest,
+            FastlyAccountType, FastlyAccountUpdateAttributes, FastlyAccountUpdateData,
+            FastlyService, FastlyServiceAttributes, FastlyServiceData, FastlyServiceRequest,
+            FastlyServiceType, FastlyServiceWriteRequest, UpdateFastlyAccountRequest,
+        },
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ACCOUNT_BODY: &str = r#"
+    {
+        "data": {
+            "attributes": {"name": "test-name", "services": [{"id": "6abc7de6893AbcDe9fghIj", "tags": ["env:prod"]}]},
+            "id": "abc123",
+            "type": "fastly-accounts"
+        }
+    }
+"#;
+
+const SERVICE_BODY: &str = r#"
+    {"data": {"attributes": {"tags": ["env:prod", "team:edge"]}, "id": "6abc7de6893AbcDe9fghIj", "type": "fastly-services"}}
+"#;
+
+#[tokio::test]
+async fn add_fastly_account() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(201).set_body_raw(ACCOUNT_BODY, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/integrations/fastly/accounts"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {
+                    "api_key": "fastly-secret-token",
+                    "name": "test-name",
+                    "services": [{"id": "6abc7de6893AbcDe9fghIj", "tags": ["env:prod"]}]
+                },
+                "type": "fastly-accounts"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let request = Request {
+        data: FastlyAccountCreateData {
+            attributes: FastlyAccountCreateAttributes {
+                api_key: "fastly-secret-token".into(),
+                name: "test-name".to_string(),
+                services: Some(vec![FastlyService {
+                    id: "6abc7de6893AbcDe9fghIj".to_string(),
+                    tags: Some(vec!["env:prod".to_string()]),
+                }]),
+            },
+            typ: FastlyAccountType::FastlyAccounts,
+        },
+    };
+    assert!(!format!("{:?}", request).contains("fastly-secret-token"));
+
+    let res = client.add_fastly_account(request).await.unwrap();
+    assert_eq!(res.data.id, "abc123");
+}
+
+#[tokio::test]
+async fn update_fastly_account() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(ACCOUNT_BODY, "application/json");
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/integrations/fastly/accounts/abc123"))
+        .and(body_json(serde_json::json!({
+            "data": {"attributes": {"api_key": "rotated-token"}, "type": "fastly-accounts"}
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .update_fastly_account(UpdateFastlyAccountRequest {
+            account_id: "abc123".to_string(),
+            account: Request {
+                data: FastlyAccountUpdateData {
+                    attributes: FastlyAccountUpdateAttributes {
+                        api_key: Some("rotated-token".into()),
+                    },
+                    typ: FastlyAccountType::FastlyAccounts,
+                },
+            },
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn list_fastly_services() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {"data": [{"attributes": {"tags": ["env:prod"]}, "id": "6abc7de6893AbcDe9fghIj", "type": "fastly-services"}]}
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v2/integrations/fastly/accounts/abc123/services"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .list_fastly_services(FastlyAccountRequest {
+            account_id: "abc123".to_string(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.data[0].id, "6abc7de6893AbcDe9fghIj");
+}
+
+#[tokio::test]
+async fn update_fastly_service() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(SERVICE_BODY, "application/json");
+    Mock::given(method("PATCH"))
+        .and(path(
+            "/api/v2/integrations/fastly/accounts/abc123/services/6abc7de6893AbcDe9fghIj",
+        ))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {"tags": ["env:prod", "team:edge"]},
+                "id": "6abc7de6893AbcDe9fghIj",
+                "type": "fastly-services"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .update_fastly_service(FastlyServiceWriteRequest {
+            account_id: "abc123".to_string(),
+            service: Request {
+                data: FastlyServiceData {
+                    attributes: Some(FastlyServiceAttributes {
+                        tags: Some(vec!["env:prod".to_string(), "team:edge".to_string()]),
+                    }),
+                    id: "6abc7de6893AbcDe9fghIj".to_string(),
+                    typ: FastlyServiceType::FastlyServices,
+                },
+            },
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.data.attributes.unwrap().tags.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn delete_fastly_service() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    Mock::given(method("DELETE"))
+        .and(path(
+            "/api/v2/integrations/fastly/accounts/abc123/services/6abc7de6893AbcDe9fghIj",
+        ))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .delete_fastly_service(FastlyServiceRequest {
+            account_id: "abc123".to_string(),
+            service_id: "6abc7de6893AbcDe9fghIj".to_string(),
+        })
+        .await
+        .unwrap();
+}
@@ -0,0 +1,238 @@
+use datadog_api_client::{
+    client::ClientBuilder,
+    models::{
+        client::Request,
+        gcp_integration::{
+            DeleteGcpStsAccountRequest, GcpHostFilter, GcpMetricNamespaceConfig,
+            GcpServiceAccountType, GcpStsServiceAccountAttributes, GcpStsServiceAccountCreateData,
+            GcpStsServiceAccountUpdateData, UpdateGcpStsAccountRequest,
+        },
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ACCOUNT_BODY: &str = r#"
+    {
+        "data": {
+            "attributes": {
+                "account_tags": ["team:infra"],
+                "automute": true,
+                "client_email": "datadog@my-project.iam.gserviceaccount.com",
+                "cloud_run_revision_filters": [],
+                "host_filters": ["env:prod", "!datadog:ignore"],
+                "is_cspm_enabled": false,
+                "metric_namespace_configs": [{"id": "cloudsql", "disabled": true}],
+                "resource_collection_enabled": true
+            },
+            "id": "d291291f-12c2-22g4-j290-123456678897",
+            "meta": {"accessible_projects": ["my-project"]},
+            "type": "gcp_service_account"
+        }
+    }
+"#;
+
+#[test]
+fn host_filter_round_trip() {
+    let filters: Vec<GcpHostFilter> =
+        serde_json::from_str(r#"["env:prod", "!datadog:ignore", "monitored"]"#).unwrap();
+    assert_eq!(filters[0], GcpHostFilter::include("env", "prod"));
+    assert_eq!(filters[1], GcpHostFilter::exclude("datadog", "ignore"));
+    assert_eq!(filters[2].value, None);
+    assert_eq!(
+        serde_json::to_value(&filters).unwrap(),
+        serde_json::json!(["env:prod", "!datadog:ignore", "monitored"])
+    );
+}
+
+#[tokio::test]
+async fn list_gcp_sts_accounts() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": [
+                {
+                    "attributes": {"client_email": "datadog@my-project.iam.gserviceaccount.com", "host_filters": ["env:prod"]},
+                    "id": "d291291f-12c2-22g4-j290-123456678897",
+                    "meta": {"accessible_projects": ["my-project", "my-other-project"]},
+                    "type": "gcp_service_account"
+                }
+            ]
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v2/integration/gcp/accounts"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client.list_gcp_sts_accounts().await.unwrap();
+    let account = &res.data[0];
+    assert_eq!(
+        account.attributes.as_ref().unwrap().host_filters,
+        Some(vec![GcpHostFilter::include("env", "prod")])
+    );
+    assert_eq!(
+        account.meta.as_ref().unwrap().accessible_projects,
+        Some(vec![
+            "my-project".to_string(),
+            "my-other-project".to_string()
+        ])
+    );
+}
+
+#[tokio::test]
+async fn create_gcp_sts_account() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(201).set_body_raw(ACCOUNT_BODY, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/integration/gcp/accounts"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {
+                    "account_tags": ["team:infra"],
+                    "automute": true,
+                    "client_email": "datadog@my-project.iam.gserviceaccount.com",
+                    "host_filters": ["env:prod", "!datadog:ignore"],
+                    "metric_namespace_configs": [{"id": "cloudsql", "disabled": true}],
+                    "resource_collection_enabled": true
+                },
+                "type": "gcp_service_account"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let mut data =
+        GcpStsServiceAccountCreateData::new("datadog@my-project.iam.gserviceaccount.com");
+    data.attributes.account_tags = Some(vec!["team:infra".to_string()]);
+    data.attributes.automute = Some(true);
+    data.attributes.host_filters = Some(vec![
+        GcpHostFilter::include("env", "prod"),
+        GcpHostFilter::exclude("datadog", "ignore"),
+    ]);
+    data.attributes.metric_namespace_configs =
+        Some(vec![GcpMetricNamespaceConfig::disabled("cloudsql")]);
+    data.attributes.resource_collection_enabled = Some(true);
+
+    let res = client
+        .create_gcp_sts_account(Request { data })
+        .await
+        .unwrap();
+    assert_eq!(res.data.id, "d291291f-12c2-22g4-j290-123456678897");
+}
+
+#[tokio::test]
+async fn update_gcp_sts_account() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(ACCOUNT_BODY, "application/json");
+    Mock::given(method("PATCH"))
+        .and(path(
+            "/api/v2/integration/gcp/accounts/d291291f-12c2-22g4-j290-123456678897",
+        ))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {"is_cspm_enabled": false},
+                "type": "gcp_service_account"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .update_gcp_sts_account(UpdateGcpStsAccountRequest {
+            account_id: "d291291f-12c2-22g4-j290-123456678897".to_string(),
+            account: Request {
+                data: GcpStsServiceAccountUpdateData {
+                    attributes: GcpStsServiceAccountAttributes {
+                        is_cspm_enabled: Some(false),
+                        ..Default::default()
+                    },
+                    id: None,
+                    typ: GcpServiceAccountType::GcpServiceAccount,
+                },
+            },
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        res.data.attributes.unwrap().metric_namespace_configs,
+        Some(vec![GcpMetricNamespaceConfig::disabled("cloudsql")])
+    );
+}
+
+#[tokio::test]
+async fn delete_gcp_sts_account() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    Mock::given(method("DELETE"))
+        .and(path(
+            "/api/v2/integration/gcp/accounts/d291291f-12c2-22g4-j290-123456678897",
+        ))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .delete_gcp_sts_account(DeleteGcpStsAccountRequest {
+            account_id: "d291291f-12c2-22g4-j290-123456678897".to_string(),
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn make_gcp_sts_delegate() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": {
+                "attributes": {"delegate_account_email": "ddgci-1234@datadog-gci-sts-us5-prod.iam.gserviceaccount.com"},
+                "id": "ddgci-1234@datadog-gci-sts-us5-prod.iam.gserviceaccount.com",
+                "type": "gcp_sts_delegate"
+            }
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/integration/gcp/sts_delegate"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client.make_gcp_sts_delegate().await.unwrap();
+    assert_eq!(
+        res.data
+            .attributes
+            .unwrap()
+            .delegate_account_email
+            .as_deref(),
+        Some("ddgci-1234@datadog-gci-sts-us5-prod.iam.gserviceaccount.com")
+    );
+}
@@ -0,0 +1,181 @@
+use datadog_api_client::{
+    client::ClientBuilder,
+    models::hosts::{
+        GetHostTotalsRequest, HostMuteSettings, HostsSortDirection, HostsSortField,
+        ListHostsRequest, MuteHostRequest, UnmuteHostRequest,
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn list_hosts() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "host_list": [
+                {
+                    "aliases": ["i-0123456789abcdef0"],
+                    "apps": ["agent", "ntp", "nginx"],
+                    "aws_name": "web-1",
+                    "host_name": "web-1.example.com",
+                    "id": 1234567890,
+                    "is_muted": false,
+                    "last_reported_time": 1706781600,
+                    "meta": {
+                        "agent_checks": [["ntp", "ntp", "ntp:d884b5186b651429", "OK", "", ""]],
+                        "agent_version": "7.50.3",
+                        "cpuCores": 4,
+                        "gohai": "{\"cpu\": {}}",
+                        "install_method": {"installer_version": "install_script-1.25.0", "tool": "install_script", "tool_version": "install_script"},
+                        "machine": "amd64",
+                        "nixV": ["ubuntu", "22.04", ""],
+                        "platform": "linux2",
+                        "pythonV": "3.11.5",
+                        "socket-fqdn": "web-1.example.com",
+                        "socket-hostname": "web-1"
+                    },
+                    "metrics": {"cpu": 4.5, "iowait": 0.2, "load": 0.65},
+                    "mute_timeout": null,
+                    "name": "web-1.example.com",
+                    "sources": ["aws", "agent"],
+                    "tags_by_source": {"Datadog": ["host:web-1"], "Amazon Web Services": ["region:us-east-1"]},
+                    "up": true
+                }
+            ],
+            "total_matching": 42,
+            "total_returned": 1
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v1/hosts"))
+        .and(query_param("filter", "env:prod"))
+        .and(query_param("sort_field", "cpu"))
+        .and(query_param("sort_dir", "desc"))
+        .and(query_param("start", "0"))
+        .and(query_param("count", "1"))
+        .and(query_param("include_hosts_metadata", "true"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .list_hosts(ListHostsRequest {
+            filter: Some("env:prod".to_string()),
+            sort_field: Some(HostsSortField::Cpu),
+            sort_dir: Some(HostsSortDirection::Desc),
+            start: Some(0),
+            count: Some(1),
+            include_hosts_metadata: Some(true),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.total_matching, Some(42));
+    let host = &res.host_list[0];
+    let meta = host.meta.as_ref().unwrap();
+    assert_eq!(meta.cpu_cores, Some(4));
+    assert_eq!(meta.socket_hostname.as_deref(), Some("web-1"));
+    assert_eq!(
+        meta.install_method.as_ref().unwrap().tool.as_deref(),
+        Some("install_script")
+    );
+    assert_eq!(host.metrics.as_ref().unwrap().load, Some(0.65));
+    assert_eq!(
+        host.tags_by_source.as_ref().unwrap()["Datadog"],
+        vec!["host:web-1"]
+    );
+}
+
+#[tokio::test]
+async fn get_host_totals() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"{"total_active": 42, "total_up": 40}"#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v1/hosts/totals"))
+        .and(query_param("from", "1706778000"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .get_host_totals(GetHostTotalsRequest {
+            from: Some(1706778000),
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.total_active, Some(42));
+    assert_eq!(res.total_up, Some(40));
+}
+
+#[tokio::test]
+async fn mute_host() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"{"action": "Muted", "end": 1706785200, "hostname": "web-1.example.com", "message": "Maintenance"}"#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v1/host/web-1.example.com/mute"))
+        .and(body_json(serde_json::json!({
+            "end": 1706785200,
+            "message": "Maintenance",
+            "override": true
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .mute_host(MuteHostRequest {
+            host_name: "web-1.example.com".to_string(),
+            settings: HostMuteSettings {
+                end: Some(1706785200),
+                message: Some("Maintenance".to_string()),
+                override_settings: Some(true),
+            },
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.action.as_deref(), Some("Muted"));
+    assert_eq!(res.end, Some(1706785200));
+}
+
+#[tokio::test]
+async fn unmute_host() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"{"action": "Unmuted", "hostname": "web-1.example.com"}"#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v1/host/web-1.example.com/unmute"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .unmute_host(UnmuteHostRequest {
+            host_name: "web-1.example.com".to_string(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.action.as_deref(), Some("Unmuted"));
+}
@@ -0,0 +1,454 @@
+use std::collections::BTreeMap;
+
+use datadog_api_client::{
+    client::{Client, ClientBuilder},
+    error::Error,
+    models::{
+        client::Request,
+        incidents::{
+            CreateIncidentRequest, CreateIncidentTodoRequest, DeleteIncidentRequest,
+            IncidentAttachmentAttributes, IncidentAttachmentDocument, IncidentAttachmentType,
+            IncidentAttachmentUpdateData, IncidentCreateAttributes, IncidentCreateData,
+            IncidentFieldAttributes, IncidentIncluded, IncidentIntegrationMetadataMetadata,
+            IncidentRequestRelationships, IncidentSearchSortOrder, IncidentSeverity, IncidentState,
+            IncidentTodoAssignee, IncidentTodoAttributes, IncidentTodoRequestData,
+            IncidentTodoType, IncidentType, IncidentUpdateAttributes, IncidentUpdateData,
+            IncidentUserRelationship, IncidentUserRelationshipData, IncidentUsersType,
+            ListIncidentIntegrationsRequest, SearchIncidentsRequest,
+            UpdateIncidentAttachmentsRequest, UpdateIncidentRequest,
+        },
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+static INCIDENT_BODY: &str = r#"
+    {
+        "data": {
+            "attributes": {
+                "created": "2024-02-01T10:00:00.000000+00:00",
+                "customer_impacted": false,
+                "fields": {
+                    "severity": {"type": "dropdown", "value": "SEV-2"},
+                    "state": {"type": "dropdown", "value": "active"},
+                    "teams": {"type": "autocomplete", "value": ["checkout"]}
+                },
+                "modified": "2024-02-01T10:00:00.000000+00:00",
+                "public_id": 42,
+                "severity": "SEV-2",
+                "state": "active",
+                "title": "Checkout errors"
+            },
+            "id": "00000000-0000-0000-1234-000000000000",
+            "relationships": {
+                "commander_user": {"data": {"id": "00000000-0000-0000-0000-000000000001", "type": "users"}},
+                "created_by_user": {"data": {"id": "00000000-0000-0000-0000-000000000001", "type": "users"}},
+                "last_modified_by_user": {"data": null}
+            },
+            "type": "incidents"
+        },
+        "included": [
+            {
+                "attributes": {"email": "jane@example.com", "handle": "jane@example.com", "name": "Jane"},
+                "id": "00000000-0000-0000-0000-000000000001",
+                "type": "users"
+            }
+        ]
+    }
+"#;
+
+fn client(mock_server: &MockServer, operation: &str) -> Client {
+    ClientBuilder::new("&", "")
+        .set_api_url(Url::parse(&mock_server.uri()).unwrap())
+        .set_unstable_operation_enabled(operation, true)
+        .build()
+        .expect("Client")
+}
+
+#[tokio::test]
+async fn unstable_operation_disabled() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder
+        .set_unstable_operation_enabled("v2.delete_incident", true)
+        .set_unstable_operation_enabled("v2.delete_incident", false)
+        .build()
+        .expect("Client");
+
+    Mock::given(method("DELETE"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .delete_incident(DeleteIncidentRequest {
+            incident_id: "00000000-0000-0000-1234-000000000000".to_string(),
+        })
+        .await;
+    assert!(
+        matches!(res, Err(Error::UnstableOperationDisabled(operation)) if operation == "v2.delete_incident")
+    );
+}
+
+#[tokio::test]
+async fn create_incident() {
+    let mock_server = MockServer::start().await;
+    let client = client(&mock_server, "v2.create_incident");
+
+    let response = ResponseTemplate::new(201).set_body_raw(INCIDENT_BODY, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/incidents"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {
+                    "customer_impacted": false,
+                    "fields": {
+                        "severity": {"type": "dropdown", "value": "SEV-2"},
+                        "teams": {"type": "autocomplete", "value": ["checkout"]}
+                    },
+                    "title": "Checkout errors"
+                },
+                "relationships": {
+                    "commander_user": {"data": {"id": "00000000-0000-0000-0000-000000000001", "type": "users"}}
+                },
+                "type": "incidents"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let mut fields = BTreeMap::new();
+    fields.insert("severity".to_string(), IncidentSeverity::Sev2.into());
+    fields.insert(
+        "teams".to_string(),
+        IncidentFieldAttributes::Autocomplete {
+            value: Some(vec!["checkout".to_string()]),
+        },
+    );
+    let res = client
+        .create_incident(CreateIncidentRequest {
+            data: IncidentCreateData {
+                attributes: IncidentCreateAttributes {
+                    customer_impact_scope: None,
+                    customer_impacted: false,
+                    fields: Some(fields),
+                    notification_handles: None,
+                    title: "Checkout errors".to_string(),
+                },
+                relationships: Some(IncidentRequestRelationships {
+                    commander_user: Some(IncidentUserRelationship {
+                        data: Some(IncidentUserRelationshipData {
+                            id: "00000000-0000-0000-0000-000000000001".to_string(),
+                            typ: IncidentUsersType::Users,
+                        }),
+                    }),
+                }),
+                typ: IncidentType::Incidents,
+            },
+        })
+        .await
+        .unwrap();
+    let attributes = res.data.attributes.unwrap();
+    assert_eq!(attributes.severity, Some(IncidentSeverity::Sev2));
+    assert_eq!(attributes.state, Some(IncidentState::Active));
+    let relationships = res.data.relationships.unwrap();
+    assert!(relationships.last_modified_by_user.unwrap().data.is_none());
+    assert!(matches!(
+        res.included.unwrap()[0],
+        IncidentIncluded::User(_)
+    ));
+}
+
+#[tokio::test]
+async fn update_incident() {
+    let mock_server = MockServer::start().await;
+    let client = client(&mock_server, "v2.update_incident");
+
+    let response = ResponseTemplate::new(200).set_body_raw(INCIDENT_BODY, "application/json");
+    Mock::given(method("PATCH"))
+        .and(path(
+            "/api/v2/incidents/00000000-0000-0000-1234-000000000000",
+        ))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {
+                    "fields": {"state": {"type": "dropdown", "value": "resolved"}}
+                },
+                "id": "00000000-0000-0000-1234-000000000000",
+                "type": "incidents"
+            }
+        })))
+        .respond_with(response)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut fields = BTreeMap::new();
+    fields.insert("state".to_string(), IncidentState::Resolved.into());
+    client
+        .update_incident(UpdateIncidentRequest {
+            incident_id: "00000000-0000-0000-1234-000000000000".to_string(),
+            incident: Request {
+                data: IncidentUpdateData {
+                    attributes: IncidentUpdateAttributes {
+                        fields: Some(fields),
+                        ..Default::default()
+                    },
+                    id: "00000000-0000-0000-1234-000000000000".to_string(),
+                    relationships: None,
+                    typ: IncidentType::Incidents,
+                },
+            },
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn search_incidents() {
+    let mock_server = MockServer::start().await;
+    let client = client(&mock_server, "v2.search_incidents");
+
+    let incident: serde_json::Value = serde_json::from_str(INCIDENT_BODY).unwrap();
+    let body = serde_json::json!({
+        "data": {
+            "attributes": {
+                "facets": {"severity": [{"count": 1, "name": "SEV-2"}]},
+                "incidents": [{"data": incident["data"]}],
+                "total": 1
+            },
+            "type": "incidents_search_results"
+        },
+        "meta": {"pagination": {"next_offset": 10, "offset": 0, "size": 10}}
+    });
+    Mock::given(method("GET"))
+        .and(path("/api/v2/incidents/search"))
+        .and(query_param("query", "state:active"))
+        .and(query_param("sort", "-created"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .search_incidents(SearchIncidentsRequest {
+            query: "state:active".to_string(),
+            sort: Some(IncidentSearchSortOrder::NegativeCreated),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let attributes = res.data.attributes.unwrap();
+    assert_eq!(attributes.total, Some(1));
+    assert_eq!(
+        attributes.incidents[0]
+            .data
+            .attributes
+            .as_ref()
+            .unwrap()
+            .public_id,
+        Some(42)
+    );
+}
+
+#[tokio::test]
+async fn create_incident_todo() {
+    let mock_server = MockServer::start().await;
+    let client = client(&mock_server, "v2.create_incident_todo");
+
+    let body = r#"
+        {
+            "data": {
+                "attributes": {
+                    "assignees": [
+                        "@jane@example.com",
+                        {"icon": "https://example.com/icon.png", "id": "U01", "name": "John", "source": "slack"}
+                    ],
+                    "completed": null,
+                    "content": "Roll back the deployment",
+                    "due_date": null,
+                    "incident_id": "00000000-0000-0000-1234-000000000000"
+                },
+                "id": "00000000-0000-0000-4567-000000000000",
+                "type": "incident_todos"
+            }
+        }
+    "#;
+    let response = ResponseTemplate::new(201).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path(
+            "/api/v2/incidents/00000000-0000-0000-1234-000000000000/relationships/todos",
+        ))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {
+                    "assignees": ["@jane@example.com"],
+                    "completed": null,
+                    "content": "Roll back the deployment",
+                    "due_date": null
+                },
+                "type": "incident_todos"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .create_incident_todo(CreateIncidentTodoRequest {
+            incident_id: "00000000-0000-0000-1234-000000000000".to_string(),
+            todo: Request {
+                data: IncidentTodoRequestData {
+                    attributes: IncidentTodoAttributes {
+                        assignees: vec![IncidentTodoAssignee::Handle(
+                            "@jane@example.com".to_string(),
+                        )],
+                        completed: None,
+                        content: "Roll back the deployment".to_string(),
+                        due_date: None,
+                        incident_id: None,
+                    },
+                    typ: IncidentTodoType::IncidentTodos,
+                },
+            },
+        })
+        .await
+        .unwrap();
+    let attributes = res.data.attributes.unwrap();
+    assert!(matches!(
+        &attributes.assignees[1],
+        IncidentTodoAssignee::Anonymous(assignee) if assignee.source == "slack"
+    ));
+}
+
+#[tokio::test]
+async fn list_incident_integrations() {
+    let mock_server = MockServer::start().await;
+    let client = client(&mock_server, "v2.list_incident_integrations");
+
+    let body = r##"
+        {
+            "data": [
+                {
+                    "attributes": {
+                        "created": "2024-02-01T10:00:00.000000+00:00",
+                        "incident_id": "00000000-0000-0000-1234-000000000000",
+                        "integration_type": 1,
+                        "metadata": {
+                            "channels": [
+                                {
+                                    "channel_id": "C0123456789",
+                                    "channel_name": "#incident-42",
+                                    "redirect_url": "https://slack.com/app_redirect?channel=C0123456789&team=T01234567",
+                                    "team_id": "T01234567"
+                                }
+                            ]
+                        },
+                        "status": 2
+                    },
+                    "id": "00000000-abcd-0001-0000-000000000000",
+                    "type": "incident_integrations"
+                }
+            ],
+            "meta": {"pagination": {"next_offset": 1, "offset": 0, "size": 1}}
+        }
+    "##;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path(
+            "/api/v2/incidents/00000000-0000-0000-1234-000000000000/relationships/integrations",
+        ))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .list_incident_integrations(ListIncidentIntegrationsRequest {
+            incident_id: "00000000-0000-0000-1234-000000000000".to_string(),
+        })
+        .await
+        .unwrap();
+    let attributes = res.data[0].attributes.as_ref().unwrap();
+    match &attributes.metadata {
+        IncidentIntegrationMetadataMetadata::Slack { channels } => {
+            assert_eq!(channels[0].channel_name, "#incident-42")
+        }
+        metadata => panic!("unexpected metadata: {:?}", metadata),
+    }
+}
+
+#[tokio::test]
+async fn update_incident_attachments() {
+    let mock_server = MockServer::start().await;
+    let client = client(&mock_server, "v2.update_incident_attachments");
+
+    let body = r#"
+        {
+            "data": [
+                {
+                    "attributes": {
+                        "attachment": {"documentUrl": "https://app.datadoghq.com/notebook/123", "title": "Postmortem"},
+                        "attachment_type": "postmortem"
+                    },
+                    "id": "00000000-abcd-0002-0000-000000000000",
+                    "type": "incident_attachments"
+                }
+            ]
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("PATCH"))
+        .and(path(
+            "/api/v2/incidents/00000000-0000-0000-1234-000000000000/attachments",
+        ))
+        .and(body_json(serde_json::json!({
+            "data": [
+                {
+                    "attributes": {
+                        "attachment": {"documentUrl": "https://app.datadoghq.com/notebook/123", "title": "Postmortem"},
+                        "attachment_type": "postmortem"
+                    },
+                    "type": "incident_attachments"
+                },
+                {
+                    "id": "00000000-abcd-0003-0000-000000000000",
+                    "type": "incident_attachments"
+                }
+            ]
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .update_incident_attachments(UpdateIncidentAttachmentsRequest {
+            incident_id: "00000000-0000-0000-1234-000000000000".to_string(),
+            attachments: Request {
+                data: vec![
+                    IncidentAttachmentUpdateData {
+                        attributes: Some(IncidentAttachmentAttributes::Postmortem {
+                            attachment: IncidentAttachmentDocument {
+                                document_url: "https://app.datadoghq.com/notebook/123".to_string(),
+                                title: "Postmortem".to_string(),
+                            },
+                        }),
+                        id: None,
+                        typ: IncidentAttachmentType::IncidentAttachments,
+                    },
+                    IncidentAttachmentUpdateData {
+                        attributes: None,
+                        id: Some("00000000-abcd-0003-0000-000000000000".to_string()),
+                        typ: IncidentAttachmentType::IncidentAttachments,
+                    },
+                ],
+            },
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.data.len(), 1);
+}
@@ -0,0 +1,474 @@
+use datadog_api_client::{
+    client::ClientBuilder,
+    error::Error,
+    models::{
+        client::Request,
+        key_management::{
+            ApiKeyCreateData, ApiKeyCreateUpdateAttributes, ApiKeyIdRequest, ApiKeysSort,
+            ApiKeysType, ApplicationKeyCreateAttributes, ApplicationKeyCreateData,
+            ApplicationKeyIdRequest, ApplicationKeyUpdateAttributes, ApplicationKeyUpdateData,
+            ApplicationKeysType, KeyIncluded, ListApiKeysRequest, ListApplicationKeysRequest,
+            UpdateApplicationKeyRequest,
+        },
+    },
+    secret::SecretString,
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, header, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const CREATED_API_KEY_BODY: &str = r#"
+    {
+        "data": {
+            "attributes": {
+                "category": "default",
+                "created_at": "2024-02-01T10:00:00.000000+00:00",
+                "key": "0123456789abcdef0123456789abcdef",
+                "last4": "cdef",
+                "modified_at": "2024-02-01T10:00:00.000000+00:00",
+                "name": "ci",
+                "remote_config_read_enabled": true
+            },
+            "id": "new-key-id",
+            "type": "api_keys"
+        }
+    }
+"#;
+
+const CURRENT_API_KEY_BODY: &str = r#"
+    {
+        "data": {
+            "attributes": {"last4": "9999", "name": "ci", "remote_config_read_enabled": true},
+            "id": "old-key-id",
+            "type": "api_keys"
+        }
+    }
+"#;
+
+#[test]
+fn secret_string_redacts_debug() {
+    let secret = SecretString::from("0123456789abcdef");
+    assert_eq!(format!("{:?}", secret), "SecretString([REDACTED])");
+    assert_eq!(secret.expose_secret(), "0123456789abcdef");
+}
+
+#[tokio::test]
+async fn create_api_key() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response =
+        ResponseTemplate::new(201).set_body_raw(CREATED_API_KEY_BODY, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/api_keys"))
+        .and(body_json(serde_json::json!({
+            "data": {"attributes": {"name": "ci"}, "type": "api_keys"}
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .create_api_key(Request {
+            data: ApiKeyCreateData {
+                attributes: ApiKeyCreateUpdateAttributes {
+                    name: "ci".to_string(),
+                    remote_config_read_enabled: None,
+                },
+                typ: ApiKeysType::ApiKeys,
+            },
+        })
+        .await
+        .unwrap();
+    let attributes = res.data.attributes.as_ref().unwrap();
+    assert_eq!(
+        attributes.key.as_ref().unwrap().expose_secret(),
+        "0123456789abcdef0123456789abcdef"
+    );
+    assert!(!format!("{:?}", res).contains("0123456789abcdef"));
+    assert!(!serde_json::to_string(&res)
+        .unwrap()
+        .contains("0123456789abcdef"));
+}
+
+#[tokio::test]
+async fn list_api_keys() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": [
+                {
+                    "attributes": {"created_at": "2024-02-01T10:00:00.000000+00:00", "last4": "9999", "name": "ci"},
+                    "id": "old-key-id",
+                    "relationships": {"created_by": {"data": {"id": "user-id", "type": "users"}}},
+                    "type": "api_keys"
+                }
+            ],
+            "included": [
+                {"attributes": {"email": "jane@example.com", "handle": "jane@example.com"}, "id": "user-id", "type": "users"}
+            ],
+            "meta": {"page": {"total_filtered_count": 1}}
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v2/api_keys"))
+        .and(query_param("sort", "-created_at"))
+        .and(query_param("filter", "ci"))
+        .and(query_param("include", "created_by"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .list_api_keys(ListApiKeysRequest {
+            sort: Some(ApiKeysSort::CreatedAtDescending),
+            filter: Some("ci".to_string()),
+            include: Some("created_by".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert!(res.data[0].attributes.as_ref().unwrap().key.is_none());
+    match &res.included.unwrap()[0] {
+        KeyIncluded::User(user) => assert_eq!(user.id, "user-id"),
+    }
+}
+
+#[tokio::test]
+async fn rotate_api_key() {
+    let mock_server = MockServer::start().await;
+    let client_builder = ClientBuilder::new("old-api-key", "app-key")
+        .set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/api_keys/old-key-id"))
+        .and(header("DD-API-KEY", "old-api-key"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_raw(CURRENT_API_KEY_BODY, "application/json"),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/api/v2/api_keys"))
+        .and(header("DD-API-KEY", "old-api-key"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {"name": "ci", "remote_config_read_enabled": true},
+                "type": "api_keys"
+            }
+        })))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_raw(CREATED_API_KEY_BODY, "application/json"),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/api_keys/old-key-id"))
+        .and(header("DD-API-KEY", "0123456789abcdef0123456789abcdef"))
+        .and(header("DD-APPLICATION-KEY", "app-key"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v2/api_keys/new-key-id"))
+        .and(header("DD-API-KEY", "0123456789abcdef0123456789abcdef"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_raw(CREATED_API_KEY_BODY, "application/json"),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let (client, created) = client
+        .rotate_api_key(ApiKeyIdRequest {
+            api_key_id: "old-key-id".to_string(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(created.data.id, "new-key-id");
+
+    client
+        .get_api_key(
+            datadog_api_client::models::key_management::GetApiKeyRequest {
+                api_key_id: "new-key-id".to_string(),
+                include: None,
+            },
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn rotate_api_key_deletes_new_key_on_failure() {
+    let mock_server = MockServer::start().await;
+    let client_builder = ClientBuilder::new("old-api-key", "app-key")
+        .set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/api_keys/old-key-id"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_raw(CURRENT_API_KEY_BODY, "application/json"),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/api/v2/api_keys"))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_raw(CREATED_API_KEY_BODY, "application/json"),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/api_keys/old-key-id"))
+        .respond_with(
+            ResponseTemplate::new(403)
+                .set_body_raw(r#"{"errors": ["Forbidden"]}"#, "application/json"),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/api_keys/new-key-id"))
+        .and(header("DD-API-KEY", "old-api-key"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .rotate_api_key(ApiKeyIdRequest {
+            api_key_id: "old-key-id".to_string(),
+        })
+        .await;
+    match res {
+        Err(Error::InvalidRequest(errors)) => assert_eq!(errors.0, vec!["Forbidden"]),
+        Err(error) => panic!("unexpected error: {:?}", error),
+        Ok(_) => panic!("the rotation should have failed"),
+    }
+}
+
+#[tokio::test]
+async fn rotate_api_key_deletes_new_key_without_value() {
+    let mock_server = MockServer::start().await;
+    let client_builder = ClientBuilder::new("old-api-key", "app-key")
+        .set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let created = r#"{"data": {"attributes": {"last4": "cdef", "name": "ci"}, "id": "new-key-id", "type": "api_keys"}}"#;
+    Mock::given(method("GET"))
+        .and(path("/api/v2/api_keys/old-key-id"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_raw(CURRENT_API_KEY_BODY, "application/json"),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/api/v2/api_keys"))
+        .respond_with(ResponseTemplate::new(201).set_body_raw(created, "application/json"))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/api_keys/old-key-id"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/api_keys/new-key-id"))
+        .and(header("DD-API-KEY", "old-api-key"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .rotate_api_key(ApiKeyIdRequest {
+            api_key_id: "old-key-id".to_string(),
+        })
+        .await;
+    assert!(matches!(res, Err(Error::KeyValueMissing)));
+}
+
+#[tokio::test]
+async fn list_application_keys() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": [
+                {
+                    "attributes": {"created_at": "2024-02-01T10:00:00.000000+00:00", "last4": "abcd", "name": "terraform", "scopes": ["dashboards_read"]},
+                    "id": "app-key-id",
+                    "relationships": {"owned_by": {"data": {"id": "user-id", "type": "users"}}},
+                    "type": "application_keys"
+                }
+            ],
+            "meta": {"page": {"total_filtered_count": 1}}
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v2/application_keys"))
+        .and(query_param("page[size]", "10"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .list_application_keys(ListApplicationKeysRequest {
+            page_size: Some(10),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        res.data[0].attributes.as_ref().unwrap().scopes,
+        Some(vec!["dashboards_read".to_string()])
+    );
+}
+
+#[tokio::test]
+async fn update_application_key() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"{"data": {"attributes": {"name": "terraform-prod"}, "id": "app-key-id", "type": "application_keys"}}"#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/application_keys/app-key-id"))
+        .and(body_json(serde_json::json!({
+            "data": {"attributes": {"name": "terraform-prod"}, "id": "app-key-id", "type": "application_keys"}
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .update_application_key(UpdateApplicationKeyRequest {
+            app_key_id: "app-key-id".to_string(),
+            application_key: Request {
+                data: ApplicationKeyUpdateData {
+                    attributes: ApplicationKeyUpdateAttributes {
+                        name: Some("terraform-prod".to_string()),
+                        ..Default::default()
+                    },
+                    id: "app-key-id".to_string(),
+                    typ: ApplicationKeysType::ApplicationKeys,
+                },
+            },
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        res.data.attributes.unwrap().name.as_deref(),
+        Some("terraform-prod")
+    );
+}
+
+#[tokio::test]
+async fn create_current_user_application_key() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": {
+                "attributes": {"key": "fedcba9876543210fedcba9876543210fedcba98", "last4": "cba98", "name": "terraform", "scopes": ["dashboards_read"]},
+                "id": "new-app-key-id",
+                "type": "application_keys"
+            }
+        }
+    "#;
+    let response = ResponseTemplate::new(201).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/current_user/application_keys"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {"name": "terraform", "scopes": ["dashboards_read"]},
+                "type": "application_keys"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .create_current_user_application_key(Request {
+            data: ApplicationKeyCreateData {
+                attributes: ApplicationKeyCreateAttributes {
+                    name: "terraform".to_string(),
+                    scopes: Some(vec!["dashboards_read".to_string()]),
+                },
+                typ: ApplicationKeysType::ApplicationKeys,
+            },
+        })
+        .await
+        .unwrap();
+    let key = res.data.attributes.unwrap().key.unwrap();
+    assert_eq!(
+        key.expose_secret(),
+        "fedcba9876543210fedcba9876543210fedcba98"
+    );
+}
+
+#[tokio::test]
+async fn rotate_current_user_application_key() {
+    let mock_server = MockServer::start().await;
+    let client_builder = ClientBuilder::new("api-key", "old-app-key")
+        .set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let current = r#"{"data": {"attributes": {"last4": "pkey", "name": "terraform", "scopes": ["dashboards_read", "monitors_read"]}, "id": "old-app-key-id", "type": "application_keys"}}"#;
+    Mock::given(method("GET"))
+        .and(path("/api/v2/current_user/application_keys/old-app-key-id"))
+        .and(header("DD-APPLICATION-KEY", "old-app-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(current, "application/json"))
+        .mount(&mock_server)
+        .await;
+    let created = r#"{"data": {"attributes": {"key": "new-app-key", "name": "terraform", "scopes": ["dashboards_read", "monitors_read"]}, "id": "new-app-key-id", "type": "application_keys"}}"#;
+    Mock::given(method("POST"))
+        .and(path("/api/v2/current_user/application_keys"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {"name": "terraform", "scopes": ["dashboards_read", "monitors_read"]},
+                "type": "application_keys"
+            }
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_raw(created, "application/json"))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/current_user/application_keys/old-app-key-id"))
+        .and(header("DD-API-KEY", "api-key"))
+        .and(header("DD-APPLICATION-KEY", "new-app-key"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let (_, created) = client
+        .rotate_current_user_application_key(ApplicationKeyIdRequest {
+            app_key_id: "old-app-key-id".to_string(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(created.data.id, "new-app-key-id");
+}
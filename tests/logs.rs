@@ -0,0 +1,275 @@
+use datadog_api_client::{
+    client::ClientBuilder,
+    models::{
+        logs::{
+            ListLogsGetRequest, ListLogsRequest, LogsAggregateBucketValue, LogsAggregateRequest,
+            LogsAggregationFunction, LogsCompute, LogsComputeType, LogsGroupBy, LogsGroupByValue,
+            LogsQueryFilter, LogsStorageTier,
+        },
+        search::{SearchPage, SearchSort},
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const LIST_LOGS_BODY: &str = r#"
+    {
+        "data": [
+            {
+                "attributes": {
+                    "attributes": {
+                        "customAttribute": 123,
+                        "duration": 2345
+                    },
+                    "host": "i-0123",
+                    "message": "Host connected to remote",
+                    "service": "agent",
+                    "status": "INFO",
+                    "tags": [
+                        "team:A"
+                    ],
+                    "timestamp": "2019-01-02T09:42:36.320Z"
+                },
+                "id": "AAAAAWgN8Xwgr1vKDQAAAABBV2dOOFh3ZzZobm1mWXJFYTR0OA",
+                "type": "log"
+            }
+        ],
+        "links": {
+            "next": "https://app.datadoghq.com/api/v2/logs/event?&page[cursor]=eyJzdGFydEF0IjoiQVFBQUFYS2tMS3pPbm40NGV3QUFBQUJCV0V0clRFdDZVbG8zY3pCRmNsbHJiVmxDWlEifQ=="
+        },
+        "meta": {
+            "elapsed": 132,
+            "page": {
+                "after": "eyJzdGFydEF0IjoiQVFBQUFYS2tMS3pPbm40NGV3QUFBQUJCV0V0clRFdDZVbG8zY3pCRmNsbHJiVmxDWlEifQ=="
+            },
+            "request_id": "MWlFUjVaWGZTTTZPYzM0VXp1OXU2d3xLSVpEMjZKQ0VKUTI0dEYtM3RSOFVR",
+            "status": "done",
+            "warnings": [
+                {
+                    "code": "unknown_index",
+                    "detail": "indexes: foo, bar",
+                    "title": "One or several indexes are missing or invalid, results hold data from the other indexes"
+                }
+            ]
+        }
+    }
+"#;
+
+#[tokio::test]
+async fn list_logs() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(LIST_LOGS_BODY, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v2/logs/events"))
+        .and(query_param("filter[query]", "service:agent"))
+        .and(query_param("filter[storage_tier]", "indexes"))
+        .and(query_param("sort", "-timestamp"))
+        .and(query_param("page[limit]", "5"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let logs = client
+        .list_logs(ListLogsGetRequest {
+            filter_query: Some("service:agent".to_string()),
+            filter_storage_tier: Some(LogsStorageTier::Indexes),
+            sort: Some(SearchSort::NegativeTimestamp),
+            page_limit: Some(5),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(logs.data.len(), 1);
+    assert_eq!(logs.data[0].attributes.host.as_deref(), Some("i-0123"));
+    assert_eq!(
+        logs.next_cursor(),
+        Some("eyJzdGFydEF0IjoiQVFBQUFYS2tMS3pPbm40NGV3QUFBQUJCV0V0clRFdDZVbG8zY3pCRmNsbHJiVmxDWlEifQ==")
+    );
+}
+
+#[tokio::test]
+async fn search_logs() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": [],
+            "meta": {
+                "elapsed": 12,
+                "request_id": "MWlFUjVaWGZTTTZPYzM0VXp1OXU2d3xLSVpEMjZKQ0VKUTI0dEYtM3RSOFVR",
+                "status": "done"
+            }
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/logs/events/search"))
+        .and(body_json(serde_json::json!({
+            "filter": {
+                "from": "now-15m",
+                "indexes": ["main", "web"],
+                "query": "service:web-app",
+                "to": "now"
+            },
+            "page": {
+                "cursor": null,
+                "limit": 25
+            },
+            "sort": "timestamp"
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let logs = client
+        .search_logs(ListLogsRequest {
+            filter: Some(LogsQueryFilter {
+                from: Some("now-15m".to_string()),
+                indexes: Some(vec!["main".to_string(), "web".to_string()]),
+                query: Some("service:web-app".to_string()),
+                storage_tier: None,
+                to: Some("now".to_string()),
+            }),
+            options: None,
+            page: Some(SearchPage {
+                cursor: None,
+                limit: 25,
+            }),
+            sort: Some(SearchSort::Timestamp),
+        })
+        .await
+        .unwrap();
+
+    assert!(logs.data.is_empty());
+    assert!(logs.next_cursor().is_none());
+}
+
+#[tokio::test]
+async fn aggregate_logs() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": {
+                "buckets": [
+                    {
+                        "by": {
+                            "service": "web-app",
+                            "@http.status_code": 200
+                        },
+                        "computes": {
+                            "c0": 1523,
+                            "c1": [
+                                {
+                                    "time": "2020-06-08T11:55:00Z",
+                                    "value": 12.5
+                                }
+                            ]
+                        }
+                    }
+                ]
+            },
+            "meta": {
+                "elapsed": 132,
+                "request_id": "MWlFUjVaWGZTTTZPYzM0VXp1OXU2d3xLSVpEMjZKQ0VKUTI0dEYtM3RSOFVR",
+                "status": "done"
+            }
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/logs/analytics/aggregate"))
+        .and(body_json(serde_json::json!({
+            "compute": [
+                {
+                    "aggregation": "count",
+                    "type": "total"
+                },
+                {
+                    "aggregation": "pc99",
+                    "interval": "5m",
+                    "metric": "@duration",
+                    "type": "timeseries"
+                }
+            ],
+            "filter": {
+                "query": "env:prod"
+            },
+            "group_by": [
+                {
+                    "facet": "service",
+                    "limit": 10
+                }
+            ]
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let aggregation = client
+        .aggregate_logs(LogsAggregateRequest {
+            compute: Some(vec![
+                LogsCompute {
+                    aggregation: LogsAggregationFunction::Count,
+                    interval: None,
+                    metric: None,
+                    typ: Some(LogsComputeType::Total),
+                },
+                LogsCompute {
+                    aggregation: LogsAggregationFunction::Pc99,
+                    interval: Some("5m".to_string()),
+                    metric: Some("@duration".to_string()),
+                    typ: Some(LogsComputeType::Timeseries),
+                },
+            ]),
+            filter: Some(LogsQueryFilter {
+                query: Some("env:prod".to_string()),
+                ..Default::default()
+            }),
+            group_by: Some(vec![LogsGroupBy {
+                facet: "service".to_string(),
+                histogram: None,
+                limit: Some(10),
+                missing: None,
+                sort: None,
+                total: None,
+            }]),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    let buckets = aggregation.data.unwrap().buckets;
+    assert_eq!(
+        buckets[0].by.get("service"),
+        Some(&LogsGroupByValue::String("web-app".to_string()))
+    );
+    assert_eq!(
+        buckets[0].by.get("@http.status_code"),
+        Some(&LogsGroupByValue::Number(200.0))
+    );
+    assert!(matches!(
+        buckets[0].computes.get("c0"),
+        Some(LogsAggregateBucketValue::Number(count)) if *count == 1523.0
+    ));
+    match buckets[0].computes.get("c1") {
+        Some(LogsAggregateBucketValue::Timeseries(points)) => {
+            assert_eq!(points[0].value, Some(12.5))
+        }
+        other => panic!("expected a timeseries, got {:?}", other),
+    }
+}
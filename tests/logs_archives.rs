@@ -0,0 +1,305 @@
+use datadog_api_client::{
+    client::ClientBuilder,
+    models::{
+        logs_archives::{
+            CreateLogsArchiveRequest, DeleteLogsArchiveRequest, LogsArchiveCreateRequestAttributes,
+            LogsArchiveCreateRequestDefinition, LogsArchiveDestination, LogsArchiveDestinationS3,
+            LogsArchiveEncryptionS3, LogsArchiveEncryptionS3Type, LogsArchiveIntegrationS3,
+            LogsArchiveOrderAttributes, LogsArchiveOrderDefinition, LogsArchiveOrderDefinitionType,
+            LogsArchiveReadRoleRequest, LogsArchiveType, UpdateLogsArchiveOrderRequest,
+        },
+        roles::{RelationshipToRoleData, RolesType},
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn list_logs_archives() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": [
+                {
+                    "attributes": {
+                        "destination": {
+                            "container": "container-name",
+                            "integration": {
+                                "client_id": "aaaaaaaa-1a1a-1a1a-1a1a-aaaaaaaaaaaa",
+                                "tenant_id": "aaaaaaaa-1a1a-1a1a-1a1a-aaaaaaaaaaaa"
+                            },
+                            "storage_account": "account-name",
+                            "type": "azure"
+                        },
+                        "include_tags": false,
+                        "name": "Nginx Archive",
+                        "query": "source:nginx",
+                        "rehydration_max_scan_size_in_gb": 100,
+                        "rehydration_tags": ["team:intake", "team:app"],
+                        "state": "WORKING"
+                    },
+                    "id": "a2zcMylnM4OCHpYusxIi1g",
+                    "type": "archives"
+                },
+                {
+                    "attributes": {
+                        "destination": {
+                            "bucket": "bucket-name",
+                            "integration": {
+                                "client_email": "youremail@example.com",
+                                "project_id": "project-id"
+                            },
+                            "path": "/path/to/archive",
+                            "type": "gcs"
+                        },
+                        "name": "Web Archive",
+                        "query": "source:web",
+                        "state": "FAILING"
+                    },
+                    "id": "b3zcMylnM4OCHpYusxIi1g",
+                    "type": "archives"
+                },
+                {
+                    "attributes": {
+                        "destination": null,
+                        "name": "Legacy Archive",
+                        "query": "*",
+                        "state": "UNKNOWN"
+                    },
+                    "id": "c4zcMylnM4OCHpYusxIi1g",
+                    "type": "archives"
+                }
+            ]
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v2/logs/config/archives"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let archives = client.list_logs_archives().await.unwrap();
+    let destinations: Vec<_> = archives
+        .data
+        .iter()
+        .map(|archive| archive.attributes.as_ref().unwrap().destination.as_ref())
+        .collect();
+
+    match destinations[0] {
+        Some(LogsArchiveDestination::Azure(azure)) => {
+            assert_eq!(azure.storage_account, "account-name")
+        }
+        other => panic!("expected an Azure destination, got {:?}", other),
+    }
+    assert!(matches!(
+        destinations[1],
+        Some(LogsArchiveDestination::Gcs(_))
+    ));
+    assert!(destinations[2].is_none());
+}
+
+#[tokio::test]
+async fn create_logs_archive() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": {
+                "attributes": {
+                    "destination": {
+                        "bucket": "my-bucket",
+                        "encryption": {
+                            "key": "arn:aws:kms:us-east-1:012345678901:key/DatadogIntegrationRoleKms",
+                            "type": "SSE_KMS"
+                        },
+                        "integration": {
+                            "account_id": "123456789012",
+                            "role_name": "DatadogGoClientTestIntegrationRole"
+                        },
+                        "path": "/path/blou",
+                        "type": "s3"
+                    },
+                    "include_tags": true,
+                    "name": "datadog-api-client-archive",
+                    "query": "service:toto",
+                    "state": "UNKNOWN"
+                },
+                "id": "a2zcMylnM4OCHpYusxIi1g",
+                "type": "archives"
+            }
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/logs/config/archives"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {
+                    "destination": {
+                        "bucket": "my-bucket",
+                        "encryption": {
+                            "key": "arn:aws:kms:us-east-1:012345678901:key/DatadogIntegrationRoleKms",
+                            "type": "SSE_KMS"
+                        },
+                        "integration": {
+                            "account_id": "123456789012",
+                            "role_name": "DatadogGoClientTestIntegrationRole"
+                        },
+                        "path": "/path/blou",
+                        "type": "s3"
+                    },
+                    "include_tags": true,
+                    "name": "datadog-api-client-archive",
+                    "query": "service:toto"
+                },
+                "type": "archives"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let archive = client
+        .create_logs_archive(CreateLogsArchiveRequest {
+            data: LogsArchiveCreateRequestDefinition {
+                attributes: LogsArchiveCreateRequestAttributes {
+                    destination: LogsArchiveDestination::S3(LogsArchiveDestinationS3 {
+                        bucket: "my-bucket".to_string(),
+                        encryption: Some(LogsArchiveEncryptionS3 {
+                            key: Some(
+                                "arn:aws:kms:us-east-1:012345678901:key/DatadogIntegrationRoleKms"
+                                    .to_string(),
+                            ),
+                            typ: LogsArchiveEncryptionS3Type::SseKms,
+                        }),
+                        integration: LogsArchiveIntegrationS3 {
+                            account_id: "123456789012".to_string(),
+                            role_name: "DatadogGoClientTestIntegrationRole".to_string(),
+                        },
+                        path: Some("/path/blou".to_string()),
+                        storage_class: None,
+                    }),
+                    include_tags: Some(true),
+                    name: "datadog-api-client-archive".to_string(),
+                    query: "service:toto".to_string(),
+                    rehydration_max_scan_size_in_gb: None,
+                    rehydration_tags: None,
+                },
+                typ: LogsArchiveType::Archives,
+            },
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(archive.data.id.as_deref(), Some("a2zcMylnM4OCHpYusxIi1g"));
+}
+
+#[tokio::test]
+async fn delete_logs_archive() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/logs/config/archives/a2zcMylnM4OCHpYusxIi1g"))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&mock_server)
+        .await;
+
+    client
+        .delete_logs_archive(DeleteLogsArchiveRequest {
+            archive_id: "a2zcMylnM4OCHpYusxIi1g".to_string(),
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn add_logs_archive_read_role() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    Mock::given(method("POST"))
+        .and(path(
+            "/api/v2/logs/config/archives/a2zcMylnM4OCHpYusxIi1g/readers",
+        ))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "id": "3653d3c6-0c75-11ea-ad28-fb5701eabc7d",
+                "type": "roles"
+            }
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&mock_server)
+        .await;
+
+    client
+        .add_logs_archive_read_role(LogsArchiveReadRoleRequest {
+            archive_id: "a2zcMylnM4OCHpYusxIi1g".to_string(),
+            role: RelationshipToRoleData {
+                id: "3653d3c6-0c75-11ea-ad28-fb5701eabc7d".to_string(),
+                typ: RolesType::Roles,
+            },
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn update_logs_archive_order() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": {
+                "attributes": {
+                    "archive_ids": ["a2zcMylnM4OCHpYusxIi1g", "a2zcMylnM4OCHpYusxIi2g"]
+                },
+                "type": "archive_order"
+            }
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("PUT"))
+        .and(path("/api/v2/logs/config/archive-order"))
+        .and(body_json(
+            serde_json::from_str::<serde_json::Value>(body).unwrap(),
+        ))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let order = client
+        .update_logs_archive_order(UpdateLogsArchiveOrderRequest {
+            data: LogsArchiveOrderDefinition {
+                attributes: LogsArchiveOrderAttributes {
+                    archive_ids: vec![
+                        "a2zcMylnM4OCHpYusxIi1g".to_string(),
+                        "a2zcMylnM4OCHpYusxIi2g".to_string(),
+                    ],
+                },
+                typ: LogsArchiveOrderDefinitionType::ArchiveOrder,
+            },
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(order.data.attributes.archive_ids.len(), 2);
+}
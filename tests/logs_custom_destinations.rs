@@ -0,0 +1,233 @@
+use datadog_api_client::{
+    client::ClientBuilder,
+    models::logs_custom_destinations::{
+        CreateLogsCustomDestinationRequest, CustomDestinationCreateRequestAttributes,
+        CustomDestinationCreateRequestDefinition, CustomDestinationForwardDestination,
+        CustomDestinationHttpDestination, CustomDestinationHttpDestinationAuth,
+        CustomDestinationType, CustomDestinationUpdateRequestAttributes,
+        CustomDestinationUpdateRequestDefinition, DeleteLogsCustomDestinationRequest,
+        GetLogsCustomDestinationRequest, UpdateLogsCustomDestinationRequest,
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn create_logs_custom_destination() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": {
+                "attributes": {
+                    "enabled": false,
+                    "forward_tags": false,
+                    "forward_tags_restriction_list": ["datacenter", "host"],
+                    "forward_tags_restriction_list_type": "ALLOW_LIST",
+                    "forwarder_destination": {
+                        "auth": {
+                            "type": "basic"
+                        },
+                        "endpoint": "https://example.com",
+                        "type": "http"
+                    },
+                    "name": "Nginx logs",
+                    "query": "source:nginx"
+                },
+                "id": "be5d7a69-d0c8-4d4d-8ee8-bba292d98139",
+                "type": "custom_destination"
+            }
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/logs/config/custom-destinations"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {
+                    "forwarder_destination": {
+                        "auth": {
+                            "password": "datadog-custom-destination-password",
+                            "type": "basic",
+                            "username": "datadog-custom-destination-username"
+                        },
+                        "endpoint": "https://example.com",
+                        "type": "http"
+                    },
+                    "name": "Nginx logs",
+                    "query": "source:nginx"
+                },
+                "type": "custom_destination"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let request = CreateLogsCustomDestinationRequest {
+        data: CustomDestinationCreateRequestDefinition {
+            attributes: CustomDestinationCreateRequestAttributes {
+                enabled: None,
+                forward_tags: None,
+                forward_tags_restriction_list: None,
+                forward_tags_restriction_list_type: None,
+                forwarder_destination: CustomDestinationForwardDestination::Http(
+                    CustomDestinationHttpDestination {
+                        auth: CustomDestinationHttpDestinationAuth::Basic {
+                            username: Some("datadog-custom-destination-username".to_string()),
+                            password: Some("datadog-custom-destination-password".into()),
+                        },
+                        endpoint: "https://example.com".to_string(),
+                    },
+                ),
+                name: "Nginx logs".to_string(),
+                query: Some("source:nginx".to_string()),
+            },
+            typ: CustomDestinationType::CustomDestination,
+        },
+    };
+    assert!(!format!("{:?}", request).contains("datadog-custom-destination-password"));
+
+    let destination = client
+        .create_logs_custom_destination(request)
+        .await
+        .unwrap();
+
+    match destination.data.attributes.unwrap().forwarder_destination {
+        Some(CustomDestinationForwardDestination::Http(http)) => assert!(matches!(
+            http.auth,
+            CustomDestinationHttpDestinationAuth::Basic { password: None, .. }
+        )),
+        other => panic!("expected an HTTP destination, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn get_logs_custom_destination() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": {
+                "attributes": {
+                    "enabled": true,
+                    "forward_tags": true,
+                    "forward_tags_restriction_list": [],
+                    "forward_tags_restriction_list_type": "BLOCK_LIST",
+                    "forwarder_destination": {
+                        "endpoint": "https://example.com",
+                        "type": "splunk_hec"
+                    },
+                    "name": "Splunk",
+                    "query": "*"
+                },
+                "id": "be5d7a69-d0c8-4d4d-8ee8-bba292d98139",
+                "type": "custom_destination"
+            }
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path(
+            "/api/v2/logs/config/custom-destinations/be5d7a69-d0c8-4d4d-8ee8-bba292d98139",
+        ))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let destination = client
+        .get_logs_custom_destination(GetLogsCustomDestinationRequest {
+            custom_destination_id: "be5d7a69-d0c8-4d4d-8ee8-bba292d98139".to_string(),
+        })
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        destination.data.attributes.unwrap().forwarder_destination,
+        Some(CustomDestinationForwardDestination::SplunkHec(_))
+    ));
+}
+
+#[tokio::test]
+async fn update_logs_custom_destination() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": {
+                "attributes": {
+                    "enabled": false,
+                    "name": "Nginx logs"
+                },
+                "id": "be5d7a69-d0c8-4d4d-8ee8-bba292d98139",
+                "type": "custom_destination"
+            }
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("PATCH"))
+        .and(path(
+            "/api/v2/logs/config/custom-destinations/be5d7a69-d0c8-4d4d-8ee8-bba292d98139",
+        ))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {
+                    "enabled": false
+                },
+                "id": "be5d7a69-d0c8-4d4d-8ee8-bba292d98139",
+                "type": "custom_destination"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .update_logs_custom_destination(UpdateLogsCustomDestinationRequest {
+            data: CustomDestinationUpdateRequestDefinition {
+                attributes: CustomDestinationUpdateRequestAttributes {
+                    enabled: Some(false),
+                    ..Default::default()
+                },
+                id: "be5d7a69-d0c8-4d4d-8ee8-bba292d98139".to_string(),
+                typ: CustomDestinationType::CustomDestination,
+            },
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn delete_logs_custom_destination() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    Mock::given(method("DELETE"))
+        .and(path(
+            "/api/v2/logs/config/custom-destinations/be5d7a69-d0c8-4d4d-8ee8-bba292d98139",
+        ))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&mock_server)
+        .await;
+
+    client
+        .delete_logs_custom_destination(DeleteLogsCustomDestinationRequest {
+            custom_destination_id: "be5d7a69-d0c8-4d4d-8ee8-bba292d98139".to_string(),
+        })
+        .await
+        .unwrap();
+}
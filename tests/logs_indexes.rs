@@ -0,0 +1,236 @@
+use datadog_api_client::{
+    client::ClientBuilder,
+    models::{
+        logs::LogsFilter,
+        logs_indexes::{
+            DeleteLogsIndexRequest, GetLogsIndexRequest, LogsExclusion, LogsExclusionFilter,
+            LogsIndex, LogsIndexUpdate, LogsIndexesOrder, UpdateLogsIndexRequest,
+        },
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const INDEX_BODY: &str = r#"
+    {
+        "daily_limit": 300000000,
+        "daily_limit_reset": {
+            "reset_time": "14:00",
+            "reset_utc_offset": "+02:00"
+        },
+        "daily_limit_warning_threshold_percentage": 70,
+        "exclusion_filters": [
+            {
+                "filter": {
+                    "query": "*",
+                    "sample_rate": 1.0
+                },
+                "is_enabled": false,
+                "name": "payment"
+            }
+        ],
+        "filter": {
+            "query": "source:python"
+        },
+        "is_rate_limited": false,
+        "name": "main",
+        "num_retention_days": 15
+    }
+"#;
+
+#[tokio::test]
+async fn list_logs_indexes() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = format!(r#"{{ "indexes": [{}] }}"#, INDEX_BODY);
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v1/logs/config/indexes"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let indexes = client.list_logs_indexes().await.unwrap();
+
+    assert_eq!(indexes.indexes[0].name, "main");
+    assert_eq!(
+        indexes.indexes[0].exclusion_filters.as_ref().unwrap()[0].name,
+        "payment"
+    );
+}
+
+#[tokio::test]
+async fn get_logs_index() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(INDEX_BODY, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v1/logs/config/indexes/main"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let index = client
+        .get_logs_index(GetLogsIndexRequest {
+            name: "main".to_string(),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(index.num_retention_days, Some(15));
+}
+
+#[tokio::test]
+async fn create_logs_index() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(INDEX_BODY, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v1/logs/config/indexes"))
+        .and(body_json(serde_json::json!({
+            "exclusion_filters": [
+                {
+                    "filter": {
+                        "query": "*",
+                        "sample_rate": 1.0
+                    },
+                    "name": "payment"
+                }
+            ],
+            "filter": {
+                "query": "source:python"
+            },
+            "name": "main",
+            "num_retention_days": 15
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .create_logs_index(LogsIndex {
+            daily_limit: None,
+            daily_limit_reset: None,
+            daily_limit_warning_threshold_percentage: None,
+            exclusion_filters: Some(vec![LogsExclusion {
+                filter: Some(LogsExclusionFilter {
+                    query: Some("*".to_string()),
+                    sample_rate: 1.0,
+                }),
+                is_enabled: None,
+                name: "payment".to_string(),
+            }]),
+            filter: LogsFilter {
+                query: Some("source:python".to_string()),
+            },
+            is_rate_limited: None,
+            name: "main".to_string(),
+            num_retention_days: Some(15),
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn update_logs_index() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(INDEX_BODY, "application/json");
+    Mock::given(method("PUT"))
+        .and(path("/api/v1/logs/config/indexes/main"))
+        .and(body_json(serde_json::json!({
+            "disable_daily_limit": true,
+            "filter": {
+                "query": "source:python"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .update_logs_index(UpdateLogsIndexRequest {
+            name: "main".to_string(),
+            index: LogsIndexUpdate {
+                daily_limit: None,
+                daily_limit_reset: None,
+                daily_limit_warning_threshold_percentage: None,
+                disable_daily_limit: Some(true),
+                exclusion_filters: None,
+                filter: LogsFilter {
+                    query: Some("source:python".to_string()),
+                },
+                num_retention_days: None,
+            },
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn delete_logs_index() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v1/logs/config/indexes/main"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&mock_server)
+        .await;
+
+    client
+        .delete_logs_index(DeleteLogsIndexRequest {
+            name: "main".to_string(),
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn update_logs_index_order() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"{ "index_names": ["main", "payments", "web"] }"#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("PUT"))
+        .and(path("/api/v1/logs/config/index-order"))
+        .and(body_json(serde_json::json!({
+            "index_names": ["main", "payments", "web"]
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let order = client
+        .update_logs_index_order(LogsIndexesOrder {
+            index_names: vec![
+                "main".to_string(),
+                "payments".to_string(),
+                "web".to_string(),
+            ],
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(order.index_names.len(), 3);
+}
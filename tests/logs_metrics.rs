@@ -0,0 +1,231 @@
+use datadog_api_client::{
+    client::ClientBuilder,
+    models::{
+        client::Request,
+        generated_metrics::{
+            GeneratedMetricAggregationType, GeneratedMetricCompute, GeneratedMetricComputeUpdate,
+            GeneratedMetricFilter, GeneratedMetricGroupBy,
+        },
+        logs_metrics::{
+            CreateLogsMetricRequest, DeleteLogsMetricRequest, LogsMetricCreateAttributes,
+            LogsMetricCreateData, LogsMetricType, LogsMetricUpdateAttributes, LogsMetricUpdateData,
+            UpdateLogsMetricRequest,
+        },
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+static METRIC_BODY: &str = r#"
+    {
+        "data": {
+            "attributes": {
+                "compute": {
+                    "aggregation_type": "distribution",
+                    "include_percentiles": true,
+                    "path": "@duration"
+                },
+                "filter": {
+                    "query": "service:web"
+                },
+                "group_by": [
+                    {
+                        "path": "@http.status_code",
+                        "tag_name": "status_code"
+                    }
+                ]
+            },
+            "id": "logs.web.duration",
+            "type": "logs_metrics"
+        }
+    }
+"#;
+
+#[tokio::test]
+async fn list_logs_metrics() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": [
+                {
+                    "attributes": {
+                        "compute": {
+                            "aggregation_type": "count"
+                        },
+                        "filter": {
+                            "query": "status:error"
+                        },
+                        "group_by": []
+                    },
+                    "id": "logs.errors",
+                    "type": "logs_metrics"
+                }
+            ]
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v2/logs/config/metrics"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client.list_logs_metrics().await.unwrap();
+    assert_eq!(res.data.len(), 1);
+    assert_eq!(res.data[0].id, "logs.errors");
+    let compute = res.data[0]
+        .attributes
+        .as_ref()
+        .unwrap()
+        .compute
+        .as_ref()
+        .unwrap();
+    assert!(matches!(
+        compute.aggregation_type,
+        GeneratedMetricAggregationType::Count
+    ));
+    assert!(compute.path.is_none());
+}
+
+#[tokio::test]
+async fn create_logs_metric() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(METRIC_BODY, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/logs/config/metrics"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {
+                    "compute": {
+                        "aggregation_type": "distribution",
+                        "include_percentiles": true,
+                        "path": "@duration"
+                    },
+                    "filter": {
+                        "query": "service:web"
+                    },
+                    "group_by": [
+                        {
+                            "path": "@http.status_code",
+                            "tag_name": "status_code"
+                        }
+                    ]
+                },
+                "id": "logs.web.duration",
+                "type": "logs_metrics"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .create_logs_metric(CreateLogsMetricRequest {
+            data: LogsMetricCreateData {
+                attributes: LogsMetricCreateAttributes {
+                    compute: GeneratedMetricCompute {
+                        aggregation_type: GeneratedMetricAggregationType::Distribution,
+                        include_percentiles: Some(true),
+                        path: Some("@duration".to_string()),
+                    },
+                    filter: Some(GeneratedMetricFilter {
+                        query: Some("service:web".to_string()),
+                    }),
+                    group_by: Some(vec![GeneratedMetricGroupBy {
+                        path: "@http.status_code".to_string(),
+                        tag_name: Some("status_code".to_string()),
+                    }]),
+                },
+                id: "logs.web.duration".to_string(),
+                typ: LogsMetricType::LogsMetrics,
+            },
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.data.id, "logs.web.duration");
+}
+
+#[tokio::test]
+async fn update_logs_metric() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(METRIC_BODY, "application/json");
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/logs/config/metrics/logs.web.duration"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {
+                    "compute": {
+                        "include_percentiles": true
+                    }
+                },
+                "type": "logs_metrics"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .update_logs_metric(UpdateLogsMetricRequest {
+            metric_id: "logs.web.duration".to_string(),
+            metric: Request {
+                data: LogsMetricUpdateData {
+                    attributes: LogsMetricUpdateAttributes {
+                        compute: Some(GeneratedMetricComputeUpdate {
+                            include_percentiles: Some(true),
+                        }),
+                        ..Default::default()
+                    },
+                    typ: LogsMetricType::LogsMetrics,
+                },
+            },
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        res.data
+            .attributes
+            .unwrap()
+            .compute
+            .unwrap()
+            .include_percentiles,
+        Some(true)
+    );
+}
+
+#[tokio::test]
+async fn delete_logs_metric() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/logs/config/metrics/logs.web.duration"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .delete_logs_metric(DeleteLogsMetricRequest {
+            metric_id: "logs.web.duration".to_string(),
+        })
+        .await
+        .unwrap();
+}
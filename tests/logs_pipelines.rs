@@ -0,0 +1,308 @@
+use datadog_api_client::{
+    client::ClientBuilder,
+    models::{
+        logs::LogsFilter,
+        logs_pipelines::{
+            DeleteLogsPipelineRequest, GetLogsPipelineRequest, LogsGrokParser, LogsGrokParserRules,
+            LogsPipeline, LogsPipelinesOrder, LogsProcessor, LogsSourcesRemapper,
+            UpdateLogsPipelineRequest,
+        },
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const PIPELINE_BODY: &str = r#"
+    {
+        "filter": {
+            "query": "source:python"
+        },
+        "id": "ShRHV8r1S0yO2qPdDavayw",
+        "is_enabled": true,
+        "is_read_only": false,
+        "name": "Python logs",
+        "processors": [
+            {
+                "grok": {
+                    "match_rules": "rule_name_1 foo\nrule_name_2 bar\n",
+                    "support_rules": "rule_name_1 foo\nrule_name_2 bar\n"
+                },
+                "is_enabled": false,
+                "name": "Parse the message",
+                "samples": [],
+                "source": "message",
+                "type": "grok-parser"
+            },
+            {
+                "is_enabled": true,
+                "name": "Define timestamp as the official date",
+                "sources": ["timestamp"],
+                "type": "date-remapper"
+            },
+            {
+                "categories": [
+                    {
+                        "filter": {
+                            "query": "@http.status_code:[200 TO 299]"
+                        },
+                        "name": "OK"
+                    }
+                ],
+                "is_enabled": true,
+                "name": "Categorise status codes",
+                "target": "http.status_category",
+                "type": "category-processor"
+            },
+            {
+                "expression": "@duration / 1000000",
+                "is_replace_missing": false,
+                "name": "Duration in milliseconds",
+                "target": "duration_ms",
+                "type": "arithmetic-processor"
+            },
+            {
+                "default_lookup": "unknown",
+                "lookup_table": ["200,OK", "404,Not Found"],
+                "name": "Status labels",
+                "source": "http.status_code",
+                "target": "http.status_label",
+                "type": "lookup-processor"
+            },
+            {
+                "filter": {
+                    "query": "service:web"
+                },
+                "name": "Nested pipeline",
+                "processors": [
+                    {
+                        "sources": ["svc"],
+                        "type": "service-remapper"
+                    }
+                ],
+                "type": "pipeline"
+            },
+            {
+                "is_enabled": true,
+                "name": "Array processor",
+                "operation": {
+                    "source": "httpRequest.headers",
+                    "target": "network.header",
+                    "type": "select",
+                    "value_to_extract": "value"
+                },
+                "type": "array-processor"
+            }
+        ],
+        "type": "pipeline"
+    }
+"#;
+
+#[tokio::test]
+async fn list_logs_pipelines() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = format!("[{}]", PIPELINE_BODY);
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v1/logs/config/pipelines"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let pipelines = client.list_logs_pipelines().await.unwrap();
+
+    assert_eq!(pipelines.len(), 1);
+    assert_eq!(pipelines[0].processors.as_ref().unwrap().len(), 7);
+}
+
+#[tokio::test]
+async fn get_logs_pipeline() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(PIPELINE_BODY, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v1/logs/config/pipelines/ShRHV8r1S0yO2qPdDavayw"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let pipeline = client
+        .get_logs_pipeline(GetLogsPipelineRequest {
+            pipeline_id: "ShRHV8r1S0yO2qPdDavayw".to_string(),
+        })
+        .await
+        .unwrap();
+    let processors = pipeline.processors.unwrap();
+
+    match &processors[0] {
+        LogsProcessor::GrokParser(parser) => assert_eq!(parser.source, "message"),
+        other => panic!("expected a grok parser, got {:?}", other),
+    }
+    assert!(matches!(processors[1], LogsProcessor::DateRemapper(_)));
+    assert!(matches!(processors[2], LogsProcessor::CategoryProcessor(_)));
+    assert!(matches!(
+        processors[3],
+        LogsProcessor::ArithmeticProcessor(_)
+    ));
+    assert!(matches!(processors[4], LogsProcessor::LookupProcessor(_)));
+    match &processors[5] {
+        LogsProcessor::Pipeline(nested) => assert!(matches!(
+            nested.processors.as_ref().unwrap()[0],
+            LogsProcessor::ServiceRemapper(_)
+        )),
+        other => panic!("expected a nested pipeline, got {:?}", other),
+    }
+    assert!(matches!(processors[6], LogsProcessor::Unknown(_)));
+    assert_eq!(processors[6].processor_type(), Some("array-processor"));
+}
+
+#[tokio::test]
+async fn update_logs_pipeline_preserves_unknown_processors() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let pipeline: LogsPipeline = serde_json::from_str(PIPELINE_BODY).unwrap();
+    let expected: serde_json::Value = serde_json::from_str(PIPELINE_BODY).unwrap();
+
+    let response = ResponseTemplate::new(200).set_body_raw(PIPELINE_BODY, "application/json");
+    Mock::given(method("PUT"))
+        .and(path("/api/v1/logs/config/pipelines/ShRHV8r1S0yO2qPdDavayw"))
+        .and(body_json(expected))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .update_logs_pipeline(UpdateLogsPipelineRequest {
+            pipeline_id: "ShRHV8r1S0yO2qPdDavayw".to_string(),
+            pipeline,
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn create_logs_pipeline() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(PIPELINE_BODY, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v1/logs/config/pipelines"))
+        .and(body_json(serde_json::json!({
+            "filter": {
+                "query": "source:python"
+            },
+            "name": "Python logs",
+            "processors": [
+                {
+                    "grok": {
+                        "match_rules": "rule_name_1 foo\nrule_name_2 bar\n"
+                    },
+                    "source": "message",
+                    "type": "grok-parser"
+                },
+                {
+                    "sources": ["status"],
+                    "type": "status-remapper"
+                }
+            ]
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .create_logs_pipeline(LogsPipeline {
+            filter: Some(LogsFilter {
+                query: Some("source:python".to_string()),
+            }),
+            id: None,
+            is_enabled: None,
+            is_read_only: None,
+            name: "Python logs".to_string(),
+            processors: Some(vec![
+                LogsProcessor::GrokParser(LogsGrokParser {
+                    grok: LogsGrokParserRules {
+                        match_rules: "rule_name_1 foo\nrule_name_2 bar\n".to_string(),
+                        support_rules: None,
+                    },
+                    is_enabled: None,
+                    name: None,
+                    samples: None,
+                    source: "message".to_string(),
+                }),
+                LogsProcessor::StatusRemapper(LogsSourcesRemapper {
+                    is_enabled: None,
+                    name: None,
+                    sources: vec!["status".to_string()],
+                }),
+            ]),
+            typ: None,
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn delete_logs_pipeline() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v1/logs/config/pipelines/ShRHV8r1S0yO2qPdDavayw"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&mock_server)
+        .await;
+
+    client
+        .delete_logs_pipeline(DeleteLogsPipelineRequest {
+            pipeline_id: "ShRHV8r1S0yO2qPdDavayw".to_string(),
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn update_logs_pipeline_order() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"{ "pipeline_ids": ["tags", "org_ids", "products"] }"#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("PUT"))
+        .and(path("/api/v1/logs/config/pipeline-order"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let order = client
+        .update_logs_pipeline_order(LogsPipelinesOrder {
+            pipeline_ids: vec![
+                "tags".to_string(),
+                "org_ids".to_string(),
+                "products".to_string(),
+            ],
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(order.pipeline_ids[0], "tags");
+}
@@ -0,0 +1,294 @@
+use std::io::Read;
+use std::time::Duration;
+
+use datadog_api_client::{
+    client::ClientBuilder,
+    error::Error,
+    logs_submitter::{LogsSubmitter, LogsSubmitterBuilder},
+    models::logs::{ContentEncoding, HttpLogItem, SubmitLogRequest},
+};
+use flate2::read::GzDecoder;
+use serde_json::Value;
+use url::Url;
+use wiremock::{
+    matchers::{header, method, path, query_param},
+    Mock, MockServer, Request, ResponseTemplate,
+};
+
+fn log(message: &str) -> HttpLogItem {
+    HttpLogItem {
+        ddsource: Some("rust".to_string()),
+        hostname: Some("i-012345678".to_string()),
+        message: message.to_string(),
+        service: Some("payment".to_string()),
+        ..Default::default()
+    }
+}
+
+fn decode_batch(request: &Request) -> Vec<Value> {
+    let mut decoder = GzDecoder::new(&request.body[..]);
+    let mut body = String::new();
+    decoder.read_to_string(&mut body).unwrap();
+    serde_json::from_str(&body).unwrap()
+}
+
+async fn mount_intake(mock_server: &MockServer, status: u16) {
+    Mock::given(method("POST"))
+        .and(path("/api/v2/logs"))
+        .respond_with(ResponseTemplate::new(status).set_body_raw("{}", "application/json"))
+        .mount(mock_server)
+        .await;
+}
+
+/// Waits until the intake received `count` requests, failing after a few seconds.
+async fn wait_for_requests(mock_server: &MockServer, count: usize) -> Vec<Request> {
+    tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            let requests = mock_server.received_requests().await.unwrap();
+            if requests.len() >= count {
+                return requests;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("requests received by the intake")
+}
+
+#[tokio::test]
+async fn submit_log() {
+    let mock_server = MockServer::start().await;
+    let client = ClientBuilder::new("&", "")
+        .set_logs_intake_url(Url::parse(&mock_server.uri()).unwrap())
+        .build()
+        .expect("Client");
+
+    let response = ResponseTemplate::new(202).set_body_raw("{}", "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/logs"))
+        .and(header("Content-Encoding", "identity"))
+        .and(query_param("ddtags", "env:staging,version:5.1"))
+        .respond_with(response)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .submit_log(SubmitLogRequest {
+            content_encoding: Some(ContentEncoding::Identity),
+            ddtags: Some("env:staging,version:5.1".to_string()),
+            body: vec![log(
+                "2019-11-19T14:37:58,995 INFO [process.name] Hello World",
+            )],
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn flush_gzip_batch() {
+    let mock_server = MockServer::start().await;
+    let client = ClientBuilder::new("&", "")
+        .set_logs_intake_url(Url::parse(&mock_server.uri()).unwrap())
+        .build()
+        .expect("Client");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/logs"))
+        .and(header("Content-Encoding", "gzip"))
+        .respond_with(ResponseTemplate::new(202).set_body_raw("{}", "application/json"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let submitter = LogsSubmitterBuilder::new(client)
+        .set_flush_interval(Duration::from_secs(3600))
+        .build();
+    submitter.submit(&log("first")).await.unwrap();
+    submitter.submit(&log("second")).await.unwrap();
+    assert_eq!(submitter.len().await, 2);
+    assert!(mock_server.received_requests().await.unwrap().is_empty());
+
+    submitter.flush().await.unwrap();
+    assert!(submitter.is_empty().await);
+
+    let requests = mock_server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 1);
+    let batch = decode_batch(&requests[0]);
+    assert_eq!(batch.len(), 2);
+    assert_eq!(batch[0]["message"], "first");
+    assert_eq!(batch[1]["service"], "payment");
+}
+
+#[tokio::test]
+async fn flush_on_entries_and_size_limits() {
+    let mock_server = MockServer::start().await;
+    let client = ClientBuilder::new("&", "")
+        .set_logs_intake_url(Url::parse(&mock_server.uri()).unwrap())
+        .build()
+        .expect("Client");
+    mount_intake(&mock_server, 202).await;
+
+    let submitter = LogsSubmitterBuilder::new(client)
+        .set_flush_interval(Duration::from_secs(3600))
+        .set_max_payload_entries(3)
+        .build();
+    for i in 0..7 {
+        submitter.submit(&log(&format!("log {}", i))).await.unwrap();
+    }
+    assert_eq!(submitter.len().await, 1);
+
+    let batches: Vec<usize> = mock_server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|request| decode_batch(request).len())
+        .collect();
+    assert_eq!(batches, vec![3, 3]);
+
+    let mock_server = MockServer::start().await;
+    let client = ClientBuilder::new("&", "")
+        .set_logs_intake_url(Url::parse(&mock_server.uri()).unwrap())
+        .build()
+        .expect("Client");
+    mount_intake(&mock_server, 202).await;
+
+    let entry_bytes = serde_json::to_vec(&log("log 0")).unwrap().len();
+    let submitter = LogsSubmitterBuilder::new(client)
+        .set_flush_interval(Duration::from_secs(3600))
+        .set_max_payload_bytes(2 + entry_bytes * 2 + 1)
+        .build();
+    for i in 0..5 {
+        submitter.submit(&log(&format!("log {}", i))).await.unwrap();
+    }
+    submitter.flush().await.unwrap();
+
+    let batches: Vec<usize> = mock_server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|request| decode_batch(request).len())
+        .collect();
+    assert_eq!(batches, vec![2, 2, 1]);
+}
+
+#[tokio::test]
+async fn flush_on_interval() {
+    let mock_server = MockServer::start().await;
+    let client = ClientBuilder::new("&", "")
+        .set_logs_intake_url(Url::parse(&mock_server.uri()).unwrap())
+        .build()
+        .expect("Client");
+    mount_intake(&mock_server, 202).await;
+
+    let submitter = LogsSubmitterBuilder::new(client)
+        .set_flush_interval(Duration::from_millis(50))
+        .build();
+    submitter.submit(&log("first")).await.unwrap();
+    assert_eq!(submitter.len().await, 1);
+
+    assert_eq!(wait_for_requests(&mock_server, 1).await.len(), 1);
+    assert!(submitter.is_empty().await);
+}
+
+#[tokio::test]
+async fn flush_on_drop() {
+    let mock_server = MockServer::start().await;
+    let client = ClientBuilder::new("&", "")
+        .set_logs_intake_url(Url::parse(&mock_server.uri()).unwrap())
+        .build()
+        .expect("Client");
+    mount_intake(&mock_server, 202).await;
+
+    let submitter = LogsSubmitterBuilder::new(client)
+        .set_flush_interval(Duration::from_secs(3600))
+        .build();
+    submitter.submit(&log("last")).await.unwrap();
+    drop(submitter);
+
+    let requests = wait_for_requests(&mock_server, 1).await;
+    assert_eq!(requests.len(), 1);
+    assert_eq!(decode_batch(&requests[0])[0]["message"], "last");
+}
+
+#[tokio::test]
+async fn reject_oversized_entry() {
+    let mock_server = MockServer::start().await;
+    let client = ClientBuilder::new("&", "")
+        .set_logs_intake_url(Url::parse(&mock_server.uri()).unwrap())
+        .build()
+        .expect("Client");
+
+    let submitter = LogsSubmitterBuilder::new(client)
+        .set_max_entry_bytes(64)
+        .build();
+    let err = submitter.submit(&log(&"a".repeat(64))).await.unwrap_err();
+
+    assert!(matches!(err, Error::LogEntryTooLarge { limit: 64, .. }));
+    assert!(submitter.is_empty().await);
+}
+
+#[tokio::test]
+async fn retry_failed_batch() {
+    let mock_server = MockServer::start().await;
+    let client = ClientBuilder::new("&", "")
+        .set_logs_intake_url(Url::parse(&mock_server.uri()).unwrap())
+        .build()
+        .expect("Client");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/logs"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(2)
+        .mount(&mock_server)
+        .await;
+    mount_intake(&mock_server, 202).await;
+
+    let submitter = LogsSubmitterBuilder::new(client)
+        .set_retry_backoff(Duration::from_millis(1))
+        .build();
+    submitter.submit(&log("first")).await.unwrap();
+    submitter.flush().await.unwrap();
+
+    assert!(submitter.is_empty().await);
+    assert_eq!(mock_server.received_requests().await.unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn keep_batch_when_retries_are_exhausted() {
+    let mock_server = MockServer::start().await;
+    let client = ClientBuilder::new("&", "")
+        .set_logs_intake_url(Url::parse(&mock_server.uri()).unwrap())
+        .build()
+        .expect("Client");
+    mount_intake(&mock_server, 500).await;
+
+    let submitter = LogsSubmitterBuilder::new(client)
+        .set_max_retries(1)
+        .set_retry_backoff(Duration::from_millis(1))
+        .build();
+    submitter.submit(&log("first")).await.unwrap();
+    let err = submitter.flush().await.unwrap_err();
+
+    assert!(matches!(err, Error::UnexpectedStatus(status) if status == 500));
+    assert_eq!(submitter.len().await, 1);
+    assert_eq!(mock_server.received_requests().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn drop_rejected_batch() {
+    let mock_server = MockServer::start().await;
+    let client = ClientBuilder::new("&", "")
+        .set_logs_intake_url(Url::parse(&mock_server.uri()).unwrap())
+        .build()
+        .expect("Client");
+    mount_intake(&mock_server, 400).await;
+
+    let submitter = LogsSubmitter::new(client);
+    submitter.submit(&log("first")).await.unwrap();
+    let err = submitter.flush().await.unwrap_err();
+
+    assert!(matches!(err, Error::UnexpectedStatus(status) if status == 400));
+    assert!(submitter.is_empty().await);
+    assert_eq!(mock_server.received_requests().await.unwrap().len(), 1);
+}
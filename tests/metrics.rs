@@ -0,0 +1,380 @@
+use datadog_api_client::{
+    client::ClientBuilder,
+    models::metrics::{
+        CreateMetricTagConfigurationAttributes, CreateMetricTagConfigurationRequest,
+        CreateMetricTagConfigurationRequestData, DeleteMetricTagConfigurationRequest,
+        EstimateMetricCardinalityRequest, GetMetricMetadataRequest, GetMetricVolumesRequest,
+        ListActiveMetricsRequest, MetricEstimateType, MetricMetadata, MetricMetadataType,
+        MetricTagConfigurationMetricTypes, MetricTagConfigurationType, MetricVolumes,
+        UpdateMetricMetadataRequest, UpdateMetricTagConfigurationAttributes,
+        UpdateMetricTagConfigurationRequest, UpdateMetricTagConfigurationRequestData,
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn list_active_metrics() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "from": "1708300800",
+            "metrics": [
+                "system.cpu.user",
+                "system.load.1"
+            ]
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v1/metrics"))
+        .and(query_param("from", "1708300800"))
+        .and(query_param("host", "my-host"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let metrics = client
+        .list_active_metrics(ListActiveMetricsRequest {
+            from: 1708300800,
+            host: Some("my-host".to_string()),
+            tag_filter: None,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(metrics.metrics.len(), 2);
+}
+
+#[tokio::test]
+async fn get_metric_metadata() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "description": "The percent of time the CPU spent running user space processes.",
+            "integration": "system",
+            "per_unit": "second",
+            "short_name": "cpu user",
+            "statsd_interval": 10,
+            "type": "gauge",
+            "unit": "percent"
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v1/metrics/system.cpu.user"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let metadata = client
+        .get_metric_metadata(GetMetricMetadataRequest {
+            metric_name: "system.cpu.user".to_string(),
+        })
+        .await
+        .unwrap();
+
+    assert!(matches!(metadata.typ, Some(MetricMetadataType::Gauge)));
+}
+
+#[tokio::test]
+async fn update_metric_metadata() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "description": "Bytes sent by the service.",
+            "per_unit": "second",
+            "type": "count",
+            "unit": "byte"
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("PUT"))
+        .and(path("/api/v1/metrics/my.service.bytes"))
+        .and(body_json(serde_json::json!({
+            "description": "Bytes sent by the service.",
+            "per_unit": "second",
+            "type": "count",
+            "unit": "byte"
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .update_metric_metadata(UpdateMetricMetadataRequest {
+            metric_name: "my.service.bytes".to_string(),
+            metadata: MetricMetadata {
+                description: Some("Bytes sent by the service.".to_string()),
+                integration: None,
+                per_unit: Some("second".to_string()),
+                short_name: None,
+                statsd_interval: None,
+                typ: Some(MetricMetadataType::Count),
+                unit: Some("byte".to_string()),
+            },
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn create_metric_tag_configuration() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": {
+                "attributes": {
+                    "aggregations": [
+                        {
+                            "space": "sum",
+                            "time": "sum"
+                        }
+                    ],
+                    "created_at": "2020-03-25T09:48:37.463835Z",
+                    "exclude_tags_mode": false,
+                    "include_percentiles": true,
+                    "metric_type": "distribution",
+                    "modified_at": "2020-04-25T09:48:37.463835Z",
+                    "tags": [
+                        "app",
+                        "datacenter"
+                    ]
+                },
+                "id": "test.metric.latency",
+                "type": "manage_tags"
+            }
+        }
+    "#;
+    let response = ResponseTemplate::new(201).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/metrics/test.metric.latency/tags"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let configuration = client
+        .create_metric_tag_configuration(CreateMetricTagConfigurationRequest {
+            data: CreateMetricTagConfigurationRequestData {
+                attributes: CreateMetricTagConfigurationAttributes {
+                    aggregations: None,
+                    exclude_tags_mode: None,
+                    include_percentiles: Some(true),
+                    metric_type: MetricTagConfigurationMetricTypes::Distribution,
+                    tags: vec!["app".to_string(), "datacenter".to_string()],
+                },
+                id: "test.metric.latency".to_string(),
+                typ: MetricTagConfigurationType::ManageTags,
+            },
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(configuration.data.attributes.tags.len(), 2);
+}
+
+#[tokio::test]
+async fn update_metric_tag_configuration() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": {
+                "attributes": {
+                    "metric_type": "gauge",
+                    "tags": [
+                        "app"
+                    ]
+                },
+                "id": "test.metric.latency",
+                "type": "manage_tags"
+            }
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/metrics/test.metric.latency/tags"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {
+                    "tags": ["app"]
+                },
+                "id": "test.metric.latency",
+                "type": "manage_tags"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .update_metric_tag_configuration(UpdateMetricTagConfigurationRequest {
+            data: UpdateMetricTagConfigurationRequestData {
+                attributes: UpdateMetricTagConfigurationAttributes {
+                    aggregations: None,
+                    exclude_tags_mode: None,
+                    include_percentiles: None,
+                    tags: Some(vec!["app".to_string()]),
+                },
+                id: "test.metric.latency".to_string(),
+                typ: MetricTagConfigurationType::ManageTags,
+            },
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn delete_metric_tag_configuration() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(204);
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/metrics/test.metric.latency/tags"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .delete_metric_tag_configuration(DeleteMetricTagConfigurationRequest {
+            metric_name: "test.metric.latency".to_string(),
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn estimate_metric_cardinality() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": {
+                "attributes": {
+                    "estimate_type": "count_or_gauge",
+                    "estimated_at": "2022-04-27T09:48:37.463835Z",
+                    "estimated_output_series": 50
+                },
+                "id": "test.metric.latency",
+                "type": "metric_cardinality_estimate"
+            }
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v2/metrics/test.metric.latency/estimate"))
+        .and(query_param("filter[groups]", "app,host"))
+        .and(query_param("filter[num_aggregations]", "4"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let estimate = client
+        .estimate_metric_cardinality(EstimateMetricCardinalityRequest {
+            metric_name: "test.metric.latency".to_string(),
+            filter_groups: Some("app,host".to_string()),
+            filter_hours_ago: None,
+            filter_num_aggregations: Some(4),
+            filter_pct: None,
+            filter_timespan_h: None,
+        })
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        estimate.data.attributes.estimate_type,
+        Some(MetricEstimateType::CountOrGauge)
+    ));
+    assert_eq!(estimate.data.attributes.estimated_output_series, Some(50));
+}
+
+#[tokio::test]
+async fn get_metric_volumes() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let distinct = r#"
+        {
+            "data": {
+                "attributes": {
+                    "distinct_volume": 10
+                },
+                "id": "test.metric.latency",
+                "type": "distinct_metric_volumes"
+            }
+        }
+    "#;
+    let ingested = r#"
+        {
+            "data": {
+                "attributes": {
+                    "indexed_volume": 10,
+                    "ingested_volume": 20
+                },
+                "id": "test.metric.count",
+                "type": "metric_volumes"
+            }
+        }
+    "#;
+    Mock::given(method("GET"))
+        .and(path("/api/v2/metrics/test.metric.latency/volumes"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(distinct, "application/json"))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v2/metrics/test.metric.count/volumes"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(ingested, "application/json"))
+        .mount(&mock_server)
+        .await;
+
+    let volumes = client
+        .get_metric_volumes(GetMetricVolumesRequest {
+            metric_name: "test.metric.latency".to_string(),
+        })
+        .await
+        .unwrap();
+    assert!(matches!(volumes.data, MetricVolumes::Distinct { .. }));
+
+    let volumes = client
+        .get_metric_volumes(GetMetricVolumesRequest {
+            metric_name: "test.metric.count".to_string(),
+        })
+        .await
+        .unwrap();
+    match volumes.data {
+        MetricVolumes::IngestedIndexed { attributes, .. } => {
+            assert_eq!(attributes.ingested_volume, Some(20));
+        }
+        _ => panic!("expected ingested and indexed volumes"),
+    }
+}
@@ -0,0 +1,430 @@
+use datadog_api_client::{
+    client::ClientBuilder,
+    error::Error,
+    models::monitors::{
+        CheckCanDeleteMonitorRequest, DeleteMonitorRequest, GetMonitorRequest, ListMonitorsRequest,
+        Monitor, MonitorOptions, MonitorOverallStates, MonitorThresholds, MonitorType,
+        MonitorUpdate, SearchMonitorsRequest, UpdateMonitorRequest,
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+static MONITOR_BODY: &str = r#"
+    {
+        "created": "2024-01-10T09:12:44.000000+00:00",
+        "creator": {
+            "email": "jane@example.com",
+            "handle": "jane@example.com",
+            "name": "Jane"
+        },
+        "deleted": null,
+        "id": 12345,
+        "message": "{{#is_alert}}CPU is high on {{host.name}}{{/is_alert}} @slack-ops",
+        "modified": "2024-01-10T09:12:44.000000+00:00",
+        "multi": true,
+        "name": "High CPU",
+        "options": {
+            "include_tags": true,
+            "notify_no_data": false,
+            "scheduling_options": {
+                "evaluation_window": {
+                    "day_starts": "04:00"
+                }
+            },
+            "thresholds": {
+                "critical": 90,
+                "warning": 80.5
+            }
+        },
+        "overall_state": "No Data",
+        "priority": null,
+        "query": "avg(last_5m):avg:system.cpu.user{*} by {host} > 90",
+        "restricted_roles": null,
+        "state": {
+            "groups": {
+                "host:web-1": {
+                    "last_nodata_ts": 0,
+                    "last_notified_ts": 0,
+                    "last_resolved_ts": 0,
+                    "last_triggered_ts": 1704877964,
+                    "name": "host:web-1",
+                    "status": "Alert"
+                }
+            }
+        },
+        "tags": ["team:ops"],
+        "type": "metric alert"
+    }
+"#;
+
+fn monitor_definition() -> Monitor {
+    serde_json::from_value(serde_json::json!({
+        "message": "{{#is_alert}}CPU is high on {{host.name}}{{/is_alert}} @slack-ops",
+        "name": "High CPU",
+        "options": {
+            "include_tags": true,
+            "thresholds": {
+                "critical": 90.0,
+                "warning": 80.5
+            }
+        },
+        "query": "avg(last_5m):avg:system.cpu.user{*} by {host} > 90",
+        "tags": ["team:ops"],
+        "type": "metric alert"
+    }))
+    .unwrap()
+}
+
+#[tokio::test]
+async fn list_monitors() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = format!("[{}]", MONITOR_BODY);
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v1/monitor"))
+        .and(query_param("monitor_tags", "team:ops"))
+        .and(query_param("page_size", "100"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .list_monitors(ListMonitorsRequest {
+            monitor_tags: Some("team:ops".to_string()),
+            page: Some(0),
+            page_size: Some(100),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.len(), 1);
+    assert_eq!(res[0].id, Some(12345));
+    assert_eq!(res[0].typ, MonitorType::MetricAlert);
+    assert_eq!(res[0].overall_state, Some(MonitorOverallStates::NoData));
+}
+
+#[tokio::test]
+async fn list_monitors_with_unknown_type() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = format!(
+        "[{}]",
+        MONITOR_BODY.replace(r#""type": "metric alert""#, r#""type": "cost alert""#)
+    );
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v1/monitor"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .list_monitors(ListMonitorsRequest::default())
+        .await
+        .unwrap();
+    assert_eq!(res[0].typ, MonitorType::Other("cost alert".to_string()));
+    assert_eq!(
+        serde_json::to_value(&res[0].typ).unwrap(),
+        serde_json::json!("cost alert")
+    );
+}
+
+#[tokio::test]
+async fn create_monitor() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(MONITOR_BODY, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v1/monitor"))
+        .and(body_json(serde_json::json!({
+            "message": "{{#is_alert}}CPU is high on {{host.name}}{{/is_alert}} @slack-ops",
+            "name": "High CPU",
+            "options": {
+                "include_tags": true,
+                "thresholds": {
+                    "critical": 90.0,
+                    "warning": 80.5
+                }
+            },
+            "query": "avg(last_5m):avg:system.cpu.user{*} by {host} > 90",
+            "tags": ["team:ops"],
+            "type": "metric alert"
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client.create_monitor(monitor_definition()).await.unwrap();
+    assert_eq!(res.id, Some(12345));
+    assert_eq!(
+        res.message.as_deref(),
+        Some("{{#is_alert}}CPU is high on {{host.name}}{{/is_alert}} @slack-ops")
+    );
+}
+
+#[tokio::test]
+async fn get_monitor() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(MONITOR_BODY, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v1/monitor/12345"))
+        .and(query_param("group_states", "all"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .get_monitor(GetMonitorRequest {
+            monitor_id: 12345,
+            group_states: Some("all".to_string()),
+            with_downtimes: None,
+        })
+        .await
+        .unwrap();
+    let options = res.options.unwrap();
+    assert_eq!(
+        options.thresholds,
+        Some(MonitorThresholds {
+            critical: Some(90.0),
+            warning: Some(80.5),
+            ..Default::default()
+        })
+    );
+    assert_eq!(
+        options.additional_properties["scheduling_options"],
+        serde_json::json!({"evaluation_window": {"day_starts": "04:00"}})
+    );
+    let groups = res.state.unwrap().groups.unwrap();
+    assert_eq!(
+        groups["host:web-1"].status,
+        Some(MonitorOverallStates::Alert)
+    );
+}
+
+#[tokio::test]
+async fn update_monitor() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(MONITOR_BODY, "application/json");
+    Mock::given(method("PUT"))
+        .and(path("/api/v1/monitor/12345"))
+        .and(body_json(serde_json::json!({
+            "options": {
+                "renotify_interval": 30,
+                "thresholds": {
+                    "critical": 95.0
+                }
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .update_monitor(UpdateMonitorRequest {
+            monitor_id: 12345,
+            monitor: MonitorUpdate {
+                options: Some(MonitorOptions {
+                    renotify_interval: Some(30),
+                    thresholds: Some(MonitorThresholds {
+                        critical: Some(95.0),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.name.as_deref(), Some("High CPU"));
+}
+
+#[tokio::test]
+async fn delete_monitor() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200)
+        .set_body_raw(r#"{"deleted_monitor_id": 12345}"#, "application/json");
+    Mock::given(method("DELETE"))
+        .and(path("/api/v1/monitor/12345"))
+        .and(query_param("force", "true"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .delete_monitor(DeleteMonitorRequest {
+            monitor_id: 12345,
+            force: Some(true),
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.deleted_monitor_id, Some(12345));
+}
+
+#[tokio::test]
+async fn search_monitors() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "counts": {
+                "muted": [{"count": 1, "name": false}],
+                "status": [{"count": 1, "name": "Alert"}],
+                "tag": [{"count": 1, "name": "team:ops"}],
+                "type": [{"count": 1, "name": "metric"}]
+            },
+            "metadata": {
+                "page": 0,
+                "page_count": 1,
+                "per_page": 30,
+                "total_count": 1
+            },
+            "monitors": [
+                {
+                    "classification": "metric",
+                    "id": 12345,
+                    "last_triggered_ts": 1704877964,
+                    "metrics": ["system.cpu.user"],
+                    "name": "High CPU",
+                    "notifications": [{"handle": "slack-ops", "name": "ops"}],
+                    "org_id": 2,
+                    "query": "avg(last_5m):avg:system.cpu.user{*} by {host} > 90",
+                    "scopes": ["*"],
+                    "status": "Alert",
+                    "tags": ["team:ops"],
+                    "type": "metric alert"
+                }
+            ]
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v1/monitor/search"))
+        .and(query_param("query", "status:alert"))
+        .and(query_param("sort", "name,asc"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .search_monitors(SearchMonitorsRequest {
+            query: Some("status:alert".to_string()),
+            sort: Some("name,asc".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.metadata.unwrap().total_count, Some(1));
+    let monitors = res.monitors.unwrap();
+    assert_eq!(monitors[0].status, Some(MonitorOverallStates::Alert));
+    assert_eq!(monitors[0].typ, Some(MonitorType::MetricAlert));
+}
+
+#[tokio::test]
+async fn validate_monitor() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw("{}", "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v1/monitor/validate"))
+        .respond_with(response)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    client.validate_monitor(monitor_definition()).await.unwrap();
+}
+
+#[tokio::test]
+async fn validate_invalid_monitor() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(400).set_body_raw(
+        r#"{"errors": ["The value provided for parameter 'query' is invalid"]}"#,
+        "application/json",
+    );
+    Mock::given(method("POST"))
+        .and(path("/api/v1/monitor/validate"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let err = client
+        .validate_monitor(monitor_definition())
+        .await
+        .err()
+        .unwrap();
+    match err {
+        Error::InvalidRequest(errors) => assert_eq!(
+            errors.0,
+            vec!["The value provided for parameter 'query' is invalid"]
+        ),
+        err => panic!("unexpected error: {:?}", err),
+    }
+}
+
+#[tokio::test]
+async fn can_delete_monitors() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": {"ok": [12345]},
+            "errors": {"67890": ["monitor [67890] is referenced in composite monitors: [111]"]}
+        }
+    "#;
+    let response = ResponseTemplate::new(409).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v1/monitor/can_delete"))
+        .and(query_param("monitor_ids", "12345,67890"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .can_delete_monitors(CheckCanDeleteMonitorRequest {
+            monitor_ids: vec![12345, 67890],
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.data.ok, Some(vec![12345]));
+    assert!(res.errors.unwrap().contains_key("67890"));
+}
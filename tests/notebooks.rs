@@ -0,0 +1,368 @@
+use datadog_api_client::{
+    client::ClientBuilder,
+    models::{
+        client::Request,
+        notebooks::{
+            DeleteNotebookRequest, GetNotebookRequest, ListNotebooksRequest,
+            LogStreamWidgetDefinition, MarkdownCellDefinition, Notebook, NotebookAttributes,
+            NotebookCell, NotebookCellDefinition, NotebookCellResourceType, NotebookGraphSize,
+            NotebookMetadata, NotebookMetadataType, NotebookRelativeTime, NotebookTime,
+            NotebooksSortDirection, NotebooksSortField, TimeseriesWidgetDefinition,
+            UpdateNotebookRequest, WidgetDisplayType, WidgetFormula, WidgetLiveSpan,
+            WidgetQueryDefinition, WidgetRequest, WidgetResponseFormat,
+        },
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const NOTEBOOK_BODY: &str = r##"
+    {
+        "data": {
+            "attributes": {
+                "author": {"email": "jane@example.com", "handle": "jane@example.com", "name": "Jane"},
+                "cells": [
+                    {
+                        "attributes": {"definition": {"text": "# Postmortem", "type": "markdown"}},
+                        "id": "abcd1234",
+                        "type": "notebook_cells"
+                    },
+                    {
+                        "attributes": {
+                            "definition": {
+                                "requests": [{"display_type": "line", "q": "avg:system.load.1{*}"}],
+                                "type": "timeseries"
+                            },
+                            "graph_size": "m",
+                            "time": {"end": "2024-02-01T11:00:00Z", "start": "2024-02-01T10:00:00Z"}
+                        },
+                        "id": "efgh5678",
+                        "type": "notebook_cells"
+                    },
+                    {
+                        "attributes": {
+                            "definition": {"requests": [{"q": "avg:system.cpu.user{*} by {host}"}], "type": "heatmap"},
+                            "graph_size": "l"
+                        },
+                        "id": "ijkl9012",
+                        "type": "notebook_cells"
+                    },
+                    {
+                        "attributes": {"definition": {"type": "image", "url": "https://example.com/graph.png"}},
+                        "id": "mnop3456",
+                        "type": "notebook_cells"
+                    }
+                ],
+                "created": "2024-02-01T12:00:00.000000+00:00",
+                "metadata": {"is_template": false, "take_snapshots": true, "type": "postmortem"},
+                "modified": "2024-02-01T12:00:00.000000+00:00",
+                "name": "Postmortem: elevated load",
+                "status": "published",
+                "time": {"live_span": "1h"}
+            },
+            "id": 123456,
+            "type": "notebooks"
+        }
+    }
+"##;
+
+#[tokio::test]
+async fn create_notebook() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(NOTEBOOK_BODY, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v1/notebooks"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {
+                    "cells": [
+                        {
+                            "attributes": {"definition": {"text": "# Postmortem", "type": "markdown"}},
+                            "type": "notebook_cells"
+                        },
+                        {
+                            "attributes": {
+                                "definition": {
+                                    "requests": [{
+                                        "display_type": "line",
+                                        "formulas": [{"alias": "load", "formula": "query1"}],
+                                        "queries": [{"data_source": "metrics", "name": "query1", "query": "avg:system.load.1{*}"}],
+                                        "response_format": "timeseries"
+                                    }],
+                                    "show_legend": true,
+                                    "type": "timeseries"
+                                },
+                                "graph_size": "m"
+                            },
+                            "type": "notebook_cells"
+                        },
+                        {
+                            "attributes": {
+                                "definition": {"indexes": ["main"], "query": "status:error", "type": "log_stream"}
+                            },
+                            "type": "notebook_cells"
+                        }
+                    ],
+                    "metadata": {"take_snapshots": true, "type": "postmortem"},
+                    "name": "Postmortem: elevated load",
+                    "status": "published",
+                    "time": {"live_span": "1h"}
+                },
+                "type": "notebooks"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let mut timeseries: NotebookCell =
+        NotebookCellDefinition::Timeseries(TimeseriesWidgetDefinition {
+            requests: vec![WidgetRequest {
+                display_type: Some(WidgetDisplayType::Line),
+                formulas: Some(vec![WidgetFormula {
+                    alias: Some("load".to_string()),
+                    formula: "query1".to_string(),
+                }]),
+                queries: Some(vec![WidgetQueryDefinition {
+                    data_source: "metrics".to_string(),
+                    name: "query1".to_string(),
+                    query: Some("avg:system.load.1{*}".to_string()),
+                    ..Default::default()
+                }]),
+                response_format: Some(WidgetResponseFormat::Timeseries),
+                ..Default::default()
+            }],
+            show_legend: Some(true),
+            ..Default::default()
+        })
+        .into();
+    timeseries.attributes.graph_size = Some(NotebookGraphSize::M);
+    let mut attributes = NotebookAttributes::new(
+        "Postmortem: elevated load",
+        NotebookTime::Relative(NotebookRelativeTime {
+            live_span: WidgetLiveSpan::PastOneHour,
+        }),
+        vec![
+            NotebookCellDefinition::Markdown(MarkdownCellDefinition {
+                text: "# Postmortem".to_string(),
+            })
+            .into(),
+            timeseries,
+            NotebookCellDefinition::LogStream(LogStreamWidgetDefinition {
+                indexes: Some(vec!["main".to_string()]),
+                query: Some("status:error".to_string()),
+                ..Default::default()
+            })
+            .into(),
+        ],
+    );
+    attributes.metadata = Some(NotebookMetadata {
+        take_snapshots: Some(true),
+        typ: Some(NotebookMetadataType::Postmortem),
+        ..Default::default()
+    });
+
+    let res = client
+        .create_notebook(Request {
+            data: attributes.into(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.data.id, Some(123456));
+    assert_eq!(res.data.attributes.cells.len(), 4);
+}
+
+#[tokio::test]
+async fn get_notebook() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(NOTEBOOK_BODY, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v1/notebooks/123456"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .get_notebook(GetNotebookRequest {
+            notebook_id: 123456,
+        })
+        .await
+        .unwrap();
+    let attributes = res.data.attributes;
+    assert_eq!(
+        attributes.author.unwrap().handle.as_deref(),
+        Some("jane@example.com")
+    );
+    assert!(matches!(attributes.time, NotebookTime::Relative(_)));
+    let cells = attributes.cells;
+    assert!(matches!(
+        cells[0].attributes.definition,
+        NotebookCellDefinition::Markdown(_)
+    ));
+    match &cells[1].attributes.time {
+        Some(NotebookTime::Absolute(time)) => assert_eq!(time.start, "2024-02-01T10:00:00Z"),
+        time => panic!("unexpected time: {:?}", time),
+    }
+    assert!(matches!(
+        cells[2].attributes.definition,
+        NotebookCellDefinition::HeatMap(_)
+    ));
+    assert!(matches!(
+        cells[3].attributes.definition,
+        NotebookCellDefinition::Unknown(_)
+    ));
+    assert_eq!(cells[3].attributes.definition.cell_type(), Some("image"));
+}
+
+#[tokio::test]
+async fn list_notebooks() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": [
+                {
+                    "attributes": {
+                        "author": {"handle": "jane@example.com"},
+                        "created": "2024-02-01T12:00:00.000000+00:00",
+                        "metadata": {"type": "postmortem"},
+                        "modified": "2024-02-01T12:00:00.000000+00:00",
+                        "name": "Postmortem: elevated load",
+                        "status": "published"
+                    },
+                    "id": 123456,
+                    "type": "notebooks"
+                }
+            ],
+            "meta": {"page": {"total_count": 12, "total_filtered_count": 1}}
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v1/notebooks"))
+        .and(query_param("sort_field", "modified"))
+        .and(query_param("sort_dir", "desc"))
+        .and(query_param("include_cells", "false"))
+        .and(query_param("type", "postmortem"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .list_notebooks(ListNotebooksRequest {
+            sort_field: Some(NotebooksSortField::Modified),
+            sort_dir: Some(NotebooksSortDirection::Desc),
+            include_cells: Some(false),
+            typ: Some(NotebookMetadataType::Postmortem),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.data[0].id, 123456);
+    assert!(res.data[0].attributes.cells.is_none());
+    assert_eq!(res.meta.unwrap().page.unwrap().total_count, Some(12));
+}
+
+#[tokio::test]
+async fn update_notebook() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(NOTEBOOK_BODY, "application/json");
+    Mock::given(method("PUT"))
+        .and(path("/api/v1/notebooks/123456"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {
+                    "cells": [
+                        {
+                            "attributes": {"definition": {"text": "# Postmortem", "type": "markdown"}},
+                            "id": "abcd1234",
+                            "type": "notebook_cells"
+                        },
+                        {
+                            "attributes": {"definition": {"text": "## Action items", "type": "markdown"}},
+                            "type": "notebook_cells"
+                        }
+                    ],
+                    "name": "Postmortem: elevated load",
+                    "status": "published",
+                    "time": {"live_span": "1d"}
+                },
+                "type": "notebooks"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let existing = NotebookCell {
+        attributes: NotebookCellDefinition::Markdown(MarkdownCellDefinition {
+            text: "# Postmortem".to_string(),
+        })
+        .into(),
+        id: Some("abcd1234".to_string()),
+        typ: NotebookCellResourceType::NotebookCells,
+    };
+    let attributes = NotebookAttributes::new(
+        "Postmortem: elevated load",
+        NotebookTime::Relative(NotebookRelativeTime {
+            live_span: WidgetLiveSpan::PastOneDay,
+        }),
+        vec![
+            existing,
+            NotebookCellDefinition::Markdown(MarkdownCellDefinition {
+                text: "## Action items".to_string(),
+            })
+            .into(),
+        ],
+    );
+
+    client
+        .update_notebook(UpdateNotebookRequest {
+            notebook_id: 123456,
+            notebook: Request {
+                data: Notebook::from(attributes),
+            },
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn delete_notebook() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v1/notebooks/123456"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .delete_notebook(DeleteNotebookRequest {
+            notebook_id: 123456,
+        })
+        .await
+        .unwrap();
+}
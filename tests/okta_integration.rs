@@ -0,0 +1,127 @@
+use datadog_api_client::{
+    client::ClientBuilder,
+    models::{
+        client::Request,
+        okta_integration::{
+            OktaAccountRequest, OktaAccountType, OktaAccountWriteAttributes, OktaAccountWriteData,
+            OktaAuthMethod,
+        },
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn add_okta_account() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": {
+                "attributes": {"auth_method": "oauth", "client_id": "client-id", "domain": "https://example.okta.com/", "name": "Okta-Prod"},
+                "id": "f749daaf-682e-4208-a38d-c9b43162c609",
+                "type": "okta-accounts"
+            }
+        }
+    "#;
+    let response = ResponseTemplate::new(201).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/integrations/okta/accounts"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {
+                    "auth_method": "oauth",
+                    "client_id": "client-id",
+                    "client_secret": "okta-client-secret",
+                    "domain": "https://example.okta.com/",
+                    "name": "Okta-Prod"
+                },
+                "type": "okta-accounts"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let request = Request {
+        data: OktaAccountWriteData {
+            attributes: OktaAccountWriteAttributes::with_oauth(
+                "Okta-Prod",
+                "https://example.okta.com/",
+                "client-id",
+                "okta-client-secret".into(),
+            ),
+            id: None,
+            typ: OktaAccountType::OktaAccounts,
+        },
+    };
+    assert!(!format!("{:?}", request).contains("okta-client-secret"));
+
+    let res = client.add_okta_account(request).await.unwrap();
+    assert_eq!(
+        res.data.attributes.unwrap().auth_method,
+        Some(OktaAuthMethod::OAuth)
+    );
+}
+
+#[tokio::test]
+async fn list_okta_accounts() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": [
+                {
+                    "attributes": {"auth_method": "api_key", "domain": "https://example.okta.com/", "name": "Okta-Prod"},
+                    "id": "f749daaf-682e-4208-a38d-c9b43162c609",
+                    "type": "okta-accounts"
+                }
+            ]
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v2/integrations/okta/accounts"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client.list_okta_accounts().await.unwrap();
+    assert_eq!(
+        res.data[0].attributes.as_ref().unwrap().auth_method,
+        Some(OktaAuthMethod::ApiKey)
+    );
+}
+
+#[tokio::test]
+async fn delete_okta_account() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    Mock::given(method("DELETE"))
+        .and(path(
+            "/api/v2/integrations/okta/accounts/f749daaf-682e-4208-a38d-c9b43162c609",
+        ))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .delete_okta_account(OktaAccountRequest {
+            account_id: "f749daaf-682e-4208-a38d-c9b43162c609".to_string(),
+        })
+        .await
+        .unwrap();
+}
@@ -0,0 +1,139 @@
+use datadog_api_client::{
+    client::ClientBuilder,
+    models::{
+        client::Request,
+        opsgenie_integration::{
+            OpsgenieServiceCreateAttributes, OpsgenieServiceCreateData, OpsgenieServiceRegion,
+            OpsgenieServiceRequest, OpsgenieServiceType, OpsgenieServiceUpdateAttributes,
+            OpsgenieServiceUpdateData, UpdateOpsgenieServiceRequest,
+        },
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const SERVICE_BODY: &str = r#"
+    {
+        "data": {
+            "attributes": {"custom_url": null, "name": "fake-opsgenie-service-name", "region": "us"},
+            "id": "596da4af-0563-4097-90ff-07230c3f9db3",
+            "type": "opsgenie-service"
+        }
+    }
+"#;
+
+#[tokio::test]
+async fn add_opsgenie_service() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(201).set_body_raw(SERVICE_BODY, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/integration/opsgenie/services"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {
+                    "name": "fake-opsgenie-service-name",
+                    "opsgenie_api_key": "00000000-0000-0000-0000-000000000000",
+                    "region": "us"
+                },
+                "type": "opsgenie-service"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let request = Request {
+        data: OpsgenieServiceCreateData {
+            attributes: OpsgenieServiceCreateAttributes {
+                custom_url: None,
+                name: "fake-opsgenie-service-name".to_string(),
+                opsgenie_api_key: "00000000-0000-0000-0000-000000000000".into(),
+                region: OpsgenieServiceRegion::Us,
+            },
+            typ: OpsgenieServiceType::OpsgenieService,
+        },
+    };
+    assert!(!format!("{:?}", request).contains("00000000-0000"));
+
+    let res = client.add_opsgenie_service(request).await.unwrap();
+    assert_eq!(
+        res.data.attributes.unwrap().region,
+        Some(OpsgenieServiceRegion::Us)
+    );
+}
+
+#[tokio::test]
+async fn update_opsgenie_service() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(SERVICE_BODY, "application/json");
+    Mock::given(method("PATCH"))
+        .and(path(
+            "/api/v2/integration/opsgenie/services/596da4af-0563-4097-90ff-07230c3f9db3",
+        ))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {"name": "updated-name"},
+                "id": "596da4af-0563-4097-90ff-07230c3f9db3",
+                "type": "opsgenie-service"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .update_opsgenie_service(UpdateOpsgenieServiceRequest {
+            integration_service_id: "596da4af-0563-4097-90ff-07230c3f9db3".to_string(),
+            service: Request {
+                data: OpsgenieServiceUpdateData {
+                    attributes: OpsgenieServiceUpdateAttributes {
+                        name: Some("updated-name".to_string()),
+                        ..Default::default()
+                    },
+                    id: "596da4af-0563-4097-90ff-07230c3f9db3".to_string(),
+                    typ: OpsgenieServiceType::OpsgenieService,
+                },
+            },
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn get_opsgenie_service() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(SERVICE_BODY, "application/json");
+    Mock::given(method("GET"))
+        .and(path(
+            "/api/v2/integration/opsgenie/services/596da4af-0563-4097-90ff-07230c3f9db3",
+        ))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .get_opsgenie_service(OpsgenieServiceRequest {
+            integration_service_id: "596da4af-0563-4097-90ff-07230c3f9db3".to_string(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        res.data.attributes.unwrap().name.as_deref(),
+        Some("fake-opsgenie-service-name")
+    );
+}
@@ -0,0 +1,321 @@
+use datadog_api_client::{
+    client::ClientBuilder,
+    models::{
+        client::Request,
+        roles::{
+            CloneRoleRequest, CreateRoleRequest, DeleteRoleRequest, ListRolesRequest,
+            PermissionsType, RelationshipToPermissionData, RelationshipToPermissions,
+            RoleCloneAttributes, RoleCloneData, RoleCreateAttributes, RoleCreateData,
+            RolePermissionRequest, RoleRelationships, RoleUserRequest, RolesSort, RolesType,
+        },
+        users::{RelationshipToUserData, UsersType},
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+static ROLE_BODY: &str = r#"
+    {
+        "data": {
+            "attributes": {
+                "created_at": "2024-02-01T10:00:00.000000+00:00",
+                "modified_at": "2024-02-01T10:00:00.000000+00:00",
+                "name": "Developers",
+                "user_count": 0
+            },
+            "id": "00000000-0000-1111-0000-000000000000",
+            "relationships": {
+                "permissions": {
+                    "data": [{"id": "00000000-0000-2222-0000-000000000000", "type": "permissions"}]
+                }
+            },
+            "type": "roles"
+        }
+    }
+"#;
+
+#[tokio::test]
+async fn list_roles() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": [
+                {
+                    "attributes": {"name": "Datadog Admin Role", "user_count": 2},
+                    "id": "00000000-0000-1111-0000-000000000001",
+                    "relationships": {
+                        "permissions": {
+                            "data": [{"id": "00000000-0000-2222-0000-000000000000", "type": "permissions"}]
+                        },
+                        "users": {
+                            "data": [{"id": "00000000-0000-3333-0000-000000000000", "type": "users"}]
+                        }
+                    },
+                    "type": "roles"
+                }
+            ],
+            "meta": {"page": {"total_count": 5, "total_filtered_count": 1}}
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v2/roles"))
+        .and(query_param("filter", "Admin"))
+        .and(query_param("sort", "-user_count"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .list_roles(ListRolesRequest {
+            filter: Some("Admin".to_string()),
+            sort: Some(RolesSort::UserCountDescending),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let relationships = res.data[0].relationships.as_ref().unwrap();
+    assert_eq!(
+        relationships.users.as_ref().unwrap().data[0].typ,
+        UsersType::Users
+    );
+    assert_eq!(
+        relationships.permissions.as_ref().unwrap().data[0].id,
+        "00000000-0000-2222-0000-000000000000"
+    );
+    assert_eq!(
+        res.meta.unwrap().page.unwrap().total_filtered_count,
+        Some(1)
+    );
+}
+
+#[tokio::test]
+async fn create_role() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(ROLE_BODY, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/roles"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {"name": "Developers"},
+                "relationships": {
+                    "permissions": {
+                        "data": [{"id": "00000000-0000-2222-0000-000000000000", "type": "permissions"}]
+                    }
+                },
+                "type": "roles"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .create_role(CreateRoleRequest {
+            data: RoleCreateData {
+                attributes: RoleCreateAttributes {
+                    name: "Developers".to_string(),
+                },
+                relationships: Some(RoleRelationships {
+                    permissions: Some(RelationshipToPermissions {
+                        data: vec![RelationshipToPermissionData {
+                            id: "00000000-0000-2222-0000-000000000000".to_string(),
+                            typ: PermissionsType::Permissions,
+                        }],
+                    }),
+                }),
+                typ: RolesType::Roles,
+            },
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.data.id, "00000000-0000-1111-0000-000000000000");
+}
+
+#[tokio::test]
+async fn clone_role() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(ROLE_BODY, "application/json");
+    Mock::given(method("POST"))
+        .and(path(
+            "/api/v2/roles/00000000-0000-1111-0000-000000000001/clone",
+        ))
+        .and(body_json(serde_json::json!({
+            "data": {"attributes": {"name": "Developers"}, "type": "roles"}
+        })))
+        .respond_with(response)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .clone_role(CloneRoleRequest {
+            role_id: "00000000-0000-1111-0000-000000000001".to_string(),
+            role: Request {
+                data: RoleCloneData {
+                    attributes: RoleCloneAttributes {
+                        name: "Developers".to_string(),
+                    },
+                    typ: RolesType::Roles,
+                },
+            },
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn add_user_to_role() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": [
+                {
+                    "attributes": {"email": "jane@example.com", "status": "Active"},
+                    "id": "00000000-0000-3333-0000-000000000000",
+                    "type": "users"
+                }
+            ],
+            "meta": {"page": {"total_count": 1, "total_filtered_count": 1}}
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path(
+            "/api/v2/roles/00000000-0000-1111-0000-000000000000/users",
+        ))
+        .and(body_json(serde_json::json!({
+            "data": {"id": "00000000-0000-3333-0000-000000000000", "type": "users"}
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .add_user_to_role(RoleUserRequest {
+            role_id: "00000000-0000-1111-0000-000000000000".to_string(),
+            user: RelationshipToUserData {
+                id: "00000000-0000-3333-0000-000000000000".to_string(),
+                typ: UsersType::Users,
+            },
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.data.len(), 1);
+}
+
+#[tokio::test]
+async fn grant_role_permission() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": [
+                {
+                    "attributes": {
+                        "display_name": "Logs Read Data",
+                        "group_name": "Log Management",
+                        "name": "logs_read_data",
+                        "restricted": false
+                    },
+                    "id": "00000000-0000-2222-0000-000000000000",
+                    "type": "permissions"
+                }
+            ]
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path(
+            "/api/v2/roles/00000000-0000-1111-0000-000000000000/permissions",
+        ))
+        .and(body_json(serde_json::json!({
+            "data": {"id": "00000000-0000-2222-0000-000000000000", "type": "permissions"}
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .grant_role_permission(RolePermissionRequest {
+            role_id: "00000000-0000-1111-0000-000000000000".to_string(),
+            permission: RelationshipToPermissionData {
+                id: "00000000-0000-2222-0000-000000000000".to_string(),
+                typ: PermissionsType::Permissions,
+            },
+        })
+        .await
+        .unwrap();
+    let attributes = res.data[0].attributes.as_ref().unwrap();
+    assert_eq!(attributes.name.as_deref(), Some("logs_read_data"));
+}
+
+#[tokio::test]
+async fn list_permissions() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": [
+                {"attributes": {"name": "dashboards_read"}, "id": "00000000-0000-2222-0000-000000000001", "type": "permissions"},
+                {"attributes": {"name": "dashboards_write"}, "id": "00000000-0000-2222-0000-000000000002", "type": "permissions"}
+            ]
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v2/permissions"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client.list_permissions().await.unwrap();
+    assert_eq!(res.data.len(), 2);
+}
+
+#[tokio::test]
+async fn delete_role() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/roles/00000000-0000-1111-0000-000000000000"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .delete_role(DeleteRoleRequest {
+            role_id: "00000000-0000-1111-0000-000000000000".to_string(),
+        })
+        .await
+        .unwrap();
+}
@@ -0,0 +1,374 @@
+use datadog_api_client::{
+    client::ClientBuilder,
+    models::{
+        client::Request,
+        logs::{
+            LogsAggregateBucketValue, LogsAggregationFunction, LogsComputeType, LogsGroupByValue,
+        },
+        rum::{
+            CreateRumApplicationRequest, DeleteRumApplicationRequest, GetRumApplicationRequest,
+            ListRumEventsRequest, RumAggregateRequest, RumApplicationCreateAttributes,
+            RumApplicationCreateData, RumApplicationCreateType, RumApplicationPlatform,
+            RumApplicationUpdateAttributes, RumApplicationUpdateData, RumApplicationUpdateType,
+            RumCompute, RumGroupBy, RumQueryFilter, SearchRumEventsRequest,
+            UpdateRumApplicationRequest,
+        },
+        search::{SearchPage, SearchSort},
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const APPLICATION_BODY: &str = r#"
+    {
+        "data": {
+            "attributes": {
+                "application_id": "abcd1234-0000-0000-0000-000000000000",
+                "client_token": "pubabcdef0123456789abcdef0123456789",
+                "created_at": 1659479836169,
+                "created_by_handle": "jane@example.com",
+                "hash": "abcd1234abcd1234abcd1234abcd1234abcd1234",
+                "is_active": true,
+                "name": "storefront",
+                "org_id": 2,
+                "type": "browser",
+                "updated_at": 1659479836169,
+                "updated_by_handle": "jane@example.com"
+            },
+            "id": "abcd1234-0000-0000-0000-000000000000",
+            "type": "rum_application"
+        }
+    }
+"#;
+
+const LIST_RUM_EVENTS_BODY: &str = r#"
+    {
+        "data": [
+            {
+                "attributes": {
+                    "attributes": {"view": {"url_path": "/checkout"}},
+                    "service": "storefront",
+                    "tags": ["env:prod"],
+                    "timestamp": "2024-02-01T10:00:00.000Z"
+                },
+                "id": "AAAAAWgN8Xwgr1vKDQAAAABBV2dOOFh3ZzZobm1mWXJFYTR0OA",
+                "type": "rum"
+            }
+        ],
+        "links": {"next": "https://app.datadoghq.com/api/v2/rum/events?page[cursor]=eyJhZnRlciI6IkFRQUFBWGgifQ"},
+        "meta": {"page": {"after": "eyJhZnRlciI6IkFRQUFBWGgifQ"}, "status": "done"}
+    }
+"#;
+
+#[tokio::test]
+async fn list_rum_applications() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": [
+                {
+                    "attributes": {
+                        "application_id": "abcd1234-0000-0000-0000-000000000000",
+                        "created_at": 1659479836169,
+                        "created_by_handle": "jane@example.com",
+                        "name": "storefront",
+                        "org_id": 2,
+                        "type": "react-native",
+                        "updated_at": 1659479836169,
+                        "updated_by_handle": "jane@example.com"
+                    },
+                    "id": "abcd1234-0000-0000-0000-000000000000",
+                    "type": "rum_application"
+                }
+            ]
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v2/rum/applications"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client.list_rum_applications().await.unwrap();
+    assert_eq!(
+        res.data[0].attributes.typ,
+        RumApplicationPlatform::ReactNative
+    );
+}
+
+#[tokio::test]
+async fn create_rum_application() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(APPLICATION_BODY, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/rum/applications"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {"name": "storefront", "type": "browser"},
+                "type": "rum_application_create"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .create_rum_application(CreateRumApplicationRequest {
+            data: RumApplicationCreateData {
+                attributes: RumApplicationCreateAttributes {
+                    name: "storefront".to_string(),
+                    typ: Some(RumApplicationPlatform::Browser),
+                },
+                typ: RumApplicationCreateType::RumApplicationCreate,
+            },
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        res.data.attributes.application_id,
+        "abcd1234-0000-0000-0000-000000000000"
+    );
+}
+
+#[tokio::test]
+async fn get_rum_application_client_token() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(APPLICATION_BODY, "application/json");
+    Mock::given(method("GET"))
+        .and(path(
+            "/api/v2/rum/applications/abcd1234-0000-0000-0000-000000000000",
+        ))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .get_rum_application(GetRumApplicationRequest {
+            id: "abcd1234-0000-0000-0000-000000000000".to_string(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        res.data.attributes.client_token,
+        "pubabcdef0123456789abcdef0123456789"
+    );
+}
+
+#[tokio::test]
+async fn update_rum_application() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(APPLICATION_BODY, "application/json");
+    Mock::given(method("PATCH"))
+        .and(path(
+            "/api/v2/rum/applications/abcd1234-0000-0000-0000-000000000000",
+        ))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {"name": "storefront"},
+                "id": "abcd1234-0000-0000-0000-000000000000",
+                "type": "rum_application_update"
+            }
+        })))
+        .respond_with(response)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .update_rum_application(UpdateRumApplicationRequest {
+            id: "abcd1234-0000-0000-0000-000000000000".to_string(),
+            application: Request {
+                data: RumApplicationUpdateData {
+                    attributes: Some(RumApplicationUpdateAttributes {
+                        name: Some("storefront".to_string()),
+                        ..Default::default()
+                    }),
+                    id: "abcd1234-0000-0000-0000-000000000000".to_string(),
+                    typ: RumApplicationUpdateType::RumApplicationUpdate,
+                },
+            },
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn delete_rum_application() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    Mock::given(method("DELETE"))
+        .and(path(
+            "/api/v2/rum/applications/abcd1234-0000-0000-0000-000000000000",
+        ))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .delete_rum_application(DeleteRumApplicationRequest {
+            id: "abcd1234-0000-0000-0000-000000000000".to_string(),
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn list_rum_events() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response =
+        ResponseTemplate::new(200).set_body_raw(LIST_RUM_EVENTS_BODY, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v2/rum/events"))
+        .and(query_param("filter[query]", "@type:view"))
+        .and(query_param("sort", "-timestamp"))
+        .and(query_param("page[limit]", "25"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .list_rum_events(ListRumEventsRequest {
+            filter_query: Some("@type:view".to_string()),
+            sort: Some(SearchSort::NegativeTimestamp),
+            page_limit: Some(25),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.data.len(), 1);
+    assert_eq!(res.next_cursor(), Some("eyJhZnRlciI6IkFRQUFBWGgifQ"));
+}
+
+#[tokio::test]
+async fn search_rum_events() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response =
+        ResponseTemplate::new(200).set_body_raw(LIST_RUM_EVENTS_BODY, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/rum/events/search"))
+        .and(body_json(serde_json::json!({
+            "filter": {"from": "now-1h", "query": "@type:view @view.url_path:/checkout", "to": "now"},
+            "page": {"cursor": null, "limit": 25},
+            "sort": "timestamp"
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .search_rum_events(SearchRumEventsRequest {
+            filter: Some(RumQueryFilter {
+                from: Some("now-1h".to_string()),
+                query: Some("@type:view @view.url_path:/checkout".to_string()),
+                to: Some("now".to_string()),
+            }),
+            page: Some(SearchPage {
+                cursor: None,
+                limit: 25,
+            }),
+            sort: Some(SearchSort::Timestamp),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        res.data[0].attributes.service.as_deref(),
+        Some("storefront")
+    );
+}
+
+#[tokio::test]
+async fn aggregate_rum_events() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": {
+                "buckets": [
+                    {"by": {"@view.url_path": "/checkout"}, "computes": {"c0": 1523}}
+                ]
+            },
+            "meta": {"elapsed": 35, "status": "done"}
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/rum/analytics/aggregate"))
+        .and(body_json(serde_json::json!({
+            "compute": [{"aggregation": "count", "type": "total"}],
+            "filter": {"query": "@type:view"},
+            "group_by": [{"facet": "@view.url_path", "limit": 10}]
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .aggregate_rum_events(RumAggregateRequest {
+            compute: Some(vec![RumCompute {
+                aggregation: LogsAggregationFunction::Count,
+                interval: None,
+                metric: None,
+                typ: Some(LogsComputeType::Total),
+            }]),
+            filter: Some(RumQueryFilter {
+                query: Some("@type:view".to_string()),
+                ..Default::default()
+            }),
+            group_by: Some(vec![RumGroupBy {
+                facet: "@view.url_path".to_string(),
+                histogram: None,
+                limit: Some(10),
+                missing: None,
+                sort: None,
+                total: None,
+            }]),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let bucket = &res.data.unwrap().buckets[0];
+    assert_eq!(
+        bucket.by["@view.url_path"],
+        LogsGroupByValue::String("/checkout".to_string())
+    );
+    assert!(matches!(
+        bucket.computes["c0"],
+        LogsAggregateBucketValue::Number(count) if count == 1523.0
+    ));
+}
@@ -0,0 +1,546 @@
+use datadog_api_client::{
+    client::ClientBuilder,
+    models::{
+        client::Request,
+        search::{SearchPage, SearchSort},
+        security_monitoring::{
+            CreateSecurityFilterRequest, CreateSecurityMonitoringSuppressionRequest,
+            EditSecurityMonitoringSignalAssigneeRequest, EditSecurityMonitoringSignalStateRequest,
+            ListSecurityMonitoringRulesRequest, SearchSecurityMonitoringSignalsRequest,
+            SecurityFilterCreateAttributes, SecurityFilterCreateData,
+            SecurityFilterExclusionFilter, SecurityFilterFilteredDataType, SecurityFilterType,
+            SecurityMonitoringRule, SecurityMonitoringRuleCase,
+            SecurityMonitoringRuleDetectionMethod, SecurityMonitoringRuleOptions,
+            SecurityMonitoringRuleSeverity, SecurityMonitoringSignalArchiveReason,
+            SecurityMonitoringSignalAssigneeUpdateAttributes,
+            SecurityMonitoringSignalAssigneeUpdateData, SecurityMonitoringSignalListRequestFilter,
+            SecurityMonitoringSignalState, SecurityMonitoringSignalStateUpdateAttributes,
+            SecurityMonitoringSignalStateUpdateData, SecurityMonitoringStandardRule,
+            SecurityMonitoringStandardRuleQuery, SecurityMonitoringSuppressionCreateAttributes,
+            SecurityMonitoringSuppressionCreateData, SecurityMonitoringSuppressionType,
+            SecurityMonitoringTriageUser, UpdateSecurityMonitoringRuleRequest,
+        },
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const TRIAGE_BODY: &str = r#"
+    {
+        "data": {
+            "attributes": {
+                "archive_reason": "false_positive",
+                "assignee": {"handle": "jane@example.com", "id": 1234, "uuid": "773b045d-ccf8-4808-bd3b-955ef6a8c940"},
+                "incident_ids": [42],
+                "state": "archived",
+                "state_update_timestamp": 1706781600000
+            },
+            "id": "AAAAAWgN8Xwgr1vKDQAAAABBV2dOOFh3ZzZobm1mWXJFYTR0OA",
+            "type": "signal_metadata"
+        }
+    }
+"#;
+
+fn brute_force_rule() -> SecurityMonitoringStandardRule {
+    SecurityMonitoringStandardRule {
+        cases: vec![SecurityMonitoringRuleCase {
+            condition: Some("a > 10".to_string()),
+            name: Some("brute force".to_string()),
+            notifications: Some(vec!["@security-team".to_string()]),
+            status: SecurityMonitoringRuleSeverity::High,
+        }],
+        is_enabled: true,
+        message: "Brute force attempt on {{@usr.id}}".to_string(),
+        name: "Brute force on login".to_string(),
+        options: SecurityMonitoringRuleOptions {
+            detection_method: Some(SecurityMonitoringRuleDetectionMethod::Threshold),
+            evaluation_window: Some(300),
+            keep_alive: Some(3600),
+            max_signal_duration: Some(86400),
+            ..Default::default()
+        },
+        queries: vec![SecurityMonitoringStandardRuleQuery {
+            group_by_fields: Some(vec!["@usr.id".to_string()]),
+            name: Some("a".to_string()),
+            query: "@evt.name:authentication @evt.outcome:failure".to_string(),
+            ..Default::default()
+        }],
+        tags: Some(vec!["team:security".to_string()]),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn create_security_monitoring_rule() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "cases": [{"condition": "a > 10", "name": "brute force", "notifications": ["@security-team"], "status": "high"}],
+            "createdAt": 1706781600000,
+            "creationAuthorId": 1234,
+            "id": "abc-def-ghi",
+            "isDefault": false,
+            "isDeleted": false,
+            "isEnabled": true,
+            "message": "Brute force attempt on {{@usr.id}}",
+            "name": "Brute force on login",
+            "options": {"detectionMethod": "threshold", "evaluationWindow": 300, "keepAlive": 3600, "maxSignalDuration": 86400},
+            "queries": [{"aggregation": "count", "groupByFields": ["@usr.id"], "name": "a", "query": "@evt.name:authentication @evt.outcome:failure"}],
+            "tags": ["team:security"],
+            "type": "log_detection",
+            "version": 1
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/security_monitoring/rules"))
+        .and(body_json(serde_json::json!({
+            "cases": [{"condition": "a > 10", "name": "brute force", "notifications": ["@security-team"], "status": "high"}],
+            "isEnabled": true,
+            "message": "Brute force attempt on {{@usr.id}}",
+            "name": "Brute force on login",
+            "options": {"detectionMethod": "threshold", "evaluationWindow": 300, "keepAlive": 3600, "maxSignalDuration": 86400},
+            "queries": [{"groupByFields": ["@usr.id"], "name": "a", "query": "@evt.name:authentication @evt.outcome:failure"}],
+            "tags": ["team:security"],
+            "type": "log_detection"
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .create_security_monitoring_rule(SecurityMonitoringRule::LogDetection(brute_force_rule()))
+        .await
+        .unwrap();
+    assert_eq!(res.id(), Some("abc-def-ghi"));
+    match res {
+        SecurityMonitoringRule::LogDetection(rule) => {
+            assert_eq!(rule.metadata.version, Some(1));
+            assert_eq!(rule.metadata.creation_author_id, Some(1234));
+        }
+        rule => panic!("unexpected rule: {:?}", rule),
+    }
+}
+
+#[tokio::test]
+async fn list_security_monitoring_rules() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": [
+                {
+                    "cases": [{"status": "medium"}],
+                    "complianceSignalOptions": {"userActivationStatus": true, "userGroupByFields": ["@account_id"]},
+                    "id": "cloud-rule",
+                    "isEnabled": true,
+                    "message": "Bucket is public",
+                    "name": "S3 bucket is public",
+                    "options": {
+                        "complianceRuleOptions": {
+                            "complexRule": false,
+                            "regoRule": {"policy": "package datadog", "resourceTypes": ["aws_s3_bucket"]},
+                            "resourceType": "aws_s3_bucket"
+                        }
+                    },
+                    "type": "cloud_configuration"
+                },
+                {
+                    "cases": [{"condition": "a > 0 && b > 0", "status": "critical"}],
+                    "id": "correlation-rule",
+                    "isEnabled": true,
+                    "message": "Correlated signals",
+                    "name": "Brute force then success",
+                    "options": {"evaluationWindow": 900, "keepAlive": 3600, "maxSignalDuration": 86400},
+                    "queries": [
+                        {"correlatedByFields": ["@usr.id"], "name": "a", "ruleId": "abc-def-ghi"},
+                        {"correlatedByFields": ["@usr.id"], "correlatedQueryIndex": 0, "name": "b", "ruleId": "jkl-mno-pqr"}
+                    ],
+                    "type": "signal_correlation"
+                },
+                {
+                    "id": "future-rule",
+                    "name": "A rule type from the future",
+                    "type": "future_detection"
+                }
+            ],
+            "meta": {"page": {"total_count": 3, "total_filtered_count": 3}}
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v2/security_monitoring/rules"))
+        .and(query_param("page[size]", "100"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .list_security_monitoring_rules(ListSecurityMonitoringRulesRequest {
+            page_size: Some(100),
+            page_number: None,
+        })
+        .await
+        .unwrap();
+    match &res.data[0] {
+        SecurityMonitoringRule::CloudConfiguration(rule) => {
+            let rego = rule.options.compliance_rule_options.rego_rule.as_ref();
+            assert_eq!(rego.unwrap().resource_types, vec!["aws_s3_bucket"]);
+        }
+        rule => panic!("unexpected rule: {:?}", rule),
+    }
+    match &res.data[1] {
+        SecurityMonitoringRule::SignalCorrelation(rule) => {
+            assert_eq!(rule.queries[1].correlated_query_index, Some(0));
+        }
+        rule => panic!("unexpected rule: {:?}", rule),
+    }
+    assert!(matches!(res.data[2], SecurityMonitoringRule::Unknown(_)));
+    assert_eq!(res.data[2].rule_type(), Some("future_detection"));
+    let page = res.meta.unwrap().page.unwrap();
+    assert_eq!(page.total_filtered_count, Some(3));
+}
+
+#[tokio::test]
+async fn update_security_monitoring_rule() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"{"id": "abc-def-ghi", "name": "Brute force on login", "type": "workload_security", "cases": [], "isEnabled": false, "message": "", "options": {}, "queries": []}"#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("PUT"))
+        .and(path("/api/v2/security_monitoring/rules/abc-def-ghi"))
+        .respond_with(response)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .update_security_monitoring_rule(UpdateSecurityMonitoringRuleRequest {
+            rule_id: "abc-def-ghi".to_string(),
+            rule: SecurityMonitoringRule::WorkloadSecurity(SecurityMonitoringStandardRule {
+                is_enabled: false,
+                ..brute_force_rule()
+            }),
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.rule_type(), Some("workload_security"));
+}
+
+#[tokio::test]
+async fn validate_security_monitoring_rule() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    Mock::given(method("POST"))
+        .and(path("/api/v2/security_monitoring/rules/validation"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .validate_security_monitoring_rule(SecurityMonitoringRule::LogDetection(brute_force_rule()))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn search_security_monitoring_signals() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": [
+                {
+                    "attributes": {
+                        "custom": {"workflow": {"triage": {"state": "open"}}},
+                        "message": "Brute force attempt on jane",
+                        "tags": ["source:auth0"],
+                        "timestamp": "2024-02-01T10:00:00Z"
+                    },
+                    "id": "AAAAAWgN8Xwgr1vKDQAAAABBV2dOOFh3ZzZobm1mWXJFYTR0OA",
+                    "type": "signal"
+                }
+            ],
+            "links": {"next": "https://app.datadoghq.com/api/v2/security_monitoring/signals?page[cursor]=eyJhZnRlciI6IkFRQUEifQ"},
+            "meta": {"page": {"after": "eyJhZnRlciI6IkFRQUEifQ"}}
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/security_monitoring/signals/search"))
+        .and(body_json(serde_json::json!({
+            "filter": {"from": "now-1d", "query": "status:(high OR critical)", "to": "now"},
+            "page": {"cursor": null, "limit": 50},
+            "sort": "-timestamp"
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .search_security_monitoring_signals(SearchSecurityMonitoringSignalsRequest {
+            filter: Some(SecurityMonitoringSignalListRequestFilter {
+                from: Some("now-1d".to_string()),
+                query: Some("status:(high OR critical)".to_string()),
+                to: Some("now".to_string()),
+            }),
+            page: Some(SearchPage {
+                cursor: None,
+                limit: 50,
+            }),
+            sort: Some(SearchSort::NegativeTimestamp),
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.data.len(), 1);
+    assert_eq!(res.next_cursor(), Some("eyJhZnRlciI6IkFRQUEifQ"));
+}
+
+#[tokio::test]
+async fn edit_security_monitoring_signal_state() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(TRIAGE_BODY, "application/json");
+    Mock::given(method("PATCH"))
+        .and(path(
+            "/api/v2/security_monitoring/signals/AAAAAWgN8Xwgr1vKDQAAAABBV2dOOFh3ZzZobm1mWXJFYTR0OA/state",
+        ))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {
+                    "archive_comment": "Pentest",
+                    "archive_reason": "testing_or_maintenance",
+                    "state": "archived"
+                }
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .edit_security_monitoring_signal_state(EditSecurityMonitoringSignalStateRequest {
+            signal_id: "AAAAAWgN8Xwgr1vKDQAAAABBV2dOOFh3ZzZobm1mWXJFYTR0OA".to_string(),
+            state: Request {
+                data: SecurityMonitoringSignalStateUpdateData {
+                    attributes: SecurityMonitoringSignalStateUpdateAttributes {
+                        archive_comment: Some("Pentest".to_string()),
+                        archive_reason: Some(
+                            SecurityMonitoringSignalArchiveReason::TestingOrMaintenance,
+                        ),
+                        state: SecurityMonitoringSignalState::Archived,
+                        version: None,
+                    },
+                },
+            },
+        })
+        .await
+        .unwrap();
+    let attributes = res.data.attributes.unwrap();
+    assert_eq!(attributes.state, SecurityMonitoringSignalState::Archived);
+    assert_eq!(attributes.incident_ids, vec![42]);
+}
+
+#[tokio::test]
+async fn edit_security_monitoring_signal_assignee() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(TRIAGE_BODY, "application/json");
+    Mock::given(method("PATCH"))
+        .and(path(
+            "/api/v2/security_monitoring/signals/AAAAAWgN8Xwgr1vKDQAAAABBV2dOOFh3ZzZobm1mWXJFYTR0OA/assignee",
+        ))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {
+                    "assignee": {"uuid": "773b045d-ccf8-4808-bd3b-955ef6a8c940"},
+                    "version": 3
+                }
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .edit_security_monitoring_signal_assignee(EditSecurityMonitoringSignalAssigneeRequest {
+            signal_id: "AAAAAWgN8Xwgr1vKDQAAAABBV2dOOFh3ZzZobm1mWXJFYTR0OA".to_string(),
+            assignee: Request {
+                data: SecurityMonitoringSignalAssigneeUpdateData {
+                    attributes: SecurityMonitoringSignalAssigneeUpdateAttributes {
+                        assignee: SecurityMonitoringTriageUser {
+                            uuid: "773b045d-ccf8-4808-bd3b-955ef6a8c940".to_string(),
+                            ..Default::default()
+                        },
+                        version: Some(3),
+                    },
+                },
+            },
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        res.data.attributes.unwrap().assignee.handle.as_deref(),
+        Some("jane@example.com")
+    );
+}
+
+#[tokio::test]
+async fn create_security_monitoring_suppression() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": {
+                "attributes": {
+                    "creation_date": 1706781600000,
+                    "creator": {"handle": "jane@example.com", "name": "Jane"},
+                    "editable": true,
+                    "enabled": true,
+                    "name": "Ignore scanners",
+                    "rule_query": "type:log_detection source:cloudtrail",
+                    "suppression_query": "env:staging",
+                    "version": 1
+                },
+                "id": "3dd-0uc-h1s",
+                "type": "suppressions"
+            }
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path(
+            "/api/v2/security_monitoring/configuration/suppressions",
+        ))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {
+                    "enabled": true,
+                    "name": "Ignore scanners",
+                    "rule_query": "type:log_detection source:cloudtrail",
+                    "suppression_query": "env:staging"
+                },
+                "type": "suppressions"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .create_security_monitoring_suppression(CreateSecurityMonitoringSuppressionRequest {
+            data: SecurityMonitoringSuppressionCreateData {
+                attributes: SecurityMonitoringSuppressionCreateAttributes {
+                    data_exclusion_query: None,
+                    description: None,
+                    enabled: true,
+                    expiration_date: None,
+                    name: "Ignore scanners".to_string(),
+                    rule_query: "type:log_detection source:cloudtrail".to_string(),
+                    suppression_query: Some("env:staging".to_string()),
+                },
+                typ: SecurityMonitoringSuppressionType::Suppressions,
+            },
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.data.attributes.unwrap().version, Some(1));
+}
+
+#[tokio::test]
+async fn create_security_filter() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": {
+                "attributes": {
+                    "exclusion_filters": [{"name": "Exclude staging", "query": "env:staging"}],
+                    "filtered_data_type": "logs",
+                    "is_builtin": false,
+                    "is_enabled": true,
+                    "name": "Custom security filter",
+                    "query": "service:api",
+                    "version": 1
+                },
+                "id": "3dd-0uc-h1s",
+                "type": "security_filters"
+            }
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path(
+            "/api/v2/security_monitoring/configuration/security_filters",
+        ))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {
+                    "exclusion_filters": [{"name": "Exclude staging", "query": "env:staging"}],
+                    "filtered_data_type": "logs",
+                    "is_enabled": true,
+                    "name": "Custom security filter",
+                    "query": "service:api"
+                },
+                "type": "security_filters"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .create_security_filter(CreateSecurityFilterRequest {
+            data: SecurityFilterCreateData {
+                attributes: SecurityFilterCreateAttributes {
+                    exclusion_filters: vec![SecurityFilterExclusionFilter {
+                        name: "Exclude staging".to_string(),
+                        query: "env:staging".to_string(),
+                    }],
+                    filtered_data_type: SecurityFilterFilteredDataType::Logs,
+                    is_enabled: true,
+                    name: "Custom security filter".to_string(),
+                    query: "service:api".to_string(),
+                },
+                typ: SecurityFilterType::SecurityFilters,
+            },
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        res.data.unwrap().attributes.unwrap().is_builtin,
+        Some(false)
+    );
+}
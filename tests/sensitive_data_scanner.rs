@@ -0,0 +1,363 @@
+use datadog_api_client::{
+    client::ClientBuilder,
+    error::Error,
+    models::sensitive_data_scanner::{
+        DeleteScanningRuleRequest, SensitiveDataScannerConfigRequest,
+        SensitiveDataScannerConfiguration, SensitiveDataScannerConfigurationData,
+        SensitiveDataScannerConfigurationRelationships, SensitiveDataScannerConfigurationType,
+        SensitiveDataScannerDeleteRequest, SensitiveDataScannerFilter, SensitiveDataScannerGroup,
+        SensitiveDataScannerGroupAttributes, SensitiveDataScannerGroupCreate,
+        SensitiveDataScannerGroupCreateRequest, SensitiveDataScannerGroupData,
+        SensitiveDataScannerGroupList, SensitiveDataScannerGroupRelationships,
+        SensitiveDataScannerGroupType, SensitiveDataScannerMetaVersionOnly,
+        SensitiveDataScannerProduct, SensitiveDataScannerReorderConfig,
+        SensitiveDataScannerRuleAttributes, SensitiveDataScannerRuleCreate,
+        SensitiveDataScannerRuleCreateRequest, SensitiveDataScannerRuleData,
+        SensitiveDataScannerRuleRelationships, SensitiveDataScannerRuleType,
+        SensitiveDataScannerStandardPattern, SensitiveDataScannerStandardPatternData,
+        SensitiveDataScannerStandardPatternType, SensitiveDataScannerTextReplacement,
+        SensitiveDataScannerTextReplacementType,
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn group(id: &str) -> SensitiveDataScannerGroup {
+    SensitiveDataScannerGroup {
+        id: id.to_string(),
+        typ: SensitiveDataScannerGroupType::SensitiveDataScannerGroup,
+    }
+}
+
+#[tokio::test]
+async fn list_scanning_groups() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": {
+                "id": "7957915c634-Sensitive-Data-Scanner",
+                "relationships": {
+                    "groups": {"data": [{"id": "group-1", "type": "sensitive_data_scanner_group"}]}
+                },
+                "type": "sensitive_data_scanner_configuration"
+            },
+            "included": [
+                {
+                    "attributes": {
+                        "filter": {"query": "service:web"},
+                        "is_enabled": true,
+                        "name": "Web",
+                        "product_list": ["logs", "rum"]
+                    },
+                    "id": "group-1",
+                    "relationships": {
+                        "configuration": {"data": {"id": "7957915c634-Sensitive-Data-Scanner", "type": "sensitive_data_scanner_configuration"}},
+                        "rules": {"data": [{"id": "rule-1", "type": "sensitive_data_scanner_rule"}]}
+                    },
+                    "type": "sensitive_data_scanner_group"
+                },
+                {
+                    "attributes": {
+                        "is_enabled": true,
+                        "name": "Credit cards",
+                        "namespaces": ["admin.email"],
+                        "tags": ["sensitive_data:credit_card"],
+                        "text_replacement": {"replacement_string": "[redacted]", "type": "replacement_string"}
+                    },
+                    "id": "rule-1",
+                    "relationships": {
+                        "group": {"data": {"id": "group-1", "type": "sensitive_data_scanner_group"}},
+                        "standard_pattern": {"data": {"id": "visa-card", "type": "sensitive_data_scanner_standard_pattern"}}
+                    },
+                    "type": "sensitive_data_scanner_rule"
+                }
+            ],
+            "meta": {
+                "count_limit": 100,
+                "group_count_limit": 20,
+                "has_highlight_enabled": true,
+                "is_pci_compliant": false,
+                "version": 12
+            }
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v2/sensitive-data-scanner/config"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client.list_scanning_groups().await.unwrap();
+    assert_eq!(res.version(), 12);
+    let groups = res.groups().collect::<Vec<_>>();
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].attributes.name.as_deref(), Some("Web"));
+    let rules = res.rules().collect::<Vec<_>>();
+    assert_eq!(rules.len(), 1);
+    assert_eq!(
+        rules[0].attributes.text_replacement.as_ref().unwrap().typ,
+        SensitiveDataScannerTextReplacementType::ReplacementString
+    );
+}
+
+#[tokio::test]
+async fn reorder_scanning_groups() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"{"meta": {"version": 13}}"#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/sensitive-data-scanner/config"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "id": "7957915c634-Sensitive-Data-Scanner",
+                "relationships": {
+                    "groups": {
+                        "data": [
+                            {"id": "group-2", "type": "sensitive_data_scanner_group"},
+                            {"id": "group-1", "type": "sensitive_data_scanner_group"}
+                        ]
+                    }
+                },
+                "type": "sensitive_data_scanner_configuration"
+            },
+            "meta": {"version": 12}
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .reorder_scanning_groups(SensitiveDataScannerConfigRequest {
+            data: SensitiveDataScannerReorderConfig {
+                id: "7957915c634-Sensitive-Data-Scanner".to_string(),
+                relationships: SensitiveDataScannerConfigurationRelationships {
+                    groups: SensitiveDataScannerGroupList {
+                        data: vec![group("group-2"), group("group-1")],
+                    },
+                },
+                typ: SensitiveDataScannerConfigurationType::SensitiveDataScannerConfiguration,
+            },
+            meta: SensitiveDataScannerMetaVersionOnly { version: 12 },
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.meta.unwrap().version, 13);
+}
+
+#[tokio::test]
+async fn create_scanning_group() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": {
+                "attributes": {"filter": {"query": "*"}, "is_enabled": false, "name": "Everything", "product_list": ["logs"]},
+                "id": "group-3",
+                "type": "sensitive_data_scanner_group"
+            },
+            "meta": {"version": 13}
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/sensitive-data-scanner/config/groups"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {"filter": {"query": "*"}, "is_enabled": false, "name": "Everything", "product_list": ["logs"]},
+                "relationships": {
+                    "configuration": {"data": {"id": "7957915c634-Sensitive-Data-Scanner", "type": "sensitive_data_scanner_configuration"}},
+                    "rules": {"data": []}
+                },
+                "type": "sensitive_data_scanner_group"
+            },
+            "meta": {"version": 12}
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .create_scanning_group(SensitiveDataScannerGroupCreateRequest {
+            data: SensitiveDataScannerGroupCreate {
+                attributes: SensitiveDataScannerGroupAttributes {
+                    filter: Some(SensitiveDataScannerFilter {
+                        query: Some("*".to_string()),
+                    }),
+                    is_enabled: Some(false),
+                    name: Some("Everything".to_string()),
+                    product_list: Some(vec![SensitiveDataScannerProduct::Logs]),
+                    ..Default::default()
+                },
+                relationships: Some(SensitiveDataScannerGroupRelationships {
+                    configuration: Some(SensitiveDataScannerConfigurationData {
+                        data: SensitiveDataScannerConfiguration {
+                            id: "7957915c634-Sensitive-Data-Scanner".to_string(),
+                            typ: SensitiveDataScannerConfigurationType::SensitiveDataScannerConfiguration,
+                        },
+                    }),
+                    rules: Some(SensitiveDataScannerRuleData { data: vec![] }),
+                }),
+                typ: SensitiveDataScannerGroupType::SensitiveDataScannerGroup,
+            },
+            meta: SensitiveDataScannerMetaVersionOnly { version: 12 },
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.data.unwrap().id.as_deref(), Some("group-3"));
+    assert_eq!(res.meta.unwrap().version, 13);
+}
+
+#[tokio::test]
+async fn create_scanning_rule_version_conflict() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"{"errors": ["Version 12 is outdated, current version is 14"]}"#;
+    let response = ResponseTemplate::new(409).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/sensitive-data-scanner/config/rules"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {
+                    "is_enabled": true,
+                    "name": "Visa cards",
+                    "tags": ["sensitive_data:credit_card"],
+                    "text_replacement": {"number_of_chars": 12, "type": "partial_replacement_from_beginning"}
+                },
+                "relationships": {
+                    "group": {"data": {"id": "group-1", "type": "sensitive_data_scanner_group"}},
+                    "standard_pattern": {"data": {"id": "visa-card", "type": "sensitive_data_scanner_standard_pattern"}}
+                },
+                "type": "sensitive_data_scanner_rule"
+            },
+            "meta": {"version": 12}
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .create_scanning_rule(SensitiveDataScannerRuleCreateRequest {
+            data: SensitiveDataScannerRuleCreate {
+                attributes: SensitiveDataScannerRuleAttributes {
+                    is_enabled: Some(true),
+                    name: Some("Visa cards".to_string()),
+                    tags: Some(vec!["sensitive_data:credit_card".to_string()]),
+                    text_replacement: Some(SensitiveDataScannerTextReplacement {
+                        number_of_chars: Some(12),
+                        replacement_string: None,
+                        typ: SensitiveDataScannerTextReplacementType::PartialReplacementFromBeginning,
+                    }),
+                    ..Default::default()
+                },
+                relationships: SensitiveDataScannerRuleRelationships {
+                    group: Some(SensitiveDataScannerGroupData {
+                        data: group("group-1"),
+                    }),
+                    standard_pattern: Some(SensitiveDataScannerStandardPatternData {
+                        data: SensitiveDataScannerStandardPattern {
+                            id: "visa-card".to_string(),
+                            typ: SensitiveDataScannerStandardPatternType::SensitiveDataScannerStandardPattern,
+                        },
+                    }),
+                },
+                typ: SensitiveDataScannerRuleType::SensitiveDataScannerRule,
+            },
+            meta: SensitiveDataScannerMetaVersionOnly { version: 12 },
+        })
+        .await;
+    match res {
+        Err(Error::VersionConflict(errors)) => assert_eq!(
+            errors.0,
+            vec!["Version 12 is outdated, current version is 14"]
+        ),
+        res => panic!("expected a version conflict, got {:?}", res),
+    }
+}
+
+#[tokio::test]
+async fn delete_scanning_rule() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"{"meta": {"version": 15}}"#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/sensitive-data-scanner/config/rules/rule-1"))
+        .and(body_json(serde_json::json!({"meta": {"version": 14}})))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .delete_scanning_rule(DeleteScanningRuleRequest {
+            rule_id: "rule-1".to_string(),
+            body: SensitiveDataScannerDeleteRequest {
+                meta: SensitiveDataScannerMetaVersionOnly { version: 14 },
+            },
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.meta.unwrap().version, 15);
+}
+
+#[tokio::test]
+async fn list_standard_patterns() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": [
+                {
+                    "attributes": {
+                        "description": "Visa card numbers",
+                        "included_keywords": ["visa", "card"],
+                        "name": "Visa Card Scanner (4x4 digits)",
+                        "priority": 1,
+                        "tags": ["sensitive_data:credit_card", "sensitive_data_category:credit_card"]
+                    },
+                    "id": "visa-card",
+                    "type": "sensitive_data_scanner_standard_pattern"
+                }
+            ]
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path(
+            "/api/v2/sensitive-data-scanner/config/standard-patterns",
+        ))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client.list_standard_patterns().await.unwrap();
+    let attributes = res.data[0].attributes.as_ref().unwrap();
+    assert_eq!(attributes.priority, Some(1));
+    assert_eq!(
+        attributes.included_keywords.as_deref(),
+        Some(&["visa".to_string(), "card".to_string()][..])
+    );
+}
@@ -0,0 +1,275 @@
+use datadog_api_client::{
+    client::ClientBuilder,
+    error::Error,
+    models::service_definitions::{
+        DeleteServiceDefinitionRequest, GetServiceDefinitionRequest, ListServiceDefinitionsRequest,
+        ServiceDefinitionContactType, ServiceDefinitionSchema, ServiceDefinitionSchemaVersion,
+        ServiceDefinitionV2Dot2Type,
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn create_or_update_service_definition_from_yaml() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": [
+                {
+                    "attributes": {
+                        "meta": {"ingested-schema-version": "v2.2", "ingestion-source": "api", "warnings": []},
+                        "schema": {
+                            "schema-version": "v2.2",
+                            "dd-service": "shopping-cart",
+                            "team": "e-commerce",
+                            "type": "web",
+                            "languages": ["rust"],
+                            "contacts": [{"type": "slack", "contact": "https://yourorg.slack.com/archives/cart"}],
+                            "extensions": {"shopping-cart/release-train": "weekly"}
+                        }
+                    },
+                    "id": "shopping-cart",
+                    "type": "service-definition"
+                }
+            ]
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/services/definitions"))
+        .and(body_json(serde_json::json!({
+            "schema-version": "v2.2",
+            "dd-service": "shopping-cart",
+            "team": "e-commerce",
+            "type": "web",
+            "languages": ["rust"],
+            "contacts": [{"type": "slack", "contact": "https://yourorg.slack.com/archives/cart"}],
+            "extensions": {"shopping-cart/release-train": "weekly"}
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let definition = ServiceDefinitionSchema::from_yaml(
+        r#"
+schema-version: v2.2
+dd-service: shopping-cart
+team: e-commerce
+type: web
+languages:
+  - rust
+contacts:
+  - type: slack
+    contact: https://yourorg.slack.com/archives/cart
+extensions:
+  shopping-cart/release-train: weekly
+"#,
+    )
+    .unwrap();
+    let res = client
+        .create_or_update_service_definition(definition)
+        .await
+        .unwrap();
+    let data = res.data.unwrap();
+    match data[0]
+        .attributes
+        .as_ref()
+        .unwrap()
+        .schema
+        .as_ref()
+        .unwrap()
+    {
+        ServiceDefinitionSchema::V2Dot2(schema) => {
+            assert_eq!(schema.typ, Some(ServiceDefinitionV2Dot2Type::Web));
+            assert_eq!(schema.contacts.as_ref().unwrap()[0].typ, "slack");
+        }
+        schema => panic!("unexpected schema: {:?}", schema),
+    }
+}
+
+#[tokio::test]
+async fn create_or_update_service_definition_from_json() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(r#"{"data": []}"#, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/services/definitions"))
+        .and(body_json(serde_json::json!({
+            "schema-version": "v2",
+            "dd-service": "shopping-cart",
+            "integrations": {"pagerduty": "https://my-org.pagerduty.com/service-directory/PSHOPPING"}
+        })))
+        .respond_with(response)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let definition = ServiceDefinitionSchema::from_json(
+        r#"{
+            "schema-version": "v2",
+            "dd-service": "shopping-cart",
+            "integrations": {"pagerduty": "https://my-org.pagerduty.com/service-directory/PSHOPPING"}
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(definition.schema_version(), Some("v2"));
+    assert_eq!(definition.dd_service(), Some("shopping-cart"));
+
+    client
+        .create_or_update_service_definition(definition)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn get_service_definition() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": {
+                "attributes": {
+                    "meta": {"ingested-schema-version": "v2.2", "last-modified-time": "2024-02-01T10:00:00Z"},
+                    "schema": {
+                        "schema-version": "v2.1",
+                        "dd-service": "shopping-cart",
+                        "contacts": [{"type": "email", "contact": "cart@example.com", "name": "Cart team"}],
+                        "integrations": {
+                            "opsgenie": {"region": "US", "service-url": "https://my-org.app.opsgenie.com/service/123"},
+                            "pagerduty": {"service-url": "https://my-org.pagerduty.com/service-directory/PSHOPPING"}
+                        },
+                        "links": [{"name": "Runbook", "type": "runbook", "url": "https://example.com/runbook"}],
+                        "tier": "1"
+                    }
+                },
+                "id": "shopping-cart",
+                "type": "service-definition"
+            }
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v2/services/definitions/shopping-cart"))
+        .and(query_param("schema_version", "v2.1"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .get_service_definition(GetServiceDefinitionRequest {
+            service_name: "shopping-cart".to_string(),
+            schema_version: Some(ServiceDefinitionSchemaVersion::V2Dot1),
+        })
+        .await
+        .unwrap();
+    match res.data.unwrap().attributes.unwrap().schema.unwrap() {
+        ServiceDefinitionSchema::V2Dot1(schema) => {
+            let contacts = schema.contacts.unwrap();
+            assert_eq!(contacts[0].typ, ServiceDefinitionContactType::Email);
+            let integrations = schema.integrations.unwrap();
+            assert_eq!(integrations.opsgenie.unwrap().region.as_deref(), Some("US"));
+        }
+        schema => panic!("unexpected schema: {:?}", schema),
+    }
+}
+
+#[tokio::test]
+async fn list_service_definitions() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": [
+                {
+                    "attributes": {"schema": {"schema-version": "v2", "dd-service": "shopping-cart"}},
+                    "id": "shopping-cart",
+                    "type": "service-definition"
+                },
+                {
+                    "attributes": {"schema": {"schema-version": "v1", "info": {"dd-service": "legacy"}}},
+                    "id": "legacy",
+                    "type": "service-definition"
+                }
+            ]
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v2/services/definitions"))
+        .and(query_param("page[size]", "10"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .list_service_definitions(ListServiceDefinitionsRequest {
+            page_size: Some(10),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let data = res.data.unwrap();
+    let schemas: Vec<_> = data
+        .iter()
+        .map(|definition| {
+            definition
+                .attributes
+                .as_ref()
+                .unwrap()
+                .schema
+                .as_ref()
+                .unwrap()
+        })
+        .collect();
+    assert!(matches!(schemas[0], ServiceDefinitionSchema::V2(_)));
+    assert!(matches!(schemas[1], ServiceDefinitionSchema::Unknown(_)));
+    assert_eq!(schemas[1].schema_version(), Some("v1"));
+}
+
+#[tokio::test]
+async fn delete_service_definition() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/services/definitions/shopping-cart"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .delete_service_definition(DeleteServiceDefinitionRequest {
+            service_name: "shopping-cart".to_string(),
+        })
+        .await
+        .unwrap();
+}
+
+#[test]
+fn invalid_yaml_service_definition() {
+    let err = ServiceDefinitionSchema::from_yaml("schema-version: [v2.2").unwrap_err();
+    match err {
+        Error::YamlDeserializationError(yaml) => assert!(!yaml.to_string().is_empty()),
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
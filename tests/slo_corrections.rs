@@ -0,0 +1,216 @@
+use datadog_api_client::{
+    client::ClientBuilder,
+    models::{
+        client::Request,
+        slo_corrections::{
+            CreateSloCorrectionRequest, DeleteSloCorrectionRequest, ListSloCorrectionsRequest,
+            SloCorrectionCategory, SloCorrectionCreateAttributes, SloCorrectionCreateData,
+            SloCorrectionType, SloCorrectionUpdateAttributes, SloCorrectionUpdateData,
+            UpdateSloCorrectionRequest,
+        },
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+static CORRECTION_BODY: &str = r#"
+    {
+        "data": {
+            "attributes": {
+                "category": "Scheduled Maintenance",
+                "created_at": 1706781600,
+                "creator": {
+                    "attributes": {"email": "jane@example.com", "handle": "jane@example.com", "name": "Jane"},
+                    "id": "00000000-0000-0000-0000-000000000001",
+                    "type": "users"
+                },
+                "description": "Weekly database maintenance",
+                "duration": 3600,
+                "end": null,
+                "modified_at": null,
+                "modifier": null,
+                "rrule": "FREQ=WEEKLY;INTERVAL=1;COUNT=4",
+                "slo_id": "c1b2a3d4e5f60718293a4b5c6d7e8f90",
+                "start": 1707015600,
+                "timezone": "UTC"
+            },
+            "id": "f7e6d5c4-0000-11ee-8000-000000000000",
+            "type": "correction"
+        }
+    }
+"#;
+
+#[tokio::test]
+async fn list_slo_corrections() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": [
+                {
+                    "attributes": {
+                        "category": "Deployment",
+                        "end": 1706785200,
+                        "slo_id": "c1b2a3d4e5f60718293a4b5c6d7e8f90",
+                        "start": 1706781600
+                    },
+                    "id": "f7e6d5c4-0000-11ee-8000-000000000001",
+                    "type": "correction"
+                }
+            ],
+            "meta": {"page": {"total_count": 1, "total_filtered_count": 1}}
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v1/slo/correction"))
+        .and(query_param("limit", "10"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .list_slo_corrections(ListSloCorrectionsRequest {
+            offset: None,
+            limit: Some(10),
+        })
+        .await
+        .unwrap();
+    let corrections = res.data.unwrap();
+    assert_eq!(
+        corrections[0].attributes.as_ref().unwrap().category,
+        Some(SloCorrectionCategory::Deployment)
+    );
+}
+
+#[tokio::test]
+async fn create_slo_correction() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(CORRECTION_BODY, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v1/slo/correction"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {
+                    "category": "Scheduled Maintenance",
+                    "description": "Weekly database maintenance",
+                    "duration": 3600,
+                    "rrule": "FREQ=WEEKLY;INTERVAL=1;COUNT=4",
+                    "slo_id": "c1b2a3d4e5f60718293a4b5c6d7e8f90",
+                    "start": 1707015600,
+                    "timezone": "UTC"
+                },
+                "type": "correction"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .create_slo_correction(CreateSloCorrectionRequest {
+            data: SloCorrectionCreateData {
+                attributes: SloCorrectionCreateAttributes {
+                    category: SloCorrectionCategory::ScheduledMaintenance,
+                    description: Some("Weekly database maintenance".to_string()),
+                    duration: Some(3600),
+                    end: None,
+                    rrule: Some("FREQ=WEEKLY;INTERVAL=1;COUNT=4".to_string()),
+                    slo_id: "c1b2a3d4e5f60718293a4b5c6d7e8f90".to_string(),
+                    start: 1707015600,
+                    timezone: Some("UTC".to_string()),
+                },
+                typ: SloCorrectionType::Correction,
+            },
+        })
+        .await
+        .unwrap();
+    let attributes = res.data.attributes.unwrap();
+    assert_eq!(
+        attributes
+            .creator
+            .unwrap()
+            .attributes
+            .unwrap()
+            .name
+            .as_deref(),
+        Some("Jane")
+    );
+    assert!(attributes.modifier.is_none());
+}
+
+#[tokio::test]
+async fn update_slo_correction() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(CORRECTION_BODY, "application/json");
+    Mock::given(method("PATCH"))
+        .and(path(
+            "/api/v1/slo/correction/f7e6d5c4-0000-11ee-8000-000000000000",
+        ))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {
+                    "duration": 7200
+                },
+                "type": "correction"
+            }
+        })))
+        .respond_with(response)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .update_slo_correction(UpdateSloCorrectionRequest {
+            slo_correction_id: "f7e6d5c4-0000-11ee-8000-000000000000".to_string(),
+            correction: Request {
+                data: SloCorrectionUpdateData {
+                    attributes: SloCorrectionUpdateAttributes {
+                        duration: Some(7200),
+                        ..Default::default()
+                    },
+                    typ: SloCorrectionType::Correction,
+                },
+            },
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn delete_slo_correction() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    Mock::given(method("DELETE"))
+        .and(path(
+            "/api/v1/slo/correction/f7e6d5c4-0000-11ee-8000-000000000000",
+        ))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .delete_slo_correction(DeleteSloCorrectionRequest {
+            slo_correction_id: "f7e6d5c4-0000-11ee-8000-000000000000".to_string(),
+        })
+        .await
+        .unwrap();
+}
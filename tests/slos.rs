@@ -0,0 +1,506 @@
+use std::collections::BTreeMap;
+
+use datadog_api_client::{
+    client::ClientBuilder,
+    models::slos::{
+        CheckCanDeleteSloRequest, DeleteSloRequest, GetSloHistoryRequest, GetSloRequest,
+        ListSlosRequest, SearchSlosRequest, ServiceLevelObjective, SloDefinition, SloMetricQuery,
+        SloThreshold, SloTimeSliceComparator, SloTimeframe, SloType, UpdateSloRequest,
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+static METRIC_SLO_BODY: &str = r#"
+    {
+        "data": [
+            {
+                "created_at": 1706781600,
+                "creator": {
+                    "email": "jane@example.com",
+                    "handle": "jane@example.com",
+                    "name": "Jane"
+                },
+                "description": "Checkout availability",
+                "id": "c1b2a3d4e5f60718293a4b5c6d7e8f90",
+                "modified_at": 1706781600,
+                "monitor_ids": [],
+                "name": "Checkout availability",
+                "query": {
+                    "denominator": "sum:checkout.requests{*}.as_count()",
+                    "numerator": "sum:checkout.requests{!status:error}.as_count()"
+                },
+                "tags": ["team:checkout"],
+                "target_threshold": 99.9,
+                "thresholds": [
+                    {
+                        "target": 99.9,
+                        "target_display": "99.9",
+                        "timeframe": "30d",
+                        "warning": 99.95,
+                        "warning_display": "99.95"
+                    }
+                ],
+                "timeframe": "30d",
+                "type": "metric",
+                "type_id": 1,
+                "warning_threshold": 99.95
+            }
+        ],
+        "errors": []
+    }
+"#;
+
+fn metric_slo() -> ServiceLevelObjective {
+    ServiceLevelObjective {
+        configured_alert_ids: None,
+        created_at: None,
+        creator: None,
+        description: Some("Checkout availability".to_string()),
+        id: None,
+        modified_at: None,
+        name: "Checkout availability".to_string(),
+        tags: Some(vec!["team:checkout".to_string()]),
+        target_threshold: None,
+        thresholds: vec![SloThreshold {
+            target: 99.9,
+            target_display: None,
+            timeframe: SloTimeframe::ThirtyDays,
+            warning: Some(99.95),
+            warning_display: None,
+        }],
+        timeframe: None,
+        warning_threshold: None,
+        definition: SloDefinition::Metric {
+            query: SloMetricQuery {
+                denominator: "sum:checkout.requests{*}.as_count()".to_string(),
+                numerator: "sum:checkout.requests{!status:error}.as_count()".to_string(),
+            },
+        },
+    }
+}
+
+#[tokio::test]
+async fn list_slos() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": [
+                {
+                    "id": "a1b2",
+                    "monitor_ids": [12345, 67890],
+                    "groups": ["env:prod"],
+                    "name": "API uptime",
+                    "thresholds": [{"target": 99.0, "timeframe": "7d"}],
+                    "type": "monitor"
+                },
+                {
+                    "id": "c3d4",
+                    "name": "API latency",
+                    "sli_specification": {
+                        "time_slice": {
+                            "comparator": "<=",
+                            "query": {
+                                "formulas": [{"formula": "query1"}],
+                                "queries": [
+                                    {
+                                        "data_source": "metrics",
+                                        "name": "query1",
+                                        "query": "p95:trace.http.request{service:api}"
+                                    }
+                                ]
+                            },
+                            "query_interval_seconds": 300,
+                            "threshold": 0.5
+                        }
+                    },
+                    "thresholds": [{"target": 97.0, "timeframe": "30d"}],
+                    "type": "time_slice"
+                }
+            ],
+            "metadata": {"page": {"total_count": 2, "total_filtered_count": 2}}
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v1/slo"))
+        .and(query_param("tags_query", "team:api"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .list_slos(ListSlosRequest {
+            tags_query: Some("team:api".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let slos = res.data.unwrap();
+    assert_eq!(
+        slos[0].definition,
+        SloDefinition::Monitor {
+            monitor_ids: vec![12345, 67890],
+            groups: Some(vec!["env:prod".to_string()]),
+        }
+    );
+    match &slos[1].definition {
+        SloDefinition::TimeSlice { sli_specification } => {
+            assert_eq!(
+                sli_specification.time_slice.comparator,
+                SloTimeSliceComparator::LessEqual
+            );
+            assert_eq!(sli_specification.time_slice.threshold, 0.5);
+        }
+        definition => panic!("unexpected definition: {:?}", definition),
+    }
+}
+
+#[tokio::test]
+async fn create_slo() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(METRIC_SLO_BODY, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v1/slo"))
+        .and(body_json(serde_json::json!({
+            "description": "Checkout availability",
+            "name": "Checkout availability",
+            "query": {
+                "denominator": "sum:checkout.requests{*}.as_count()",
+                "numerator": "sum:checkout.requests{!status:error}.as_count()"
+            },
+            "tags": ["team:checkout"],
+            "thresholds": [
+                {
+                    "target": 99.9,
+                    "timeframe": "30d",
+                    "warning": 99.95
+                }
+            ],
+            "type": "metric"
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client.create_slo(metric_slo()).await.unwrap();
+    let slo = &res.data.unwrap()[0];
+    assert_eq!(slo.id.as_deref(), Some("c1b2a3d4e5f60718293a4b5c6d7e8f90"));
+    assert_eq!(slo.definition, metric_slo().definition);
+}
+
+#[tokio::test]
+async fn get_and_update_slo() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = serde_json::json!({
+        "data": serde_json::from_str::<serde_json::Value>(METRIC_SLO_BODY).unwrap()["data"][0]
+    });
+    Mock::given(method("GET"))
+        .and(path("/api/v1/slo/c1b2a3d4e5f60718293a4b5c6d7e8f90"))
+        .and(query_param("with_configured_alert_ids", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/api/v1/slo/c1b2a3d4e5f60718293a4b5c6d7e8f90"))
+        .and(body_json(serde_json::json!({
+            "description": "Checkout availability",
+            "name": "Checkout availability",
+            "query": {
+                "denominator": "sum:checkout.requests{*}.as_count()",
+                "numerator": "sum:checkout.requests{!status:error}.as_count()"
+            },
+            "tags": ["team:checkout"],
+            "target_threshold": 99.9,
+            "thresholds": [
+                {
+                    "target": 99.5,
+                    "target_display": "99.9",
+                    "timeframe": "30d",
+                    "warning": 99.95,
+                    "warning_display": "99.95"
+                }
+            ],
+            "timeframe": "30d",
+            "type": "metric",
+            "warning_threshold": 99.95
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(METRIC_SLO_BODY, "application/json"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut slo = client
+        .get_slo(GetSloRequest {
+            slo_id: "c1b2a3d4e5f60718293a4b5c6d7e8f90".to_string(),
+            with_configured_alert_ids: Some(true),
+        })
+        .await
+        .unwrap()
+        .data
+        .unwrap();
+    slo.thresholds[0].target = 99.5;
+    client
+        .update_slo(UpdateSloRequest {
+            slo_id: slo.id.clone().unwrap(),
+            slo,
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn delete_slo() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(
+        r#"{"data": ["c1b2a3d4e5f60718293a4b5c6d7e8f90"], "errors": {}}"#,
+        "application/json",
+    );
+    Mock::given(method("DELETE"))
+        .and(path("/api/v1/slo/c1b2a3d4e5f60718293a4b5c6d7e8f90"))
+        .and(query_param("force", "true"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .delete_slo(DeleteSloRequest {
+            slo_id: "c1b2a3d4e5f60718293a4b5c6d7e8f90".to_string(),
+            force: Some(true),
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        res.data,
+        Some(vec!["c1b2a3d4e5f60718293a4b5c6d7e8f90".to_string()])
+    );
+}
+
+#[tokio::test]
+async fn bulk_delete_slos() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": {"deleted": ["a1b2"], "updated": ["c3d4"]},
+            "errors": []
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v1/slo/bulk_delete"))
+        .and(body_json(serde_json::json!({
+            "a1b2": ["7d"],
+            "c3d4": ["30d", "90d"]
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let mut request = BTreeMap::new();
+    request.insert("a1b2".to_string(), vec![SloTimeframe::SevenDays]);
+    request.insert(
+        "c3d4".to_string(),
+        vec![SloTimeframe::ThirtyDays, SloTimeframe::NinetyDays],
+    );
+    let res = client.bulk_delete_slos(request).await.unwrap();
+    let data = res.data.unwrap();
+    assert_eq!(data.deleted, Some(vec!["a1b2".to_string()]));
+    assert_eq!(data.updated, Some(vec!["c3d4".to_string()]));
+}
+
+#[tokio::test]
+async fn get_slo_history() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": {
+                "from_ts": 1704067200,
+                "groups": [
+                    {
+                        "error_budget_remaining": {"7d": 42.5},
+                        "group": "env:prod",
+                        "history": [[1704067200, 0], [1704070800, 1]],
+                        "monitor_modified": 1700000000,
+                        "monitor_type": "metric alert",
+                        "name": "env:prod",
+                        "precision": {"7d": 2},
+                        "preview": false,
+                        "sli_value": 99.425,
+                        "span_precision": 3
+                    }
+                ],
+                "overall": {
+                    "error_budget_remaining": {"7d": null},
+                    "sli_value": 99.5,
+                    "span_precision": 2
+                },
+                "thresholds": {
+                    "7d": {"target": 99.0, "target_display": "99.", "timeframe": "7d"}
+                },
+                "to_ts": 1704672000,
+                "type": "monitor",
+                "type_id": 0
+            },
+            "errors": null
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v1/slo/a1b2/history"))
+        .and(query_param("from_ts", "1704067200"))
+        .and(query_param("to_ts", "1704672000"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .get_slo_history(GetSloHistoryRequest {
+            slo_id: "a1b2".to_string(),
+            from_ts: 1704067200,
+            to_ts: 1704672000,
+            target: None,
+            apply_correction: None,
+        })
+        .await
+        .unwrap();
+    let data = res.data.unwrap();
+    assert_eq!(data.typ, Some(SloType::Monitor));
+    let group = &data.groups.unwrap()[0];
+    assert_eq!(group.sli_value, Some(99.425));
+    assert_eq!(
+        group.error_budget_remaining.as_ref().unwrap()["7d"],
+        Some(42.5)
+    );
+    assert_eq!(
+        data.overall.unwrap().error_budget_remaining.unwrap()["7d"],
+        None
+    );
+    assert_eq!(
+        data.thresholds.unwrap()["7d"].timeframe,
+        SloTimeframe::SevenDays
+    );
+}
+
+#[tokio::test]
+async fn search_slos() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": {
+                "attributes": {
+                    "facets": null,
+                    "slos": [
+                        {
+                            "data": {
+                                "attributes": {
+                                    "all_tags": ["team:checkout"],
+                                    "name": "Checkout availability",
+                                    "overall_status": [
+                                        {
+                                            "error_budget_remaining": 62.1,
+                                            "state": "ok",
+                                            "status": 99.96,
+                                            "target": 99.9,
+                                            "timeframe": "30d"
+                                        }
+                                    ],
+                                    "slo_type": "metric"
+                                },
+                                "id": "c1b2a3d4e5f60718293a4b5c6d7e8f90",
+                                "type": "slo"
+                            }
+                        }
+                    ]
+                },
+                "type": "search_slo"
+            },
+            "meta": {"pagination": {"number": 0, "size": 10, "total": 1}}
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v1/slo/search"))
+        .and(query_param("query", "team:checkout"))
+        .and(query_param("page[size]", "10"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .search_slos(SearchSlosRequest {
+            query: Some("team:checkout".to_string()),
+            page_size: Some(10),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let slos = res.data.unwrap().attributes.unwrap().slos.unwrap();
+    let attributes = slos[0].data.as_ref().unwrap().attributes.as_ref().unwrap();
+    assert_eq!(attributes.slo_type, Some(SloType::Metric));
+    assert_eq!(
+        attributes.overall_status.as_ref().unwrap()[0].error_budget_remaining,
+        Some(62.1)
+    );
+}
+
+#[tokio::test]
+async fn can_delete_slos() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": {"ok": ["a1b2"]},
+            "errors": {"c3d4": "slo c3d4 is used in dashboard 123-abc-456"}
+        }
+    "#;
+    let response = ResponseTemplate::new(409).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v1/slo/can_delete"))
+        .and(query_param("ids", "a1b2,c3d4"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .can_delete_slos(CheckCanDeleteSloRequest {
+            ids: vec!["a1b2".to_string(), "c3d4".to_string()],
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.data.unwrap().ok, Some(vec!["a1b2".to_string()]));
+    assert!(res.errors.unwrap().contains_key("c3d4"));
+}
@@ -0,0 +1,220 @@
+use datadog_api_client::{
+    client::ClientBuilder,
+    models::{
+        client::Request,
+        logs::{
+            LogsAggregateBucketValue, LogsAggregateSortType, LogsAggregationFunction,
+            LogsComputeType, LogsGroupByValue, LogsSortOrder,
+        },
+        search::SearchSort,
+        spans::{
+            ListSpansGetRequest, SpansAggregateRequestAttributes, SpansAggregateSort, SpansCompute,
+            SpansGroupBy, SpansListRequestAttributes, SpansListRequestPage, SpansQueryFilter,
+        },
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const LIST_SPANS_BODY: &str = r#"
+    {
+        "data": [
+            {
+                "attributes": {
+                    "attributes": {"duration": 1234567},
+                    "env": "prod",
+                    "resource_name": "GET /checkout",
+                    "service": "storefront",
+                    "span_id": "6018312389329120435",
+                    "start_timestamp": "2024-02-01T10:00:00.000Z",
+                    "tags": ["env:prod"],
+                    "trace_id": "4903637426393425930",
+                    "type": "web"
+                },
+                "id": "AAAAAYkRJ9SkqKGXGgAAAABBWWtSSjliOQ",
+                "type": "spans"
+            }
+        ],
+        "links": {"next": "https://app.datadoghq.com/api/v2/spans/events?page[cursor]=eyJhZnRlciI6IkFBQUEifQ"},
+        "meta": {"elapsed": 48, "page": {"after": "eyJhZnRlciI6IkFBQUEifQ"}, "status": "done"}
+    }
+"#;
+
+#[tokio::test]
+async fn list_spans_get() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(LIST_SPANS_BODY, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v2/spans/events"))
+        .and(query_param("filter[query]", "service:storefront"))
+        .and(query_param("page[cursor]", "eyJhZnRlciI6IkFBQUEifQ"))
+        .and(query_param("page[limit]", "50"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .list_spans_get(ListSpansGetRequest {
+            filter_query: Some("service:storefront".to_string()),
+            page_cursor: Some("eyJhZnRlciI6IkFBQUEifQ".to_string()),
+            page_limit: Some(50),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let attributes = res.data[0].attributes.as_ref().unwrap();
+    assert_eq!(attributes.resource_name.as_deref(), Some("GET /checkout"));
+    assert_eq!(res.next_cursor(), Some("eyJhZnRlciI6IkFBQUEifQ"));
+}
+
+#[tokio::test]
+async fn list_spans() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(LIST_SPANS_BODY, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/spans/events/search"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {
+                    "filter": {"from": "now-15m", "query": "service:storefront", "to": "now"},
+                    "page": {"limit": 25},
+                    "sort": "-timestamp"
+                },
+                "type": "search_request"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .list_spans(Request {
+            data: SpansListRequestAttributes {
+                filter: Some(SpansQueryFilter {
+                    from: Some("now-15m".to_string()),
+                    query: Some("service:storefront".to_string()),
+                    to: Some("now".to_string()),
+                }),
+                page: Some(SpansListRequestPage {
+                    cursor: None,
+                    limit: Some(25),
+                }),
+                sort: Some(SearchSort::NegativeTimestamp),
+                ..Default::default()
+            }
+            .into(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.data.len(), 1);
+}
+
+#[tokio::test]
+async fn aggregate_spans_latency_percentiles() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": [
+                {
+                    "attributes": {
+                        "by": {"resource_name": "GET /checkout"},
+                        "computes": {"c0": 1250000000.0}
+                    },
+                    "id": "NhBRKnXWQASbbqBbHFlJOA",
+                    "type": "bucket"
+                },
+                {
+                    "attributes": {
+                        "by": {"resource_name": "GET /cart"},
+                        "computes": {"c0": 350000000.0}
+                    },
+                    "id": "fHBtDR9BS7iykSvjRpSAXA",
+                    "type": "bucket"
+                }
+            ],
+            "meta": {"elapsed": 112, "request_id": "cXdxaFBRNnNMVkZxRGxDfGVOUmQzQ0xENWdOcFlsV2E", "status": "done"}
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/spans/analytics/aggregate"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {
+                    "compute": [{"aggregation": "pc99", "metric": "@duration", "type": "total"}],
+                    "filter": {"from": "now-1h", "query": "service:storefront", "to": "now"},
+                    "group_by": [
+                        {
+                            "facet": "resource_name",
+                            "limit": 25,
+                            "sort": {"aggregation": "pc99", "metric": "@duration", "order": "desc", "type": "measure"}
+                        }
+                    ]
+                },
+                "type": "aggregate_request"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .aggregate_spans(Request {
+            data: SpansAggregateRequestAttributes {
+                compute: Some(vec![SpansCompute {
+                    aggregation: LogsAggregationFunction::Pc99,
+                    interval: None,
+                    metric: Some("@duration".to_string()),
+                    typ: Some(LogsComputeType::Total),
+                }]),
+                filter: Some(SpansQueryFilter {
+                    from: Some("now-1h".to_string()),
+                    query: Some("service:storefront".to_string()),
+                    to: Some("now".to_string()),
+                }),
+                group_by: Some(vec![SpansGroupBy {
+                    facet: "resource_name".to_string(),
+                    histogram: None,
+                    limit: Some(25),
+                    missing: None,
+                    sort: Some(SpansAggregateSort {
+                        aggregation: Some(LogsAggregationFunction::Pc99),
+                        metric: Some("@duration".to_string()),
+                        order: Some(LogsSortOrder::Desc),
+                        typ: Some(LogsAggregateSortType::Measure),
+                    }),
+                    total: None,
+                }]),
+                options: None,
+            }
+            .into(),
+        })
+        .await
+        .unwrap();
+    let buckets = res.data.unwrap();
+    assert_eq!(buckets.len(), 2);
+    let attributes = buckets[0].attributes.as_ref().unwrap();
+    assert_eq!(
+        attributes.by["resource_name"],
+        LogsGroupByValue::String("GET /checkout".to_string())
+    );
+    assert!(matches!(
+        attributes.computes["c0"],
+        LogsAggregateBucketValue::Number(latency) if latency == 1250000000.0
+    ));
+}
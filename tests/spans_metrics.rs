@@ -0,0 +1,138 @@
+use datadog_api_client::{
+    client::ClientBuilder,
+    models::{
+        generated_metrics::{
+            GeneratedMetricAggregationType, GeneratedMetricCompute, GeneratedMetricFilter,
+        },
+        spans_metrics::{
+            CreateSpansMetricRequest, DeleteSpansMetricRequest, GetSpansMetricRequest,
+            SpansMetricCreateAttributes, SpansMetricCreateData, SpansMetricType,
+        },
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+static METRIC_BODY: &str = r#"
+    {
+        "data": {
+            "attributes": {
+                "compute": {
+                    "aggregation_type": "count"
+                },
+                "filter": {
+                    "query": "service:checkout @http.status_code:500"
+                },
+                "group_by": [
+                    {
+                        "path": "resource_name",
+                        "tag_name": "resource_name"
+                    }
+                ]
+            },
+            "id": "spans.checkout.errors",
+            "type": "spans_metrics"
+        }
+    }
+"#;
+
+#[tokio::test]
+async fn create_spans_metric() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(METRIC_BODY, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/apm/config/metrics"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {
+                    "compute": {
+                        "aggregation_type": "count"
+                    },
+                    "filter": {
+                        "query": "service:checkout @http.status_code:500"
+                    }
+                },
+                "id": "spans.checkout.errors",
+                "type": "spans_metrics"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .create_spans_metric(CreateSpansMetricRequest {
+            data: SpansMetricCreateData {
+                attributes: SpansMetricCreateAttributes {
+                    compute: GeneratedMetricCompute {
+                        aggregation_type: GeneratedMetricAggregationType::Count,
+                        include_percentiles: None,
+                        path: None,
+                    },
+                    filter: Some(GeneratedMetricFilter {
+                        query: Some("service:checkout @http.status_code:500".to_string()),
+                    }),
+                    group_by: None,
+                },
+                id: "spans.checkout.errors".to_string(),
+                typ: SpansMetricType::SpansMetrics,
+            },
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.data.id, "spans.checkout.errors");
+}
+
+#[tokio::test]
+async fn get_spans_metric() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(METRIC_BODY, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v2/apm/config/metrics/spans.checkout.errors"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .get_spans_metric(GetSpansMetricRequest {
+            metric_id: "spans.checkout.errors".to_string(),
+        })
+        .await
+        .unwrap();
+    let group_by = res.data.attributes.unwrap().group_by.unwrap();
+    assert_eq!(group_by[0].path, "resource_name");
+    assert_eq!(group_by[0].tag_name.as_deref(), Some("resource_name"));
+}
+
+#[tokio::test]
+async fn delete_spans_metric() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/apm/config/metrics/spans.checkout.errors"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .delete_spans_metric(DeleteSpansMetricRequest {
+            metric_id: "spans.checkout.errors".to_string(),
+        })
+        .await
+        .unwrap();
+}
@@ -0,0 +1,565 @@
+use std::time::Duration;
+
+use datadog_api_client::{
+    client::ClientBuilder,
+    models::synthetics::{
+        DeleteSyntheticsTestsRequest, ListSyntheticsTestResultsRequest, ListSyntheticsTestsRequest,
+        SyntheticsApiTest, SyntheticsApiTestConfig, SyntheticsApiTestType, SyntheticsAssertion,
+        SyntheticsAssertionJsonPathOperator, SyntheticsAssertionJsonPathTarget,
+        SyntheticsAssertionJsonPathTargetTarget, SyntheticsAssertionOperator,
+        SyntheticsAssertionTarget, SyntheticsAssertionTargetValue, SyntheticsAssertionType,
+        SyntheticsBasicAuth, SyntheticsCiTest, SyntheticsCiTestMetadata,
+        SyntheticsCiTestMetadataGit, SyntheticsGlobalVariable, SyntheticsGlobalVariableValue,
+        SyntheticsPrivateLocation, SyntheticsStatus, SyntheticsStepType, SyntheticsTestDetails,
+        SyntheticsTestDetailsSubType, SyntheticsTestOptions, SyntheticsTestPauseStatus,
+        SyntheticsTestRequest, TriggerCiSyntheticsTestsRequest, UpdateSyntheticsTestStatusRequest,
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn create_synthetics_api_test() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "config": {
+                "assertions": [
+                    {"operator": "is", "target": 200, "type": "statusCode"},
+                    {
+                        "operator": "validatesJSONPath",
+                        "target": {"jsonPath": "$.status", "operator": "is", "targetValue": "ok"},
+                        "type": "body"
+                    }
+                ],
+                "request": {"method": "GET", "url": "https://example.com/health"}
+            },
+            "creator": {"email": "jane@example.com", "handle": "jane@example.com", "name": "Jane"},
+            "locations": ["aws:eu-central-1"],
+            "message": "Health check failed @team-checkout",
+            "monitor_id": 12345678,
+            "name": "Health check",
+            "options": {"tick_every": 60, "rumSettings": {"isEnabled": false}},
+            "public_id": "abc-def-ghi",
+            "status": "live",
+            "subtype": "http",
+            "tags": ["team:checkout"],
+            "type": "api"
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v1/synthetics/tests/api"))
+        .and(body_json(serde_json::json!({
+            "config": {
+                "assertions": [
+                    {"operator": "is", "target": 200.0, "type": "statusCode"},
+                    {
+                        "operator": "validatesJSONPath",
+                        "target": {"jsonPath": "$.status", "operator": "is", "targetValue": "ok"},
+                        "type": "body"
+                    }
+                ],
+                "request": {"method": "GET", "url": "https://example.com/health"}
+            },
+            "locations": ["aws:eu-central-1"],
+            "message": "Health check failed @team-checkout",
+            "name": "Health check",
+            "options": {"tick_every": 60},
+            "subtype": "http",
+            "tags": ["team:checkout"],
+            "type": "api"
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .create_synthetics_api_test(SyntheticsApiTest {
+            config: SyntheticsApiTestConfig {
+                assertions: vec![
+                    SyntheticsAssertion::Target(SyntheticsAssertionTarget {
+                        operator: SyntheticsAssertionOperator::Is,
+                        property: None,
+                        target: Some(SyntheticsAssertionTargetValue::Number(200.0)),
+                        timings_scope: None,
+                        typ: SyntheticsAssertionType::StatusCode,
+                    }),
+                    SyntheticsAssertion::JsonPath(SyntheticsAssertionJsonPathTarget {
+                        operator: SyntheticsAssertionJsonPathOperator::ValidatesJsonPath,
+                        property: None,
+                        target: SyntheticsAssertionJsonPathTargetTarget {
+                            json_path: "$.status".to_string(),
+                            operator: SyntheticsAssertionOperator::Is,
+                            target_value: Some(SyntheticsAssertionTargetValue::String(
+                                "ok".to_string(),
+                            )),
+                        },
+                        typ: SyntheticsAssertionType::Body,
+                    }),
+                ],
+                request: Some(SyntheticsTestRequest {
+                    method: Some("GET".to_string()),
+                    url: Some("https://example.com/health".to_string()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            creator: None,
+            locations: vec!["aws:eu-central-1".to_string()],
+            message: "Health check failed @team-checkout".to_string(),
+            monitor_id: None,
+            name: "Health check".to_string(),
+            options: SyntheticsTestOptions {
+                tick_every: Some(60),
+                ..Default::default()
+            },
+            public_id: None,
+            status: None,
+            subtype: Some(SyntheticsTestDetailsSubType::Http),
+            tags: Some(vec!["team:checkout".to_string()]),
+            typ: SyntheticsApiTestType::Api,
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.public_id.as_deref(), Some("abc-def-ghi"));
+    assert_eq!(res.status, Some(SyntheticsTestPauseStatus::Live));
+    assert!(res
+        .options
+        .additional_properties
+        .contains_key("rumSettings"));
+    assert!(matches!(
+        res.config.assertions[1],
+        SyntheticsAssertion::JsonPath(_)
+    ));
+}
+
+#[tokio::test]
+async fn list_synthetics_tests() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "tests": [
+                {
+                    "config": {
+                        "assertions": [{"operator": "lessThan", "target": 1000, "type": "responseTime"}],
+                        "request": {"host": "example.com", "port": 443}
+                    },
+                    "locations": ["aws:us-east-2"],
+                    "message": "",
+                    "name": "TCP check",
+                    "options": {"tick_every": 300},
+                    "public_id": "tcp-123-abc",
+                    "subtype": "tcp",
+                    "type": "api"
+                },
+                {
+                    "config": {
+                        "assertions": [],
+                        "request": {"method": "GET", "url": "https://example.com"}
+                    },
+                    "locations": ["aws:us-east-2"],
+                    "message": "",
+                    "name": "Login journey",
+                    "options": {"device_ids": ["chrome.laptop_large"], "tick_every": 900},
+                    "public_id": "brw-456-def",
+                    "status": "paused",
+                    "steps": [{"name": "Click login", "params": {"element": "..."}, "type": "click"}],
+                    "type": "browser"
+                },
+                {
+                    "config": {},
+                    "locations": ["aws:us-east-2"],
+                    "message": "",
+                    "name": "Mobile app",
+                    "options": {},
+                    "public_id": "mob-789-ghi",
+                    "type": "mobile"
+                }
+            ]
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v1/synthetics/tests"))
+        .and(query_param("page_size", "3"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .list_synthetics_tests(ListSyntheticsTestsRequest {
+            page_size: Some(3),
+            page_number: None,
+        })
+        .await
+        .unwrap();
+    let tests = res.tests.unwrap();
+    match &tests[0] {
+        SyntheticsTestDetails::Api(test) => {
+            assert_eq!(test.subtype, Some(SyntheticsTestDetailsSubType::Tcp))
+        }
+        test => panic!("unexpected test: {:?}", test),
+    }
+    match &tests[1] {
+        SyntheticsTestDetails::Browser(test) => {
+            assert_eq!(test.status, Some(SyntheticsTestPauseStatus::Paused));
+            assert_eq!(
+                test.steps.as_ref().unwrap()[0].typ,
+                SyntheticsStepType::Click
+            );
+        }
+        test => panic!("unexpected test: {:?}", test),
+    }
+    assert!(matches!(tests[2], SyntheticsTestDetails::Other(_)));
+}
+
+#[tokio::test]
+async fn update_synthetics_test_status() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw("true", "application/json");
+    Mock::given(method("PUT"))
+        .and(path("/api/v1/synthetics/tests/abc-def-ghi/status"))
+        .and(body_json(serde_json::json!({"new_status": "paused"})))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .update_synthetics_test_status(UpdateSyntheticsTestStatusRequest {
+            public_id: "abc-def-ghi".to_string(),
+            new_status: SyntheticsTestPauseStatus::Paused,
+        })
+        .await
+        .unwrap();
+    assert!(res);
+}
+
+#[tokio::test]
+async fn delete_synthetics_tests() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {"deleted_tests": [{"deleted_at": "2024-02-01T10:00:00.000000+00:00", "public_id": "abc-def-ghi"}]}
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v1/synthetics/tests/delete"))
+        .and(body_json(
+            serde_json::json!({"public_ids": ["abc-def-ghi"]}),
+        ))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .delete_synthetics_tests(DeleteSyntheticsTestsRequest {
+            force_delete_dependencies: None,
+            public_ids: vec!["abc-def-ghi".to_string()],
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        res.deleted_tests.unwrap()[0].public_id.as_deref(),
+        Some("abc-def-ghi")
+    );
+}
+
+#[tokio::test]
+async fn trigger_ci_synthetics_tests_and_wait_for_batch() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "batch_id": "batch-123",
+            "locations": [{"display_name": "Frankfurt (AWS)", "id": 30, "name": "aws:eu-central-1"}],
+            "results": [{"location": 30, "public_id": "abc-def-ghi", "result_id": "9876543210"}],
+            "triggered_check_ids": ["abc-def-ghi"]
+        }
+    "#;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/synthetics/tests/trigger/ci"))
+        .and(body_json(serde_json::json!({
+            "tests": [
+                {
+                    "metadata": {"git": {"branch": "main", "commitSha": "0123abcd"}},
+                    "public_id": "abc-def-ghi",
+                    "startUrl": "https://staging.example.com"
+                }
+            ]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/json"))
+        .mount(&mock_server)
+        .await;
+    let in_progress = r#"{"data": {"results": [], "status": "in_progress"}}"#;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/synthetics/ci/batch/batch-123"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(in_progress, "application/json"))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let passed = r#"
+        {
+            "data": {
+                "results": [
+                    {
+                        "duration": 1234.5,
+                        "execution_rule": "blocking",
+                        "location": "aws:eu-central-1",
+                        "result_id": "9876543210",
+                        "retries": 0,
+                        "status": "passed",
+                        "test_name": "Health check",
+                        "test_public_id": "abc-def-ghi",
+                        "test_type": "api"
+                    }
+                ],
+                "status": "passed"
+            }
+        }
+    "#;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/synthetics/ci/batch/batch-123"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(passed, "application/json"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let triggered = client
+        .trigger_ci_synthetics_tests(TriggerCiSyntheticsTestsRequest {
+            tests: vec![SyntheticsCiTest {
+                metadata: Some(SyntheticsCiTestMetadata {
+                    ci: None,
+                    git: Some(SyntheticsCiTestMetadataGit {
+                        branch: Some("main".to_string()),
+                        commit_sha: Some("0123abcd".to_string()),
+                    }),
+                }),
+                public_id: "abc-def-ghi".to_string(),
+                start_url: Some("https://staging.example.com".to_string()),
+                ..Default::default()
+            }],
+        })
+        .await
+        .unwrap();
+    let batch = client
+        .wait_for_synthetics_ci_batch(
+            &triggered.batch_id.unwrap(),
+            Duration::from_millis(10),
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+    let data = batch.data.unwrap();
+    assert_eq!(data.status, Some(SyntheticsStatus::Passed));
+    assert_eq!(
+        data.results.unwrap()[0].result_id.as_deref(),
+        Some("9876543210")
+    );
+}
+
+#[tokio::test]
+async fn list_synthetics_api_test_results() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "last_timestamp_fetched": 1706781600000,
+            "public_id": "abc-def-ghi",
+            "results": [
+                {
+                    "check_time": 1706781600000.0,
+                    "probe_dc": "aws:eu-central-1",
+                    "result": {
+                        "passed": false,
+                        "timings": {"dns": 1.5, "total": 120.25},
+                        "failure": {"code": "TIMEOUT", "message": "Request timed out"},
+                        "eventType": "finished"
+                    },
+                    "result_id": "9876543210",
+                    "status": 2
+                }
+            ]
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v1/synthetics/tests/abc-def-ghi/results"))
+        .and(query_param("from_ts", "1706778000000"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .list_synthetics_api_test_results(ListSyntheticsTestResultsRequest {
+            public_id: "abc-def-ghi".to_string(),
+            from_ts: Some(1706778000000),
+            to_ts: None,
+        })
+        .await
+        .unwrap();
+    let result = res.results.unwrap().remove(0).result.unwrap();
+    assert_eq!(result.passed, Some(false));
+    assert_eq!(result.timings.unwrap()["total"], 120.25);
+    assert_eq!(result.failure.unwrap().code.as_deref(), Some("TIMEOUT"));
+    assert!(result.additional_properties.contains_key("eventType"));
+}
+
+#[tokio::test]
+async fn create_synthetics_global_variable() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "description": "Staging API token",
+            "id": "f1e2d3c4-0000-0000-0000-000000000000",
+            "name": "STAGING_TOKEN",
+            "tags": ["env:staging"],
+            "value": {"secure": true}
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v1/synthetics/variables"))
+        .and(body_json(serde_json::json!({
+            "description": "Staging API token",
+            "name": "STAGING_TOKEN",
+            "tags": ["env:staging"],
+            "value": {"secure": true, "value": "s3cr3t"}
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .create_synthetics_global_variable(SyntheticsGlobalVariable {
+            attributes: None,
+            description: "Staging API token".to_string(),
+            id: None,
+            is_fido: None,
+            is_totp: None,
+            name: "STAGING_TOKEN".to_string(),
+            parse_test_options: None,
+            parse_test_public_id: None,
+            tags: vec!["env:staging".to_string()],
+            value: SyntheticsGlobalVariableValue {
+                options: None,
+                secure: Some(true),
+                value: Some("s3cr3t".to_string()),
+            },
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        res.id.as_deref(),
+        Some("f1e2d3c4-0000-0000-0000-000000000000")
+    );
+    assert!(res.value.value.is_none());
+}
+
+#[tokio::test]
+async fn create_synthetics_private_location() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "config": {"site": "datadoghq.com"},
+            "private_location": {
+                "description": "Office network",
+                "id": "pl:office-0123abcd",
+                "name": "office",
+                "secrets": {
+                    "authentication": {"id": "access-key", "key": "secret-key"},
+                    "config_decryption": {"key": "private-key"}
+                },
+                "tags": ["site:office"]
+            },
+            "result_encryption": {"id": "fingerprint", "key": "public-key"}
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v1/synthetics/private-locations"))
+        .and(body_json(serde_json::json!({
+            "description": "Office network",
+            "name": "office",
+            "tags": ["site:office"]
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .create_synthetics_private_location(SyntheticsPrivateLocation {
+            description: "Office network".to_string(),
+            id: None,
+            metadata: None,
+            name: "office".to_string(),
+            secrets: None,
+            tags: vec!["site:office".to_string()],
+        })
+        .await
+        .unwrap();
+    let location = res.private_location.unwrap();
+    assert_eq!(location.id.as_deref(), Some("pl:office-0123abcd"));
+    let debug = format!("{:?}", location);
+    assert!(!debug.contains("secret-key") && !debug.contains("private-key"));
+
+    let secrets = location.secrets.unwrap();
+    let authentication = secrets.authentication.unwrap();
+    assert_eq!(authentication.id.as_deref(), Some("access-key"));
+    assert_eq!(authentication.key.unwrap().expose_secret(), "secret-key");
+    assert_eq!(
+        secrets
+            .config_decryption
+            .unwrap()
+            .key
+            .unwrap()
+            .expose_secret(),
+        "private-key"
+    );
+}
+
+#[test]
+fn synthetics_basic_auth_redacts_password() {
+    let auth = SyntheticsBasicAuth::Web {
+        username: "datadog".to_string(),
+        password: "hunter2".into(),
+    };
+    assert!(!format!("{:?}", auth).contains("hunter2"));
+    assert_eq!(
+        serde_json::to_value(&auth).unwrap(),
+        serde_json::json!({"type": "web", "username": "datadog", "password": "hunter2"})
+    );
+}
@@ -0,0 +1,156 @@
+use datadog_api_client::{
+    client::ClientBuilder,
+    models::tags::{HostTags, HostTagsRequest, ListHostTagsRequest, UpdateHostTagsRequest},
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn list_host_tags() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "tags": {
+                "env:prod": ["web-1.example.com", "web-2.example.com"],
+                "role:database": ["db-1.example.com"]
+            }
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v1/tags/hosts"))
+        .and(query_param("source", "chef"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .list_host_tags(ListHostTagsRequest {
+            source: Some("chef".to_string()),
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.tags["env:prod"].len(), 2);
+    assert_eq!(res.tags["role:database"], vec!["db-1.example.com"]);
+}
+
+#[tokio::test]
+async fn get_host_tags() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"{"host": "web-1.example.com", "tags": ["env:prod", "role:web"]}"#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v1/tags/hosts/web-1.example.com"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .get_host_tags(HostTagsRequest {
+            host_name: "web-1.example.com".to_string(),
+            source: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.tags, vec!["env:prod", "role:web"]);
+}
+
+#[tokio::test]
+async fn create_host_tags() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body =
+        r#"{"host": "web-1.example.com", "tags": ["env:prod", "role:web", "team:platform"]}"#;
+    let response = ResponseTemplate::new(201).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v1/tags/hosts/web-1.example.com"))
+        .and(query_param("source", "users"))
+        .and(body_json(serde_json::json!({"tags": ["team:platform"]})))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .create_host_tags(UpdateHostTagsRequest {
+            host_name: "web-1.example.com".to_string(),
+            source: Some("users".to_string()),
+            tags: HostTags {
+                host: None,
+                tags: vec!["team:platform".to_string()],
+            },
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.tags.len(), 3);
+}
+
+#[tokio::test]
+async fn update_host_tags() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"{"host": "web-1.example.com", "tags": ["team:storage"]}"#;
+    let response = ResponseTemplate::new(201).set_body_raw(body, "application/json");
+    Mock::given(method("PUT"))
+        .and(path("/api/v1/tags/hosts/web-1.example.com"))
+        .and(body_json(serde_json::json!({
+            "host": "web-1.example.com",
+            "tags": ["team:storage"]
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .update_host_tags(UpdateHostTagsRequest {
+            host_name: "web-1.example.com".to_string(),
+            source: None,
+            tags: HostTags {
+                host: Some("web-1.example.com".to_string()),
+                tags: vec!["team:storage".to_string()],
+            },
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.tags, vec!["team:storage"]);
+}
+
+#[tokio::test]
+async fn delete_host_tags() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v1/tags/hosts/web-1.example.com"))
+        .and(query_param("source", "users"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .delete_host_tags(HostTagsRequest {
+            host_name: "web-1.example.com".to_string(),
+            source: Some("users".to_string()),
+        })
+        .await
+        .unwrap();
+}
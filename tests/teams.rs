@@ -0,0 +1,430 @@
+use datadog_api_client::{
+    client::ClientBuilder,
+    models::{
+        client::{Request, Response},
+        teams::{
+            CreateTeamLinkRequest, CreateTeamMembershipRequest, CreateTeamRequest,
+            DeleteTeamMembershipRequest, ListTeamMembershipsRequest, ListTeamsRequest,
+            TeamCreateAttributes, TeamCreateData, TeamIncluded, TeamLinkAttributes, TeamLinkData,
+            TeamLinkType, TeamPermissionSettingAction, TeamPermissionSettingType,
+            TeamPermissionSettingUpdateAttributes, TeamPermissionSettingUpdateData,
+            TeamPermissionSettingValue, TeamType, TeamsSort, UpdateTeamMembershipRequest,
+            UpdateTeamPermissionSettingRequest, UserTeamAttributes, UserTeamCreateData,
+            UserTeamRelationships, UserTeamRole, UserTeamType, UserTeamUpdateData, UserTeamsSort,
+        },
+        users::{RelationshipToUserData, UsersType},
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+static MEMBERSHIP_BODY: &str = r#"
+    {
+        "data": {
+            "attributes": {"role": "admin"},
+            "id": "TeamMembership-aeadc05e-98a8-11ec-ac2c-da7ad0900001-38835",
+            "relationships": {
+                "user": {"data": {"id": "b8626d7e-cedd-11eb-abf5-da7ad0900001", "type": "users"}}
+            },
+            "type": "team_memberships"
+        }
+    }
+"#;
+
+#[tokio::test]
+async fn list_teams() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": [
+                {
+                    "attributes": {
+                        "handle": "platform",
+                        "link_count": 1,
+                        "name": "Platform",
+                        "summary": "Owns the platform.",
+                        "user_count": 12
+                    },
+                    "id": "aeadc05e-98a8-11ec-ac2c-da7ad0900001",
+                    "relationships": {
+                        "team_links": {
+                            "data": [{"id": "b8626d7e-cedd-11eb-abf5-da7ad0900002", "type": "team_links"}],
+                            "links": {"related": "/api/v2/team/aeadc05e-98a8-11ec-ac2c-da7ad0900001/links"}
+                        }
+                    },
+                    "type": "team"
+                }
+            ],
+            "included": [
+                {
+                    "attributes": {"label": "Runbook", "position": 0, "team_id": "aeadc05e-98a8-11ec-ac2c-da7ad0900001", "url": "https://example.com/runbook"},
+                    "id": "b8626d7e-cedd-11eb-abf5-da7ad0900002",
+                    "type": "team_links"
+                }
+            ],
+            "links": {"self": "https://api.datadoghq.com/api/v2/team?page[number]=1", "next": null},
+            "meta": {"pagination": {"limit": 10, "offset": 0, "total": 1, "type": "offset_limit"}}
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v2/team"))
+        .and(query_param("page[number]", "1"))
+        .and(query_param("sort", "-user_count"))
+        .and(query_param("include", "team_links"))
+        .and(query_param("filter[keyword]", "platform"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .list_teams(ListTeamsRequest {
+            page_number: Some(1),
+            sort: Some(TeamsSort::UserCountDescending),
+            include: Some("team_links".to_string()),
+            filter_keyword: Some("platform".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.data[0].attributes.user_count, Some(12));
+    match &res.included.unwrap()[0] {
+        TeamIncluded::TeamLink(link) => assert_eq!(link.attributes.label, "Runbook"),
+        included => panic!("unexpected included item: {:?}", included),
+    }
+    assert_eq!(res.meta.unwrap().pagination.unwrap().total, Some(1));
+}
+
+#[tokio::test]
+async fn create_team() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": {
+                "attributes": {"handle": "platform", "name": "Platform", "user_count": 0},
+                "id": "aeadc05e-98a8-11ec-ac2c-da7ad0900001",
+                "type": "team"
+            }
+        }
+    "#;
+    let response = ResponseTemplate::new(201).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/team"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {"handle": "platform", "name": "Platform", "description": "Platform team"},
+                "type": "team"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .create_team(CreateTeamRequest {
+            data: TeamCreateData {
+                attributes: TeamCreateAttributes {
+                    handle: "platform".to_string(),
+                    name: "Platform".to_string(),
+                    description: Some("Platform team".to_string()),
+                    ..Default::default()
+                },
+                relationships: None,
+                typ: TeamType::Team,
+            },
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.data.id, "aeadc05e-98a8-11ec-ac2c-da7ad0900001");
+}
+
+#[tokio::test]
+async fn list_team_memberships() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": [
+                {
+                    "attributes": {"role": null},
+                    "id": "TeamMembership-aeadc05e-98a8-11ec-ac2c-da7ad0900001-38835",
+                    "relationships": {
+                        "user": {"data": {"id": "b8626d7e-cedd-11eb-abf5-da7ad0900001", "type": "users"}}
+                    },
+                    "type": "team_memberships"
+                }
+            ],
+            "included": [
+                {
+                    "attributes": {"email": "jane@example.com", "name": "Jane"},
+                    "id": "b8626d7e-cedd-11eb-abf5-da7ad0900001",
+                    "type": "users"
+                }
+            ],
+            "meta": {"pagination": {"total": 1}}
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path(
+            "/api/v2/team/aeadc05e-98a8-11ec-ac2c-da7ad0900001/memberships",
+        ))
+        .and(query_param("sort", "-name"))
+        .and(query_param("page[size]", "50"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .list_team_memberships(ListTeamMembershipsRequest {
+            team_id: "aeadc05e-98a8-11ec-ac2c-da7ad0900001".to_string(),
+            page_size: Some(50),
+            page_number: None,
+            sort: Some(UserTeamsSort::NameDescending),
+            filter_keyword: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.data[0].attributes.as_ref().unwrap().role, None);
+    match &res.included.unwrap()[0] {
+        TeamIncluded::User(user) => assert_eq!(
+            user.attributes.as_ref().unwrap().email.as_deref(),
+            Some("jane@example.com")
+        ),
+        included => panic!("unexpected included item: {:?}", included),
+    }
+}
+
+#[tokio::test]
+async fn create_team_membership() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(MEMBERSHIP_BODY, "application/json");
+    Mock::given(method("POST"))
+        .and(path(
+            "/api/v2/team/aeadc05e-98a8-11ec-ac2c-da7ad0900001/memberships",
+        ))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {"role": "admin"},
+                "relationships": {
+                    "user": {"data": {"id": "b8626d7e-cedd-11eb-abf5-da7ad0900001", "type": "users"}}
+                },
+                "type": "team_memberships"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .create_team_membership(CreateTeamMembershipRequest {
+            team_id: "aeadc05e-98a8-11ec-ac2c-da7ad0900001".to_string(),
+            membership: Request {
+                data: UserTeamCreateData {
+                    attributes: Some(UserTeamAttributes {
+                        role: Some(UserTeamRole::Admin),
+                        ..Default::default()
+                    }),
+                    relationships: UserTeamRelationships {
+                        team: None,
+                        user: Some(Response {
+                            data: RelationshipToUserData {
+                                id: "b8626d7e-cedd-11eb-abf5-da7ad0900001".to_string(),
+                                typ: UsersType::Users,
+                            },
+                        }),
+                    },
+                    typ: UserTeamType::TeamMemberships,
+                },
+            },
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.data.attributes.unwrap().role, Some(UserTeamRole::Admin));
+}
+
+#[tokio::test]
+async fn update_team_membership() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(MEMBERSHIP_BODY, "application/json");
+    Mock::given(method("PATCH"))
+        .and(path(
+            "/api/v2/team/aeadc05e-98a8-11ec-ac2c-da7ad0900001/memberships/b8626d7e-cedd-11eb-abf5-da7ad0900001",
+        ))
+        .and(body_json(serde_json::json!({
+            "data": {"attributes": {"role": null}, "type": "team_memberships"}
+        })))
+        .respond_with(response)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .update_team_membership(UpdateTeamMembershipRequest {
+            team_id: "aeadc05e-98a8-11ec-ac2c-da7ad0900001".to_string(),
+            user_id: "b8626d7e-cedd-11eb-abf5-da7ad0900001".to_string(),
+            membership: Request {
+                data: UserTeamUpdateData {
+                    attributes: UserTeamAttributes::default(),
+                    typ: UserTeamType::TeamMemberships,
+                },
+            },
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn delete_team_membership() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    Mock::given(method("DELETE"))
+        .and(path(
+            "/api/v2/team/aeadc05e-98a8-11ec-ac2c-da7ad0900001/memberships/b8626d7e-cedd-11eb-abf5-da7ad0900001",
+        ))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .delete_team_membership(DeleteTeamMembershipRequest {
+            team_id: "aeadc05e-98a8-11ec-ac2c-da7ad0900001".to_string(),
+            user_id: "b8626d7e-cedd-11eb-abf5-da7ad0900001".to_string(),
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn create_team_link() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": {
+                "attributes": {"label": "Runbook", "position": 0, "team_id": "aeadc05e-98a8-11ec-ac2c-da7ad0900001", "url": "https://example.com/runbook"},
+                "id": "b8626d7e-cedd-11eb-abf5-da7ad0900002",
+                "type": "team_links"
+            }
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path(
+            "/api/v2/team/aeadc05e-98a8-11ec-ac2c-da7ad0900001/links",
+        ))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {"label": "Runbook", "url": "https://example.com/runbook"},
+                "type": "team_links"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .create_team_link(CreateTeamLinkRequest {
+            team_id: "aeadc05e-98a8-11ec-ac2c-da7ad0900001".to_string(),
+            link: Request {
+                data: TeamLinkData {
+                    attributes: TeamLinkAttributes {
+                        label: "Runbook".to_string(),
+                        position: None,
+                        team_id: None,
+                        url: "https://example.com/runbook".to_string(),
+                    },
+                    typ: TeamLinkType::TeamLinks,
+                },
+            },
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        res.data.attributes.team_id.as_deref(),
+        Some("aeadc05e-98a8-11ec-ac2c-da7ad0900001")
+    );
+}
+
+#[tokio::test]
+async fn update_team_permission_setting() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": {
+                "attributes": {
+                    "action": "manage_membership",
+                    "editable": true,
+                    "options": ["admins", "members", "organization"],
+                    "title": "Manage Membership",
+                    "value": "admins"
+                },
+                "id": "TeamPermission-aeadc05e-98a8-11ec-ac2c-da7ad0900001-manage_membership",
+                "type": "team_permission_settings"
+            }
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("PUT"))
+        .and(path(
+            "/api/v2/team/aeadc05e-98a8-11ec-ac2c-da7ad0900001/permission-settings/manage_membership",
+        ))
+        .and(body_json(serde_json::json!({
+            "data": {"attributes": {"value": "admins"}, "type": "team_permission_settings"}
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .update_team_permission_setting(UpdateTeamPermissionSettingRequest {
+            team_id: "aeadc05e-98a8-11ec-ac2c-da7ad0900001".to_string(),
+            action: TeamPermissionSettingAction::ManageMembership,
+            setting: Request {
+                data: TeamPermissionSettingUpdateData {
+                    attributes: TeamPermissionSettingUpdateAttributes {
+                        value: TeamPermissionSettingValue::Admins,
+                    },
+                    typ: TeamPermissionSettingType::TeamPermissionSettings,
+                },
+            },
+        })
+        .await
+        .unwrap();
+    let attributes = res.data.attributes.unwrap();
+    assert_eq!(attributes.options.unwrap().len(), 3);
+    assert_eq!(attributes.value, Some(TeamPermissionSettingValue::Admins));
+}
@@ -0,0 +1,351 @@
+use chrono::{Duration, NaiveDate};
+use datadog_api_client::{
+    client::ClientBuilder,
+    models::usage_metering::{
+        total_hourly_usage, CostView, GetEstimatedCostRequest, GetHourlyUsageRequest,
+        GetMonthlyUsageAttributionRequest, GetProjectedCostRequest, UsageDateRange,
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{header, method, path, query_param, query_param_is_missing},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn usage_date_range_windows() {
+    let range = UsageDateRange::from_dates(date(2024, 1, 15), date(2024, 3, 5));
+
+    let windows = range.windows(Duration::days(31));
+    assert_eq!(windows.len(), 2);
+    assert_eq!(windows[0].start_hour(), "2024-01-15T00");
+    assert_eq!(windows[0].end_hour(), "2024-02-15T00");
+    assert_eq!(windows[1].start_day(), "2024-02-15");
+    assert_eq!(windows[1].end_day(), "2024-03-05");
+
+    let months = range.month_windows();
+    assert_eq!(months.len(), 3);
+    assert_eq!(months[0].start_day(), "2024-01-15");
+    assert_eq!(months[0].end_day(), "2024-02-01");
+    assert_eq!(months[1].start_month(), "2024-02");
+    assert_eq!(months[2].start_day(), "2024-03-01");
+    assert_eq!(months[2].end_day(), "2024-03-05");
+
+    let empty = UsageDateRange::from_dates(date(2024, 1, 15), date(2024, 1, 15));
+    assert!(empty.windows(Duration::days(31)).is_empty());
+    assert!(empty.month_windows().is_empty());
+
+    assert!(std::panic::catch_unwind(|| range.windows(Duration::zero())).is_err());
+    assert!(std::panic::catch_unwind(|| range.windows(Duration::days(-1))).is_err());
+}
+
+fn hourly_usage_body(timestamp: &str, value: i64, next_record_id: Option<&str>) -> String {
+    let meta = match next_record_id {
+        Some(next_record_id) => {
+            serde_json::json!({"pagination": {"next_record_id": next_record_id}})
+        }
+        None => serde_json::json!({"pagination": {}}),
+    };
+    serde_json::json!({
+        "data": [{
+            "attributes": {
+                "measurements": [
+                    {"usage_type": "infra_host_count", "value": value},
+                    {"usage_type": "infra_container_count", "value": null}
+                ],
+                "org_name": "Acme",
+                "product_family": "infra_hosts",
+                "public_id": "abcdef",
+                "region": "us",
+                "timestamp": timestamp
+            },
+            "id": timestamp,
+            "type": "usage_timeseries"
+        }],
+        "meta": meta
+    })
+    .to_string()
+}
+
+#[tokio::test]
+async fn get_hourly_usage_over_range() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    for (start, end, next_record_id, page_cursor, timestamp, value) in [
+        (
+            "2024-01-01T00",
+            "2024-02-01T00",
+            Some("page-2"),
+            None,
+            "2024-01-01T00:00:00+00:00",
+            10,
+        ),
+        (
+            "2024-01-01T00",
+            "2024-02-01T00",
+            None,
+            Some("page-2"),
+            "2024-01-20T00:00:00+00:00",
+            20,
+        ),
+        (
+            "2024-02-01T00",
+            "2024-02-05T00",
+            None,
+            None,
+            "2024-02-01T00:00:00+00:00",
+            12,
+        ),
+    ] {
+        let response = ResponseTemplate::new(200).set_body_raw(
+            hourly_usage_body(timestamp, value, next_record_id),
+            "application/json",
+        );
+        let mock = Mock::given(method("GET"))
+            .and(path("/api/v2/usage/hourly_usage"))
+            .and(header("accept", "application/json;datetime-format=rfc3339"))
+            .and(query_param("filter[timestamp][start]", start))
+            .and(query_param("filter[timestamp][end]", end))
+            .and(query_param("filter[product_families]", "infra_hosts"));
+        let mock = match page_cursor {
+            Some(page_cursor) => mock.and(query_param("page[next_record_id]", page_cursor)),
+            None => mock.and(query_param_is_missing("page[next_record_id]")),
+        };
+        mock.respond_with(response)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let usages = client
+        .get_hourly_usage_over_range(
+            UsageDateRange::from_dates(date(2024, 1, 1), date(2024, 2, 5)),
+            GetHourlyUsageRequest {
+                filter_product_families: "infra_hosts".to_string(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(usages.len(), 3);
+
+    let totals = total_hourly_usage(&usages);
+    assert_eq!(totals["infra_hosts"]["infra_host_count"], 42);
+    assert!(!totals["infra_hosts"].contains_key("infra_container_count"));
+}
+
+#[tokio::test]
+async fn get_estimated_cost() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": [
+                {
+                    "attributes": {
+                        "account_name": "Acme",
+                        "charges": [
+                            {"charge_type": "committed", "cost": 150.0, "product_name": "infra_host"},
+                            {"charge_type": "on_demand", "cost": 12.5, "product_name": "infra_host"}
+                        ],
+                        "date": "2024-02-01T00:00:00+00:00",
+                        "org_name": "Acme",
+                        "public_id": "abcdef",
+                        "region": "us",
+                        "total_cost": 162.5
+                    },
+                    "id": "abcdef-2024-02",
+                    "type": "cost_by_org"
+                }
+            ]
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v2/usage/estimated_cost"))
+        .and(header("accept", "application/json;datetime-format=rfc3339"))
+        .and(query_param("view", "sub-org"))
+        .and(query_param("start_month", "2024-02"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .get_estimated_cost(GetEstimatedCostRequest {
+            view: Some(CostView::SubOrg),
+            start_month: Some("2024-02".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let attributes = res.data[0].attributes.as_ref().unwrap();
+    assert_eq!(attributes.total_cost, Some(162.5));
+    assert_eq!(attributes.charges.as_ref().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn get_projected_cost() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": [
+                {
+                    "attributes": {
+                        "charges": [{"charge_type": "total", "cost": 1024.0, "product_name": "logs"}],
+                        "date": "2024-02-01T00:00:00+00:00",
+                        "org_name": "Acme",
+                        "projected_total_cost": 1024.0,
+                        "public_id": "abcdef",
+                        "region": "us"
+                    },
+                    "id": "abcdef-2024-02",
+                    "type": "projected_cost"
+                }
+            ]
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v2/usage/projected_cost"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .get_projected_cost(GetProjectedCostRequest::default())
+        .await
+        .unwrap();
+    assert_eq!(
+        res.data[0]
+            .attributes
+            .as_ref()
+            .unwrap()
+            .projected_total_cost,
+        Some(1024.0)
+    );
+}
+
+#[tokio::test]
+async fn get_monthly_usage_attribution() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "metadata": {
+                "aggregates": [{"agg_type": "sum", "field": "infra_host_usage", "value": 120.0}],
+                "pagination": {"next_record_id": "next-page"}
+            },
+            "usage": [
+                {
+                    "month": "2024-01-01T00:00:00+00:00",
+                    "org_name": "Acme",
+                    "public_id": "abcdef",
+                    "region": "us",
+                    "tag_config_source": "Acme:::team",
+                    "tags": {"team": ["platform"], "service": null},
+                    "updated_at": "2024-02-01T10:00:00+00:00",
+                    "values": {"infra_host_usage": 80.0, "infra_host_percentage": 66.67}
+                }
+            ]
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v1/usage/monthly-attribution"))
+        .and(query_param("start_month", "2024-01"))
+        .and(query_param(
+            "fields",
+            "infra_host_usage,infra_host_percentage",
+        ))
+        .and(query_param("tag_breakdown_keys", "team"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .get_monthly_usage_attribution(GetMonthlyUsageAttributionRequest {
+            start_month: "2024-01".to_string(),
+            fields: "infra_host_usage,infra_host_percentage".to_string(),
+            tag_breakdown_keys: Some("team".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.next_record_id(), Some("next-page"));
+    let usage = &res.usage[0];
+    assert_eq!(usage.values["infra_host_usage"], 80.0);
+    assert_eq!(usage.values["infra_host_percentage"], 66.67);
+    let tags = usage.tags.as_ref().unwrap();
+    assert_eq!(tags["team"], Some(vec!["platform".to_string()]));
+    assert_eq!(tags["service"], None);
+}
+
+#[tokio::test]
+async fn get_usage_billable_summary_over_range() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    for (month, usage) in [("2024-01", 40), ("2024-02", 42)] {
+        let body = serde_json::json!({
+            "usage": [{
+                "billing_plan": "Pro",
+                "end_date": format!("{}-28T00:00:00+00:00", month),
+                "num_orgs": 1,
+                "org_name": "Acme",
+                "public_id": "abcdef",
+                "ratio_in_month": 1.0,
+                "start_date": format!("{}-01T00:00:00+00:00", month),
+                "usage": {
+                    "infra_host_top99p": {
+                        "account_billable_usage": usage,
+                        "elapsed_usage_hours": 672,
+                        "org_billable_usage": usage,
+                        "percentage_in_account": 100.0,
+                        "usage_unit": "hosts"
+                    }
+                }
+            }]
+        });
+        let response =
+            ResponseTemplate::new(200).set_body_raw(body.to_string(), "application/json");
+        Mock::given(method("GET"))
+            .and(path("/api/v1/usage/billable-summary"))
+            .and(query_param("month", month))
+            .respond_with(response)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let res = client
+        .get_usage_billable_summary_over_range(UsageDateRange::from_dates(
+            date(2024, 1, 1),
+            date(2024, 3, 1),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(res.len(), 2);
+    assert_eq!(
+        res[1].usage["infra_host_top99p"].org_billable_usage,
+        Some(42)
+    );
+}
@@ -0,0 +1,320 @@
+use datadog_api_client::{
+    client::ClientBuilder,
+    models::{
+        client::{Request, Response},
+        roles::{RelationshipToRoleData, RelationshipToRoles, RolesType},
+        users::{
+            CreateUserRequest, DisableUserRequest, GetInvitationRequest, ListUsersRequest,
+            QuerySortOrder, RelationshipToUserData, SendInvitationsRequest, UpdateUserRequest,
+            UserCreateAttributes, UserCreateData, UserInvitationData, UserInvitationRelationships,
+            UserInvitationsType, UserRelationships, UserResponseIncludedItem, UserUpdateAttributes,
+            UserUpdateData, UsersType,
+        },
+    },
+};
+use url::Url;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+static USER_BODY: &str = r#"
+    {
+        "data": {
+            "attributes": {
+                "created_at": "2024-02-01T10:00:00.000000+00:00",
+                "disabled": false,
+                "email": "jane@example.com",
+                "handle": "jane@example.com",
+                "name": "Jane",
+                "service_account": false,
+                "status": "Pending",
+                "title": "Engineer",
+                "verified": false
+            },
+            "id": "00000000-0000-3333-0000-000000000000",
+            "relationships": {
+                "org": {"data": {"id": "00000000-0000-4444-0000-000000000000", "type": "orgs"}},
+                "roles": {"data": [{"id": "00000000-0000-1111-0000-000000000000", "type": "roles"}]}
+            },
+            "type": "users"
+        },
+        "included": [
+            {
+                "attributes": {"name": "Acme", "public_id": "abcdef"},
+                "id": "00000000-0000-4444-0000-000000000000",
+                "type": "orgs"
+            },
+            {
+                "attributes": {"name": "Developers", "user_count": 12},
+                "id": "00000000-0000-1111-0000-000000000000",
+                "type": "roles"
+            }
+        ]
+    }
+"#;
+
+#[tokio::test]
+async fn list_users() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": [
+                {
+                    "attributes": {"email": "john@example.com", "disabled": true, "status": "Disabled"},
+                    "id": "00000000-0000-3333-0000-000000000001",
+                    "type": "users"
+                }
+            ],
+            "meta": {"page": {"total_count": 30, "total_filtered_count": 1}}
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path("/api/v2/users"))
+        .and(query_param("filter[status]", "Disabled"))
+        .and(query_param("sort", "email"))
+        .and(query_param("sort_dir", "desc"))
+        .and(query_param("page[size]", "50"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .list_users(ListUsersRequest {
+            page_size: Some(50),
+            sort: Some("email".to_string()),
+            sort_dir: Some(QuerySortOrder::Desc),
+            filter_status: Some("Disabled".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        res.data[0].attributes.as_ref().unwrap().disabled,
+        Some(true)
+    );
+}
+
+#[tokio::test]
+async fn create_user() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(201).set_body_raw(USER_BODY, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/users"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {"email": "jane@example.com", "name": "Jane", "title": "Engineer"},
+                "relationships": {
+                    "roles": {"data": [{"id": "00000000-0000-1111-0000-000000000000", "type": "roles"}]}
+                },
+                "type": "users"
+            }
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .create_user(CreateUserRequest {
+            data: UserCreateData {
+                attributes: UserCreateAttributes {
+                    email: "jane@example.com".to_string(),
+                    name: Some("Jane".to_string()),
+                    title: Some("Engineer".to_string()),
+                },
+                relationships: Some(UserRelationships {
+                    roles: Some(RelationshipToRoles {
+                        data: vec![RelationshipToRoleData {
+                            id: "00000000-0000-1111-0000-000000000000".to_string(),
+                            typ: RolesType::Roles,
+                        }],
+                    }),
+                }),
+                typ: UsersType::Users,
+            },
+        })
+        .await
+        .unwrap();
+    let relationships = res.data.relationships.unwrap();
+    assert_eq!(
+        relationships.org.unwrap().data.id,
+        "00000000-0000-4444-0000-000000000000"
+    );
+    assert_eq!(relationships.roles.unwrap().data[0].typ, RolesType::Roles);
+    match &res.included.unwrap()[1] {
+        UserResponseIncludedItem::Role(role) => {
+            assert_eq!(role.attributes.as_ref().unwrap().user_count, Some(12))
+        }
+        included => panic!("unexpected included item: {:?}", included),
+    }
+}
+
+#[tokio::test]
+async fn update_user() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let response = ResponseTemplate::new(200).set_body_raw(USER_BODY, "application/json");
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/users/00000000-0000-3333-0000-000000000000"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "attributes": {"name": "Jane Doe"},
+                "id": "00000000-0000-3333-0000-000000000000",
+                "type": "users"
+            }
+        })))
+        .respond_with(response)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .update_user(UpdateUserRequest {
+            user_id: "00000000-0000-3333-0000-000000000000".to_string(),
+            user: Request {
+                data: UserUpdateData {
+                    attributes: UserUpdateAttributes {
+                        name: Some("Jane Doe".to_string()),
+                        ..Default::default()
+                    },
+                    id: "00000000-0000-3333-0000-000000000000".to_string(),
+                    typ: UsersType::Users,
+                },
+            },
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn disable_user() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/users/00000000-0000-3333-0000-000000000000"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .disable_user(DisableUserRequest {
+            user_id: "00000000-0000-3333-0000-000000000000".to_string(),
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn send_invitations() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": [
+                {
+                    "attributes": {
+                        "created_at": "2024-02-01T10:00:00.000000+00:00",
+                        "expires_at": "2024-02-03T10:00:00.000000+00:00",
+                        "invite_type": "basic_invite",
+                        "uuid": "00000000-0000-5555-0000-000000000000"
+                    },
+                    "id": "00000000-0000-5555-0000-000000000000",
+                    "relationships": {
+                        "user": {"data": {"id": "00000000-0000-3333-0000-000000000000", "type": "users"}}
+                    },
+                    "type": "user_invitations"
+                }
+            ]
+        }
+    "#;
+    let response = ResponseTemplate::new(201).set_body_raw(body, "application/json");
+    Mock::given(method("POST"))
+        .and(path("/api/v2/user_invitations"))
+        .and(body_json(serde_json::json!({
+            "data": [
+                {
+                    "relationships": {
+                        "user": {"data": {"id": "00000000-0000-3333-0000-000000000000", "type": "users"}}
+                    },
+                    "type": "user_invitations"
+                }
+            ]
+        })))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .send_invitations(SendInvitationsRequest {
+            data: vec![UserInvitationData {
+                relationships: UserInvitationRelationships {
+                    user: Response {
+                        data: RelationshipToUserData {
+                            id: "00000000-0000-3333-0000-000000000000".to_string(),
+                            typ: UsersType::Users,
+                        },
+                    },
+                },
+                typ: UserInvitationsType::UserInvitations,
+            }],
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        res.data[0].attributes.as_ref().unwrap().uuid.as_deref(),
+        Some("00000000-0000-5555-0000-000000000000")
+    );
+}
+
+#[tokio::test]
+async fn get_invitation() {
+    let mock_server = MockServer::start().await;
+    let client_builder =
+        ClientBuilder::new("&", "").set_api_url(Url::parse(&mock_server.uri()).unwrap());
+    let client = client_builder.build().expect("Client");
+
+    let body = r#"
+        {
+            "data": {
+                "attributes": {"invite_type": "basic_invite", "uuid": "00000000-0000-5555-0000-000000000000"},
+                "id": "00000000-0000-5555-0000-000000000000",
+                "type": "user_invitations"
+            }
+        }
+    "#;
+    let response = ResponseTemplate::new(200).set_body_raw(body, "application/json");
+    Mock::given(method("GET"))
+        .and(path(
+            "/api/v2/user_invitations/00000000-0000-5555-0000-000000000000",
+        ))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .get_invitation(GetInvitationRequest {
+            user_invitation_uuid: "00000000-0000-5555-0000-000000000000".to_string(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(res.data.typ, Some(UserInvitationsType::UserInvitations));
+}